@@ -6,6 +6,7 @@ use bech32::{self, FromBase32, Variant};
 use sbor::rust::prelude::*;
 
 /// Represents a decoder which understands how to decode Scrypto addresses in Bech32.
+#[derive(Debug)]
 pub struct AddressBech32Decoder {
     pub hrp_set: HrpSet,
 }
@@ -143,6 +143,12 @@ pub const POOL_PACKAGE: PackageAddress = PackageAddress::new_or_panic([
     148, 250, 42, 166, 49, 140, 99, 24, 198,
 ]);
 
+/// The native package for vesting.
+pub const VESTING_PACKAGE: PackageAddress = PackageAddress::new_or_panic([
+    13, 144, 99, 24, 198, 49, 140, 102, 102, 11, 154, 12, 99, 24, 198, 49, 140, 247, 198, 165, 247,
+    168, 122, 106, 166, 49, 140, 99, 24, 198,
+]);
+
 /// The native package for the transaction processor.
 pub const TRANSACTION_PROCESSOR_PACKAGE: PackageAddress = PackageAddress::new_or_panic([
     13, 144, 99, 24, 198, 49, 140, 101, 154, 97, 48, 204, 99, 24, 198, 49, 140, 247, 168, 186, 82,
@@ -333,6 +339,11 @@ mod tests {
             EntityType::GlobalPackage,
             "package_rdx1pkgxxxxxxxxxplxxxxxxxxxxxxx020379220524xxxxxxxxxplxxxx",
         );
+        check_address(
+            VESTING_PACKAGE.as_ref(),
+            EntityType::GlobalPackage,
+            "package_rdx1pkgxxxxxxxxxvestngxxxxxxxxx003497758564xxxxxxxxxvestng",
+        );
         check_address(
             TRANSACTION_PROCESSOR_PACKAGE.as_ref(),
             EntityType::GlobalPackage,
@@ -0,0 +1,184 @@
+use super::converter::*;
+use super::model::*;
+use super::*;
+use crate::internal_prelude::*;
+
+/// The context required to parse manifest values from their text or programmatic JSON representations.
+///
+/// Buckets, proofs, address reservations and named addresses are expected to be given by their ids.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ManifestValueParsingContext<'a> {
+    pub address_bech32_decoder: Option<&'a AddressBech32Decoder>,
+}
+
+impl<'a> ManifestValueParsingContext<'a> {
+    pub fn no_context() -> Self {
+        Self::default()
+    }
+
+    pub fn with_optional_bech32(address_bech32_decoder: Option<&'a AddressBech32Decoder>) -> Self {
+        Self {
+            address_bech32_decoder,
+        }
+    }
+}
+
+impl ParseableCustomExtension for ManifestCustomExtension {
+    type CustomValue = ManifestCustomValue;
+    type CustomParsingContext<'a> = ManifestValueParsingContext<'a>;
+
+    fn parse_custom_value_kind(name: &str) -> Option<Self::CustomValueKind> {
+        let value_kind = match name {
+            "Address" => ManifestCustomValueKind::Address,
+            "Bucket" => ManifestCustomValueKind::Bucket,
+            "Proof" => ManifestCustomValueKind::Proof,
+            "Expression" => ManifestCustomValueKind::Expression,
+            "Blob" => ManifestCustomValueKind::Blob,
+            "Decimal" => ManifestCustomValueKind::Decimal,
+            "PreciseDecimal" => ManifestCustomValueKind::PreciseDecimal,
+            "NonFungibleLocalId" => ManifestCustomValueKind::NonFungibleLocalId,
+            "AddressReservation" => ManifestCustomValueKind::AddressReservation,
+            _ => return None,
+        };
+        Some(value_kind)
+    }
+
+    fn parse_custom_value(
+        context: &Self::CustomParsingContext<'_>,
+        value_kind: Self::CustomValueKind,
+        value: &str,
+    ) -> Result<Self::CustomValue, String> {
+        let custom_value = match value_kind {
+            ManifestCustomValueKind::Address => {
+                ManifestCustomValue::Address(parse_address(context, value)?)
+            }
+            ManifestCustomValueKind::Bucket => {
+                ManifestCustomValue::Bucket(ManifestBucket(parse_id(value)?))
+            }
+            ManifestCustomValueKind::Proof => {
+                ManifestCustomValue::Proof(ManifestProof(parse_id(value)?))
+            }
+            ManifestCustomValueKind::AddressReservation => ManifestCustomValue::AddressReservation(
+                ManifestAddressReservation(parse_id(value)?),
+            ),
+            ManifestCustomValueKind::Expression => {
+                let expression = match value {
+                    "ENTIRE_WORKTOP" => ManifestExpression::EntireWorktop,
+                    "ENTIRE_AUTH_ZONE" => ManifestExpression::EntireAuthZone,
+                    _ => return Err(format!("Invalid Expression: {}", value)),
+                };
+                ManifestCustomValue::Expression(expression)
+            }
+            ManifestCustomValueKind::Blob => {
+                let hash: [u8; 32] = hex::decode(value)
+                    .ok()
+                    .and_then(|bytes| bytes.try_into().ok())
+                    .ok_or_else(|| format!("Invalid Blob hash: {}", value))?;
+                ManifestCustomValue::Blob(ManifestBlobRef(hash))
+            }
+            ManifestCustomValueKind::Decimal => ManifestCustomValue::Decimal(from_decimal(
+                &Decimal::from_str(value).map_err(|_| format!("Invalid Decimal: {}", value))?,
+            )),
+            ManifestCustomValueKind::PreciseDecimal => {
+                ManifestCustomValue::PreciseDecimal(from_precise_decimal(
+                    &PreciseDecimal::from_str(value)
+                        .map_err(|_| format!("Invalid PreciseDecimal: {}", value))?,
+                ))
+            }
+            ManifestCustomValueKind::NonFungibleLocalId => {
+                ManifestCustomValue::NonFungibleLocalId(from_non_fungible_local_id(
+                    NonFungibleLocalId::from_str(value)
+                        .map_err(|_| format!("Invalid NonFungibleLocalId: {}", value))?,
+                ))
+            }
+        };
+        Ok(custom_value)
+    }
+}
+
+fn parse_id(value: &str) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Expected a numeric id, but found: {}", value))
+}
+
+/// Inverts the address serialization - accepting a named address id, a static address as hex,
+/// or a static address as bech32.
+fn parse_address(
+    context: &ManifestValueParsingContext,
+    value: &str,
+) -> Result<ManifestAddress, String> {
+    if let Ok(address_id) = value.parse::<u32>() {
+        return Ok(ManifestAddress::Named(address_id));
+    }
+    let bytes = if value.len() == NodeId::LENGTH * 2 {
+        hex::decode(value).map_err(|_| format!("Invalid address hex: {}", value))?
+    } else {
+        let decoder = context
+            .address_bech32_decoder
+            .ok_or_else(|| format!("Cannot decode address without a bech32 decoder: {}", value))?;
+        let (_, bytes) = decoder
+            .validate_and_decode(value)
+            .map_err(|error| format!("Invalid address {}: {:?}", value, error))?;
+        bytes
+    };
+    let bytes: [u8; NodeId::LENGTH] = bytes
+        .try_into()
+        .map_err(|_| format!("Invalid address length: {}", value))?;
+    Ok(ManifestAddress::Static(NodeId(bytes)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::test_addresses::*;
+
+    #[test]
+    fn test_rustlike_string_parsing_with_network() {
+        let decoder = AddressBech32Decoder::for_simulator();
+        let text = format!("Tuple(Address(\"{FUNGIBLE_RESOURCE_SIM_ADDRESS}\"), Address(\"3\"), Blob(\"0000000000000000000000000000000000000000000000000000000000000000\"), Bucket(\"0\"), Proof(\"1\"), Decimal(\"0\"), NonFungibleLocalId(\"<hello>\"), Expression(\"ENTIRE_WORKTOP\"), [Bucket(\"2\")])");
+        let expected = manifest_encode(&(
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Address(ManifestAddress::Static(
+                    *FUNGIBLE_RESOURCE.as_node_id(),
+                )),
+            },
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Address(ManifestAddress::Named(3)),
+            },
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Blob(ManifestBlobRef([0; 32])),
+            },
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Bucket(ManifestBucket(0)),
+            },
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Proof(ManifestProof(1)),
+            },
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Decimal(ManifestDecimal([0; DECIMAL_SIZE])),
+            },
+            ManifestValue::Custom {
+                value: ManifestCustomValue::NonFungibleLocalId(ManifestNonFungibleLocalId::String(
+                    "hello".to_string(),
+                )),
+            },
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Expression(ManifestExpression::EntireWorktop),
+            },
+            vec![ManifestBucket(2)],
+        ))
+        .unwrap();
+
+        let payload = parse_rustlike_payload(
+            &text,
+            &RustLikeParsingParameters::Schemaless::<ManifestCustomExtension> {
+                custom_context: ManifestValueParsingContext::with_optional_bech32(Some(&decoder)),
+                depth_limit: MANIFEST_SBOR_V1_MAX_DEPTH,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(payload, expected);
+    }
+}
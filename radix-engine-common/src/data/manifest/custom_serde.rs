@@ -92,7 +92,7 @@ impl SerializableCustomExtension for ManifestCustomExtension {
 mod tests {
     use super::*;
     use crate::address::test_addresses::*;
-    use crate::address::AddressBech32Encoder;
+    use crate::address::{AddressBech32Decoder, AddressBech32Encoder};
     use crate::types::*;
     use sbor::rust::vec;
     use serde::Serialize;
@@ -285,6 +285,113 @@ mod tests {
         assert_programmatic_json_matches(&value, context, expected_programmatic);
     }
 
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn test_programmatic_json_round_trip() {
+        let encoder = AddressBech32Encoder::for_simulator();
+        let decoder = AddressBech32Decoder::for_simulator();
+        let value = (
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Address(ManifestAddress::Static(
+                    *FUNGIBLE_RESOURCE.as_node_id(),
+                )),
+            },
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Address(ManifestAddress::Named(3)),
+            },
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Blob(ManifestBlobRef([7; 32])),
+            },
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Bucket(ManifestBucket(1)),
+            },
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Proof(ManifestProof(2)),
+            },
+            ManifestValue::Custom {
+                value: ManifestCustomValue::AddressReservation(ManifestAddressReservation(4)),
+            },
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Decimal(from_decimal(
+                    &Decimal::from_str("1.5").unwrap(),
+                )),
+            },
+            ManifestValue::Custom {
+                value: ManifestCustomValue::PreciseDecimal(from_precise_decimal(
+                    &PreciseDecimal::from_str("-0.25").unwrap(),
+                )),
+            },
+            ManifestValue::Custom {
+                value: ManifestCustomValue::NonFungibleLocalId(
+                    ManifestNonFungibleLocalId::Integer(5),
+                ),
+            },
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Expression(ManifestExpression::EntireWorktop),
+            },
+            vec![1u8, 2, 3],
+        );
+
+        assert_programmatic_json_round_trips(
+            &value,
+            ManifestValueDisplayContext::with_optional_bech32(Some(&encoder)),
+            ManifestValueParsingContext::with_optional_bech32(Some(&decoder)),
+        );
+        assert_programmatic_json_round_trips(
+            &value,
+            ManifestValueDisplayContext::no_context(),
+            ManifestValueParsingContext::no_context(),
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn test_bech32_address_without_decoder_is_rejected() {
+        let json = json!({
+            "kind": "Address",
+            "value": FUNGIBLE_RESOURCE_SIM_ADDRESS
+        });
+
+        let result = deserialize_programmatic_payload(
+            json,
+            &DeserializationParameters::Schemaless::<ManifestCustomExtension> {
+                custom_context: ManifestValueParsingContext::no_context(),
+                depth_limit: MANIFEST_SBOR_V1_MAX_DEPTH,
+            },
+        );
+
+        assert!(result.is_err());
+    }
+
+    fn assert_programmatic_json_round_trips<T: ManifestEncode>(
+        value: &T,
+        display_context: ManifestValueDisplayContext,
+        parsing_context: ManifestValueParsingContext,
+    ) {
+        let payload = manifest_encode(&value).unwrap();
+        let json = to_value(
+            ManifestRawPayload::new_from_valid_slice(&payload).serializable(
+                SerializationParameters::Schemaless {
+                    mode: SerializationMode::Programmatic,
+                    custom_context: display_context,
+                    depth_limit: MANIFEST_SBOR_V1_MAX_DEPTH,
+                },
+            ),
+        )
+        .unwrap();
+
+        let deserialized = deserialize_programmatic_payload(
+            json,
+            &DeserializationParameters::Schemaless::<ManifestCustomExtension> {
+                custom_context: parsing_context,
+                depth_limit: MANIFEST_SBOR_V1_MAX_DEPTH,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(deserialized, payload);
+    }
+
     fn assert_natural_json_matches<
         'a,
         T: ManifestEncode,
@@ -5,6 +5,7 @@ pub mod model;
 // Modules which should appear part of `manifest`
 mod custom_extension;
 mod custom_formatting;
+mod custom_parsing;
 mod custom_payload_wrappers;
 #[cfg(feature = "serde")]
 mod custom_serde;
@@ -16,6 +17,7 @@ mod definitions;
 mod display_context;
 
 pub use custom_extension::*;
+pub use custom_parsing::*;
 pub use custom_payload_wrappers::*;
 pub use custom_traversal::*;
 pub use custom_value::*;
@@ -32,6 +34,7 @@ pub mod prelude {
 
     // Private modules to include in prelude
     pub use super::custom_extension::*;
+    pub use super::custom_parsing::*;
     pub use super::custom_payload_wrappers::*;
     pub use super::custom_traversal::*;
     pub use super::custom_value::*;
@@ -136,4 +136,41 @@ mod tests {
         assert_eq!(actual_rustlike, expected);
         assert_eq!(actual_nested, expected);
     }
+
+    #[derive(ScryptoSbor)]
+    struct Named {
+        resource: ResourceAddress,
+        amount: Decimal,
+    }
+
+    #[test]
+    fn test_rustlike_string_format_of_named_custom_values() {
+        let encoder = AddressBech32Encoder::for_simulator();
+        let (type_id, schema) =
+            generate_full_schema_from_single_type::<Named, ScryptoCustomSchema>();
+        let payload = scrypto_encode(&Named {
+            resource: FUNGIBLE_RESOURCE,
+            amount: Decimal::ONE,
+        })
+        .unwrap();
+
+        let actual = ScryptoRawPayload::new_from_valid_slice(&payload).to_string(
+            ValueDisplayParameters::Annotated {
+                display_mode: DisplayMode::RustLike,
+                print_mode: PrintMode::SingleLine,
+                custom_context: ScryptoValueDisplayContext::with_optional_bech32(Some(&encoder)),
+                schema: schema.v1(),
+                type_id,
+                depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+            },
+        );
+
+        // A custom value whose type has a name is wrapped in a new-type-like struct, which is now
+        // closed. It used to be printed without the closing bracket, eg:
+        // `ResourceAddress(Reference("resource_sim1..."), amount: ...`
+        assert_eq!(
+            actual,
+            format!("Named {{ resource: ResourceAddress(Reference(\"{FUNGIBLE_RESOURCE_SIM_ADDRESS}\")), amount: Decimal(\"1\") }}")
+        );
+    }
 }
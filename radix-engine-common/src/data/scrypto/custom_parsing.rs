@@ -0,0 +1,167 @@
+use crate::internal_prelude::*;
+
+/// The context required to parse Scrypto values from their text or programmatic JSON representations.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ScryptoValueParsingContext<'a> {
+    pub address_bech32_decoder: Option<&'a AddressBech32Decoder>,
+}
+
+impl<'a> ScryptoValueParsingContext<'a> {
+    pub fn no_context() -> Self {
+        Self::default()
+    }
+
+    pub fn with_optional_bech32(address_bech32_decoder: Option<&'a AddressBech32Decoder>) -> Self {
+        Self {
+            address_bech32_decoder,
+        }
+    }
+}
+
+impl<'a> Into<ScryptoValueParsingContext<'a>> for &'a AddressBech32Decoder {
+    fn into(self) -> ScryptoValueParsingContext<'a> {
+        ScryptoValueParsingContext::with_optional_bech32(Some(self))
+    }
+}
+
+impl ParseableCustomExtension for ScryptoCustomExtension {
+    type CustomValue = ScryptoCustomValue;
+    type CustomParsingContext<'a> = ScryptoValueParsingContext<'a>;
+
+    fn parse_custom_value_kind(name: &str) -> Option<Self::CustomValueKind> {
+        let value_kind = match name {
+            "Reference" => ScryptoCustomValueKind::Reference,
+            "Own" => ScryptoCustomValueKind::Own,
+            "Decimal" => ScryptoCustomValueKind::Decimal,
+            "PreciseDecimal" => ScryptoCustomValueKind::PreciseDecimal,
+            "NonFungibleLocalId" => ScryptoCustomValueKind::NonFungibleLocalId,
+            _ => return None,
+        };
+        Some(value_kind)
+    }
+
+    fn parse_custom_value(
+        context: &Self::CustomParsingContext<'_>,
+        value_kind: Self::CustomValueKind,
+        value: &str,
+    ) -> Result<Self::CustomValue, String> {
+        let custom_value = match value_kind {
+            ScryptoCustomValueKind::Reference => {
+                ScryptoCustomValue::Reference(Reference(parse_node_id(context, value)?))
+            }
+            ScryptoCustomValueKind::Own => {
+                ScryptoCustomValue::Own(Own(parse_node_id(context, value)?))
+            }
+            ScryptoCustomValueKind::Decimal => ScryptoCustomValue::Decimal(
+                Decimal::from_str(value).map_err(|_| format!("Invalid Decimal: {}", value))?,
+            ),
+            ScryptoCustomValueKind::PreciseDecimal => ScryptoCustomValue::PreciseDecimal(
+                PreciseDecimal::from_str(value)
+                    .map_err(|_| format!("Invalid PreciseDecimal: {}", value))?,
+            ),
+            ScryptoCustomValueKind::NonFungibleLocalId => ScryptoCustomValue::NonFungibleLocalId(
+                NonFungibleLocalId::from_str(value)
+                    .map_err(|_| format!("Invalid NonFungibleLocalId: {}", value))?,
+            ),
+        };
+        Ok(custom_value)
+    }
+}
+
+/// Inverts the `NodeId` display - accepting either a bech32 address or `NodeId(<hex>)`.
+fn parse_node_id(context: &ScryptoValueParsingContext, value: &str) -> Result<NodeId, String> {
+    let bytes = if let Some(hex) = value
+        .strip_prefix("NodeId(")
+        .and_then(|value| value.strip_suffix(')'))
+    {
+        hex::decode(hex).map_err(|_| format!("Invalid NodeId hex: {}", hex))?
+    } else {
+        let decoder = context
+            .address_bech32_decoder
+            .ok_or_else(|| format!("Cannot decode address without a bech32 decoder: {}", value))?;
+        let (_, bytes) = decoder
+            .validate_and_decode(value)
+            .map_err(|error| format!("Invalid address {}: {:?}", value, error))?;
+        bytes
+    };
+    let bytes: [u8; NodeId::LENGTH] = bytes
+        .try_into()
+        .map_err(|_| format!("Invalid NodeId length: {}", value))?;
+    Ok(NodeId(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::test_addresses::*;
+    use utils::ContextualDisplay;
+
+    #[derive(ScryptoSbor, Debug, PartialEq, Eq)]
+    struct Payment {
+        resource: ResourceAddress,
+        amount: Decimal,
+        ids: Vec<NonFungibleLocalId>,
+        memo: Option<String>,
+    }
+
+    #[test]
+    fn test_rustlike_string_round_trip_with_network() {
+        let encoder = AddressBech32Encoder::for_simulator();
+        let decoder = AddressBech32Decoder::for_simulator();
+        let (type_id, schema) =
+            generate_full_schema_from_single_type::<Payment, ScryptoCustomSchema>();
+        let payment = Payment {
+            resource: FUNGIBLE_RESOURCE,
+            amount: Decimal::ONE.checked_div(100).unwrap(),
+            ids: vec![
+                NonFungibleLocalId::integer(123),
+                NonFungibleLocalId::string("hello").unwrap(),
+            ],
+            memo: Some("\"Thanks!\"\n".to_string()),
+        };
+        let payload = scrypto_encode(&payment).unwrap();
+
+        let text = ScryptoRawPayload::new_from_valid_slice(&payload).to_string(
+            ValueDisplayParameters::Annotated {
+                display_mode: DisplayMode::RustLike,
+                print_mode: PrintMode::SingleLine,
+                custom_context: ScryptoValueDisplayContext::with_optional_bech32(Some(&encoder)),
+                schema: schema.v1(),
+                type_id,
+                depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+            },
+        );
+        assert!(text.contains(&format!(
+            "ResourceAddress(Reference(\"{FUNGIBLE_RESOURCE_SIM_ADDRESS}\"))"
+        )));
+
+        let parsed_payload = parse_rustlike_payload(
+            &text,
+            &RustLikeParsingParameters::Annotated::<ScryptoCustomExtension> {
+                custom_context: (&decoder).into(),
+                schema: schema.v1(),
+                type_id,
+                depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+            },
+        )
+        .unwrap();
+        assert_eq!(parsed_payload, payload);
+
+        let value = parse_rustlike_value(
+            r##"Payment {
+                resource: Reference("resource_sim1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxakj8n3"),
+                amount: Decimal("0.01"),
+                ids: [NonFungibleLocalId("#123#"), NonFungibleLocalId("<hello>")],
+                memo: Option::Some("\"Thanks!\"\n"),
+            }"##,
+            &RustLikeParsingParameters::Annotated::<ScryptoCustomExtension> {
+                custom_context: (&decoder).into(),
+                schema: schema.v1(),
+                type_id,
+                depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+            },
+        )
+        .unwrap();
+        assert_eq!(scrypto_encode(&value).unwrap(), payload);
+    }
+}
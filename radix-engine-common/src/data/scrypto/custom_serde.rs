@@ -45,7 +45,7 @@ impl SerializableCustomExtension for ScryptoCustomExtension {
 mod tests {
     use super::*;
     use crate::address::test_addresses::*;
-    use crate::address::AddressBech32Encoder;
+    use crate::address::{AddressBech32Decoder, AddressBech32Encoder};
     use crate::data::scrypto::model::*;
     use crate::data::scrypto::{scrypto_encode, ScryptoValue};
     use crate::math::*;
@@ -236,6 +236,125 @@ mod tests {
         assert_programmatic_json_matches(&value, context, expected_programmatic);
     }
 
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn test_programmatic_json_round_trip() {
+        let encoder = AddressBech32Encoder::for_simulator();
+        let decoder = AddressBech32Decoder::for_simulator();
+        let value = ScryptoValue::Tuple {
+            fields: vec![
+                Value::Custom {
+                    value: ScryptoCustomValue::Reference(Reference(FUNGIBLE_RESOURCE_NODE_ID)),
+                },
+                Value::Custom {
+                    value: ScryptoCustomValue::Own(Own(FUNGIBLE_RESOURCE_NODE_ID)),
+                },
+                Value::Custom {
+                    value: ScryptoCustomValue::Decimal(Decimal::ONE.checked_div(100).unwrap()),
+                },
+                Value::Custom {
+                    value: ScryptoCustomValue::PreciseDecimal(PreciseDecimal::MAX),
+                },
+                Value::Custom {
+                    value: ScryptoCustomValue::NonFungibleLocalId(
+                        NonFungibleLocalId::string("hello").unwrap(),
+                    ),
+                },
+                Value::Custom {
+                    value: ScryptoCustomValue::NonFungibleLocalId(NonFungibleLocalId::ruid(
+                        [0x11; 32],
+                    )),
+                },
+                Value::Map {
+                    key_value_kind: ValueKind::String,
+                    value_value_kind: ValueKind::Custom(ScryptoCustomValueKind::Decimal),
+                    entries: vec![(
+                        Value::String {
+                            value: "a".to_string(),
+                        },
+                        Value::Custom {
+                            value: ScryptoCustomValue::Decimal(Decimal::MIN),
+                        },
+                    )],
+                },
+            ],
+        };
+
+        assert_programmatic_json_round_trips(
+            &value,
+            ScryptoValueDisplayContext::with_optional_bech32(Some(&encoder)),
+            ScryptoValueParsingContext::with_optional_bech32(Some(&decoder)),
+        );
+        assert_programmatic_json_round_trips(
+            &value,
+            ScryptoValueDisplayContext::no_context(),
+            ScryptoValueParsingContext::no_context(),
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn test_programmatic_json_is_validated_against_schema() {
+        let decoder = AddressBech32Decoder::for_simulator();
+        let (type_id, schema) =
+            generate_full_schema_from_single_type::<Sample, ScryptoCustomSchema>();
+        let params = DeserializationParameters::WithSchema::<ScryptoCustomExtension> {
+            custom_context: (&decoder).into(),
+            schema: schema.v1(),
+            type_id,
+            depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+        };
+        let resource_json = json!({
+            "kind": "Tuple",
+            "fields": [{ "kind": "Reference", "value": FUNGIBLE_RESOURCE_SIM_ADDRESS }]
+        });
+        let decimal_json = json!({
+            "kind": "Tuple",
+            "fields": [{ "kind": "Decimal", "value": "1" }]
+        });
+
+        let resource_payload = deserialize_programmatic_payload(resource_json, &params).unwrap();
+        let decimal_result = deserialize_programmatic_payload(decimal_json, &params);
+
+        assert_eq!(
+            resource_payload,
+            scrypto_encode(&Sample {
+                a: FUNGIBLE_RESOURCE
+            })
+            .unwrap()
+        );
+        assert!(decimal_result.is_err());
+    }
+
+    fn assert_programmatic_json_round_trips<T: ScryptoEncode>(
+        value: &T,
+        display_context: ScryptoValueDisplayContext,
+        parsing_context: ScryptoValueParsingContext,
+    ) {
+        let payload = scrypto_encode(&value).unwrap();
+        let json = to_value(
+            ScryptoRawPayload::new_from_valid_slice(&payload).serializable(
+                SerializationParameters::Schemaless {
+                    mode: SerializationMode::Programmatic,
+                    custom_context: display_context,
+                    depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+                },
+            ),
+        )
+        .unwrap();
+
+        let deserialized = deserialize_programmatic_payload(
+            json,
+            &DeserializationParameters::Schemaless::<ScryptoCustomExtension> {
+                custom_context: parsing_context,
+                depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(deserialized, payload);
+    }
+
     fn assert_natural_json_matches<
         'a,
         T: ScryptoEncode,
@@ -34,6 +34,9 @@ impl<T: SborTuple<ScryptoCustomValueKind> + ?Sized> ScryptoSborTuple for T {}
 pub trait ScryptoDecode: for<'a> Decode<ScryptoCustomValueKind, ScryptoDecoder<'a>> {}
 impl<T: for<'a> Decode<ScryptoCustomValueKind, ScryptoDecoder<'a>>> ScryptoDecode for T {}
 
+pub trait ScryptoBorrowedDecode<'a>: Decode<ScryptoCustomValueKind, ScryptoDecoder<'a>> {}
+impl<'a, T: Decode<ScryptoCustomValueKind, ScryptoDecoder<'a>>> ScryptoBorrowedDecode<'a> for T {}
+
 pub trait ScryptoEncode: for<'a> Encode<ScryptoCustomValueKind, ScryptoEncoder<'a>> {}
 impl<T: for<'a> Encode<ScryptoCustomValueKind, ScryptoEncoder<'a>> + ?Sized> ScryptoEncode for T {}
 
@@ -69,3 +72,17 @@ pub fn scrypto_decode_with_depth_limit<T: ScryptoDecode>(
 ) -> Result<T, DecodeError> {
     ScryptoDecoder::new(buf, depth_limit).decode_payload(SCRYPTO_SBOR_V1_PAYLOAD_PREFIX)
 }
+
+/// Decodes a data structure which may borrow strings and byte slices from the byte array.
+pub fn scrypto_decode_borrowed<'a, T: ScryptoBorrowedDecode<'a>>(
+    buf: &'a [u8],
+) -> Result<T, DecodeError> {
+    scrypto_decode_borrowed_with_depth_limit(buf, SCRYPTO_SBOR_V1_MAX_DEPTH)
+}
+
+pub fn scrypto_decode_borrowed_with_depth_limit<'a, T: ScryptoBorrowedDecode<'a>>(
+    buf: &'a [u8],
+    depth_limit: usize,
+) -> Result<T, DecodeError> {
+    ScryptoDecoder::new(buf, depth_limit).decode_payload(SCRYPTO_SBOR_V1_PAYLOAD_PREFIX)
+}
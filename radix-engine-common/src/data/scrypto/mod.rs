@@ -6,6 +6,8 @@ pub mod model;
 /// Defines the full Scrypto extension.
 mod custom_extension;
 mod custom_formatting;
+/// Defines how the custom values are parsed from their string representations.
+mod custom_parsing;
 mod custom_payload_wrappers;
 /// Defines the custom Scrypto schema types.
 mod custom_schema;
@@ -26,6 +28,7 @@ mod definitions;
 
 pub use custom_extension::*;
 pub use custom_formatting::*;
+pub use custom_parsing::*;
 pub use custom_payload_wrappers::*;
 pub use custom_schema::*;
 pub use custom_traversal::*;
@@ -44,6 +47,7 @@ pub mod prelude {
     // Private modules to include in prelude
     pub use super::custom_extension::*;
     pub use super::custom_formatting::*;
+    pub use super::custom_parsing::*;
     pub use super::custom_payload_wrappers::*;
     pub use super::custom_schema::*;
     pub use super::custom_traversal::*;
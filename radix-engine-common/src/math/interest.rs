@@ -0,0 +1,198 @@
+use num_bigint::BigInt;
+
+use crate::math::bnum_integer::*;
+use crate::math::decimal::*;
+use crate::math::precise_decimal::*;
+use crate::math::rounding_mode::*;
+use crate::math::traits::*;
+use crate::math::transcendental;
+
+/// The number of seconds in a 365 day year.
+pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+/// The factor `(1 + rate)^periods` by which an amount grows when it's compounded at `rate` per
+/// period.
+///
+/// The power is evaluated in a single step, so the precision doesn't degrade with the number of
+/// periods.
+pub fn checked_compound_factor(rate: PreciseDecimal, periods: u64) -> Option<PreciseDecimal> {
+    PreciseDecimal::ONE
+        .checked_add(rate)?
+        .checked_pow(PreciseDecimal::from(periods))
+}
+
+/// Compounds `principal` at `rate` per period over the given number of periods, with the
+/// resulting amount rounded with the given mode.
+///
+/// The compound factor is kept at the working scale of the transcendental functions, so the
+/// amount is only rounded once - and its precision doesn't depend on the size of the principal.
+pub fn checked_compound(
+    principal: Decimal,
+    rate: PreciseDecimal,
+    periods: u64,
+    mode: RoundingMode,
+) -> Option<Decimal> {
+    let base = PreciseDecimal::ONE.checked_add(rate)?;
+    let factor = transcendental::pow_working(
+        &BigInt::from(base.0),
+        &BigInt::from(PreciseDecimal::from(periods).0),
+        PreciseDecimal::SCALE,
+    )?;
+    let amount = div_with_rounding(
+        &(BigInt::from(principal.0) * factor),
+        &BigInt::from(10).pow(transcendental::WORKING_SCALE),
+        mode,
+    );
+    I192::try_from(amount).ok().map(Decimal)
+}
+
+/// The rate per period which compounds to the annual percentage yield `apy` over a year of
+/// `periods_per_year` periods, ie `(1 + apy)^(1 / periods_per_year) - 1`.
+pub fn checked_periodic_rate_from_apy(
+    apy: PreciseDecimal,
+    periods_per_year: u64,
+) -> Option<PreciseDecimal> {
+    if periods_per_year == 0 {
+        return None;
+    }
+    PreciseDecimal::ONE
+        .checked_add(apy)?
+        .checked_ln()?
+        .checked_div(PreciseDecimal::from(periods_per_year))?
+        .checked_exp()?
+        .checked_sub(PreciseDecimal::ONE)
+}
+
+/// The rate per second which compounds to the annual percentage yield `apy` over a 365 day year.
+pub fn checked_per_second_rate_from_apy(apy: PreciseDecimal) -> Option<PreciseDecimal> {
+    checked_periodic_rate_from_apy(apy, SECONDS_PER_YEAR)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! test_dec {
+        ($x:literal) => {
+            $crate::math::Decimal::try_from($x).unwrap()
+        };
+    }
+
+    macro_rules! test_pdec {
+        ($x:literal) => {
+            $crate::math::PreciseDecimal::try_from($x).unwrap()
+        };
+    }
+
+    #[test]
+    fn test_compound_factor() {
+        assert_eq!(
+            checked_compound_factor(test_pdec!("0.05"), 10).unwrap(),
+            test_pdec!("1.62889462677744140625")
+        );
+        assert_eq!(
+            checked_compound_factor(test_pdec!("0.000000001"), SECONDS_PER_YEAR).unwrap(),
+            test_pdec!("1.032038528297639106730113880074257906")
+        );
+        assert_eq!(
+            checked_compound_factor(test_pdec!("0.05"), 0).unwrap(),
+            PreciseDecimal::ONE
+        );
+        assert_eq!(checked_compound_factor(test_pdec!(1), u64::MAX), None);
+    }
+
+    #[test]
+    fn test_compound_rounds_with_mode() {
+        let principal = test_dec!(100);
+        let rate = test_pdec!("0.000000001");
+
+        assert_eq!(
+            checked_compound(principal, rate, SECONDS_PER_YEAR, RoundingMode::ToZero).unwrap(),
+            test_dec!("103.203852829763910673")
+        );
+        assert_eq!(
+            checked_compound(
+                principal,
+                rate,
+                SECONDS_PER_YEAR,
+                RoundingMode::AwayFromZero
+            )
+            .unwrap(),
+            test_dec!("103.203852829763910674")
+        );
+    }
+
+    #[test]
+    fn test_compound_keeps_precision_of_large_principals() {
+        let principal = test_dec!("1000000000000000000000000000000");
+        let rate = test_pdec!("0.000000001");
+
+        assert_eq!(
+            checked_compound(principal, rate, SECONDS_PER_YEAR, RoundingMode::ToZero).unwrap(),
+            test_dec!("1032038528297639106730113880074.257906419125924376")
+        );
+        assert_eq!(
+            checked_compound(
+                principal,
+                rate,
+                SECONDS_PER_YEAR,
+                RoundingMode::AwayFromZero
+            )
+            .unwrap(),
+            test_dec!("1032038528297639106730113880074.257906419125924377")
+        );
+        assert_eq!(
+            checked_compound(Decimal::MAX, rate, SECONDS_PER_YEAR, RoundingMode::ToZero),
+            None
+        );
+    }
+
+    #[test]
+    fn test_compound_rounds_negative_amounts_with_mode() {
+        let principal = test_dec!(-100);
+        let rate = test_pdec!("0.000000001");
+        let compound = |mode| checked_compound(principal, rate, SECONDS_PER_YEAR, mode).unwrap();
+
+        assert_eq!(
+            compound(RoundingMode::ToZero),
+            test_dec!("-103.203852829763910673")
+        );
+        assert_eq!(
+            compound(RoundingMode::ToNearestMidpointTowardZero),
+            test_dec!("-103.203852829763910673")
+        );
+        assert_eq!(
+            compound(RoundingMode::ToNegativeInfinity),
+            test_dec!("-103.203852829763910674")
+        );
+        assert_eq!(
+            checked_compound(
+                test_dec!(100),
+                test_pdec!("-0.05"),
+                2,
+                RoundingMode::ToNearestMidpointAwayFromZero
+            )
+            .unwrap(),
+            test_dec!("90.25")
+        );
+    }
+
+    #[test]
+    fn test_periodic_rate_from_apy() {
+        let per_second_rate = checked_per_second_rate_from_apy(test_pdec!("0.05")).unwrap();
+        assert_eq!(
+            per_second_rate,
+            test_pdec!("0.000000001547125957863212449045862997")
+        );
+        assert_eq!(
+            checked_compound_factor(per_second_rate, SECONDS_PER_YEAR).unwrap(),
+            test_pdec!("1.049999999999999999999999999994243881")
+        );
+
+        assert_eq!(
+            checked_periodic_rate_from_apy(test_pdec!(1), 12).unwrap(),
+            test_pdec!("0.0594630943592952645618252949463417")
+        );
+        assert_eq!(checked_periodic_rate_from_apy(test_pdec!("0.05"), 0), None);
+    }
+}
@@ -1,11 +1,14 @@
 pub mod bnum_integer;
 pub mod decimal;
+pub mod interest;
 pub mod precise_decimal;
 pub mod rounding_mode;
 pub mod traits;
+mod transcendental;
 
 pub use bnum_integer::*;
 pub use decimal::*;
+pub use interest::*;
 pub use precise_decimal::*;
 pub use rounding_mode::*;
 pub use traits::*;
@@ -19,6 +19,7 @@ use crate::math::bnum_integer::*;
 use crate::math::decimal::*;
 use crate::math::rounding_mode::*;
 use crate::math::traits::*;
+use crate::math::transcendental;
 use crate::well_known_scrypto_custom_type;
 use crate::*;
 
@@ -208,6 +209,25 @@ impl PreciseDecimal {
         Some(Self(rounded_subunits))
     }
 
+    /// Multiplies by `multiplier` and divides by `divisor` as a single operation, with the result
+    /// rounded with the given mode.
+    ///
+    /// Unlike a multiplication followed by a division, the intermediate product can't overflow,
+    /// and is not truncated.
+    pub fn checked_mul_div(
+        &self,
+        multiplier: Self,
+        divisor: Self,
+        mode: RoundingMode,
+    ) -> Option<Self> {
+        if divisor.is_zero() {
+            return None;
+        }
+        let numerator = BigInt::from(self.0) * BigInt::from(multiplier.0);
+        let result = div_with_rounding(&numerator, &BigInt::from(divisor.0), mode);
+        I256::try_from(result).ok().map(Self)
+    }
+
     /// Calculates power using exponentiation by squaring.
     pub fn checked_powi(&self, exp: i64) -> Option<Self> {
         let one_384 = I384::from(Self::ONE.0);
@@ -293,6 +313,42 @@ impl PreciseDecimal {
             Some(Self(nth_root))
         }
     }
+
+    /// Natural logarithm of a PreciseDecimal, or `None` if it isn't positive.
+    ///
+    /// Like the other transcendental functions, the result is rounded to the nearest
+    /// representable value, with ties rounded away from zero.
+    pub fn checked_ln(&self) -> Option<Self> {
+        transcendental::ln(&BigInt::from(self.0), Self::SCALE).and_then(Self::from_bigint)
+    }
+
+    /// Base 2 logarithm of a PreciseDecimal, or `None` if it isn't positive.
+    pub fn checked_log2(&self) -> Option<Self> {
+        transcendental::log(&BigInt::from(self.0), 2, Self::SCALE).and_then(Self::from_bigint)
+    }
+
+    /// Base 10 logarithm of a PreciseDecimal, or `None` if it isn't positive.
+    pub fn checked_log10(&self) -> Option<Self> {
+        transcendental::log(&BigInt::from(self.0), 10, Self::SCALE).and_then(Self::from_bigint)
+    }
+
+    /// Exponential function of a PreciseDecimal, ie `e` raised to its power.
+    pub fn checked_exp(&self) -> Option<Self> {
+        transcendental::exp(&BigInt::from(self.0), Self::SCALE).and_then(Self::from_bigint)
+    }
+
+    /// Raises a PreciseDecimal to a real-valued power.
+    ///
+    /// Negative numbers can only be raised to integer powers, and zero can't be raised to a
+    /// negative power.
+    pub fn checked_pow(&self, exp: Self) -> Option<Self> {
+        transcendental::pow(&BigInt::from(self.0), &BigInt::from(exp.0), Self::SCALE)
+            .and_then(Self::from_bigint)
+    }
+
+    fn from_bigint(subunits: BigInt) -> Option<Self> {
+        I256::try_from(subunits).ok().map(Self)
+    }
 }
 
 macro_rules! from_primitive_type {
@@ -2134,6 +2190,247 @@ mod tests {
         assert_eq!(root_0, None);
     }
 
+    #[test]
+    fn test_ln() {
+        assert_eq!(
+            test_pdec!(42).checked_ln().unwrap(),
+            test_pdec!("3.737669618283368305917830101823882002")
+        );
+        assert_eq!(
+            test_pdec!("0.5").checked_ln().unwrap(),
+            test_pdec!("-0.693147180559945309417232121458176568")
+        );
+        assert_eq!(
+            test_pdec!("0.000000000000000000000000000000000001")
+                .checked_ln()
+                .unwrap(),
+            test_pdec!("-82.893063347785644624647692368637111474")
+        );
+        assert_eq!(
+            PreciseDecimal::MAX.checked_ln().unwrap(),
+            test_pdec!("93.859467695000409276746498603197913386")
+        );
+        assert_eq!(test_pdec!(1).checked_ln().unwrap(), test_pdec!(0));
+        assert_eq!(test_pdec!(0).checked_ln(), None);
+        assert_eq!(test_pdec!("-1").checked_ln(), None);
+    }
+
+    #[test]
+    fn test_log2_and_log10() {
+        assert_eq!(
+            test_pdec!(42).checked_log2().unwrap(),
+            test_pdec!("5.392317422778760288895708261179647317")
+        );
+        assert_eq!(
+            test_pdec!(42).checked_log10().unwrap(),
+            test_pdec!("1.623249290397900463220983056572244529")
+        );
+        assert_eq!(test_pdec!("0.5").checked_log2().unwrap(), test_pdec!("-1"));
+        assert_eq!(
+            test_pdec!("0.5").checked_log10().unwrap(),
+            test_pdec!("-0.301029995663981195213738894724493027")
+        );
+        assert_eq!(test_pdec!(1024).checked_log2().unwrap(), test_pdec!(10));
+        assert_eq!(test_pdec!(1000).checked_log10().unwrap(), test_pdec!(3));
+        assert_eq!(test_pdec!(0).checked_log2(), None);
+        assert_eq!(test_pdec!("-10").checked_log10(), None);
+    }
+
+    #[test]
+    fn test_exp() {
+        assert_eq!(
+            test_pdec!(1).checked_exp().unwrap(),
+            test_pdec!("2.718281828459045235360287471352662498")
+        );
+        assert_eq!(
+            test_pdec!("2.5").checked_exp().unwrap(),
+            test_pdec!("12.182493960703473438070175951167966183")
+        );
+        assert_eq!(
+            test_pdec!("-10").checked_exp().unwrap(),
+            test_pdec!("0.000045399929762484851535591515560551")
+        );
+        assert_eq!(
+            test_pdec!(80).checked_exp().unwrap(),
+            test_pdec!("55406223843935100525711733958316612.9248567288326853228703001882820457")
+        );
+        assert_eq!(test_pdec!(0).checked_exp().unwrap(), test_pdec!(1));
+        assert_eq!(test_pdec!("-100").checked_exp().unwrap(), test_pdec!(0));
+        assert_eq!(test_pdec!(100).checked_exp(), None);
+        assert_eq!(PreciseDecimal::MAX.checked_exp(), None);
+        assert_eq!(PreciseDecimal::MIN.checked_exp().unwrap(), test_pdec!(0));
+    }
+
+    #[test]
+    fn test_pow() {
+        assert_eq!(
+            test_pdec!(2).checked_pow(test_pdec!("0.5")).unwrap(),
+            test_pdec!("1.414213562373095048801688724209698079")
+        );
+        assert_eq!(
+            test_pdec!("1.05").checked_pow(test_pdec!("12.5")).unwrap(),
+            test_pdec!("1.840205135548584653147212456021944816")
+        );
+        assert_eq!(
+            test_pdec!(42).checked_pow(test_pdec!("-1.5")).unwrap(),
+            test_pdec!("0.003673889284811712149073677684857141")
+        );
+        assert_eq!(
+            test_pdec!(10).checked_pow(test_pdec!("0.3")).unwrap(),
+            test_pdec!("1.995262314968879601352455396739535558")
+        );
+        assert_eq!(
+            test_pdec!(4).checked_pow(test_pdec!("0.5")).unwrap(),
+            test_pdec!(2)
+        );
+        assert_eq!(
+            test_pdec!("-2").checked_pow(test_pdec!(3)).unwrap(),
+            test_pdec!("-8")
+        );
+        assert_eq!(
+            test_pdec!("-2").checked_pow(test_pdec!(2)).unwrap(),
+            test_pdec!(4)
+        );
+        assert_eq!(test_pdec!("-2").checked_pow(test_pdec!("0.5")), None);
+        assert_eq!(
+            test_pdec!(0).checked_pow(test_pdec!(2)).unwrap(),
+            test_pdec!(0)
+        );
+        assert_eq!(
+            test_pdec!(0).checked_pow(test_pdec!(0)).unwrap(),
+            test_pdec!(1)
+        );
+        assert_eq!(test_pdec!(0).checked_pow(test_pdec!("-1")), None);
+        assert_eq!(PreciseDecimal::MAX.checked_pow(test_pdec!(2)), None);
+    }
+
+    #[test]
+    fn test_mul_div() {
+        let two_thirds = |mode| test_pdec!(1).checked_mul_div(test_pdec!(2), test_pdec!(3), mode);
+        assert_eq!(
+            two_thirds(RoundingMode::ToZero).unwrap(),
+            test_pdec!("0.666666666666666666666666666666666666")
+        );
+        assert_eq!(
+            two_thirds(RoundingMode::ToNearestMidpointTowardZero).unwrap(),
+            test_pdec!("0.666666666666666666666666666666666667")
+        );
+        assert_eq!(
+            test_pdec!("-1")
+                .checked_mul_div(
+                    test_pdec!(2),
+                    test_pdec!(3),
+                    RoundingMode::ToNegativeInfinity
+                )
+                .unwrap(),
+            test_pdec!("-0.666666666666666666666666666666666667")
+        );
+        assert_eq!(
+            test_pdec!("-1")
+                .checked_mul_div(
+                    test_pdec!(2),
+                    test_pdec!(3),
+                    RoundingMode::ToPositiveInfinity
+                )
+                .unwrap(),
+            test_pdec!("-0.666666666666666666666666666666666666")
+        );
+
+        let midpoint = |mode| {
+            test_pdec!("0.000000000000000000000000000000000005").checked_mul_div(
+                test_pdec!("0.5"),
+                test_pdec!(1),
+                mode,
+            )
+        };
+        assert_eq!(
+            midpoint(RoundingMode::ToNearestMidpointToEven).unwrap(),
+            test_pdec!("0.000000000000000000000000000000000002")
+        );
+        assert_eq!(
+            midpoint(RoundingMode::ToNearestMidpointAwayFromZero).unwrap(),
+            test_pdec!("0.000000000000000000000000000000000003")
+        );
+
+        // The intermediate product doesn't overflow
+        assert_eq!(
+            PreciseDecimal::MAX
+                .checked_mul_div(test_pdec!(3), test_pdec!(3), RoundingMode::ToZero)
+                .unwrap(),
+            PreciseDecimal::MAX
+        );
+        assert_eq!(
+            PreciseDecimal::MAX.checked_mul_div(test_pdec!(2), test_pdec!(1), RoundingMode::ToZero),
+            None
+        );
+        assert_eq!(
+            test_pdec!(1).checked_mul_div(test_pdec!(1), test_pdec!(0), RoundingMode::ToZero),
+            None
+        );
+    }
+
+    #[test]
+    fn test_mul_div_with_negative_operands() {
+        let one_third = |x, y, mode| test_pdec!(1).checked_mul_div(x, y, mode).unwrap();
+        for mode in [
+            RoundingMode::ToNearestMidpointTowardZero,
+            RoundingMode::ToNearestMidpointAwayFromZero,
+            RoundingMode::ToNearestMidpointToEven,
+            RoundingMode::ToZero,
+        ] {
+            assert_eq!(
+                one_third(test_pdec!(-1), test_pdec!(3), mode),
+                test_pdec!("-0.333333333333333333333333333333333333")
+            );
+            assert_eq!(
+                one_third(test_pdec!(1), test_pdec!(-3), mode),
+                test_pdec!("-0.333333333333333333333333333333333333")
+            );
+            assert_eq!(
+                one_third(test_pdec!(-1), test_pdec!(-3), mode),
+                test_pdec!("0.333333333333333333333333333333333333")
+            );
+        }
+        assert_eq!(
+            one_third(
+                test_pdec!(-2),
+                test_pdec!(3),
+                RoundingMode::ToNearestMidpointTowardZero
+            ),
+            test_pdec!("-0.666666666666666666666666666666666667")
+        );
+        assert_eq!(
+            one_third(test_pdec!(-1), test_pdec!(3), RoundingMode::AwayFromZero),
+            test_pdec!("-0.333333333333333333333333333333333334")
+        );
+        assert_eq!(
+            one_third(
+                test_pdec!(2),
+                test_pdec!(-3),
+                RoundingMode::ToNegativeInfinity
+            ),
+            test_pdec!("-0.666666666666666666666666666666666667")
+        );
+
+        let midpoint = |mode| {
+            test_pdec!("-0.000000000000000000000000000000000005")
+                .checked_mul_div(test_pdec!("0.5"), test_pdec!(1), mode)
+                .unwrap()
+        };
+        assert_eq!(
+            midpoint(RoundingMode::ToNearestMidpointTowardZero),
+            test_pdec!("-0.000000000000000000000000000000000002")
+        );
+        assert_eq!(
+            midpoint(RoundingMode::ToNearestMidpointAwayFromZero),
+            test_pdec!("-0.000000000000000000000000000000000003")
+        );
+        assert_eq!(
+            midpoint(RoundingMode::ToNearestMidpointToEven),
+            test_pdec!("-0.000000000000000000000000000000000002")
+        );
+    }
+
     #[test]
     fn no_panic_with_36_decimal_places() {
         // Arrange
@@ -1,6 +1,9 @@
 #[cfg(feature = "radix_engine_fuzzing")]
 use arbitrary::Arbitrary;
 use core::cmp::Ordering;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{Signed, Zero};
 use sbor::Sbor;
 #[cfg(feature = "radix_engine_fuzzing")]
 use serde::{Deserialize, Serialize};
@@ -88,3 +91,38 @@ impl ResolvedRoundingStrategy {
         }
     }
 }
+
+/// Divides `numerator` by a non-zero `divisor`, rounding the quotient with the given mode.
+pub(crate) fn div_with_rounding(
+    numerator: &BigInt,
+    divisor: &BigInt,
+    mode: RoundingMode,
+) -> BigInt {
+    // Normalize the divisor to be positive, so that the floored remainder is never negative, and
+    // it is the distance of the exact result from its rounded down value (as in `checked_round`).
+    let (numerator, divisor) = if divisor.is_negative() {
+        (-numerator, -divisor)
+    } else {
+        (numerator.clone(), divisor.clone())
+    };
+    let (rounded_down, remainder) = numerator.div_mod_floor(&divisor);
+    if remainder.is_zero() {
+        return rounded_down;
+    }
+    let rounded_up = &rounded_down + 1;
+    let is_positive = numerator.is_positive();
+
+    let resolved_strategy =
+        ResolvedRoundingStrategy::from_mode(mode, is_positive, || (remainder * 2u32).cmp(&divisor));
+    match resolved_strategy {
+        ResolvedRoundingStrategy::RoundUp => rounded_up,
+        ResolvedRoundingStrategy::RoundDown => rounded_down,
+        ResolvedRoundingStrategy::RoundToEven => {
+            if rounded_down.is_even() {
+                rounded_down
+            } else {
+                rounded_up
+            }
+        }
+    }
+}
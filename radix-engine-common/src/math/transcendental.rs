@@ -0,0 +1,193 @@
+//! Fixed-point implementations of the transcendental functions of the decimal types.
+//!
+//! Values are passed around as `BigInt`s of subunits at a given scale, ie `value * 10^scale`.
+//! The functions are evaluated at `WORKING_SCALE` decimal places, and the result is then rounded
+//! to the nearest value at the requested scale, with ties rounded away from zero.
+//!
+//! Only integer arithmetic is used, so the results are deterministic across platforms.
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{Signed, ToPrimitive, Zero};
+
+/// The number of decimal places at which the functions are evaluated.
+///
+/// This keeps the error of `pow` below half a unit of the last place at scale 36, even when the
+/// error of the logarithm of the base is scaled up by an exponent of up to ~10^41.
+pub(crate) const WORKING_SCALE: u32 = 128;
+
+/// The magnitude of the exponent above which `exp` overflows, and below which it rounds to zero,
+/// for all decimal types.
+const MAX_EXP_ARGUMENT: u32 = 256;
+
+/// The natural logarithm of `x`, or `None` if `x` isn't positive.
+pub(crate) fn ln(x: &BigInt, scale: u32) -> Option<BigInt> {
+    if !x.is_positive() {
+        return None;
+    }
+    Some(to_scale(ln_working(&from_scale(x, scale)), scale))
+}
+
+/// The logarithm of `x` in the given integer base, or `None` if `x` isn't positive.
+pub(crate) fn log(x: &BigInt, base: u32, scale: u32) -> Option<BigInt> {
+    if !x.is_positive() {
+        return None;
+    }
+    let ln_base = ln_working(&(BigInt::from(base) * working_one()));
+    Some(to_scale(
+        div(&ln_working(&from_scale(x, scale)), &ln_base),
+        scale,
+    ))
+}
+
+/// The exponential function of `x`, or `None` if it's too large to be represented by any decimal
+/// type.
+pub(crate) fn exp(x: &BigInt, scale: u32) -> Option<BigInt> {
+    exp_working(&from_scale(x, scale)).map(|result| to_scale(result, scale))
+}
+
+/// `base` raised to the power of `exponent`.
+///
+/// Negative bases are only supported for integer exponents, and zero can't be raised to a negative
+/// power, in which cases `None` is returned.
+pub(crate) fn pow(base: &BigInt, exponent: &BigInt, scale: u32) -> Option<BigInt> {
+    pow_working(base, exponent, scale).map(|result| to_scale(result, scale))
+}
+
+/// `base` raised to the power of `exponent`, as [`pow`], but left at the working scale - so that
+/// further computations with the result only need to round once, at the end.
+pub(crate) fn pow_working(base: &BigInt, exponent: &BigInt, scale: u32) -> Option<BigInt> {
+    if exponent.is_zero() {
+        return Some(working_one());
+    }
+    if base.is_zero() {
+        return exponent.is_positive().then(BigInt::zero);
+    }
+
+    let one = BigInt::from(10).pow(scale);
+    let is_negative = if base.is_negative() {
+        let (integer_exponent, fraction) = exponent.div_rem(&one);
+        if !fraction.is_zero() {
+            return None;
+        }
+        integer_exponent.is_odd()
+    } else {
+        false
+    };
+
+    let ln_base = ln_working(&from_scale(&base.abs(), scale));
+    let magnitude = exp_working(&mul(&ln_base, &from_scale(exponent, scale)))?;
+    Some(if is_negative { -magnitude } else { magnitude })
+}
+
+fn working_one() -> BigInt {
+    BigInt::from(10).pow(WORKING_SCALE)
+}
+
+fn from_scale(x: &BigInt, scale: u32) -> BigInt {
+    x * BigInt::from(10).pow(WORKING_SCALE - scale)
+}
+
+fn to_scale(x: BigInt, scale: u32) -> BigInt {
+    let divisor = BigInt::from(10).pow(WORKING_SCALE - scale);
+    let (quotient, remainder) = x.div_rem(&divisor);
+    if remainder.abs() * 2 >= divisor {
+        quotient + x.signum()
+    } else {
+        quotient
+    }
+}
+
+fn mul(a: &BigInt, b: &BigInt) -> BigInt {
+    a * b / working_one()
+}
+
+fn div(a: &BigInt, b: &BigInt) -> BigInt {
+    a * working_one() / b
+}
+
+/// The natural logarithm of a positive `x` at the working scale.
+fn ln_working(x: &BigInt) -> BigInt {
+    let one = working_one();
+    let two = &one * 2;
+
+    // Reduce `x` to `y = x / 2^k`, with `y` in `[1, 2)`, so that `ln(x) = ln(y) + k * ln(2)`.
+    let mut k = x.bits() as i64 - one.bits() as i64;
+    let mut y = if k >= 0 {
+        x >> (k as usize)
+    } else {
+        x << (-k as usize)
+    };
+    while y >= two {
+        y >>= 1;
+        k += 1;
+    }
+    while y < one {
+        y <<= 1;
+        k -= 1;
+    }
+
+    let ln_y = ln_series(&y);
+    if k == 0 {
+        ln_y
+    } else {
+        ln_y + ln_series(&two) * k
+    }
+}
+
+/// The natural logarithm of `y` in `[1, 2]`, as `2 * atanh((y - 1) / (y + 1))`.
+fn ln_series(y: &BigInt) -> BigInt {
+    let one = working_one();
+    let z = div(&(y - &one), &(y + &one));
+    let z_squared = mul(&z, &z);
+
+    let mut sum = BigInt::zero();
+    let mut power = z;
+    let mut n = 1u32;
+    loop {
+        let term = &power / n;
+        if term.is_zero() {
+            break;
+        }
+        sum += term;
+        power = mul(&power, &z_squared);
+        n += 2;
+    }
+    sum * 2
+}
+
+/// The exponential function of `x` at the working scale, or `None` if it overflows.
+fn exp_working(x: &BigInt) -> Option<BigInt> {
+    let one = working_one();
+    let max_argument = &one * MAX_EXP_ARGUMENT;
+    if x > &max_argument {
+        return None;
+    }
+    if x < &-max_argument {
+        return Some(BigInt::zero());
+    }
+
+    // Reduce `x` to `r = x - k * ln(2)`, with `|r| <= ln(2) / 2`, so that `exp(x) = exp(r) * 2^k`.
+    let ln_2 = ln_series(&(&one * 2));
+    let k = (x * 2u32 + &ln_2).div_floor(&(&ln_2 * 2));
+    let r = x - &k * &ln_2;
+
+    let mut sum = one.clone();
+    let mut term = one;
+    let mut n = 1u32;
+    loop {
+        term = mul(&term, &r) / n;
+        if term.is_zero() {
+            break;
+        }
+        sum += &term;
+        n += 1;
+    }
+
+    let k = k.to_i64().expect("Bounded by the maximum argument");
+    Some(if k >= 0 {
+        sum << (k as usize)
+    } else {
+        sum >> (-k as usize)
+    })
+}
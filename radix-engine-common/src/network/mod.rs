@@ -10,9 +10,43 @@ pub struct NetworkDefinition {
     pub hrp_suffix: String,
 }
 
+/// The maximum length of the HRP suffix of a custom network, which keeps the longest HRP
+/// (`internal_keyvaluestore_{suffix}`) within the 83 characters allowed by Bech32.
+const MAX_HRP_SUFFIX_LENGTH: usize = 60;
+
 // NOTE: Most Network Definitions live in the node codebase
 // Some are duplicated here so that they can be easily used by scrypto and resim
 impl NetworkDefinition {
+    /// The network definition for a network which isn't built in, such as a private test network.
+    ///
+    /// The HRP suffix must consist of lowercase letters, digits and underscores, eg `tdx_f0_`.
+    pub fn custom(
+        id: u8,
+        logical_name: &str,
+        hrp_suffix: &str,
+    ) -> Result<NetworkDefinition, ParseNetworkError> {
+        if logical_name.is_empty() || logical_name.chars().any(|c| c.is_whitespace()) {
+            return Err(ParseNetworkError::InvalidLogicalName(String::from(
+                logical_name,
+            )));
+        }
+        if hrp_suffix.is_empty()
+            || hrp_suffix.len() > MAX_HRP_SUFFIX_LENGTH
+            || !hrp_suffix
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err(ParseNetworkError::InvalidHrpSuffix(String::from(
+                hrp_suffix,
+            )));
+        }
+        Ok(NetworkDefinition {
+            id,
+            logical_name: String::from(logical_name),
+            hrp_suffix: String::from(hrp_suffix),
+        })
+    }
+
     pub fn simulator() -> NetworkDefinition {
         NetworkDefinition {
             id: 242,
@@ -104,11 +138,15 @@ impl FromStr for NetworkDefinition {
 #[derive(Debug)]
 pub enum ParseNetworkError {
     InvalidNetworkString,
+    InvalidLogicalName(String),
+    InvalidHrpSuffix(String),
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::address::{AddressBech32Decoder, AddressBech32Encoder};
+    use crate::constants::XRD;
 
     #[test]
     fn network_from_string_fail() {
@@ -134,4 +172,38 @@ mod tests {
             assert_eq!(NetworkDefinition::from_str(name).unwrap().id, id)
         }
     }
+
+    #[test]
+    fn custom_network() {
+        let network = NetworkDefinition::custom(0xf0, "privatenet", "tdx_f0_").unwrap();
+        assert_eq!(network.id, 0xf0);
+        assert_eq!(network.logical_name, "privatenet");
+        assert_eq!(network.hrp_suffix, "tdx_f0_");
+
+        let address = AddressBech32Encoder::new(&network)
+            .encode(XRD.as_ref())
+            .unwrap();
+        assert!(address.starts_with("resource_tdx_f0_1"));
+        assert_eq!(
+            AddressBech32Decoder::new(&network)
+                .validate_and_decode(&address)
+                .unwrap()
+                .1,
+            XRD.as_ref()
+        );
+    }
+
+    #[test]
+    fn custom_network_with_invalid_names_fails() {
+        assert!(matches!(
+            NetworkDefinition::custom(0xf0, "", "tdx_f0_").unwrap_err(),
+            ParseNetworkError::InvalidLogicalName(_)
+        ));
+        for hrp_suffix in ["", "TDX", "tdx-f0", "tdx f0", &"a".repeat(61)] {
+            assert!(matches!(
+                NetworkDefinition::custom(0xf0, "privatenet", hrp_suffix).unwrap_err(),
+                ParseNetworkError::InvalidHrpSuffix(_)
+            ));
+        }
+    }
 }
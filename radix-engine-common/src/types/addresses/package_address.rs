@@ -67,6 +67,7 @@ impl PackageAddress {
             || self.0 == crate::constants::CONSENSUS_MANAGER_PACKAGE.0
             || self.0 == crate::constants::ACCESS_CONTROLLER_PACKAGE.0
             || self.0 == crate::constants::POOL_PACKAGE.0
+            || self.0 == crate::constants::VESTING_PACKAGE.0
             || self.0 == crate::constants::TRANSACTION_PROCESSOR_PACKAGE.0
             || self.0 == crate::constants::METADATA_MODULE_PACKAGE.0
             || self.0 == crate::constants::ROYALTY_MODULE_PACKAGE.0
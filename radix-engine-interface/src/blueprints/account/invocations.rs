@@ -30,6 +30,25 @@ pub enum DefaultDepositRule {
     AllowExisting,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ScryptoSbor, ManifestSbor)]
+pub enum WithdrawalLimitPeriod {
+    /// The limit applies to the withdrawals made within a single epoch.
+    Epoch,
+
+    /// The limit applies to the withdrawals made within a single UTC day.
+    Day,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
+pub struct WithdrawalLimit {
+    /// The maximum amount of the resource which can be withdrawn in a single period. For
+    /// non-fungible resources, this is the maximum number of non-fungibles.
+    pub amount: Decimal,
+
+    /// The period after which the withdrawn amount is reset.
+    pub period: WithdrawalLimitPeriod,
+}
+
 pub const ACCOUNT_BLUEPRINT: &str = "Account";
 
 define_type_info_marker!(Some(ACCOUNT_PACKAGE), Account);
@@ -401,3 +420,30 @@ pub struct AccountRemoveAuthorizedDepositorInput {
 }
 
 pub type AccountRemoveAuthorizedDepositorOutput = ();
+
+//===============================
+// Account Set Withdrawal Limit
+//===============================
+
+pub const ACCOUNT_SET_WITHDRAWAL_LIMIT_IDENT: &str = "set_withdrawal_limit";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountSetWithdrawalLimitInput {
+    pub resource_address: ResourceAddress,
+    pub limit: WithdrawalLimit,
+}
+
+pub type AccountSetWithdrawalLimitOutput = ();
+
+//==================================
+// Account Remove Withdrawal Limit
+//==================================
+
+pub const ACCOUNT_REMOVE_WITHDRAWAL_LIMIT_IDENT: &str = "remove_withdrawal_limit";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountRemoveWithdrawalLimitInput {
+    pub resource_address: ResourceAddress,
+}
+
+pub type AccountRemoveWithdrawalLimitOutput = ();
@@ -7,6 +7,7 @@ pub mod pool;
 pub mod resource;
 pub mod test_utils;
 pub mod transaction_processor;
+pub mod vesting;
 
 pub mod component;
 pub mod macros;
@@ -0,0 +1,129 @@
+use crate::*;
+#[cfg(feature = "radix_engine_fuzzing")]
+use arbitrary::Arbitrary;
+use radix_engine_common::math::*;
+use radix_engine_common::time::*;
+
+/// The schedule by which the tokens held by a vesting component are released.
+///
+/// Tokens vest linearly between `start` and `end`. If a `cliff` is set, nothing can be claimed
+/// before it - at the cliff, everything which vested since the `start` is released at once. A
+/// schedule with its cliff at its end (or with equal `start` and `end`) releases all tokens at
+/// once, which makes it a simple time-lock.
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct VestingSchedule {
+    pub start: Instant,
+    pub cliff: Option<Instant>,
+    pub end: Instant,
+}
+
+impl VestingSchedule {
+    pub fn linear(start: Instant, end: Instant) -> Self {
+        Self {
+            start,
+            cliff: None,
+            end,
+        }
+    }
+
+    pub fn with_cliff(start: Instant, cliff: Instant, end: Instant) -> Self {
+        Self {
+            start,
+            cliff: Some(cliff),
+            end,
+        }
+    }
+
+    /// Checks that `start <= cliff <= end`.
+    pub fn is_valid(&self) -> bool {
+        let start = self.start.seconds_since_unix_epoch;
+        let end = self.end.seconds_since_unix_epoch;
+        match self.cliff {
+            Some(cliff) => {
+                start <= cliff.seconds_since_unix_epoch && cliff.seconds_since_unix_epoch <= end
+            }
+            None => start <= end,
+        }
+    }
+
+    /// Returns the part of the `total_amount` which has vested at the given instant, or `None` on
+    /// overflow. The result is not rounded to the divisibility of any resource.
+    pub fn vested_amount(&self, total_amount: Decimal, now: Instant) -> Option<Decimal> {
+        let now = now.seconds_since_unix_epoch;
+        let start = self.start.seconds_since_unix_epoch;
+        let end = self.end.seconds_since_unix_epoch;
+        let released_from = self
+            .cliff
+            .map(|cliff| cliff.seconds_since_unix_epoch)
+            .unwrap_or(start);
+
+        if now < released_from {
+            return Some(Decimal::ZERO);
+        }
+        if now >= end {
+            return Some(total_amount);
+        }
+
+        let elapsed = PreciseDecimal::from(now.checked_sub(start)?);
+        let duration = PreciseDecimal::from(end.checked_sub(start)?);
+        let vested = elapsed
+            .checked_div(duration)
+            .and_then(|fraction| fraction.checked_mul(PreciseDecimal::from(total_amount)))?;
+        Decimal::try_from(vested).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear_schedule_vests_proportionally_to_elapsed_time() {
+        let schedule = VestingSchedule::linear(Instant::new(1000), Instant::new(2000));
+
+        assert_eq!(
+            schedule.vested_amount(dec!(100), Instant::new(999)),
+            Some(dec!(0))
+        );
+        assert_eq!(
+            schedule.vested_amount(dec!(100), Instant::new(1250)),
+            Some(dec!(25))
+        );
+        assert_eq!(
+            schedule.vested_amount(dec!(100), Instant::new(2000)),
+            Some(dec!(100))
+        );
+        assert_eq!(
+            schedule.vested_amount(dec!(100), Instant::new(3000)),
+            Some(dec!(100))
+        );
+    }
+
+    #[test]
+    fn nothing_vests_before_cliff() {
+        let schedule =
+            VestingSchedule::with_cliff(Instant::new(1000), Instant::new(1500), Instant::new(2000));
+
+        assert_eq!(
+            schedule.vested_amount(dec!(100), Instant::new(1499)),
+            Some(dec!(0))
+        );
+        assert_eq!(
+            schedule.vested_amount(dec!(100), Instant::new(1500)),
+            Some(dec!(50))
+        );
+    }
+
+    #[test]
+    fn schedule_validity_is_checked() {
+        assert!(VestingSchedule::linear(Instant::new(1000), Instant::new(1000)).is_valid());
+        assert!(!VestingSchedule::linear(Instant::new(1000), Instant::new(999)).is_valid());
+        assert!(!VestingSchedule::with_cliff(
+            Instant::new(1000),
+            Instant::new(2001),
+            Instant::new(2000)
+        )
+        .is_valid());
+    }
+}
@@ -0,0 +1,66 @@
+use crate::blueprints::component::*;
+use crate::blueprints::macros::*;
+use crate::blueprints::resource::*;
+use crate::blueprints::vesting::*;
+use radix_engine_common::data::manifest::model::*;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::*;
+
+pub const VESTING_BLUEPRINT: &str = "Vesting";
+
+define_type_info_marker!(Some(VESTING_PACKAGE), Vesting);
+
+define_invocation! {
+    blueprint_name: Vesting,
+    function_name: instantiate,
+    input: struct {
+        owner_role: OwnerRole,
+        beneficiary_rule: AccessRule,
+        admin_rule: AccessRule,
+        bucket: Bucket,
+        schedule: VestingSchedule,
+        address_reservation: Option<GlobalAddressReservation>
+    },
+    output: type Global<VestingObjectTypeInfo>,
+    manifest_input: struct {
+        owner_role: OwnerRole,
+        beneficiary_rule: AccessRule,
+        admin_rule: AccessRule,
+        bucket: ManifestBucket,
+        schedule: VestingSchedule,
+        address_reservation: Option<ManifestAddressReservation>
+    }
+}
+
+define_invocation! {
+    blueprint_name: Vesting,
+    function_name: claim,
+    input: struct {},
+    output: type Bucket,
+    manifest_input: struct {}
+}
+
+define_invocation! {
+    blueprint_name: Vesting,
+    function_name: revoke,
+    input: struct {},
+    output: type Bucket,
+    manifest_input: struct {}
+}
+
+define_invocation! {
+    blueprint_name: Vesting,
+    function_name: get_vested_amount,
+    input: struct {},
+    output: type Decimal,
+    manifest_input: struct {}
+}
+
+define_invocation! {
+    blueprint_name: Vesting,
+    function_name: get_claimable_amount,
+    input: struct {},
+    output: type Decimal,
+    manifest_input: struct {}
+}
@@ -43,6 +43,7 @@ lazy_static! {
             GENESIS_HELPER_PACKAGE.into(),
             FAUCET_PACKAGE.into(),
             POOL_PACKAGE.into(),
+            VESTING_PACKAGE.into(),
             TRANSACTION_TRACKER_PACKAGE.into(),
             // components
             CONSENSUS_MANAGER.into(),
@@ -266,6 +266,7 @@ blueprint_partition_offset!(
         ResourceVaultKeyValue,
         ResourcePreferenceKeyValue,
         AuthorizedDepositorKeyValue,
+        WithdrawalLimitKeyValue,
     }
 );
 
@@ -287,6 +288,12 @@ blueprint_partition_offset!(
     }
 );
 
+blueprint_partition_offset!(
+    pub enum VestingPartitionOffset {
+        Field,
+    }
+);
+
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, Sbor, PartialEq, Eq, Hash, PartialOrd, Ord, FromRepr)]
 pub enum TransactionTrackerField {
@@ -6,6 +6,7 @@ use crate::typed_substate_layout::*;
 use radix_engine::blueprints::account;
 use radix_engine::blueprints::native_schema::*;
 use radix_engine::blueprints::pool::v1::events as pool_events;
+use radix_engine::blueprints::vesting;
 use radix_engine::types::*;
 
 /// Given an [`EventTypeIdentifier`] and the raw event data, this function attempts to convert the
@@ -51,7 +52,7 @@ fn resolve_typed_event_key_from_event_type_identifier(
             )
             .map(TypedNativeEventKey::from),
             ACCOUNT_PACKAGE => TypedAccountPackageEventKey::new(
-                &ACCOUNT_PACKAGE_DEFINITION,
+                &ACCOUNT_PACKAGE_DEFINITION_V1_1,
                 &blueprint_id.blueprint_name,
                 &event_name,
             )
@@ -80,6 +81,12 @@ fn resolve_typed_event_key_from_event_type_identifier(
                 &event_name,
             )
             .map(TypedNativeEventKey::from),
+            VESTING_PACKAGE => TypedVestingPackageEventKey::new(
+                &VESTING_PACKAGE_DEFINITION,
+                &blueprint_id.blueprint_name,
+                &event_name,
+            )
+            .map(TypedNativeEventKey::from),
             TRANSACTION_PROCESSOR_PACKAGE => TypedTransactionProcessorPackageEventKey::new(
                 &TRANSACTION_PROCESSOR_PACKAGE_DEFINITION,
                 &blueprint_id.blueprint_name,
@@ -205,7 +212,9 @@ define_structure! {
             AccountRemoveResourcePreferenceEvent,
             AccountSetDefaultDepositRuleEvent,
             AccountAddAuthorizedDepositorEvent,
-            AccountRemoveAuthorizedDepositorEvent
+            AccountRemoveAuthorizedDepositorEvent,
+            AccountSetWithdrawalLimitEvent,
+            AccountRemoveWithdrawalLimitEvent
         ]
     },
     Identity => {
@@ -275,6 +284,12 @@ define_structure! {
             BurnNonFungibleResourceEvent,
         ]
     },
+    Vesting => {
+        Vesting => [
+            VestingClaimEvent,
+            VestingRevokeEvent,
+        ]
+    },
     TransactionProcessor => {
         TransactionProcessor => []
     },
@@ -335,6 +350,11 @@ type AccountRemoveResourcePreferenceEvent = account::RemoveResourcePreferenceEve
 type AccountSetDefaultDepositRuleEvent = account::SetDefaultDepositRuleEvent;
 type AccountAddAuthorizedDepositorEvent = account::AddAuthorizedDepositorEvent;
 type AccountRemoveAuthorizedDepositorEvent = account::RemoveAuthorizedDepositorEvent;
+type AccountSetWithdrawalLimitEvent = account::SetWithdrawalLimitEvent;
+type AccountRemoveWithdrawalLimitEvent = account::RemoveWithdrawalLimitEvent;
+
+type VestingClaimEvent = vesting::ClaimEvent;
+type VestingRevokeEvent = vesting::RevokeEvent;
 
 /// This enum uses some special syntax to define the structure of events. This makes the code for
 /// model definitions very compact, allows for very easy addition of more packages, blueprints or
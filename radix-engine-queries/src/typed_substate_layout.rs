@@ -2,7 +2,9 @@ use radix_engine::types::*;
 
 // Import and re-export these types so they are available easily with a single import
 pub use radix_engine::blueprints::access_controller::*;
-pub use radix_engine::blueprints::account::{AccountBlueprint, AccountError, AccountNativePackage};
+pub use radix_engine::blueprints::account::{
+    AccountBlueprint, AccountError, AccountNativePackage, AccountV1MinorVersion,
+};
 use radix_engine::blueprints::account::{AccountTypedSubstateKey, AccountTypedSubstateValue};
 pub use radix_engine::blueprints::consensus_manager::*;
 pub use radix_engine::blueprints::package::*;
@@ -154,3 +154,99 @@ pub trait ListableSubstateDatabase {
     /// Iterates over all partition keys, in an arbitrary order.
     fn list_partition_keys(&self) -> Box<dyn Iterator<Item = DbPartitionKey> + '_>;
 }
+
+/// A read interface of a database which retains (a bounded range of) its past states, where each
+/// commit produces a next state version.
+pub trait VersionedSubstateDatabase {
+    /// Returns the version of the latest state (i.e. the number of commits applied so far).
+    fn get_current_version(&self) -> u64;
+
+    /// Returns the earliest state version which can still be read.
+    fn get_earliest_available_version(&self) -> u64;
+
+    /// Reads a substate value by its partition and sort key, as of the given state version, or
+    /// [`Option::None`] if missing.
+    /// Panics if the given version is not available (see [`Self::at_version()`] for a checked
+    /// alternative).
+    fn get_substate_at_version(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue>;
+
+    /// Iterates over all entries of the given partition as of the given state version, with the
+    /// same semantics as [`SubstateDatabase::list_entries_from()`].
+    /// Panics if the given version is not available (see [`Self::at_version()`] for a checked
+    /// alternative).
+    fn list_entries_from_at_version(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_>;
+
+    /// Returns a read-only [`SubstateDatabase`] view of the state as of the given version (e.g. to
+    /// be used by a `SystemDatabaseReader`), or an error if the version is not available.
+    fn at_version(
+        &self,
+        state_version: u64,
+    ) -> Result<SubstateDatabaseAtVersion<'_, Self>, StateVersionNotAvailableError>
+    where
+        Self: Sized,
+    {
+        let earliest_available_version = self.get_earliest_available_version();
+        let current_version = self.get_current_version();
+        if state_version < earliest_available_version || state_version > current_version {
+            return Err(StateVersionNotAvailableError {
+                requested_version: state_version,
+                earliest_available_version,
+                current_version,
+            });
+        }
+        Ok(SubstateDatabaseAtVersion {
+            database: self,
+            state_version,
+        })
+    }
+}
+
+/// An error returned when a state version outside of the retained history is requested.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateVersionNotAvailableError {
+    pub requested_version: u64,
+    pub earliest_available_version: u64,
+    pub current_version: u64,
+}
+
+/// A [`SubstateDatabase`] view of a [`VersionedSubstateDatabase`]'s state as of a specific version.
+pub struct SubstateDatabaseAtVersion<'d, D> {
+    database: &'d D,
+    state_version: u64,
+}
+
+impl<'d, D> SubstateDatabaseAtVersion<'d, D> {
+    pub fn state_version(&self) -> u64 {
+        self.state_version
+    }
+}
+
+impl<'d, D: VersionedSubstateDatabase> SubstateDatabase for SubstateDatabaseAtVersion<'d, D> {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        self.database
+            .get_substate_at_version(self.state_version, partition_key, sort_key)
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.database
+            .list_entries_from_at_version(self.state_version, partition_key, from_sort_key)
+    }
+}
@@ -1,6 +1,7 @@
 use crate::hash_tree::tree_store::StaleTreePart;
 use crate::hash_tree::types::{LeafKey, LeafNode, SPARSE_MERKLE_PLACEHOLDER_HASH};
 use jellyfish::JellyfishMerkleTree;
+use proof::{SubstateProof, SubstateProofError, TierProof};
 use radix_engine_common::crypto::{hash, Hash};
 use radix_engine_store_interface::interface::{
     DatabaseUpdate, DatabaseUpdates, DbNodeKey, DbPartitionKey, DbPartitionNum, DbSortKey,
@@ -14,11 +15,12 @@ use utils::rust::collections::{index_map_new, IndexMap};
 use utils::rust::vec::Vec;
 
 pub mod hash_tree_facade;
+pub mod proof;
 pub mod tree_store;
 
-// The sources copied from Aptos (the `jellyfish` and `types` modules) contain support for
-// generating proofs, which we plan to use in near future. Hence, we do not delete that code, but
-// suppress warnings.
+// The sources copied from Aptos (the `jellyfish` and `types` modules) contain more proof-related
+// support (e.g. range proofs) than we currently use (see the `proof` module). Hence, we do not
+// delete that code, but suppress warnings.
 
 #[allow(dead_code)]
 mod jellyfish;
@@ -106,8 +108,70 @@ pub fn list_substate_hashes_at_version<S: ReadableTreeStore>(
     by_db_partition
 }
 
+/// Generates a proof of inclusion (or exclusion) of the given substate within the state of the
+/// "3-Tier JMT" at the given version (where `None` denotes an empty, initial state of the tree).
+/// The returned proof may be verified against the state root hash of that version (see
+/// [`SubstateProof::verify()`]).
+/// Returns an error if any of the required tree nodes is not available in the given `TreeStore`
+/// (e.g. because the requested version was already pruned).
+pub fn get_substate_proof_at_version<S: ReadableTreeStore>(
+    node_tier_store: &S,
+    node_root_version: Option<Version>,
+    partition_key: &DbPartitionKey,
+    sort_key: &DbSortKey,
+) -> Result<SubstateProof, SubstateProofError> {
+    let Some(node_root_version) = node_root_version else {
+        return Ok(SubstateProof::empty());
+    };
+    let (node_tier, partition_root_version) =
+        get_tier_proof(node_tier_store, node_root_version, &partition_key.node_key)?;
+    let Some(partition_root_version) = partition_root_version else {
+        return Ok(SubstateProof {
+            node_tier,
+            partition_tier: None,
+            substate_tier: None,
+        });
+    };
+    let partition_tier_store =
+        NestedTreeStore::new(node_tier_store, partition_key.node_key.clone());
+    let partition_key_bytes = vec![partition_key.partition_num];
+    let (partition_tier, substate_root_version) = get_tier_proof(
+        &partition_tier_store,
+        partition_root_version,
+        &partition_key_bytes,
+    )?;
+    let Some(substate_root_version) = substate_root_version else {
+        return Ok(SubstateProof {
+            node_tier,
+            partition_tier: Some(partition_tier),
+            substate_tier: None,
+        });
+    };
+    let substate_tier_store = NestedTreeStore::new(&partition_tier_store, partition_key_bytes);
+    let (substate_tier, _) =
+        get_tier_proof(&substate_tier_store, substate_root_version, &sort_key.0)?;
+    Ok(SubstateProof {
+        node_tier,
+        partition_tier: Some(partition_tier),
+        substate_tier: Some(substate_tier),
+    })
+}
+
 // only internals below
 
+fn get_tier_proof<S: ReadableTreeStore>(
+    store: &S,
+    version: Version,
+    leaf_bytes: &[u8],
+) -> Result<(TierProof, Option<Version>), SubstateProofError> {
+    let (leaf_node_data, proof) =
+        JellyfishMerkleTree::new(store).get_with_proof(&LeafKey::new(leaf_bytes), version)?;
+    Ok((
+        TierProof::from(proof),
+        leaf_node_data.map(|(_hash, last_hash_change_version, _version)| last_hash_change_version),
+    ))
+}
+
 fn list_leaves<S: ReadableTreeStore>(tree_store: &S, version: Version) -> Vec<LeafNode<Version>> {
     let mut leaves = Vec::new();
     list_leaves_recursively(tree_store, NodeKey::new_empty_path(version), &mut leaves);
@@ -0,0 +1,249 @@
+use super::tree_store::NodeKey;
+use super::types::{
+    IteratedLeafKey, LeafKey, SparseMerkleInternalNode, SparseMerkleLeafNode, SparseMerkleProof,
+    StorageError, SPARSE_MERKLE_PLACEHOLDER_HASH,
+};
+use radix_engine_common::crypto::{hash, Hash};
+use radix_engine_common::Sbor;
+use radix_engine_store_interface::interface::{DbPartitionKey, DbSortKey, DbSubstateValue};
+use sbor::rust::string::String;
+use sbor::rust::vec;
+use sbor::rust::vec::Vec;
+
+/// A proof of inclusion (or exclusion) of a single substate within the state captured by the
+/// "3-Tier JMT" (see `put_at_next_version()`).
+/// It is composed of a proof for each of the tiers which had to be traversed in order to reach the
+/// substate (or to prove its absence). A verifier only needs the state root hash to check it - see
+/// [`SubstateProof::verify()`].
+#[derive(Clone, Debug, PartialEq, Eq, Sbor)]
+pub struct SubstateProof {
+    /// A proof of the ReNode's leaf within the top-most tree (whose root hash is the state root
+    /// hash).
+    pub node_tier: TierProof,
+    /// A proof of the partition's leaf within the ReNode's tree.
+    /// Only present when the `node_tier` proves the inclusion of the ReNode.
+    pub partition_tier: Option<TierProof>,
+    /// A proof of the substate's leaf within the partition's tree.
+    /// Only present when the `partition_tier` proves the inclusion of the partition.
+    pub substate_tier: Option<TierProof>,
+}
+
+/// A proof of inclusion (or exclusion) of a single leaf within a single tier's tree.
+#[derive(Clone, Debug, PartialEq, Eq, Sbor)]
+pub struct TierProof {
+    /// The leaf reached when traversing the tree by the requested key.
+    /// - If it has the requested key, then this is an inclusion proof.
+    /// - If it has a different key, then this is an exclusion proof (the reached leaf is the only
+    ///   one existing in the subtree where the requested key would have to be).
+    /// - If it is `None`, then this is an exclusion proof (the subtree is empty).
+    pub leaf: Option<TierProofLeaf>,
+    /// Hashes of all siblings on the path, ordered from the bottom level to the root level.
+    pub siblings: Vec<Hash>,
+}
+
+/// A leaf captured by the [`TierProof`].
+#[derive(Clone, Debug, PartialEq, Eq, Sbor)]
+pub struct TierProofLeaf {
+    /// The tier-local key of the leaf (i.e. a ReNode key, a partition number or a sort key).
+    pub key: Vec<u8>,
+    /// The hash of the leaf's value (i.e. a lower tier's root hash, or a hash of a substate value).
+    pub value_hash: Hash,
+}
+
+/// An error while generating a [`SubstateProof`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubstateProofError {
+    /// The requested state version does not exist yet.
+    StateVersionNotFound(u64),
+    /// A tree node needed for the proof is not available (e.g. the requested state version was
+    /// already pruned).
+    MissingTreeNode(NodeKey),
+    /// The stored tree nodes violate some JMT property.
+    InconsistentTree,
+    /// An unexpected failure of the underlying storage.
+    StorageFailure(String),
+}
+
+/// An error while verifying a [`SubstateProof`] (i.e. the proof is not valid for the given root
+/// hash and substate).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubstateProofVerificationError {
+    /// The tier's proof resolves to a different root hash than the one expected (i.e. the state
+    /// root hash, or the hash captured by the higher tier's leaf).
+    RootHashMismatch {
+        tier: ProofTier,
+        expected: Hash,
+        actual: Hash,
+    },
+    /// The tier's proof is structurally invalid (e.g. has more siblings than the key's bits, or
+    /// its exclusion leaf does not share the key's path).
+    MalformedTierProof(ProofTier),
+    /// The proof of a lower tier is missing, although the higher tier proves that it exists.
+    MissingTierProof(ProofTier),
+    /// The proof of a lower tier is present, although the higher tier proves that it is empty.
+    UnexpectedTierProof(ProofTier),
+    /// The proof is valid, but it proves a different substate value than the expected one.
+    ValueMismatch {
+        expected: Option<Hash>,
+        actual: Option<Hash>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofTier {
+    ReNode,
+    Partition,
+    Substate,
+}
+
+impl SubstateProof {
+    /// Creates a proof of exclusion of any substate from an empty state.
+    pub fn empty() -> Self {
+        Self {
+            node_tier: TierProof {
+                leaf: None,
+                siblings: vec![],
+            },
+            partition_tier: None,
+            substate_tier: None,
+        }
+    }
+
+    /// Verifies this proof against the given state root hash.
+    /// On success, returns the hash of the substate's value (or `None` if this is a valid proof of
+    /// the substate's absence).
+    pub fn verify(
+        &self,
+        state_root_hash: &Hash,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Result<Option<Hash>, SubstateProofVerificationError> {
+        let partition_tier_root_hash =
+            self.node_tier
+                .verify(ProofTier::ReNode, state_root_hash, &partition_key.node_key)?;
+        let substate_tier_root_hash = Self::verify_lower_tier(
+            ProofTier::Partition,
+            self.partition_tier.as_ref(),
+            partition_tier_root_hash,
+            &[partition_key.partition_num],
+        )?;
+        Self::verify_lower_tier(
+            ProofTier::Substate,
+            self.substate_tier.as_ref(),
+            substate_tier_root_hash,
+            &sort_key.0,
+        )
+    }
+
+    /// Verifies this proof against the given state root hash, and checks that it proves the given
+    /// substate value (or the substate's absence, if `None` is given).
+    pub fn verify_value(
+        &self,
+        state_root_hash: &Hash,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+        value: Option<&DbSubstateValue>,
+    ) -> Result<(), SubstateProofVerificationError> {
+        let expected = value.map(|value| hash(value));
+        let actual = self.verify(state_root_hash, partition_key, sort_key)?;
+        if actual != expected {
+            return Err(SubstateProofVerificationError::ValueMismatch { expected, actual });
+        }
+        Ok(())
+    }
+
+    fn verify_lower_tier(
+        tier: ProofTier,
+        tier_proof: Option<&TierProof>,
+        expected_root_hash: Option<Hash>,
+        key: &[u8],
+    ) -> Result<Option<Hash>, SubstateProofVerificationError> {
+        match (expected_root_hash, tier_proof) {
+            (Some(root_hash), Some(tier_proof)) => tier_proof.verify(tier, &root_hash, key),
+            (Some(_), None) => Err(SubstateProofVerificationError::MissingTierProof(tier)),
+            (None, Some(_)) => Err(SubstateProofVerificationError::UnexpectedTierProof(tier)),
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+impl TierProof {
+    /// Verifies this proof against the given root hash of the tier's tree.
+    /// On success, returns the value hash of the leaf of the given key (or `None` if this is a
+    /// valid proof of the leaf's absence).
+    pub fn verify(
+        &self,
+        tier: ProofTier,
+        expected_root_hash: &Hash,
+        key: &[u8],
+    ) -> Result<Option<Hash>, SubstateProofVerificationError> {
+        let key = LeafKey::new(key);
+        let key_bits = key.iter_bits().collect::<Vec<_>>();
+        if self.siblings.len() > key_bits.len() {
+            return Err(SubstateProofVerificationError::MalformedTierProof(tier));
+        }
+        let (leaf_hash, proven_value_hash) = match &self.leaf {
+            Some(leaf) => {
+                let leaf_node = SparseMerkleLeafNode::new(LeafKey::new(&leaf.key), leaf.value_hash);
+                if leaf.key == key.bytes {
+                    (leaf_node.hash(), Some(leaf.value_hash))
+                } else {
+                    // The other leaf only proves the exclusion if it occupies the requested key's
+                    // entire path (i.e. the path described by all the siblings).
+                    let common_prefix_bits = leaf_node
+                        .key()
+                        .iter_bits()
+                        .zip(key_bits.iter())
+                        .take_while(|(leaf_bit, key_bit)| leaf_bit == *key_bit)
+                        .count();
+                    if common_prefix_bits < self.siblings.len() {
+                        return Err(SubstateProofVerificationError::MalformedTierProof(tier));
+                    }
+                    (leaf_node.hash(), None)
+                }
+            }
+            None => (SPARSE_MERKLE_PLACEHOLDER_HASH, None),
+        };
+        let actual_root_hash = self
+            .siblings
+            .iter()
+            .zip(key_bits[..self.siblings.len()].iter().rev())
+            .fold(leaf_hash, |current_hash, (sibling_hash, is_right)| {
+                if *is_right {
+                    SparseMerkleInternalNode::new(*sibling_hash, current_hash).hash()
+                } else {
+                    SparseMerkleInternalNode::new(current_hash, *sibling_hash).hash()
+                }
+            });
+        if actual_root_hash != *expected_root_hash {
+            return Err(SubstateProofVerificationError::RootHashMismatch {
+                tier,
+                expected: *expected_root_hash,
+                actual: actual_root_hash,
+            });
+        }
+        Ok(proven_value_hash)
+    }
+}
+
+impl From<SparseMerkleProof> for TierProof {
+    fn from(proof: SparseMerkleProof) -> Self {
+        Self {
+            leaf: proof.leaf().map(|leaf| TierProofLeaf {
+                key: leaf.key().bytes.clone(),
+                value_hash: *leaf.value_hash(),
+            }),
+            siblings: proof.siblings().to_vec(),
+        }
+    }
+}
+
+impl From<StorageError> for SubstateProofError {
+    fn from(error: StorageError) -> Self {
+        match error {
+            StorageError::NotFound(node_key) => SubstateProofError::MissingTreeNode(node_key),
+            StorageError::InconsistentState => SubstateProofError::InconsistentTree,
+            StorageError::UnexpectedIoError(message) => SubstateProofError::StorageFailure(message),
+        }
+    }
+}
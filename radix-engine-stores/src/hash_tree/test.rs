@@ -1,11 +1,12 @@
 use super::types::{Nibble, NibblePath, Version, SPARSE_MERKLE_PLACEHOLDER_HASH};
 use crate::hash_tree::jellyfish::JellyfishMerkleTree;
-use crate::hash_tree::put_at_next_version;
+use crate::hash_tree::proof::{ProofTier, SubstateProof, SubstateProofVerificationError};
 use crate::hash_tree::tree_store::{
     SerializedInMemoryTreeStore, StaleTreePart, TreeChildEntry, TreeInternalNode, TreeLeafNode,
     TreeNode, TreeStore, TypedInMemoryTreeStore,
 };
 use crate::hash_tree::types::{LeafKey, NodeKey};
+use crate::hash_tree::{get_substate_proof_at_version, put_at_next_version};
 use itertools::Itertools;
 use radix_engine_common::crypto::{hash, Hash};
 use radix_engine_common::data::scrypto::{scrypto_decode, scrypto_encode};
@@ -418,6 +419,156 @@ fn serialized_keys_are_strictly_increasing() {
     assert!(min_next_key > max_previous_key);
 }
 
+#[test]
+fn proves_inclusion_of_existing_substate() {
+    let mut tester = HashTreeTester::new_empty();
+    let root_hash = tester.put_substate_changes(vec![
+        change(1, 6, 2, Some(30)),
+        change(1, 6, 9, Some(40)),
+        change(1, 7, 3, Some(50)),
+        change(2, 6, 2, Some(60)),
+    ]);
+    let (partition_key, sort_key) = substate_key(1, 6, 9);
+    let proof = tester.get_substate_proof(&partition_key, &sort_key);
+    assert_eq!(
+        proof.verify(&root_hash, &partition_key, &sort_key),
+        Ok(Some(hash(from_seed(40))))
+    );
+    assert_eq!(
+        proof.verify_value(&root_hash, &partition_key, &sort_key, Some(&from_seed(40))),
+        Ok(())
+    );
+    assert_eq!(
+        proof.verify_value(&root_hash, &partition_key, &sort_key, Some(&from_seed(41))),
+        Err(SubstateProofVerificationError::ValueMismatch {
+            expected: Some(hash(from_seed(41))),
+            actual: Some(hash(from_seed(40))),
+        })
+    );
+}
+
+#[test]
+fn proves_exclusion_of_missing_substate_on_every_tier() {
+    let mut tester = HashTreeTester::new_empty();
+    let root_hash = tester.put_substate_changes(vec![
+        change(1, 6, 2, Some(30)),
+        change(1, 6, 9, Some(40)),
+        change(2, 7, 3, Some(50)),
+    ]);
+    for (node_key_seed, partition_num, sort_key_seed) in [(3, 6, 2), (1, 8, 2), (1, 6, 4)] {
+        let (partition_key, sort_key) = substate_key(node_key_seed, partition_num, sort_key_seed);
+        let proof = tester.get_substate_proof(&partition_key, &sort_key);
+        assert_eq!(
+            proof.verify_value(&root_hash, &partition_key, &sort_key, None),
+            Ok(())
+        );
+    }
+}
+
+#[test]
+fn proves_exclusion_of_any_substate_from_empty_state() {
+    let mut tester = HashTreeTester::new_empty();
+    let (partition_key, sort_key) = substate_key(1, 6, 2);
+    assert_eq!(
+        tester.get_substate_proof(&partition_key, &sort_key),
+        SubstateProof::empty()
+    );
+    let root_hash = tester.put_substate_changes(vec![]);
+    let proof = tester.get_substate_proof(&partition_key, &sort_key);
+    assert_eq!(
+        proof.verify_value(&root_hash, &partition_key, &sort_key, None),
+        Ok(())
+    );
+}
+
+#[test]
+fn proof_does_not_verify_against_different_key_or_root() {
+    let mut tester = HashTreeTester::new_empty();
+    let root_hash = tester.put_substate_changes(vec![
+        change(1, 6, 2, Some(30)),
+        change(1, 6, 9, Some(40)),
+        change(2, 7, 3, Some(50)),
+    ]);
+    let (partition_key, sort_key) = substate_key(1, 6, 9);
+    let proof = tester.get_substate_proof(&partition_key, &sort_key);
+
+    let (other_partition_key, other_sort_key) = substate_key(1, 6, 2);
+    assert!(proof
+        .verify(&root_hash, &other_partition_key, &other_sort_key)
+        .is_err());
+
+    let other_root_hash = tester.put_substate_changes(vec![change(1, 6, 9, Some(41))]);
+    assert!(matches!(
+        proof.verify(&other_root_hash, &partition_key, &sort_key),
+        Err(SubstateProofVerificationError::RootHashMismatch {
+            tier: ProofTier::ReNode,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn tampered_proof_does_not_verify() {
+    let mut tester = HashTreeTester::new_empty();
+    let root_hash = tester.put_substate_changes(vec![
+        change(1, 6, 2, Some(30)),
+        change(1, 6, 9, Some(40)),
+        change(1, 6, 7, Some(50)),
+    ]);
+    let (partition_key, sort_key) = substate_key(1, 6, 9);
+    let mut proof = tester.get_substate_proof(&partition_key, &sort_key);
+    let substate_tier = proof.substate_tier.as_mut().unwrap();
+    substate_tier.leaf.as_mut().unwrap().value_hash = hash(from_seed(41));
+    assert!(matches!(
+        proof.verify(&root_hash, &partition_key, &sort_key),
+        Err(SubstateProofVerificationError::RootHashMismatch {
+            tier: ProofTier::Substate,
+            ..
+        })
+    ));
+
+    let mut proof = tester.get_substate_proof(&partition_key, &sort_key);
+    proof.substate_tier = None;
+    assert_eq!(
+        proof.verify(&root_hash, &partition_key, &sort_key),
+        Err(SubstateProofVerificationError::MissingTierProof(
+            ProofTier::Substate
+        ))
+    );
+}
+
+#[test]
+fn proves_substate_at_historical_version_of_unpruned_tree() {
+    let mut tester = HashTreeTester::new_empty();
+    let root_hash_v1 = tester.put_substate_changes(vec![change(1, 6, 2, Some(30))]);
+    let root_hash_v2 = tester.put_substate_changes(vec![change(1, 6, 2, Some(40))]);
+    let (partition_key, sort_key) = substate_key(1, 6, 2);
+    let proof_v1 =
+        get_substate_proof_at_version(&tester.tree_store, Some(1), &partition_key, &sort_key)
+            .unwrap();
+    assert_eq!(
+        proof_v1.verify_value(
+            &root_hash_v1,
+            &partition_key,
+            &sort_key,
+            Some(&from_seed(30))
+        ),
+        Ok(())
+    );
+    let proof_v2 =
+        get_substate_proof_at_version(&tester.tree_store, Some(2), &partition_key, &sort_key)
+            .unwrap();
+    assert_eq!(
+        proof_v2.verify_value(
+            &root_hash_v2,
+            &partition_key,
+            &sort_key,
+            Some(&from_seed(40))
+        ),
+        Ok(())
+    );
+}
+
 type SingleSubstateChange = (DbSubstateKey, DatabaseUpdate);
 
 fn change(
@@ -454,6 +605,16 @@ pub fn change_exact(
     )
 }
 
+fn substate_key(node_key_seed: u8, partition_num: u8, sort_key_seed: u8) -> DbSubstateKey {
+    (
+        DbPartitionKey {
+            node_key: from_seed(node_key_seed),
+            partition_num,
+        },
+        DbSortKey(from_seed(sort_key_seed)),
+    )
+}
+
 fn from_seed(node_key_seed: u8) -> Vec<u8> {
     vec![node_key_seed; node_key_seed as usize]
 }
@@ -515,6 +676,20 @@ impl<S: TreeStore> HashTreeTester<S> {
         })
     }
 
+    pub fn get_substate_proof(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> SubstateProof {
+        get_substate_proof_at_version(
+            &self.tree_store,
+            self.current_version,
+            partition_key,
+            sort_key,
+        )
+        .unwrap()
+    }
+
     fn apply_database_updates(&mut self, database_updates: &DatabaseUpdates) -> Hash {
         let next_version = self.current_version.unwrap_or(0) + 1;
         let current_version = self.current_version.replace(next_version);
@@ -1,5 +1,8 @@
+use crate::hash_tree::proof::{SubstateProof, SubstateProofError};
 use crate::hash_tree::tree_store::{TypedInMemoryTreeStore, Version};
-use crate::hash_tree::{list_substate_hashes_at_version, put_at_next_version};
+use crate::hash_tree::{
+    get_substate_proof_at_version, list_substate_hashes_at_version, put_at_next_version,
+};
 use radix_engine_common::prelude::*;
 use radix_engine_store_interface::interface::{
     CommittableSubstateDatabase, DatabaseUpdates, DbPartitionKey, DbSortKey, DbSubstateValue,
@@ -36,6 +39,19 @@ impl<D> HashTreeUpdatingDatabase<D> {
         list_substate_hashes_at_version(&mut self.tree_store, self.current_version)
     }
 
+    pub fn get_substate_proof(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Result<SubstateProof, SubstateProofError> {
+        get_substate_proof_at_version(
+            &self.tree_store,
+            Some(self.current_version).filter(|version| *version > 0),
+            partition_key,
+            sort_key,
+        )
+    }
+
     fn update_with(&mut self, db_updates: &DatabaseUpdates) {
         self.current_hash = put_at_next_version(
             &mut self.tree_store,
@@ -7,9 +7,11 @@ compile_error!("Feature `std` and `alloc` can't be enabled at the same time.");
 
 pub mod hash_tree;
 pub mod memory_db;
+pub mod overlay_db;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db_with_merkle_tree;
 
 pub mod hash_tree_support;
+pub mod state_history_support;
@@ -0,0 +1,327 @@
+use itertools::{EitherOrBoth, Itertools};
+use radix_engine_store_interface::interface::*;
+use sbor::rust::ops::Bound;
+use sbor::rust::prelude::*;
+
+/// A database which layers uncommitted [`DatabaseUpdates`] on top of any other (i.e. "root")
+/// [`SubstateDatabase`], without ever modifying it.
+///
+/// Committing to this database only affects the overlay - hence, it can be used e.g. to chain a
+/// series of previews (each one observing the results of the previous ones) and then simply
+/// discard them, without copying the root database.
+pub struct OverlaySubstateDatabase<'d, D> {
+    root: &'d D,
+    overlay: BTreeMap<DbPartitionKey, PartitionOverlay>,
+}
+
+/// Uncommitted changes of a single partition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PartitionOverlay {
+    /// Changes of individual substates, on top of the root's partition contents.
+    Delta {
+        substate_updates: BTreeMap<DbSortKey, DatabaseUpdate>,
+    },
+    /// Entire contents of the partition, replacing the root's partition contents.
+    Reset {
+        substate_values: BTreeMap<DbSortKey, DbSubstateValue>,
+    },
+}
+
+impl<'d, D> OverlaySubstateDatabase<'d, D> {
+    /// Creates an empty overlay on top of the given root database.
+    pub fn new(root: &'d D) -> Self {
+        Self {
+            root,
+            overlay: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the root database (i.e. without any changes from the overlay).
+    pub fn root(&self) -> &'d D {
+        self.root
+    }
+
+    /// Discards all changes committed to the overlay.
+    pub fn discard(&mut self) {
+        self.overlay.clear();
+    }
+
+    /// Returns all changes committed to the overlay, as a single set of [`DatabaseUpdates`] which
+    /// could be committed to the root database.
+    pub fn to_database_updates(&self) -> DatabaseUpdates {
+        let mut database_updates = DatabaseUpdates::default();
+        for (partition_key, partition_overlay) in &self.overlay {
+            let partition_updates = match partition_overlay {
+                PartitionOverlay::Delta { substate_updates } => PartitionDatabaseUpdates::Delta {
+                    substate_updates: substate_updates
+                        .iter()
+                        .map(|(sort_key, update)| (sort_key.clone(), update.clone()))
+                        .collect(),
+                },
+                PartitionOverlay::Reset { substate_values } => PartitionDatabaseUpdates::Reset {
+                    new_substate_values: substate_values
+                        .iter()
+                        .map(|(sort_key, value)| (sort_key.clone(), value.clone()))
+                        .collect(),
+                },
+            };
+            database_updates
+                .node_updates
+                .entry(partition_key.node_key.clone())
+                .or_default()
+                .partition_updates
+                .insert(partition_key.partition_num, partition_updates);
+        }
+        database_updates
+    }
+}
+
+impl<'d, D: SubstateDatabase> SubstateDatabase for OverlaySubstateDatabase<'d, D> {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        match self.overlay.get(partition_key) {
+            Some(PartitionOverlay::Delta { substate_updates }) => {
+                match substate_updates.get(sort_key) {
+                    Some(DatabaseUpdate::Set(value)) => Some(value.clone()),
+                    Some(DatabaseUpdate::Delete) => None,
+                    None => self.root.get_substate(partition_key, sort_key),
+                }
+            }
+            Some(PartitionOverlay::Reset { substate_values }) => {
+                substate_values.get(sort_key).cloned()
+            }
+            None => self.root.get_substate(partition_key, sort_key),
+        }
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        let range = (
+            from_sort_key
+                .map(|sort_key| Bound::Included(sort_key.clone()))
+                .unwrap_or(Bound::Unbounded),
+            Bound::Unbounded,
+        );
+        match self.overlay.get(partition_key) {
+            Some(PartitionOverlay::Delta { substate_updates }) => Box::new(
+                self.root
+                    .list_entries_from(partition_key, from_sort_key)
+                    .merge_join_by(
+                        substate_updates.range(range),
+                        |(root_sort_key, _), (overlay_sort_key, _)| {
+                            root_sort_key.cmp(*overlay_sort_key)
+                        },
+                    )
+                    .filter_map(|entry| match entry {
+                        EitherOrBoth::Left(root_entry) => Some(root_entry),
+                        EitherOrBoth::Right((sort_key, update))
+                        | EitherOrBoth::Both(_, (sort_key, update)) => match update {
+                            DatabaseUpdate::Set(value) => Some((sort_key.clone(), value.clone())),
+                            DatabaseUpdate::Delete => None,
+                        },
+                    }),
+            ),
+            Some(PartitionOverlay::Reset { substate_values }) => Box::new(
+                substate_values
+                    .range(range)
+                    .map(|(sort_key, value)| (sort_key.clone(), value.clone())),
+            ),
+            None => self.root.list_entries_from(partition_key, from_sort_key),
+        }
+    }
+}
+
+impl<'d, D: SubstateDatabase + ListableSubstateDatabase> ListableSubstateDatabase
+    for OverlaySubstateDatabase<'d, D>
+{
+    fn list_partition_keys(&self) -> Box<dyn Iterator<Item = DbPartitionKey> + '_> {
+        let root_partition_keys = self
+            .root
+            .list_partition_keys()
+            .filter(|partition_key| !self.overlay.contains_key(partition_key));
+        // The overlay may have emptied some of the root's partitions (or only deleted some
+        // non-existent substates) - we need to check for any remaining entries.
+        let overlay_partition_keys = self
+            .overlay
+            .keys()
+            .filter(|partition_key| self.list_entries(partition_key).next().is_some())
+            .cloned();
+        Box::new(root_partition_keys.chain(overlay_partition_keys))
+    }
+}
+
+impl<'d, D> CommittableSubstateDatabase for OverlaySubstateDatabase<'d, D> {
+    fn commit(&mut self, database_updates: &DatabaseUpdates) {
+        for (node_key, node_updates) in &database_updates.node_updates {
+            for (partition_num, partition_updates) in &node_updates.partition_updates {
+                let partition_key = DbPartitionKey {
+                    node_key: node_key.clone(),
+                    partition_num: *partition_num,
+                };
+                match partition_updates {
+                    PartitionDatabaseUpdates::Delta {
+                        substate_updates: new_substate_updates,
+                    } => match self.overlay.entry(partition_key).or_insert_with(|| {
+                        PartitionOverlay::Delta {
+                            substate_updates: BTreeMap::new(),
+                        }
+                    }) {
+                        PartitionOverlay::Delta { substate_updates } => {
+                            for (sort_key, update) in new_substate_updates {
+                                substate_updates.insert(sort_key.clone(), update.clone());
+                            }
+                        }
+                        PartitionOverlay::Reset { substate_values } => {
+                            for (sort_key, update) in new_substate_updates {
+                                match update {
+                                    DatabaseUpdate::Set(value) => {
+                                        substate_values.insert(sort_key.clone(), value.clone())
+                                    }
+                                    DatabaseUpdate::Delete => substate_values.remove(sort_key),
+                                };
+                            }
+                        }
+                    },
+                    PartitionDatabaseUpdates::Reset {
+                        new_substate_values,
+                    } => {
+                        self.overlay.insert(
+                            partition_key,
+                            PartitionOverlay::Reset {
+                                substate_values: new_substate_values
+                                    .iter()
+                                    .map(|(sort_key, value)| (sort_key.clone(), value.clone()))
+                                    .collect(),
+                            },
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory_db::InMemorySubstateDatabase;
+
+    #[test]
+    fn reads_through_to_root_until_overlaid() {
+        let mut root = InMemorySubstateDatabase::standard();
+        root.commit(&delta(0, vec![(1, Some(10)), (2, Some(20))]));
+        let mut overlay = OverlaySubstateDatabase::new(&root);
+        assert_eq!(read_all(&overlay, 0), vec![(1, 10), (2, 20)]);
+
+        overlay.commit(&delta(0, vec![(2, Some(21)), (3, Some(30)), (1, None)]));
+        assert_eq!(read_all(&overlay, 0), vec![(2, 21), (3, 30)]);
+        assert_eq!(overlay.get_substate(&partition_key(0), &sort_key(1)), None);
+        assert_eq!(read_all(overlay.root(), 0), vec![(1, 10), (2, 20)]);
+    }
+
+    #[test]
+    fn chained_commits_accumulate_and_can_be_discarded() {
+        let mut root = InMemorySubstateDatabase::standard();
+        root.commit(&delta(0, vec![(1, Some(10))]));
+        let mut overlay = OverlaySubstateDatabase::new(&root);
+
+        overlay.commit(&reset(0, vec![(5, 50)]));
+        overlay.commit(&delta(0, vec![(6, Some(60))]));
+        overlay.commit(&delta(1, vec![(7, Some(70))]));
+        assert_eq!(read_all(&overlay, 0), vec![(5, 50), (6, 60)]);
+        assert_eq!(read_all(&overlay, 1), vec![(7, 70)]);
+        assert_eq!(overlay.list_partition_keys().count(), 2);
+
+        let mut committed_root = root.clone();
+        committed_root.commit(&overlay.to_database_updates());
+        assert_eq!(read_all(&committed_root, 0), read_all(&overlay, 0));
+        assert_eq!(read_all(&committed_root, 1), read_all(&overlay, 1));
+
+        overlay.discard();
+        assert_eq!(read_all(&overlay, 0), vec![(1, 10)]);
+        assert_eq!(read_all(&overlay, 1), vec![]);
+    }
+
+    #[test]
+    fn lists_entries_from_given_sort_key() {
+        let mut root = InMemorySubstateDatabase::standard();
+        root.commit(&delta(0, vec![(1, Some(10)), (4, Some(40))]));
+        let mut overlay = OverlaySubstateDatabase::new(&root);
+        overlay.commit(&delta(0, vec![(2, Some(20)), (3, Some(30)), (4, None)]));
+
+        let entries = overlay
+            .list_entries_from(&partition_key(0), Some(&sort_key(2)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            vec![(sort_key(2), vec![20]), (sort_key(3), vec![30])]
+        );
+    }
+
+    #[test]
+    fn does_not_list_partitions_emptied_by_overlay() {
+        let mut root = InMemorySubstateDatabase::standard();
+        root.commit(&delta(0, vec![(1, Some(10))]));
+        root.commit(&delta(1, vec![(1, Some(10))]));
+        let mut overlay = OverlaySubstateDatabase::new(&root);
+        overlay.commit(&delta(0, vec![(1, None)]));
+        overlay.commit(&reset(1, vec![]));
+        overlay.commit(&delta(2, vec![(1, None)]));
+
+        assert_eq!(overlay.list_partition_keys().count(), 0);
+    }
+
+    fn read_all<S: SubstateDatabase>(db: &S, partition_num: u8) -> Vec<(u8, u8)> {
+        db.list_entries(&partition_key(partition_num))
+            .map(|(sort_key, value)| (sort_key.0[0], value[0]))
+            .collect()
+    }
+
+    fn delta(partition_num: u8, changes: Vec<(u8, Option<u8>)>) -> DatabaseUpdates {
+        DatabaseUpdates::from_delta_maps(indexmap!(
+            partition_key(partition_num) => changes
+                .into_iter()
+                .map(|(sort_key_byte, value)| (
+                    sort_key(sort_key_byte),
+                    value
+                        .map(|value| DatabaseUpdate::Set(vec![value]))
+                        .unwrap_or(DatabaseUpdate::Delete),
+                ))
+                .collect()
+        ))
+    }
+
+    fn reset(partition_num: u8, values: Vec<(u8, u8)>) -> DatabaseUpdates {
+        DatabaseUpdates {
+            node_updates: indexmap!(
+                partition_key(partition_num).node_key => NodeDatabaseUpdates {
+                    partition_updates: indexmap!(
+                        partition_num => PartitionDatabaseUpdates::Reset {
+                            new_substate_values: values
+                                .into_iter()
+                                .map(|(sort_key_byte, value)| (sort_key(sort_key_byte), vec![value]))
+                                .collect()
+                        }
+                    )
+                }
+            ),
+        }
+    }
+
+    fn partition_key(partition_num: u8) -> DbPartitionKey {
+        DbPartitionKey {
+            node_key: vec![7],
+            partition_num,
+        }
+    }
+
+    fn sort_key(byte: u8) -> DbSortKey {
+        DbSortKey(vec![byte])
+    }
+}
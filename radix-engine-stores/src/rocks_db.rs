@@ -1,11 +1,15 @@
+use crate::state_history_support::StateHistoryStore;
 use itertools::Itertools;
 use radix_engine_common::constants::MAX_SUBSTATE_KEY_SIZE;
+use radix_engine_common::data::scrypto::{scrypto_decode, scrypto_encode};
+use radix_engine_derive::ScryptoSbor;
 use radix_engine_store_interface::interface::*;
 pub use rocksdb::{BlockBasedOptions, LogLevel, Options};
 use rocksdb::{
     ColumnFamily, ColumnFamilyDescriptor, DBWithThreadMode, Direction, IteratorMode,
-    SingleThreaded, DB,
+    SingleThreaded, WriteBatch, DB,
 };
+use sbor::rust::borrow::Cow;
 use sbor::rust::prelude::*;
 use std::path::PathBuf;
 use utils::copy_u8_array;
@@ -14,9 +18,19 @@ pub struct RocksdbSubstateStore {
     db: DBWithThreadMode<SingleThreaded>,
 }
 
+/// The range of the state versions retained in the [`RocksdbSubstateStore::STATE_HISTORY_CF`].
+#[derive(Debug, Clone, ScryptoSbor)]
+struct StateHistoryMetadata {
+    earliest_available_version: u64,
+    current_version: u64,
+}
+
 impl RocksdbSubstateStore {
-    // Technically we don't need CFs here at all; however, delete range API is only available for CF
+    // Technically we don't need a CF for substates; however, delete range API is only available for CF
     const THE_ONLY_CF: &'static str = "the_only";
+    // Holds the reverting updates of each retained state version (keyed by the big-endian version),
+    // and the `StateHistoryMetadata` (under the empty key), when used as a `StateHistoryStore`
+    const STATE_HISTORY_CF: &'static str = "state_history";
 
     pub fn standard(root: PathBuf) -> Self {
         Self::with_options(&Options::default(), root)
@@ -28,10 +42,10 @@ impl RocksdbSubstateStore {
         let db = DB::open_cf_descriptors(
             &options,
             root.as_path(),
-            vec![ColumnFamilyDescriptor::new(
-                Self::THE_ONLY_CF,
-                Options::default(),
-            )],
+            vec![
+                ColumnFamilyDescriptor::new(Self::THE_ONLY_CF, Options::default()),
+                ColumnFamilyDescriptor::new(Self::STATE_HISTORY_CF, Options::default()),
+            ],
         )
         .unwrap();
         Self { db }
@@ -40,6 +54,63 @@ impl RocksdbSubstateStore {
     fn cf(&self) -> &ColumnFamily {
         self.db.cf_handle(Self::THE_ONLY_CF).unwrap()
     }
+
+    fn state_history_cf(&self) -> &ColumnFamily {
+        self.db.cf_handle(Self::STATE_HISTORY_CF).unwrap()
+    }
+
+    fn get_state_history_metadata(&self) -> StateHistoryMetadata {
+        self.db
+            .get_cf(self.state_history_cf(), [])
+            .expect("IO error")
+            .map(|bytes| scrypto_decode::<StateHistoryMetadata>(&bytes).unwrap())
+            .unwrap_or(StateHistoryMetadata {
+                earliest_available_version: 0,
+                current_version: 0,
+            })
+    }
+
+    fn write_updates(&self, batch: &mut WriteBatch, database_updates: &DatabaseUpdates) {
+        for (node_key, node_updates) in &database_updates.node_updates {
+            for (partition_num, partition_updates) in &node_updates.partition_updates {
+                let partition_key = DbPartitionKey {
+                    node_key: node_key.clone(),
+                    partition_num: *partition_num,
+                };
+                match partition_updates {
+                    PartitionDatabaseUpdates::Delta { substate_updates } => {
+                        for (sort_key, update) in substate_updates {
+                            let key_bytes = encode_to_rocksdb_bytes(&partition_key, sort_key);
+                            match update {
+                                DatabaseUpdate::Set(value_bytes) => {
+                                    batch.put_cf(self.cf(), key_bytes, value_bytes)
+                                }
+                                DatabaseUpdate::Delete => batch.delete_cf(self.cf(), key_bytes),
+                            }
+                        }
+                    }
+                    PartitionDatabaseUpdates::Reset {
+                        new_substate_values,
+                    } => {
+                        // Note: a plain `delete_range()` is missing from rocksdb's API, and
+                        // (at the moment of writing) this is the only reason of having CF.
+                        batch.delete_range_cf(
+                            self.cf(),
+                            encode_to_rocksdb_bytes(&partition_key, &DbSortKey(vec![])),
+                            encode_to_rocksdb_bytes(
+                                &partition_key,
+                                &DbSortKey(vec![u8::MAX; 2 * MAX_SUBSTATE_KEY_SIZE]),
+                            ),
+                        );
+                        for (sort_key, value_bytes) in new_substate_values {
+                            let key_bytes = encode_to_rocksdb_bytes(&partition_key, sort_key);
+                            batch.put_cf(self.cf(), key_bytes, value_bytes);
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
 
 impl SubstateDatabase for RocksdbSubstateStore {
@@ -81,50 +152,73 @@ impl SubstateDatabase for RocksdbSubstateStore {
 
 impl CommittableSubstateDatabase for RocksdbSubstateStore {
     fn commit(&mut self, database_updates: &DatabaseUpdates) {
-        for (node_key, node_updates) in &database_updates.node_updates {
-            for (partition_num, partition_updates) in &node_updates.partition_updates {
-                let partition_key = DbPartitionKey {
-                    node_key: node_key.clone(),
-                    partition_num: *partition_num,
-                };
-                match partition_updates {
-                    PartitionDatabaseUpdates::Delta { substate_updates } => {
-                        for (sort_key, update) in substate_updates {
-                            let key_bytes = encode_to_rocksdb_bytes(&partition_key, sort_key);
-                            match update {
-                                DatabaseUpdate::Set(value_bytes) => {
-                                    self.db.put_cf(self.cf(), key_bytes, value_bytes)
-                                }
-                                DatabaseUpdate::Delete => self.db.delete_cf(self.cf(), key_bytes),
-                            }
-                            .expect("IO error");
-                        }
-                    }
-                    PartitionDatabaseUpdates::Reset {
-                        new_substate_values,
-                    } => {
-                        // Note: a plain `delete_range()` is missing from rocksdb's API, and
-                        // (at the moment of writing) this is the only reason of having CF.
-                        self.db
-                            .delete_range_cf(
-                                self.cf(),
-                                encode_to_rocksdb_bytes(&partition_key, &DbSortKey(vec![])),
-                                encode_to_rocksdb_bytes(
-                                    &partition_key,
-                                    &DbSortKey(vec![u8::MAX; 2 * MAX_SUBSTATE_KEY_SIZE]),
-                                ),
-                            )
-                            .expect("IO error");
-                        for (sort_key, value_bytes) in new_substate_values {
-                            let key_bytes = encode_to_rocksdb_bytes(&partition_key, sort_key);
-                            self.db
-                                .put_cf(self.cf(), key_bytes, value_bytes)
-                                .expect("IO error");
-                        }
-                    }
-                }
-            }
+        let mut batch = WriteBatch::default();
+        self.write_updates(&mut batch, database_updates);
+        self.db.write(batch).expect("IO error");
+    }
+}
+
+/// Persists the history in a column family, committed atomically with the substates, so that it
+/// survives restarts. Note: once used as a [`StateHistoryStore`], the database should only be
+/// committed to via [`StateHistoryStore::commit_with_history()`].
+impl StateHistoryStore for RocksdbSubstateStore {
+    fn get_current_version(&self) -> u64 {
+        self.get_state_history_metadata().current_version
+    }
+
+    fn get_earliest_available_version(&self) -> u64 {
+        self.get_state_history_metadata().earliest_available_version
+    }
+
+    fn get_reverting_updates(&self, state_version: u64) -> Cow<'_, DatabaseUpdates> {
+        let bytes = self
+            .db
+            .get_cf(self.state_history_cf(), state_version.to_be_bytes())
+            .expect("IO error")
+            .unwrap_or_else(|| panic!("state version {} is not available", state_version));
+        Cow::Owned(scrypto_decode(&bytes).unwrap())
+    }
+
+    fn commit_with_history(
+        &mut self,
+        database_updates: &DatabaseUpdates,
+        reverting_updates: Option<DatabaseUpdates>,
+        max_history_len: usize,
+    ) {
+        let metadata = self.get_state_history_metadata();
+        let next_version = metadata.current_version + 1;
+
+        let mut batch = WriteBatch::default();
+        self.write_updates(&mut batch, database_updates);
+        let mut earliest_available_version = next_version;
+        if let Some(reverting_updates) = reverting_updates {
+            batch.put_cf(
+                self.state_history_cf(),
+                next_version.to_be_bytes(),
+                scrypto_encode(&reverting_updates).unwrap(),
+            );
+            earliest_available_version = metadata
+                .earliest_available_version
+                .max(next_version.saturating_sub(max_history_len as u64));
         }
+        if earliest_available_version > metadata.earliest_available_version {
+            // The reverting updates of version `N` are needed to read version `N - 1`
+            batch.delete_range_cf(
+                self.state_history_cf(),
+                (metadata.earliest_available_version + 1).to_be_bytes(),
+                (earliest_available_version + 1).to_be_bytes(),
+            );
+        }
+        batch.put_cf(
+            self.state_history_cf(),
+            [],
+            scrypto_encode(&StateHistoryMetadata {
+                earliest_available_version,
+                current_version: next_version,
+            })
+            .unwrap(),
+        );
+        self.db.write(batch).expect("IO error");
     }
 }
 
@@ -224,4 +318,57 @@ mod tests {
         });
         assert_eq!(db.list_partition_keys().count(), 8);
     }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn test_state_history_survives_reopening() {
+        use crate::state_history_support::StateHistoryTrackingDatabase;
+        use radix_engine_store_interface::interface::VersionedSubstateDatabase;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let partition_key = DbPartitionKey {
+            node_key: vec![1],
+            partition_num: 0,
+        };
+        let sort_key = DbSortKey(vec![2]);
+        let set = |value: u8| DatabaseUpdates {
+            node_updates: indexmap! {
+                vec![1] => NodeDatabaseUpdates {
+                    partition_updates: indexmap! {
+                        0 => PartitionDatabaseUpdates::Delta {
+                            substate_updates: indexmap! {
+                                DbSortKey(vec![2]) => DatabaseUpdate::Set(vec![value])
+                            }
+                        }
+                    }
+                }
+            },
+        };
+
+        {
+            let mut db = StateHistoryTrackingDatabase::with_store(
+                RocksdbSubstateStore::standard(temp_dir.path().to_path_buf()),
+                2,
+            );
+            for value in 1..=3 {
+                db.commit(&set(value));
+            }
+        }
+
+        let db = StateHistoryTrackingDatabase::with_store(
+            RocksdbSubstateStore::standard(temp_dir.path().to_path_buf()),
+            2,
+        );
+        assert_eq!(db.get_current_version(), 3);
+        assert_eq!(db.get_earliest_available_version(), 1);
+        assert_eq!(
+            db.get_substate_at_version(1, &partition_key, &sort_key),
+            Some(vec![1])
+        );
+        assert_eq!(
+            db.get_substate_at_version(2, &partition_key, &sort_key),
+            Some(vec![2])
+        );
+        assert_eq!(db.get_substate(&partition_key, &sort_key), Some(vec![3]));
+    }
 }
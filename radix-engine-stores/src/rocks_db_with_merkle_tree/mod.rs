@@ -1,3 +1,5 @@
+use crate::hash_tree::get_substate_proof_at_version;
+use crate::hash_tree::proof::{SubstateProof, SubstateProofError};
 use crate::hash_tree::tree_store::{
     encode_key, NodeKey, ReadableTreeStore, StaleTreePart, TreeNode, TreeNodeV1, VersionedTreeNode,
 };
@@ -94,6 +96,27 @@ impl RocksDBWithMerkleTreeSubstateStore {
             })
             .unwrap_or(Hash([0u8; Hash::LENGTH]))
     }
+
+    /// Generates a proof of inclusion (or exclusion) of the given substate at the given state
+    /// version, verifiable against that version's state root hash (see
+    /// [`SubstateProof::verify()`]).
+    /// Note: historical versions are only available if this store was opened with pruning disabled.
+    pub fn get_substate_proof(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+        state_version: u64,
+    ) -> Result<SubstateProof, SubstateProofError> {
+        if state_version > self.get_current_version() {
+            return Err(SubstateProofError::StateVersionNotFound(state_version));
+        }
+        get_substate_proof_at_version(
+            self,
+            Some(state_version).filter(|version| *version > 0),
+            partition_key,
+            sort_key,
+        )
+    }
 }
 
 impl SubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
@@ -0,0 +1,476 @@
+use radix_engine_store_interface::interface::*;
+use sbor::rust::borrow::Cow;
+use sbor::rust::prelude::*;
+
+/// A storage of the history retained by a [`StateHistoryTrackingDatabase`], i.e. of the
+/// "reverting" [`DatabaseUpdates`] of its retained commits (which would bring the state at version
+/// `N` back to version `N - 1`).
+///
+/// Implementations which commit the substates and the history atomically (e.g. the RocksDB-based
+/// one, which keeps the history in a column family) also retain the history across restarts.
+pub trait StateHistoryStore {
+    /// Returns the version of the latest state (i.e. the number of commits recorded so far).
+    fn get_current_version(&self) -> u64;
+
+    /// Returns the earliest state version which can still be read.
+    fn get_earliest_available_version(&self) -> u64;
+
+    /// Returns the updates which revert the state at the given version into the previous one.
+    /// Panics if the version is not in the `(earliest available, current]` range.
+    fn get_reverting_updates(&self, state_version: u64) -> Cow<'_, DatabaseUpdates>;
+
+    /// Commits the given updates as the next state version, recording the given updates which
+    /// revert them (unless the history is disabled, i.e. `max_history_len == 0`), and forgetting
+    /// the oldest ones if more than `max_history_len` would be retained.
+    fn commit_with_history(
+        &mut self,
+        database_updates: &DatabaseUpdates,
+        reverting_updates: Option<DatabaseUpdates>,
+        max_history_len: usize,
+    );
+}
+
+/// A [`StateHistoryStore`] wrapping any committable database, which holds the history in memory.
+/// The history is lost when it is dropped (e.g. on restart), and the state version `0` denotes the
+/// state of the underlying database at the moment of wrapping.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InMemoryStateHistory<D> {
+    underlying: D,
+    current_version: u64,
+    /// Reverting updates of the retained commits, ordered from the oldest to the most recent one
+    /// (i.e. the last element reverts `current_version` into `current_version - 1`).
+    reverting_updates: VecDeque<DatabaseUpdates>,
+}
+
+impl<D> InMemoryStateHistory<D> {
+    pub fn new(underlying: D) -> Self {
+        InMemoryStateHistory {
+            underlying,
+            current_version: 0,
+            reverting_updates: VecDeque::new(),
+        }
+    }
+}
+
+impl<D: CommittableSubstateDatabase> StateHistoryStore for InMemoryStateHistory<D> {
+    fn get_current_version(&self) -> u64 {
+        self.current_version
+    }
+
+    fn get_earliest_available_version(&self) -> u64 {
+        self.current_version - self.reverting_updates.len() as u64
+    }
+
+    fn get_reverting_updates(&self, state_version: u64) -> Cow<'_, DatabaseUpdates> {
+        let index = state_version - self.get_earliest_available_version() - 1;
+        Cow::Borrowed(&self.reverting_updates[index as usize])
+    }
+
+    fn commit_with_history(
+        &mut self,
+        database_updates: &DatabaseUpdates,
+        reverting_updates: Option<DatabaseUpdates>,
+        max_history_len: usize,
+    ) {
+        self.underlying.commit(database_updates);
+        self.current_version += 1;
+        self.reverting_updates.extend(reverting_updates);
+        while self.reverting_updates.len() > max_history_len {
+            self.reverting_updates.pop_front();
+        }
+    }
+}
+
+impl<D: SubstateDatabase> SubstateDatabase for InMemoryStateHistory<D> {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        self.underlying.get_substate(partition_key, sort_key)
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.underlying
+            .list_entries_from(partition_key, from_sort_key)
+    }
+}
+
+impl<D: ListableSubstateDatabase> ListableSubstateDatabase for InMemoryStateHistory<D> {
+    fn list_partition_keys(&self) -> Box<dyn Iterator<Item = DbPartitionKey> + '_> {
+        self.underlying.list_partition_keys()
+    }
+}
+
+/// A wrapper of a database, which retains a bounded history of its past states (so that they can
+/// be read via [`VersionedSubstateDatabase`]).
+///
+/// The history is captured as a sequence of "reverting" [`DatabaseUpdates`] (i.e. updates which
+/// would bring the state at version `N` back to version `N - 1`), computed from the underlying
+/// database before each commit, and kept in a [`StateHistoryStore`]:
+/// * [`StateHistoryTrackingDatabase::new()`] works with any underlying database, but holds the
+///   history in memory (see [`InMemoryStateHistory`]), so it resets on restart;
+/// * [`StateHistoryTrackingDatabase::with_store()`] uses a store which persists the history (e.g.
+///   `RocksdbSubstateStore`), so it survives restarts.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct StateHistoryTrackingDatabase<S> {
+    store: S,
+    max_history_len: usize,
+}
+
+impl<D> StateHistoryTrackingDatabase<InMemoryStateHistory<D>> {
+    /// Wraps the given database, retaining at most `max_history_len` past states in memory.
+    pub fn new(underlying: D, max_history_len: usize) -> Self {
+        Self::with_store(InMemoryStateHistory::new(underlying), max_history_len)
+    }
+}
+
+impl<S> StateHistoryTrackingDatabase<S> {
+    /// Wraps the given store, retaining at most `max_history_len` past states in it.
+    pub fn with_store(store: S, max_history_len: usize) -> Self {
+        StateHistoryTrackingDatabase {
+            store,
+            max_history_len,
+        }
+    }
+}
+
+impl<S: StateHistoryStore> StateHistoryTrackingDatabase<S> {
+    /// Returns the given partition's reverting updates of all retained commits which happened
+    /// after the given version, ordered from the oldest to the most recent one.
+    fn reverting_partition_updates_since(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+    ) -> Vec<PartitionDatabaseUpdates> {
+        let earliest_available_version = self.store.get_earliest_available_version();
+        let current_version = self.store.get_current_version();
+        if state_version < earliest_available_version || state_version > current_version {
+            panic!(
+                "state version {} is not available (available range: {}..={})",
+                state_version, earliest_available_version, current_version
+            );
+        }
+        (state_version + 1..=current_version)
+            .filter_map(|version| {
+                self.store
+                    .get_reverting_updates(version)
+                    .node_updates
+                    .get(&partition_key.node_key)
+                    .and_then(|node_updates| {
+                        node_updates
+                            .partition_updates
+                            .get(&partition_key.partition_num)
+                    })
+                    .cloned()
+            })
+            .collect()
+    }
+}
+
+impl<S: SubstateDatabase> StateHistoryTrackingDatabase<S> {
+    /// Computes the updates which would revert the given updates (if applied after them).
+    fn compute_reverting_updates(&self, database_updates: &DatabaseUpdates) -> DatabaseUpdates {
+        let mut reverting_updates = DatabaseUpdates::default();
+        for (node_key, node_updates) in &database_updates.node_updates {
+            let reverting_node_updates = reverting_updates
+                .node_updates
+                .entry(node_key.clone())
+                .or_default();
+            for (partition_num, partition_updates) in &node_updates.partition_updates {
+                let partition_key = DbPartitionKey {
+                    node_key: node_key.clone(),
+                    partition_num: *partition_num,
+                };
+                let reverting_partition_updates = match partition_updates {
+                    PartitionDatabaseUpdates::Delta { substate_updates } => {
+                        PartitionDatabaseUpdates::Delta {
+                            substate_updates: substate_updates
+                                .keys()
+                                .map(|sort_key| {
+                                    let previous_update =
+                                        match self.store.get_substate(&partition_key, sort_key) {
+                                            Some(value) => DatabaseUpdate::Set(value),
+                                            None => DatabaseUpdate::Delete,
+                                        };
+                                    (sort_key.clone(), previous_update)
+                                })
+                                .collect(),
+                        }
+                    }
+                    PartitionDatabaseUpdates::Reset { .. } => PartitionDatabaseUpdates::Reset {
+                        new_substate_values: self.store.list_entries(&partition_key).collect(),
+                    },
+                };
+                reverting_node_updates
+                    .partition_updates
+                    .insert(*partition_num, reverting_partition_updates);
+            }
+        }
+        reverting_updates
+    }
+}
+
+impl<S> VersionedSubstateDatabase for StateHistoryTrackingDatabase<S>
+where
+    S: SubstateDatabase + StateHistoryStore,
+{
+    fn get_current_version(&self) -> u64 {
+        self.store.get_current_version()
+    }
+
+    fn get_earliest_available_version(&self) -> u64 {
+        self.store.get_earliest_available_version()
+    }
+
+    fn get_substate_at_version(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        // The first commit (after the requested version) which touched the substate has captured
+        // its value from the requested version.
+        for partition_updates in
+            self.reverting_partition_updates_since(state_version, partition_key)
+        {
+            match partition_updates {
+                PartitionDatabaseUpdates::Delta { substate_updates } => {
+                    if let Some(update) = substate_updates.get(sort_key) {
+                        return match update {
+                            DatabaseUpdate::Set(value) => Some(value.clone()),
+                            DatabaseUpdate::Delete => None,
+                        };
+                    }
+                }
+                PartitionDatabaseUpdates::Reset {
+                    new_substate_values,
+                } => {
+                    return new_substate_values.get(sort_key).cloned();
+                }
+            }
+        }
+        self.store.get_substate(partition_key, sort_key)
+    }
+
+    fn list_entries_from_at_version(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        let reverting_partition_updates =
+            self.reverting_partition_updates_since(state_version, partition_key);
+        if reverting_partition_updates.is_empty() {
+            return self.store.list_entries_from(partition_key, from_sort_key);
+        }
+
+        // The partition was touched since the requested version - we need to materialize its
+        // historical contents by applying the reverting updates (from the most recent one).
+        let mut entries = self
+            .store
+            .list_entries(partition_key)
+            .collect::<BTreeMap<_, _>>();
+        for partition_updates in reverting_partition_updates.into_iter().rev() {
+            match partition_updates {
+                PartitionDatabaseUpdates::Delta { substate_updates } => {
+                    for (sort_key, update) in substate_updates {
+                        match update {
+                            DatabaseUpdate::Set(value) => entries.insert(sort_key, value),
+                            DatabaseUpdate::Delete => entries.remove(&sort_key),
+                        };
+                    }
+                }
+                PartitionDatabaseUpdates::Reset {
+                    new_substate_values,
+                } => {
+                    entries = new_substate_values.into_iter().collect();
+                }
+            }
+        }
+        let from_sort_key = from_sort_key.cloned();
+        Box::new(
+            entries
+                .into_iter()
+                .skip_while(move |(sort_key, _value)| Some(sort_key) < from_sort_key.as_ref()),
+        )
+    }
+}
+
+impl<S: SubstateDatabase> SubstateDatabase for StateHistoryTrackingDatabase<S> {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        self.store.get_substate(partition_key, sort_key)
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.store.list_entries_from(partition_key, from_sort_key)
+    }
+}
+
+impl<S: ListableSubstateDatabase> ListableSubstateDatabase for StateHistoryTrackingDatabase<S> {
+    fn list_partition_keys(&self) -> Box<dyn Iterator<Item = DbPartitionKey> + '_> {
+        self.store.list_partition_keys()
+    }
+}
+
+impl<S: SubstateDatabase + StateHistoryStore> CommittableSubstateDatabase
+    for StateHistoryTrackingDatabase<S>
+{
+    fn commit(&mut self, database_updates: &DatabaseUpdates) {
+        let reverting_updates = if self.max_history_len > 0 {
+            Some(self.compute_reverting_updates(database_updates))
+        } else {
+            None
+        };
+        self.store
+            .commit_with_history(database_updates, reverting_updates, self.max_history_len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory_db::InMemorySubstateDatabase;
+
+    #[test]
+    fn reads_substates_at_past_versions() {
+        let mut db = StateHistoryTrackingDatabase::new(InMemorySubstateDatabase::standard(), 10);
+        db.commit(&delta(vec![(1, Some(10)), (2, Some(20))]));
+        db.commit(&delta(vec![(1, Some(11)), (3, Some(30))]));
+        db.commit(&delta(vec![(2, None)]));
+
+        assert_eq!(db.get_current_version(), 3);
+        assert_eq!(db.get_earliest_available_version(), 0);
+        assert_eq!(read_all(&db, 0), vec![]);
+        assert_eq!(read_all(&db, 1), vec![(1, 10), (2, 20)]);
+        assert_eq!(read_all(&db, 2), vec![(1, 11), (2, 20), (3, 30)]);
+        assert_eq!(read_all(&db, 3), vec![(1, 11), (3, 30)]);
+        for (version, expected) in [(0, None), (1, Some(10)), (2, Some(11)), (3, Some(11))] {
+            assert_eq!(
+                db.get_substate_at_version(version, &partition_key(), &sort_key(1)),
+                expected.map(|value| vec![value])
+            );
+        }
+    }
+
+    #[test]
+    fn reads_reset_partitions_at_past_versions() {
+        let mut db = StateHistoryTrackingDatabase::new(InMemorySubstateDatabase::standard(), 10);
+        db.commit(&delta(vec![(1, Some(10)), (2, Some(20))]));
+        db.commit(&reset(vec![(3, 30)]));
+        db.commit(&delta(vec![(4, Some(40))]));
+
+        assert_eq!(read_all(&db, 1), vec![(1, 10), (2, 20)]);
+        assert_eq!(read_all(&db, 2), vec![(3, 30)]);
+        assert_eq!(read_all(&db, 3), vec![(3, 30), (4, 40)]);
+        assert_eq!(
+            db.get_substate_at_version(1, &partition_key(), &sort_key(2)),
+            Some(vec![20])
+        );
+        assert_eq!(
+            db.get_substate_at_version(2, &partition_key(), &sort_key(2)),
+            None
+        );
+    }
+
+    #[test]
+    fn lists_entries_from_given_sort_key_at_past_version() {
+        let mut db = StateHistoryTrackingDatabase::new(InMemorySubstateDatabase::standard(), 10);
+        db.commit(&delta(vec![(1, Some(10)), (3, Some(30)), (5, Some(50))]));
+        db.commit(&delta(vec![(3, None)]));
+
+        let entries = db
+            .list_entries_from_at_version(1, &partition_key(), Some(&sort_key(2)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            vec![(sort_key(3), vec![30]), (sort_key(5), vec![50])]
+        );
+    }
+
+    #[test]
+    fn retains_bounded_history() {
+        let mut db = StateHistoryTrackingDatabase::new(InMemorySubstateDatabase::standard(), 2);
+        for value in 1..=5 {
+            db.commit(&delta(vec![(1, Some(value))]));
+        }
+
+        assert_eq!(db.get_current_version(), 5);
+        assert_eq!(db.get_earliest_available_version(), 3);
+        assert_eq!(read_all(&db, 3), vec![(1, 3)]);
+        assert_eq!(
+            db.at_version(2).err(),
+            Some(StateVersionNotAvailableError {
+                requested_version: 2,
+                earliest_available_version: 3,
+                current_version: 5,
+            })
+        );
+        assert!(db.at_version(6).is_err());
+    }
+
+    fn read_all(
+        db: &StateHistoryTrackingDatabase<InMemoryStateHistory<InMemorySubstateDatabase>>,
+        version: u64,
+    ) -> Vec<(u8, u8)> {
+        db.at_version(version)
+            .unwrap()
+            .list_entries(&partition_key())
+            .map(|(sort_key, value)| (sort_key.0[0], value[0]))
+            .collect()
+    }
+
+    fn delta(changes: Vec<(u8, Option<u8>)>) -> DatabaseUpdates {
+        DatabaseUpdates::from_delta_maps(indexmap!(
+            partition_key() => changes
+                .into_iter()
+                .map(|(sort_key_byte, value)| (
+                    sort_key(sort_key_byte),
+                    value
+                        .map(|value| DatabaseUpdate::Set(vec![value]))
+                        .unwrap_or(DatabaseUpdate::Delete),
+                ))
+                .collect()
+        ))
+    }
+
+    fn reset(values: Vec<(u8, u8)>) -> DatabaseUpdates {
+        DatabaseUpdates {
+            node_updates: indexmap!(
+                partition_key().node_key => NodeDatabaseUpdates {
+                    partition_updates: indexmap!(
+                        partition_key().partition_num => PartitionDatabaseUpdates::Reset {
+                            new_substate_values: values
+                                .into_iter()
+                                .map(|(sort_key_byte, value)| (sort_key(sort_key_byte), vec![value]))
+                                .collect()
+                        }
+                    )
+                }
+            ),
+        }
+    }
+
+    fn partition_key() -> DbPartitionKey {
+        DbPartitionKey {
+            node_key: vec![7],
+            partition_num: 3,
+        }
+    }
+
+    fn sort_key(byte: u8) -> DbSortKey {
+        DbSortKey(vec![byte])
+    }
+}
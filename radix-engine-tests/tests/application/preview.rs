@@ -1,8 +1,11 @@
 use radix_engine::system::system_modules::costing::FeeTable;
 use radix_engine::transaction::CostingParameters;
 use radix_engine::transaction::ExecutionConfig;
+use radix_engine::transaction::DEFAULT_FEE_ESTIMATION_MARGIN_PERCENTAGE;
 use radix_engine::types::*;
-use radix_engine_interface::rule;
+use radix_engine_interface::api::node_modules::ModuleConfig;
+use radix_engine_interface::{metadata, metadata_init, rule};
+use radix_engine_stores::overlay_db::OverlaySubstateDatabase;
 use scrypto_unit::*;
 use transaction::prelude::*;
 use transaction::validation::NotarizedTransactionValidator;
@@ -143,6 +146,91 @@ fn test_assume_all_signature_proofs_flag_method_authorization() {
     result.unwrap().expect_commit_success();
 }
 
+#[test]
+fn test_chained_previews_on_overlay_database_do_not_affect_actual_state() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let preview_flags = PreviewFlags {
+        use_free_credit: true,
+        assume_all_signature_proofs: true,
+        skip_epoch_check: false,
+    };
+    let mut overlay = OverlaySubstateDatabase::new(test_runner.substate_db());
+
+    // Act: preview a resource creation, followed by a transfer of the (previewed) resource
+    let create_receipt = test_runner.preview_manifest_onto(
+        &mut overlay,
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .new_token_fixed(OwnerRole::None, metadata!(), dec!(100))
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+        vec![],
+        0,
+        preview_flags.clone(),
+    );
+    let resource_address = create_receipt
+        .expect_commit_success()
+        .new_resource_addresses()[0];
+    let transfer_receipt = test_runner.preview_manifest_onto(
+        &mut overlay,
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account, resource_address, dec!(30))
+            .try_deposit_entire_worktop_or_abort(other_account, None)
+            .build(),
+        vec![],
+        0,
+        preview_flags,
+    );
+
+    // Assert
+    transfer_receipt.expect_commit_success();
+    drop(overlay);
+    assert_eq!(
+        test_runner.get_component_balance(account, resource_address),
+        Decimal::ZERO
+    );
+    assert_eq!(
+        test_runner.get_component_balance(other_account, resource_address),
+        Decimal::ZERO
+    );
+}
+
+#[test]
+fn test_estimated_fee_is_sufficient_for_actual_execution() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let transfer = |builder: ManifestBuilder| {
+        builder
+            .withdraw_from_account(account, XRD, dec!(10))
+            .try_deposit_entire_worktop_or_abort(other_account, None)
+            .build()
+    };
+
+    // Act
+    let estimate = test_runner
+        .estimate_fee(
+            transfer(ManifestBuilder::new()),
+            vec![public_key.into()],
+            DEFAULT_FEE_ESTIMATION_MARGIN_PERCENTAGE,
+        )
+        .unwrap();
+    let receipt = test_runner.execute_manifest(
+        transfer(ManifestBuilder::new().lock_fee(account, estimate.recommended_lock_fee())),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert!(estimate.execution_cost_in_xrd > estimate.fee_summary.total_execution_cost_in_xrd);
+    assert!(receipt.fee_summary.total_cost() <= estimate.recommended_lock_fee());
+}
+
 fn prepare_matching_test_tx_and_preview_intent(
     test_runner: &mut DefaultTestRunner,
     network: &NetworkDefinition,
@@ -0,0 +1,96 @@
+use radix_engine::transaction::{CostingParameters, ExecutionConfig};
+use radix_engine::types::*;
+use radix_engine::vm::wasm::{DefaultWasmEngine, WasmValidatorConfigV1};
+use radix_engine::vm::{DefaultNativeVm, ScryptoVm, Vm};
+use radix_engine_stores::memory_db::InMemorySubstateDatabase;
+use scrypto_unit::*;
+use transaction::prelude::*;
+use transaction_scenarios::recording::RecordedScenario;
+use transaction_scenarios::runners::replayer::*;
+
+#[test]
+fn replaying_recorded_session_results_in_same_state() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_recording("transfer_between_accounts")
+        .build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    test_runner.set_current_epoch(Epoch::of(50));
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account, XRD, dec!(100))
+            .try_deposit_entire_worktop_or_abort(other_account, None)
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+    let recorded_scenario =
+        RecordedScenario::from_bytes(&test_runner.recorded_scenario().unwrap().to_bytes().unwrap())
+            .unwrap();
+
+    // Act
+    let scrypto_vm = ScryptoVm {
+        wasm_engine: DefaultWasmEngine::default(),
+        wasm_validator_config: WasmValidatorConfigV1::new(),
+    };
+    let native_vm = DefaultNativeVm::new();
+    let vm = Vm::new(&scrypto_vm, native_vm);
+    let mut substate_db = InMemorySubstateDatabase::standard();
+    bootstrap_for_recorded_scenario(
+        &mut substate_db,
+        vm.clone(),
+        &recorded_scenario.protocol_updates,
+    );
+    let mut replayed_receipts = 0;
+    run_recorded_scenario(&mut substate_db, vm, &recorded_scenario, |receipt| {
+        receipt.expect_commit_success();
+        replayed_receipts += 1;
+    })
+    .unwrap();
+
+    // Assert
+    assert_eq!(replayed_receipts, 5);
+    assert_eq!(&substate_db, test_runner.substate_db());
+}
+
+#[test]
+fn transaction_executed_from_bare_executable_is_recorded_as_unreplayable() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_recording("bare_executable")
+        .build();
+    let transaction = TestTransaction::new_from_nonce(
+        ManifestBuilder::new().lock_fee_from_faucet().build(),
+        test_runner.next_transaction_nonce(),
+    );
+    let prepared = transaction.prepare().unwrap();
+
+    // Act
+    test_runner
+        .execute_transaction(
+            prepared.get_executable(btreeset!()),
+            CostingParameters::default(),
+            ExecutionConfig::for_test_transaction(),
+        )
+        .expect_commit_success();
+
+    // Assert
+    let recorded_scenario = test_runner.recorded_scenario().unwrap().clone();
+    assert!(!recorded_scenario.is_replayable());
+    let scrypto_vm = ScryptoVm {
+        wasm_engine: DefaultWasmEngine::default(),
+        wasm_validator_config: WasmValidatorConfigV1::new(),
+    };
+    let native_vm = DefaultNativeVm::new();
+    let vm = Vm::new(&scrypto_vm, native_vm);
+    let mut substate_db = InMemorySubstateDatabase::standard();
+    bootstrap_for_recorded_scenario(
+        &mut substate_db,
+        vm.clone(),
+        &recorded_scenario.protocol_updates,
+    );
+    let result = run_recorded_scenario(&mut substate_db, vm, &recorded_scenario, |_| {});
+    assert!(result.is_err());
+}
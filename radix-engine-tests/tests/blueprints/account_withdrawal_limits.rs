@@ -0,0 +1,624 @@
+use radix_engine::blueprints::account::{
+    AccountError, WITHDRAWAL_LIMIT_ADMIN_ROLE, WITHDRAWAL_LIMIT_RELAXATION_DELAY_IN_DAYS,
+};
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::account::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn setting_withdrawal_limit_without_owner_auth_fails() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+
+    // Act
+    let receipt = test_env.set_withdrawal_limit(
+        WithdrawalLimit {
+            amount: dec!(10),
+            period: WithdrawalLimitPeriod::Epoch,
+        },
+        false,
+    );
+
+    // Assert
+    receipt.expect_auth_failure();
+}
+
+#[test]
+fn removing_withdrawal_limit_without_owner_auth_fails() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+
+    // Act
+    let receipt = test_env.remove_withdrawal_limit(false);
+
+    // Assert
+    receipt.expect_auth_failure();
+}
+
+#[test]
+fn owner_cannot_set_withdrawal_limit_once_admin_role_is_assigned() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+    let (admin_public_key, _) = test_env.test_runner.new_key_pair();
+    let admin_badge = NonFungibleGlobalId::from_public_key(&admin_public_key);
+    test_env.assign_admin_role(admin_badge.clone());
+
+    // Act
+    let receipt = test_env.set_withdrawal_limit(
+        WithdrawalLimit {
+            amount: dec!(10),
+            period: WithdrawalLimitPeriod::Epoch,
+        },
+        true,
+    );
+
+    // Assert
+    receipt.expect_auth_failure();
+}
+
+#[test]
+fn owner_cannot_remove_withdrawal_limit_once_admin_role_is_assigned() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+    test_env
+        .set_withdrawal_limit(
+            WithdrawalLimit {
+                amount: dec!(10),
+                period: WithdrawalLimitPeriod::Epoch,
+            },
+            true,
+        )
+        .expect_commit_success();
+    let (admin_public_key, _) = test_env.test_runner.new_key_pair();
+    let admin_badge = NonFungibleGlobalId::from_public_key(&admin_public_key);
+    test_env.assign_admin_role(admin_badge.clone());
+
+    // Act
+    let receipt = test_env.remove_withdrawal_limit(true);
+
+    // Assert
+    receipt.expect_auth_failure();
+}
+
+#[test]
+fn admin_role_cannot_be_assigned_without_owner_auth() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+    let (admin_public_key, _) = test_env.test_runner.new_key_pair();
+    let admin_badge = NonFungibleGlobalId::from_public_key(&admin_public_key);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .set_main_role(
+            test_env.account,
+            WITHDRAWAL_LIMIT_ADMIN_ROLE,
+            rule!(require(admin_badge)),
+        )
+        .build();
+    let receipt = test_env.execute_manifest(manifest, false);
+
+    // Assert
+    receipt.expect_auth_failure();
+}
+
+#[test]
+fn owner_can_reassign_admin_role_but_not_lift_limit_instantly() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+    test_env
+        .set_withdrawal_limit(
+            WithdrawalLimit {
+                amount: dec!(10),
+                period: WithdrawalLimitPeriod::Epoch,
+            },
+            true,
+        )
+        .expect_commit_success();
+    let (admin_public_key, _) = test_env.test_runner.new_key_pair();
+    let admin_badge = NonFungibleGlobalId::from_public_key(&admin_public_key);
+    test_env.assign_admin_role(admin_badge);
+
+    // Act
+    let owner_badge = NonFungibleGlobalId::from_public_key(&test_env.public_key);
+    test_env.assign_admin_role(owner_badge);
+    test_env
+        .remove_withdrawal_limit(true)
+        .expect_commit_success();
+    let receipt = test_env.withdraw(dec!(11));
+
+    // Assert
+    receipt.expect_specific_failure(is_withdrawal_limit_exceeded_error);
+}
+
+#[test]
+fn admin_role_can_set_withdrawal_limit() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+    let (admin_public_key, _) = test_env.test_runner.new_key_pair();
+    let admin_badge = NonFungibleGlobalId::from_public_key(&admin_public_key);
+    test_env.assign_admin_role(admin_badge.clone());
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            test_env.account,
+            ACCOUNT_SET_WITHDRAWAL_LIMIT_IDENT,
+            AccountSetWithdrawalLimitInput {
+                resource_address: test_env.resource_address,
+                limit: WithdrawalLimit {
+                    amount: dec!(10),
+                    period: WithdrawalLimitPeriod::Epoch,
+                },
+            },
+        )
+        .build();
+    let receipt = test_env
+        .test_runner
+        .execute_manifest(manifest, vec![admin_badge]);
+
+    // Assert
+    receipt.expect_commit_success();
+    test_env
+        .withdraw(dec!(11))
+        .expect_specific_failure(is_withdrawal_limit_exceeded_error);
+}
+
+#[test]
+fn setting_negative_withdrawal_limit_fails() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+
+    // Act
+    let receipt = test_env.set_withdrawal_limit(
+        WithdrawalLimit {
+            amount: dec!(-1),
+            period: WithdrawalLimitPeriod::Epoch,
+        },
+        true,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AccountError(
+                AccountError::InvalidWithdrawalLimit { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn withdrawals_within_limit_succeed() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+    test_env
+        .set_withdrawal_limit(
+            WithdrawalLimit {
+                amount: dec!(10),
+                period: WithdrawalLimitPeriod::Epoch,
+            },
+            true,
+        )
+        .expect_commit_success();
+
+    // Act
+    let first_receipt = test_env.withdraw(dec!(4));
+    let second_receipt = test_env.withdraw(dec!(6));
+
+    // Assert
+    first_receipt.expect_commit_success();
+    second_receipt.expect_commit_success();
+}
+
+#[test]
+fn withdrawals_exceeding_limit_fail() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+    test_env
+        .set_withdrawal_limit(
+            WithdrawalLimit {
+                amount: dec!(10),
+                period: WithdrawalLimitPeriod::Epoch,
+            },
+            true,
+        )
+        .expect_commit_success();
+    test_env.withdraw(dec!(4)).expect_commit_success();
+
+    // Act
+    let receipt = test_env.withdraw(dec!(7));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AccountError(
+                AccountError::WithdrawalLimitExceeded {
+                    remaining_amount,
+                    requested_amount,
+                    ..
+                }
+            )) if *remaining_amount == dec!(6) && *requested_amount == dec!(7)
+        )
+    });
+}
+
+#[test]
+fn lock_fee_and_withdraw_is_subject_to_withdrawal_limit() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+    test_env
+        .set_withdrawal_limit(
+            WithdrawalLimit {
+                amount: dec!(10),
+                period: WithdrawalLimitPeriod::Epoch,
+            },
+            true,
+        )
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_and_withdraw(test_env.account, 500, test_env.resource_address, 11)
+        .try_deposit_entire_worktop_or_abort(test_env.account, None)
+        .build();
+    let receipt = test_env.execute_manifest(manifest, true);
+
+    // Assert
+    receipt.expect_specific_failure(is_withdrawal_limit_exceeded_error);
+}
+
+#[test]
+fn epoch_withdrawal_limit_is_reset_in_next_epoch() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+    test_env
+        .set_withdrawal_limit(
+            WithdrawalLimit {
+                amount: dec!(10),
+                period: WithdrawalLimitPeriod::Epoch,
+            },
+            true,
+        )
+        .expect_commit_success();
+    test_env.withdraw(dec!(10)).expect_commit_success();
+    let current_epoch = test_env.test_runner.get_current_epoch();
+    test_env
+        .test_runner
+        .set_current_epoch(current_epoch.next().unwrap());
+
+    // Act
+    let receipt = test_env.withdraw(dec!(10));
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn daily_withdrawal_limit_is_reset_on_next_day() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+    let one_day_ms = 24 * 60 * 60 * 1000;
+    test_env
+        .test_runner
+        .advance_to_round_at_timestamp(Round::of(1), one_day_ms)
+        .expect_commit_success();
+    test_env
+        .set_withdrawal_limit(
+            WithdrawalLimit {
+                amount: dec!(10),
+                period: WithdrawalLimitPeriod::Day,
+            },
+            true,
+        )
+        .expect_commit_success();
+    test_env.withdraw(dec!(10)).expect_commit_success();
+
+    // Act
+    test_env
+        .test_runner
+        .advance_to_round_at_timestamp(Round::of(2), 2 * one_day_ms - 60 * 1000)
+        .expect_commit_success();
+    let same_day_receipt = test_env.withdraw(dec!(1));
+    test_env
+        .test_runner
+        .advance_to_round_at_timestamp(Round::of(3), 2 * one_day_ms)
+        .expect_commit_success();
+    let next_day_receipt = test_env.withdraw(dec!(10));
+
+    // Assert
+    same_day_receipt.expect_specific_failure(is_withdrawal_limit_exceeded_error);
+    next_day_receipt.expect_commit_success();
+}
+
+#[test]
+fn setting_withdrawal_limit_again_does_not_reset_withdrawn_amount() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+    let limit = WithdrawalLimit {
+        amount: dec!(10),
+        period: WithdrawalLimitPeriod::Epoch,
+    };
+    test_env
+        .set_withdrawal_limit(limit, true)
+        .expect_commit_success();
+    test_env.withdraw(dec!(10)).expect_commit_success();
+    test_env
+        .set_withdrawal_limit(limit, true)
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_env.withdraw(dec!(1));
+
+    // Assert
+    receipt.expect_specific_failure(is_withdrawal_limit_exceeded_error);
+}
+
+#[test]
+fn removing_withdrawal_limit_allows_unlimited_withdrawals_after_delay() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+    test_env
+        .set_withdrawal_limit(
+            WithdrawalLimit {
+                amount: dec!(10),
+                period: WithdrawalLimitPeriod::Epoch,
+            },
+            true,
+        )
+        .expect_commit_success();
+    test_env.withdraw(dec!(10)).expect_commit_success();
+
+    // Act
+    test_env
+        .remove_withdrawal_limit(true)
+        .expect_commit_success();
+    let before_delay_receipt = test_env.withdraw(dec!(100));
+    test_env.advance_time_by_days(WITHDRAWAL_LIMIT_RELAXATION_DELAY_IN_DAYS);
+    let after_delay_receipt = test_env.withdraw(dec!(100));
+
+    // Assert
+    before_delay_receipt.expect_specific_failure(is_withdrawal_limit_exceeded_error);
+    after_delay_receipt.expect_commit_success();
+}
+
+#[test]
+fn raising_withdrawal_limit_takes_effect_after_delay() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+    test_env
+        .set_withdrawal_limit(
+            WithdrawalLimit {
+                amount: dec!(10),
+                period: WithdrawalLimitPeriod::Epoch,
+            },
+            true,
+        )
+        .expect_commit_success();
+
+    // Act
+    test_env
+        .set_withdrawal_limit(
+            WithdrawalLimit {
+                amount: dec!(20),
+                period: WithdrawalLimitPeriod::Epoch,
+            },
+            true,
+        )
+        .expect_commit_success();
+    let before_delay_receipt = test_env.withdraw(dec!(15));
+    test_env.advance_time_by_days(WITHDRAWAL_LIMIT_RELAXATION_DELAY_IN_DAYS);
+    let after_delay_receipt = test_env.withdraw(dec!(15));
+
+    // Assert
+    before_delay_receipt.expect_specific_failure(is_withdrawal_limit_exceeded_error);
+    after_delay_receipt.expect_commit_success();
+}
+
+#[test]
+fn lowering_withdrawal_limit_takes_effect_immediately_and_cancels_pending_raise() {
+    // Arrange
+    let mut test_env = WithdrawalLimitTestEnvironment::new();
+    test_env
+        .set_withdrawal_limit(
+            WithdrawalLimit {
+                amount: dec!(10),
+                period: WithdrawalLimitPeriod::Epoch,
+            },
+            true,
+        )
+        .expect_commit_success();
+    test_env
+        .remove_withdrawal_limit(true)
+        .expect_commit_success();
+
+    // Act
+    test_env
+        .set_withdrawal_limit(
+            WithdrawalLimit {
+                amount: dec!(5),
+                period: WithdrawalLimitPeriod::Epoch,
+            },
+            true,
+        )
+        .expect_commit_success();
+    let before_delay_receipt = test_env.withdraw(dec!(6));
+    test_env.advance_time_by_days(WITHDRAWAL_LIMIT_RELAXATION_DELAY_IN_DAYS);
+    let after_delay_receipt = test_env.withdraw(dec!(6));
+
+    // Assert
+    before_delay_receipt.expect_specific_failure(is_withdrawal_limit_exceeded_error);
+    after_delay_receipt.expect_specific_failure(is_withdrawal_limit_exceeded_error);
+}
+
+#[test]
+fn non_fungible_withdrawal_limit_counts_non_fungibles() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_account_v1_1()
+        .without_trace()
+        .build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let proofs = vec![NonFungibleGlobalId::from_public_key(&public_key)];
+    test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(
+                    account,
+                    ACCOUNT_SET_WITHDRAWAL_LIMIT_IDENT,
+                    AccountSetWithdrawalLimitInput {
+                        resource_address,
+                        limit: WithdrawalLimit {
+                            amount: dec!(2),
+                            period: WithdrawalLimitPeriod::Epoch,
+                        },
+                    },
+                )
+                .build(),
+            proofs.clone(),
+        )
+        .expect_commit_success();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_non_fungibles_from_account(
+                account,
+                resource_address,
+                [
+                    NonFungibleLocalId::integer(1),
+                    NonFungibleLocalId::integer(2),
+                    NonFungibleLocalId::integer(3),
+                ],
+            )
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+        proofs,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_withdrawal_limit_exceeded_error);
+}
+
+fn is_withdrawal_limit_exceeded_error(error: &RuntimeError) -> bool {
+    matches!(
+        error,
+        RuntimeError::ApplicationError(ApplicationError::AccountError(
+            AccountError::WithdrawalLimitExceeded { .. }
+        ))
+    )
+}
+
+struct WithdrawalLimitTestEnvironment {
+    test_runner: DefaultTestRunner,
+    public_key: Secp256k1PublicKey,
+    account: ComponentAddress,
+    resource_address: ResourceAddress,
+    round: u64,
+}
+
+impl WithdrawalLimitTestEnvironment {
+    fn new() -> Self {
+        let genesis = CustomGenesis::default(
+            Epoch::of(1),
+            CustomGenesis::default_consensus_manager_config(),
+        );
+        let mut test_runner = TestRunnerBuilder::new()
+            .with_custom_genesis(genesis)
+            .with_account_v1_1()
+            .without_trace()
+            .build();
+        let (public_key, _, account) = test_runner.new_allocated_account();
+        let resource_address = test_runner.create_fungible_resource(dec!(1000), 18, account);
+
+        Self {
+            test_runner,
+            public_key,
+            account,
+            resource_address,
+            round: 0,
+        }
+    }
+
+    fn set_withdrawal_limit(&mut self, limit: WithdrawalLimit, sign: bool) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.account,
+                ACCOUNT_SET_WITHDRAWAL_LIMIT_IDENT,
+                AccountSetWithdrawalLimitInput {
+                    resource_address: self.resource_address,
+                    limit,
+                },
+            )
+            .build();
+        self.execute_manifest(manifest, sign)
+    }
+
+    fn remove_withdrawal_limit(&mut self, sign: bool) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.account,
+                ACCOUNT_REMOVE_WITHDRAWAL_LIMIT_IDENT,
+                AccountRemoveWithdrawalLimitInput {
+                    resource_address: self.resource_address,
+                },
+            )
+            .build();
+        self.execute_manifest(manifest, sign)
+    }
+
+    fn assign_admin_role(&mut self, admin_badge: NonFungibleGlobalId) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .set_main_role(
+                self.account,
+                WITHDRAWAL_LIMIT_ADMIN_ROLE,
+                rule!(require(admin_badge)),
+            )
+            .build();
+        self.execute_manifest(manifest, true)
+            .expect_commit_success();
+    }
+
+    fn advance_time_by_days(&mut self, days: i64) {
+        self.round += 1;
+        let timestamp_ms =
+            self.test_runner.get_current_proposer_timestamp_ms() + days * 24 * 60 * 60 * 1000;
+        self.test_runner
+            .advance_to_round_at_timestamp(Round::of(self.round), timestamp_ms)
+            .expect_commit_success();
+    }
+
+    fn withdraw(&mut self, amount: Decimal) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(self.account, self.resource_address, amount)
+            .try_deposit_entire_worktop_or_abort(self.account, None)
+            .build();
+        self.execute_manifest(manifest, true)
+    }
+
+    fn execute_manifest(
+        &mut self,
+        manifest: TransactionManifestV1,
+        sign: bool,
+    ) -> TransactionReceipt {
+        let initial_proofs = if sign {
+            vec![NonFungibleGlobalId::from_public_key(&self.public_key)]
+        } else {
+            vec![]
+        };
+        self.test_runner.execute_manifest(manifest, initial_proofs)
+    }
+}
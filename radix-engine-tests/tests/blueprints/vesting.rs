@@ -0,0 +1,345 @@
+use radix_engine::blueprints::vesting::VestingError;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::vesting::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+const START: i64 = 60 * 60;
+const END: i64 = START + 100 * 60;
+
+#[test]
+fn vesting_component_can_be_instantiated() {
+    VestingTestEnvironment::new(VestingSchedule::linear(
+        Instant::new(START),
+        Instant::new(END),
+    ));
+}
+
+#[test]
+fn vesting_component_cannot_be_instantiated_with_invalid_schedule() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+
+    // Act
+    let receipt = instantiate_vesting(
+        &mut test_runner,
+        &public_key,
+        account,
+        resource_address,
+        dec!(100),
+        VestingSchedule::linear(Instant::new(END), Instant::new(START)),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VestingError(
+                VestingError::InvalidSchedule { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn vesting_component_cannot_be_instantiated_with_non_fungible_resource() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+
+    // Act
+    let receipt = instantiate_vesting(
+        &mut test_runner,
+        &public_key,
+        account,
+        resource_address,
+        dec!(3),
+        VestingSchedule::linear(Instant::new(START), Instant::new(END)),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VestingError(
+                VestingError::NonFungibleResourcesAreNotAccepted { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn nothing_can_be_claimed_before_start() {
+    // Arrange
+    let mut test_env = VestingTestEnvironment::new(VestingSchedule::linear(
+        Instant::new(START),
+        Instant::new(END),
+    ));
+    test_env.set_current_time(START - 60);
+
+    // Act
+    let receipt = test_env.claim(true);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(test_env.account_balance(), dec!(0));
+    assert_eq!(test_env.get_claimable_amount(), dec!(0));
+}
+
+#[test]
+fn half_of_tokens_can_be_claimed_half_way_through_linear_schedule() {
+    // Arrange
+    let mut test_env = VestingTestEnvironment::new(VestingSchedule::linear(
+        Instant::new(START),
+        Instant::new(END),
+    ));
+    test_env.set_current_time(START + 50 * 60);
+
+    // Act
+    let receipt = test_env.claim(true);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(test_env.account_balance(), dec!(50));
+    assert_eq!(test_env.get_vested_amount(), dec!(50));
+    assert_eq!(test_env.get_claimable_amount(), dec!(0));
+}
+
+#[test]
+fn nothing_can_be_claimed_before_cliff() {
+    // Arrange
+    let mut test_env = VestingTestEnvironment::new(VestingSchedule::with_cliff(
+        Instant::new(START),
+        Instant::new(START + 25 * 60),
+        Instant::new(END),
+    ));
+    test_env.set_current_time(START + 24 * 60);
+
+    // Act
+    test_env.claim(true).expect_commit_success();
+    let balance_before_cliff = test_env.account_balance();
+    test_env.set_current_time(START + 25 * 60);
+    test_env.claim(true).expect_commit_success();
+    let balance_at_cliff = test_env.account_balance();
+
+    // Assert
+    assert_eq!(balance_before_cliff, dec!(0));
+    assert_eq!(balance_at_cliff, dec!(25));
+}
+
+#[test]
+fn all_tokens_can_be_claimed_after_end() {
+    // Arrange
+    let mut test_env = VestingTestEnvironment::new(VestingSchedule::linear(
+        Instant::new(START),
+        Instant::new(END),
+    ));
+    test_env.set_current_time(START + 30 * 60);
+    test_env.claim(true).expect_commit_success();
+    test_env.set_current_time(END + 60);
+
+    // Act
+    let receipt = test_env.claim(true);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(test_env.account_balance(), dec!(100));
+}
+
+#[test]
+fn claiming_without_beneficiary_badge_fails() {
+    // Arrange
+    let mut test_env = VestingTestEnvironment::new(VestingSchedule::linear(
+        Instant::new(START),
+        Instant::new(END),
+    ));
+    test_env.set_current_time(END);
+
+    // Act
+    let receipt = test_env.claim(false);
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn revoking_returns_unvested_tokens_and_freezes_vesting() {
+    // Arrange
+    let mut test_env = VestingTestEnvironment::new(VestingSchedule::linear(
+        Instant::new(START),
+        Instant::new(END),
+    ));
+    test_env.set_current_time(START + 40 * 60);
+
+    // Act
+    let receipt = test_env.revoke();
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(test_env.account_balance(), dec!(60));
+    test_env.set_current_time(END);
+    assert_eq!(test_env.get_vested_amount(), dec!(40));
+    test_env.claim(true).expect_commit_success();
+    assert_eq!(test_env.account_balance(), dec!(100));
+}
+
+#[test]
+fn revoking_twice_fails() {
+    // Arrange
+    let mut test_env = VestingTestEnvironment::new(VestingSchedule::linear(
+        Instant::new(START),
+        Instant::new(END),
+    ));
+    test_env.set_current_time(START + 40 * 60);
+    test_env.revoke().expect_commit_success();
+
+    // Act
+    let receipt = test_env.revoke();
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VestingError(
+                VestingError::AlreadyRevoked
+            ))
+        )
+    });
+}
+
+fn instantiate_vesting(
+    test_runner: &mut DefaultTestRunner,
+    public_key: &Secp256k1PublicKey,
+    account: ComponentAddress,
+    resource_address: ResourceAddress,
+    amount: Decimal,
+    schedule: VestingSchedule,
+) -> TransactionReceipt {
+    let badge = NonFungibleGlobalId::from_public_key(public_key);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, resource_address, amount)
+        .take_all_from_worktop(resource_address, "bucket")
+        .create_vesting(
+            OwnerRole::None,
+            rule!(require(badge.clone())),
+            rule!(require(badge)),
+            "bucket",
+            schedule,
+        )
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(public_key)],
+    )
+}
+
+struct VestingTestEnvironment {
+    test_runner: DefaultTestRunner,
+    public_key: Secp256k1PublicKey,
+    account: ComponentAddress,
+    resource_address: ResourceAddress,
+    vesting_component_address: ComponentAddress,
+    round: u64,
+}
+
+impl VestingTestEnvironment {
+    fn new(schedule: VestingSchedule) -> Self {
+        let genesis = CustomGenesis::default(
+            Epoch::of(1),
+            CustomGenesis::default_consensus_manager_config(),
+        );
+        let mut test_runner = TestRunnerBuilder::new()
+            .with_custom_genesis(genesis)
+            .without_trace()
+            .build();
+        let (public_key, _, account) = test_runner.new_allocated_account();
+        let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+
+        let receipt = instantiate_vesting(
+            &mut test_runner,
+            &public_key,
+            account,
+            resource_address,
+            dec!(100),
+            schedule,
+        );
+        let vesting_component_address =
+            receipt.expect_commit_success().new_component_addresses()[0];
+
+        Self {
+            test_runner,
+            public_key,
+            account,
+            resource_address,
+            vesting_component_address,
+            round: 0,
+        }
+    }
+
+    fn set_current_time(&mut self, seconds_since_unix_epoch: i64) {
+        self.round += 1;
+        self.test_runner
+            .advance_to_round_at_timestamp(Round::of(self.round), seconds_since_unix_epoch * 1000)
+            .expect_commit_success();
+    }
+
+    fn claim(&mut self, sign: bool) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .claim_vested(self.vesting_component_address)
+            .try_deposit_entire_worktop_or_abort(self.account, None)
+            .build();
+        let initial_proofs = if sign {
+            vec![NonFungibleGlobalId::from_public_key(&self.public_key)]
+        } else {
+            vec![]
+        };
+        self.test_runner.execute_manifest(manifest, initial_proofs)
+    }
+
+    fn revoke(&mut self) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .revoke_vesting(self.vesting_component_address)
+            .try_deposit_entire_worktop_or_abort(self.account, None)
+            .build();
+        self.test_runner.execute_manifest(
+            manifest,
+            vec![NonFungibleGlobalId::from_public_key(&self.public_key)],
+        )
+    }
+
+    fn get_vested_amount(&mut self) -> Decimal {
+        self.call_getter(VESTING_GET_VESTED_AMOUNT_IDENT)
+    }
+
+    fn get_claimable_amount(&mut self) -> Decimal {
+        self.call_getter(VESTING_GET_CLAIMABLE_AMOUNT_IDENT)
+    }
+
+    fn call_getter(&mut self, method_name: &str) -> Decimal {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                self.vesting_component_address,
+                method_name,
+                manifest_args!(),
+            )
+            .build();
+        self.test_runner
+            .execute_manifest(manifest, vec![])
+            .expect_commit_success()
+            .output(1)
+    }
+
+    fn account_balance(&mut self) -> Decimal {
+        self.test_runner
+            .get_component_balance(self.account, self.resource_address)
+    }
+}
@@ -0,0 +1,54 @@
+use radix_engine::system::system_db_reader::SystemDatabaseReader;
+use radix_engine::types::*;
+use radix_engine_queries::query::ResourceAccounter;
+use radix_engine_store_interface::interface::VersionedSubstateDatabase;
+use radix_engine_stores::memory_db::InMemorySubstateDatabase;
+use radix_engine_stores::state_history_support::StateHistoryTrackingDatabase;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn system_reader_and_queries_can_read_past_state_versions() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_database(StateHistoryTrackingDatabase::new(
+            InMemorySubstateDatabase::standard(),
+            100,
+        ))
+        .build();
+    let version_before_account = test_runner.substate_db().get_current_version();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let version_before_deposit = test_runner.substate_db().get_current_version();
+    let balance_before_deposit = test_runner.get_component_balance(account, XRD);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // Assert
+    let balance_after_deposit = test_runner.get_component_balance(account, XRD);
+    assert_ne!(balance_before_deposit, balance_after_deposit);
+
+    let database = test_runner.substate_db();
+    let state_before_deposit = database.at_version(version_before_deposit).unwrap();
+    let mut accounter = ResourceAccounter::new(&state_before_deposit);
+    accounter.traverse(account.as_node_id().clone());
+    assert_eq!(
+        accounter.close().balances.get(&XRD).cloned(),
+        Some(balance_before_deposit)
+    );
+
+    let state_before_account = database.at_version(version_before_account).unwrap();
+    assert!(SystemDatabaseReader::new(&state_before_account)
+        .get_type_info(account.as_node_id())
+        .is_err());
+    assert!(SystemDatabaseReader::new(database)
+        .get_type_info(account.as_node_id())
+        .is_ok());
+}
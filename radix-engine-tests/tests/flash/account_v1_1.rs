@@ -0,0 +1,80 @@
+use radix_engine::types::*;
+use radix_engine::utils::generate_account_v1_1_state_updates;
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_store_interface::db_key_mapper::SpreadPrefixKeyMapper;
+use radix_engine_store_interface::interface::CommittableSubstateDatabase;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn setting_withdrawal_limit_without_state_flash_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn setting_withdrawal_limit_with_state_flash_should_succeed() {
+    run_flash_test(true, true);
+}
+
+#[test]
+fn account_created_before_state_flash_can_withdraw_after_it() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+
+    // Act
+    let state_updates = generate_account_v1_1_state_updates(test_runner.substate_db());
+    let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
+    test_runner.substate_db_mut().commit(&db_updates);
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, resource_address, dec!(10))
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+fn run_flash_test(flash_substates: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    if flash_substates {
+        let state_updates = generate_account_v1_1_state_updates(test_runner.substate_db());
+        let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
+        test_runner.substate_db_mut().commit(&db_updates);
+    }
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            account,
+            ACCOUNT_SET_WITHDRAWAL_LIMIT_IDENT,
+            AccountSetWithdrawalLimitInput {
+                resource_address: XRD,
+                limit: WithdrawalLimit {
+                    amount: dec!(10),
+                    period: WithdrawalLimitPeriod::Epoch,
+                },
+            },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    if expect_success {
+        receipt.expect_commit_success();
+    } else {
+        assert!(!receipt.is_commit_success());
+    }
+}
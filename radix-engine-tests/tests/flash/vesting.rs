@@ -0,0 +1,54 @@
+use radix_engine::types::*;
+use radix_engine::utils::generate_vesting_package_state_updates;
+use radix_engine_interface::blueprints::vesting::*;
+use radix_engine_store_interface::db_key_mapper::SpreadPrefixKeyMapper;
+use radix_engine_store_interface::interface::CommittableSubstateDatabase;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+#[test]
+fn creating_vesting_without_state_flash_should_fail() {
+    run_flash_test(false, false);
+}
+
+#[test]
+fn creating_vesting_with_state_flash_should_succeed() {
+    run_flash_test(true, true);
+}
+
+fn run_flash_test(flash_substates: bool, expect_success: bool) {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new()
+        .without_vesting_package()
+        .without_trace()
+        .build();
+    if flash_substates {
+        let state_updates = generate_vesting_package_state_updates();
+        let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
+        test_runner.substate_db_mut().commit(&db_updates);
+    }
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let badge = NonFungibleGlobalId::from_public_key(&public_key);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, dec!(100))
+        .take_all_from_worktop(XRD, "bucket")
+        .create_vesting(
+            OwnerRole::None,
+            rule!(require(badge.clone())),
+            rule!(require(badge.clone())),
+            "bucket",
+            VestingSchedule::linear(Instant::new(0), Instant::new(100)),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![badge]);
+
+    // Assert
+    if expect_success {
+        receipt.expect_commit_success();
+    } else {
+        assert!(!receipt.is_commit_success());
+    }
+}
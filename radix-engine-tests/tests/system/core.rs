@@ -1,13 +1,15 @@
-use radix_engine_tests::common::*;
 use radix_engine::errors::{CallFrameError, KernelError};
 use radix_engine::kernel::call_frame::{
     CloseSubstateError, CreateNodeError, ProcessSubstateError, TakeNodeError,
 };
+use radix_engine::system::system_db_reader::SystemDatabaseReader;
+use radix_engine::system::system_substates::FieldSubstate;
 use radix_engine::{
     errors::{RuntimeError, SystemError},
     types::*,
 };
 use radix_engine_interface::blueprints::resource::FromPublicKey;
+use radix_engine_tests::common::*;
 use scrypto_test::prelude::{OpenSubstateError, ProcessSubstateKeyError};
 use scrypto_unit::*;
 use transaction::prelude::*;
@@ -17,6 +19,12 @@ struct Compo {
     message: String,
 }
 
+#[derive(ScryptoDecode, PartialEq, Eq, Debug)]
+#[sbor(borrow = "'a")]
+struct BorrowedCompo<'a> {
+    message: &'a str,
+}
+
 #[test]
 fn inspect_component_state() {
     // Arrange
@@ -43,6 +51,96 @@ fn inspect_component_state() {
     );
 }
 
+fn create_compo(test_runner: &mut DefaultTestRunner) -> ComponentAddress {
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("core"));
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(package_address, "Compo", "new", manifest_args!())
+            .build(),
+        vec![],
+    );
+    receipt.expect_commit(true).new_component_addresses()[0]
+}
+
+#[test]
+fn component_state_equal_to_expected_state_passes_assertion() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let component_address = create_compo(&mut test_runner);
+
+    // Act & Assert
+    test_runner.assert_component_state_eq(
+        component_address,
+        &Compo {
+            message: "Hi".to_owned(),
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "message: \"Hi\" -> \"Bye\"")]
+fn component_state_different_to_expected_state_fails_assertion_with_diff() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let component_address = create_compo(&mut test_runner);
+
+    // Act & Assert
+    test_runner.assert_component_state_eq(
+        component_address,
+        &Compo {
+            message: "Bye".to_owned(),
+        },
+    );
+}
+
+#[test]
+fn inspect_component_state_with_system_database_reader() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("core"));
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(package_address, "Compo", "new", manifest_args!())
+            .build(),
+        vec![],
+    );
+    let component_address = receipt.expect_commit(true).new_component_addresses()[0];
+
+    // Act
+    let reader = SystemDatabaseReader::new(test_runner.substate_db());
+    let state = reader
+        .read_object_field(
+            component_address.as_node_id(),
+            ModuleId::Main,
+            ComponentField::State0.into(),
+        )
+        .unwrap();
+    let substate_bytes = reader
+        .read_object_field_bytes(
+            component_address.as_node_id(),
+            ModuleId::Main,
+            ComponentField::State0.into(),
+        )
+        .unwrap();
+    let borrowed_substate: FieldSubstate<BorrowedCompo> =
+        scrypto_decode_borrowed(&substate_bytes).unwrap();
+
+    // Assert
+    assert_eq!(
+        state.as_typed::<Compo>().unwrap(),
+        Compo {
+            message: "Hi".to_owned()
+        }
+    );
+    let borrowed_state = borrowed_substate.into_payload();
+    assert_eq!(borrowed_state, BorrowedCompo { message: "Hi" });
+    assert!(substate_bytes
+        .as_ptr_range()
+        .contains(&borrowed_state.message.as_ptr()));
+}
+
 #[test]
 fn test_globalize_with_unflushed_invalid_own() {
     let mut test_runner = TestRunnerBuilder::new().build();
@@ -0,0 +1,119 @@
+use radix_engine::transaction::{
+    CostingParameters, ExecutionConfig, StateUpdateSummary, TransactionReceipt,
+};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn execute_faucet_withdrawal(
+    test_runner: &mut DefaultTestRunner,
+    execution_config: ExecutionConfig,
+) -> TransactionReceipt {
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let transaction =
+        TestTransaction::new_from_nonce(manifest, test_runner.next_transaction_nonce());
+
+    test_runner.execute_transaction(
+        transaction
+            .prepare()
+            .expect("expected transaction to be preparable")
+            .get_executable(btreeset!(NonFungibleGlobalId::from_public_key(&public_key))),
+        CostingParameters::default(),
+        execution_config,
+    )
+}
+
+#[test]
+fn field_changes_are_not_calculated_unless_enabled() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new()
+        .without_field_change_diffs()
+        .build();
+
+    // Act
+    let receipt =
+        execute_faucet_withdrawal(&mut test_runner, ExecutionConfig::for_test_transaction());
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert!(result.field_changes().is_empty());
+}
+
+#[test]
+fn field_changes_are_calculated_and_displayed_by_the_test_runner() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let faucet_vault = test_runner.get_component_vaults(FAUCET, XRD)[0];
+
+    // Act
+    let receipt =
+        execute_faucet_withdrawal(&mut test_runner, ExecutionConfig::for_test_transaction());
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert!(result.field_changes().contains_key(&faucet_vault));
+    let display = format!("{:?}", receipt);
+    assert!(display.contains("Field Changes:"));
+    assert!(display.contains("FungibleVaultBalanceFieldPayload::V1.0: LiquidFungibleResource("));
+}
+
+#[test]
+fn field_changes_of_existing_vault_are_reported_with_old_and_new_values() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let faucet_vault = test_runner.get_component_vaults(FAUCET, XRD)[0];
+
+    // Act
+    let receipt = execute_faucet_withdrawal(
+        &mut test_runner,
+        ExecutionConfig::for_test_transaction().with_field_change_diffs(true),
+    );
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let faucet_vault_changes = result
+        .field_changes()
+        .get(&faucet_vault)
+        .expect("The faucet vault balance should have changed");
+    let balance_change = faucet_vault_changes
+        .iter()
+        .flat_map(|field_change| field_change.diff.changes.iter())
+        .find(|change| change.path.starts_with("FungibleVaultBalanceFieldPayload"))
+        .expect("The change should be located by the payload type name");
+    assert!(balance_change.old.is_some());
+    assert!(balance_change.new.is_some());
+    assert_ne!(balance_change.old, balance_change.new);
+    assert!(format!("{:?}", receipt).contains("Field Changes:"));
+}
+
+#[test]
+fn field_changes_are_not_part_of_the_encoded_receipt() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let receipt = execute_faucet_withdrawal(
+        &mut test_runner,
+        ExecutionConfig::for_test_transaction().with_field_change_diffs(true),
+    );
+    let summary = receipt.expect_commit_success().state_update_summary.clone();
+    assert!(!summary.field_changes.is_empty());
+
+    // Act
+    let encoded = scrypto_encode(&summary).unwrap();
+
+    // Assert
+    let summary_without_field_changes = StateUpdateSummary {
+        field_changes: index_map_new(),
+        ..summary
+    };
+    assert_eq!(
+        encoded,
+        scrypto_encode(&summary_without_field_changes).unwrap()
+    );
+    let decoded: StateUpdateSummary = scrypto_decode(&encoded).unwrap();
+    assert!(decoded.field_changes.is_empty());
+}
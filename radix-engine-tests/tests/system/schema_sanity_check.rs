@@ -6,7 +6,9 @@ use radix_engine::{
     types::*,
 };
 use radix_engine_interface::schema::TypeRef;
-use radix_engine_queries::typed_substate_layout::{AccountNativePackage, BlueprintPayloadDef};
+use radix_engine_queries::typed_substate_layout::{
+    AccountNativePackage, AccountV1MinorVersion, BlueprintPayloadDef,
+};
 use radix_engine_tests::common::*;
 use sbor::basic_well_known_types::*;
 use scrypto_test::prelude::*;
@@ -443,7 +445,7 @@ pub fn test_fake_bucket() {
 
 #[test]
 fn native_blueprints_with_typed_addresses_have_expected_schema() {
-    let mut blueprint_definition = AccountNativePackage::definition()
+    let mut blueprint_definition = AccountNativePackage::definition(AccountV1MinorVersion::One)
         .blueprints
         .remove("Account")
         .unwrap();
@@ -237,10 +237,11 @@ fn typed_native_event_type_contains_all_native_events() {
     // Arrange
     let package_name_definition_mapping = hashmap! {
         "ConsensusManager" => CONSENSUS_MANAGER_PACKAGE_DEFINITION.deref(),
-        "Account" => ACCOUNT_PACKAGE_DEFINITION.deref(),
+        "Account" => ACCOUNT_PACKAGE_DEFINITION_V1_1.deref(),
         "Identity" => IDENTITY_PACKAGE_DEFINITION.deref(),
         "AccessController" => ACCESS_CONTROLLER_PACKAGE_DEFINITION.deref(),
         "Pool" => POOL_PACKAGE_DEFINITION_V1_0.deref(),
+        "Vesting" => VESTING_PACKAGE_DEFINITION.deref(),
         "TransactionTracker" => TRANSACTION_TRACKER_PACKAGE_DEFINITION.deref(),
         "Resource" => RESOURCE_PACKAGE_DEFINITION.deref(),
         "Package" => PACKAGE_PACKAGE_DEFINITION.deref(),
@@ -16,6 +16,7 @@ use radix_engine_interface::api::node_modules::metadata::*;
 use radix_engine_interface::api::FieldValue;
 use radix_engine_interface::api::{AttachedModuleId, ClientApi, GenericArgs, ACTOR_STATE_SELF};
 use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::consensus_manager::TimePrecision;
 use radix_engine_interface::blueprints::resource::{Bucket, Proof};
 use radix_engine_interface::hooks::OnVirtualizeInput;
 use radix_engine_interface::hooks::OnVirtualizeOutput;
@@ -35,12 +36,47 @@ pub struct AccountSubstate {
     pub default_deposit_rule: DefaultDepositRule,
 }
 
+#[derive(Debug, PartialEq, Eq, ScryptoSbor, Clone)]
+pub struct AccountWithdrawalLimitSubstate {
+    pub limit: WithdrawalLimit,
+    /// The period (an epoch number or a day number, depending on the limit) in which the last
+    /// withdrawal was made.
+    pub current_period: u64,
+    /// The amount withdrawn in the `current_period`.
+    pub withdrawn_in_current_period: Decimal,
+    /// A relaxation of the limit which was requested, but hasn't taken effect yet.
+    pub pending_relaxation: Option<PendingWithdrawalLimitRelaxation>,
+}
+
+/// A raise of a withdrawal limit (or its removal, if the `limit` is `None`), which only takes
+/// effect after a delay - so that a leaked key can't lift the limit which caps the damage it can do.
+#[derive(Debug, PartialEq, Eq, ScryptoSbor, Clone)]
+pub struct PendingWithdrawalLimitRelaxation {
+    pub limit: Option<WithdrawalLimit>,
+    pub effective_at: Instant,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum AccountError {
-    VaultDoesNotExist { resource_address: ResourceAddress },
-    DepositIsDisallowed { resource_address: ResourceAddress },
+    VaultDoesNotExist {
+        resource_address: ResourceAddress,
+    },
+    DepositIsDisallowed {
+        resource_address: ResourceAddress,
+    },
     NotAllBucketsCouldBeDeposited,
-    NotAnAuthorizedDepositor { depositor: ResourceOrNonFungible },
+    NotAnAuthorizedDepositor {
+        depositor: ResourceOrNonFungible,
+    },
+    InvalidWithdrawalLimit {
+        limit: WithdrawalLimit,
+    },
+    WithdrawalLimitExceeded {
+        resource_address: ResourceAddress,
+        limit: WithdrawalLimit,
+        remaining_amount: Decimal,
+        requested_amount: Decimal,
+    },
 }
 
 impl From<AccountError> for RuntimeError {
@@ -51,6 +87,16 @@ impl From<AccountError> for RuntimeError {
 
 pub const SECURIFY_ROLE: &'static str = "securify";
 
+/// The role which can set and remove withdrawal limits. Until it's assigned, it falls back to the
+/// owner role. It can be assigned (by the owner) to a separate badge, e.g. one in cold storage.
+pub const WITHDRAWAL_LIMIT_ADMIN_ROLE: &'static str = "withdrawal_limit_admin";
+
+/// The delay after which a raise or a removal of a withdrawal limit takes effect. Lowering a limit
+/// takes effect immediately.
+pub const WITHDRAWAL_LIMIT_RELAXATION_DELAY_IN_DAYS: i64 = 1;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
 struct SecurifiedAccount;
 
 impl SecurifiedRoleAssignment for SecurifiedAccount {
@@ -109,6 +155,17 @@ declare_native_blueprint_state! {
             },
             allow_ownership: false,
         },
+        withdrawal_limits: KeyValue {
+            entry_ident: WithdrawalLimit,
+            key_type: {
+                kind: Static,
+                content_type: ResourceAddress,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
     }
 }
 
@@ -116,15 +173,21 @@ pub type AccountDepositRuleV1 = AccountSubstate;
 pub type AccountResourceVaultV1 = Vault;
 pub type AccountResourcePreferenceV1 = ResourcePreference;
 pub type AccountAuthorizedDepositorV1 = ();
+pub type AccountWithdrawalLimitV1 = AccountWithdrawalLimitSubstate;
 
 pub struct AccountBlueprint;
 
 impl AccountBlueprint {
-    pub fn get_definition() -> BlueprintDefinitionInit {
+    pub fn get_definition(minor_version: AccountV1MinorVersion) -> BlueprintDefinitionInit {
         let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
 
         let feature_set = AccountFeatureSet::all_features();
-        let state = AccountStateSchemaInit::create_schema_init(&mut aggregator);
+        let state = match minor_version {
+            AccountV1MinorVersion::Zero => Self::v1_0_state_schema_init(&mut aggregator),
+            AccountV1MinorVersion::One => {
+                AccountStateSchemaInit::create_schema_init(&mut aggregator)
+            }
+        };
 
         let mut functions = index_map_new();
 
@@ -480,7 +543,41 @@ impl AccountBlueprint {
             },
         );
 
-        let events = event_schema! {
+        if minor_version >= AccountV1MinorVersion::One {
+            functions.insert(
+                ACCOUNT_SET_WITHDRAWAL_LIMIT_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<AccountSetWithdrawalLimitInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<AccountSetWithdrawalLimitOutput>(),
+                    ),
+                    export: ACCOUNT_SET_WITHDRAWAL_LIMIT_IDENT.to_string(),
+                },
+            );
+
+            functions.insert(
+                ACCOUNT_REMOVE_WITHDRAWAL_LIMIT_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<AccountRemoveWithdrawalLimitInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<AccountRemoveWithdrawalLimitOutput>(),
+                    ),
+                    export: ACCOUNT_REMOVE_WITHDRAWAL_LIMIT_IDENT.to_string(),
+                },
+            );
+        }
+
+        let mut events = event_schema! {
             aggregator,
             [
                 WithdrawEvent,
@@ -490,12 +587,66 @@ impl AccountBlueprint {
                 RemoveResourcePreferenceEvent,
                 SetDefaultDepositRuleEvent,
                 AddAuthorizedDepositorEvent,
-                RemoveAuthorizedDepositorEvent,
+                RemoveAuthorizedDepositorEvent
             ]
         };
+        if minor_version >= AccountV1MinorVersion::One {
+            events.event_schema.extend(
+                event_schema! {
+                    aggregator,
+                    [SetWithdrawalLimitEvent, RemoveWithdrawalLimitEvent]
+                }
+                .event_schema,
+            );
+        }
 
         let schema = generate_full_schema(aggregator);
 
+        let mut role_definition = roles_template!(
+            roles {
+                SECURIFY_ROLE => updaters: [SELF_ROLE];
+            },
+            methods {
+                ACCOUNT_SECURIFY_IDENT => [SECURIFY_ROLE];
+
+                ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT => [OWNER_ROLE];
+                ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT => [OWNER_ROLE];
+                ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT => [OWNER_ROLE];
+                ACCOUNT_WITHDRAW_IDENT => [OWNER_ROLE];
+                ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
+                ACCOUNT_LOCK_FEE_IDENT => [OWNER_ROLE];
+                ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => [OWNER_ROLE];
+                ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => [OWNER_ROLE];
+                ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
+                ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT => [OWNER_ROLE];
+                ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
+                ACCOUNT_DEPOSIT_IDENT => [OWNER_ROLE];
+                ACCOUNT_DEPOSIT_BATCH_IDENT => [OWNER_ROLE];
+                ACCOUNT_BURN_IDENT => [OWNER_ROLE];
+                ACCOUNT_BURN_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
+                ACCOUNT_ADD_AUTHORIZED_DEPOSITOR => [OWNER_ROLE];
+                ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR => [OWNER_ROLE];
+
+                ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT => MethodAccessibility::Public;
+                ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT => MethodAccessibility::Public;
+                ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT => MethodAccessibility::Public;
+                ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT => MethodAccessibility::Public;
+            }
+        );
+        if minor_version >= AccountV1MinorVersion::One {
+            if let RoleSpecification::Normal(roles) = &mut role_definition.roles {
+                roles.insert(WITHDRAWAL_LIMIT_ADMIN_ROLE.into(), [OWNER_ROLE].into());
+            }
+            role_definition.methods.insert(
+                ACCOUNT_SET_WITHDRAWAL_LIMIT_IDENT.into(),
+                [WITHDRAWAL_LIMIT_ADMIN_ROLE].into(),
+            );
+            role_definition.methods.insert(
+                ACCOUNT_REMOVE_WITHDRAWAL_LIMIT_IDENT.into(),
+                [WITHDRAWAL_LIMIT_ADMIN_ROLE].into(),
+            );
+        }
+
         BlueprintDefinitionInit {
             blueprint_type: BlueprintType::default(),
             is_transient: false,
@@ -522,41 +673,64 @@ impl AccountBlueprint {
             royalty_config: PackageRoyaltyConfig::default(),
             auth_config: AuthConfig {
                 function_auth: FunctionAuth::AllowAll,
-                method_auth: MethodAuthTemplate::StaticRoleDefinition(roles_template!(
-                    roles {
-                        SECURIFY_ROLE => updaters: [SELF_ROLE];
-                    },
-                    methods {
-                        ACCOUNT_SECURIFY_IDENT => [SECURIFY_ROLE];
-
-                        ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT => [OWNER_ROLE];
-                        ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT => [OWNER_ROLE];
-                        ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT => [OWNER_ROLE];
-                        ACCOUNT_WITHDRAW_IDENT => [OWNER_ROLE];
-                        ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
-                        ACCOUNT_LOCK_FEE_IDENT => [OWNER_ROLE];
-                        ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => [OWNER_ROLE];
-                        ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => [OWNER_ROLE];
-                        ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
-                        ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT => [OWNER_ROLE];
-                        ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
-                        ACCOUNT_DEPOSIT_IDENT => [OWNER_ROLE];
-                        ACCOUNT_DEPOSIT_BATCH_IDENT => [OWNER_ROLE];
-                        ACCOUNT_BURN_IDENT => [OWNER_ROLE];
-                        ACCOUNT_BURN_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
-                        ACCOUNT_ADD_AUTHORIZED_DEPOSITOR => [OWNER_ROLE];
-                        ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR => [OWNER_ROLE];
-
-                        ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT => MethodAccessibility::Public;
-                        ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT => MethodAccessibility::Public;
-                        ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT => MethodAccessibility::Public;
-                        ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT => MethodAccessibility::Public;
-                    }
-                )),
+                method_auth: MethodAuthTemplate::StaticRoleDefinition(role_definition),
             },
         }
     }
 
+    /// The state schema of v1.0 of the blueprint, i.e. without the withdrawal limits collection.
+    /// It must add exactly the same types (in the same order) as the state declaration did before
+    /// the collection was added, so that the definition published at genesis is unchanged.
+    fn v1_0_state_schema_init(
+        aggregator: &mut TypeAggregator<ScryptoCustomTypeKind>,
+    ) -> BlueprintStateSchemaInit {
+        BlueprintStateSchemaInit {
+            fields: vec![FieldSchema {
+                field: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountDepositRuleFieldPayload>(),
+                ),
+                condition: Condition::Always,
+                transience: FieldTransience::NotTransient,
+            }],
+            collections: vec![
+                BlueprintCollectionSchema::KeyValueStore(BlueprintKeyValueSchema {
+                    key: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AccountResourceVaultKeyContent>(),
+                    ),
+                    value: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<AccountResourceVaultEntryPayload>(),
+                    ),
+                    allow_ownership: true,
+                }),
+                BlueprintCollectionSchema::KeyValueStore(BlueprintKeyValueSchema {
+                    key: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<AccountResourcePreferenceKeyContent>(),
+                    ),
+                    value: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<AccountResourcePreferenceEntryPayload>(
+                            ),
+                    ),
+                    allow_ownership: false,
+                }),
+                BlueprintCollectionSchema::KeyValueStore(BlueprintKeyValueSchema {
+                    key: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<AccountAuthorizedDepositorKeyContent>(),
+                    ),
+                    value: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<AccountAuthorizedDepositorEntryPayload>(
+                            ),
+                    ),
+                    allow_ownership: false,
+                }),
+            ],
+        }
+    }
+
     fn create_modules<Y>(
         role_assignment: RoleAssignment,
         metadata_init: MetadataInit,
@@ -982,6 +1156,7 @@ impl AccountBlueprint {
     pub fn withdraw<Y>(
         resource_address: ResourceAddress,
         amount: Decimal,
+        minor_version: AccountV1MinorVersion,
         api: &mut Y,
     ) -> Result<Bucket, RuntimeError>
     where
@@ -993,6 +1168,7 @@ impl AccountBlueprint {
             false,
             api,
         )?;
+        Self::record_withdrawal(resource_address, &bucket, minor_version, api)?;
         let event = if resource_address.is_fungible() {
             WithdrawEvent::Fungible(resource_address, bucket.amount(api)?)
         } else {
@@ -1006,6 +1182,7 @@ impl AccountBlueprint {
     pub fn withdraw_non_fungibles<Y>(
         resource_address: ResourceAddress,
         ids: IndexSet<NonFungibleLocalId>,
+        minor_version: AccountV1MinorVersion,
         api: &mut Y,
     ) -> Result<Bucket, RuntimeError>
     where
@@ -1017,6 +1194,7 @@ impl AccountBlueprint {
             false,
             api,
         )?;
+        Self::record_withdrawal(resource_address, &bucket, minor_version, api)?;
         let event =
             WithdrawEvent::NonFungible(resource_address, bucket.non_fungible_local_ids(api)?);
         Runtime::emit_event(api, event)?;
@@ -1060,6 +1238,7 @@ impl AccountBlueprint {
         amount_to_lock: Decimal,
         resource_address: ResourceAddress,
         amount: Decimal,
+        minor_version: AccountV1MinorVersion,
         api: &mut Y,
     ) -> Result<Bucket, RuntimeError>
     where
@@ -1073,6 +1252,7 @@ impl AccountBlueprint {
             false,
             api,
         )?;
+        Self::record_withdrawal(resource_address, &bucket, minor_version, api)?;
 
         Ok(bucket)
     }
@@ -1081,6 +1261,7 @@ impl AccountBlueprint {
         amount_to_lock: Decimal,
         resource_address: ResourceAddress,
         ids: IndexSet<NonFungibleLocalId>,
+        minor_version: AccountV1MinorVersion,
         api: &mut Y,
     ) -> Result<Bucket, RuntimeError>
     where
@@ -1094,6 +1275,7 @@ impl AccountBlueprint {
             false,
             api,
         )?;
+        Self::record_withdrawal(resource_address, &bucket, minor_version, api)?;
 
         Ok(bucket)
     }
@@ -1271,6 +1453,281 @@ impl AccountBlueprint {
         Ok(())
     }
 
+    pub fn set_withdrawal_limit<Y>(
+        resource_address: ResourceAddress,
+        limit: WithdrawalLimit,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if limit.amount.is_negative() {
+            return Err(AccountError::InvalidWithdrawalLimit { limit }.into());
+        }
+
+        let now = Runtime::current_time(api, TimePrecision::Minute)?;
+        let encoded_key = scrypto_encode(&resource_address).expect("Impossible Case!");
+        let kv_store_entry_lock_handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            AccountCollection::WithdrawalLimitKeyValue.collection_index(),
+            &encoded_key,
+            LockFlags::MUTABLE,
+        )?;
+
+        let existing = match api
+            .key_value_entry_get_typed::<AccountWithdrawalLimitEntryPayload>(
+                kv_store_entry_lock_handle,
+            )?
+            .map(|v| v.into_latest())
+        {
+            Some(existing) => Self::apply_due_relaxation(existing, now, api)?,
+            None => None,
+        };
+        let (substate, effective_at) = match existing {
+            // Changing the limit doesn't reset what was already withdrawn in the current period,
+            // so that the limit can't be bypassed by setting it again.
+            Some(mut existing) if Self::is_at_least_as_strict(&limit, &existing.limit) => {
+                existing.limit = limit;
+                existing.pending_relaxation = None;
+                (existing, now)
+            }
+            Some(mut existing) => {
+                let effective_at = Self::relaxation_effective_at(now);
+                existing.pending_relaxation = Some(PendingWithdrawalLimitRelaxation {
+                    limit: Some(limit),
+                    effective_at,
+                });
+                (existing, effective_at)
+            }
+            None => {
+                let substate = AccountWithdrawalLimitV1 {
+                    limit,
+                    current_period: Self::current_withdrawal_period(limit.period, api)?,
+                    withdrawn_in_current_period: Decimal::ZERO,
+                    pending_relaxation: None,
+                };
+                (substate, now)
+            }
+        };
+
+        api.key_value_entry_set_typed(
+            kv_store_entry_lock_handle,
+            &AccountWithdrawalLimitEntryPayload::from_content_source(substate),
+        )?;
+        api.key_value_entry_close(kv_store_entry_lock_handle)?;
+
+        Runtime::emit_event(
+            api,
+            SetWithdrawalLimitEvent {
+                resource_address,
+                limit,
+                effective_at,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    pub fn remove_withdrawal_limit<Y>(
+        resource_address: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let now = Runtime::current_time(api, TimePrecision::Minute)?;
+        let encoded_key = scrypto_encode(&resource_address).expect("Impossible Case!");
+        let kv_store_entry_lock_handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            AccountCollection::WithdrawalLimitKeyValue.collection_index(),
+            &encoded_key,
+            LockFlags::MUTABLE,
+        )?;
+
+        let existing = match api
+            .key_value_entry_get_typed::<AccountWithdrawalLimitEntryPayload>(
+                kv_store_entry_lock_handle,
+            )?
+            .map(|v| v.into_latest())
+        {
+            Some(existing) => Self::apply_due_relaxation(existing, now, api)?,
+            None => None,
+        };
+        let effective_at = match existing {
+            Some(mut existing) => {
+                let effective_at = Self::relaxation_effective_at(now);
+                existing.pending_relaxation = Some(PendingWithdrawalLimitRelaxation {
+                    limit: None,
+                    effective_at,
+                });
+                api.key_value_entry_set_typed(
+                    kv_store_entry_lock_handle,
+                    &AccountWithdrawalLimitEntryPayload::from_content_source(existing),
+                )?;
+                effective_at
+            }
+            None => {
+                api.key_value_entry_remove(kv_store_entry_lock_handle)?;
+                now
+            }
+        };
+        api.key_value_entry_close(kv_store_entry_lock_handle)?;
+
+        Runtime::emit_event(
+            api,
+            RemoveWithdrawalLimitEvent {
+                resource_address,
+                effective_at,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    /// Adds the amount of the withdrawn bucket to the amount withdrawn in the current period,
+    /// failing if this exceeds the withdrawal limit of the resource. Resources without a limit are
+    /// not tracked. Withdrawal limits were introduced in v1.1, so v1.0 accounts don't track any.
+    fn record_withdrawal<Y>(
+        resource_address: ResourceAddress,
+        bucket: &Bucket,
+        minor_version: AccountV1MinorVersion,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if minor_version < AccountV1MinorVersion::One {
+            return Ok(());
+        }
+
+        let amount = bucket.amount(api)?;
+        let encoded_key = scrypto_encode(&resource_address).expect("Impossible Case!");
+        let kv_store_entry_lock_handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            AccountCollection::WithdrawalLimitKeyValue.collection_index(),
+            &encoded_key,
+            LockFlags::MUTABLE,
+        )?;
+
+        let entry = api
+            .key_value_entry_get_typed::<AccountWithdrawalLimitEntryPayload>(
+                kv_store_entry_lock_handle,
+            )?
+            .map(|v| v.into_latest());
+        let mut substate = match entry {
+            Some(substate) if substate.pending_relaxation.is_some() => {
+                let now = Runtime::current_time(api, TimePrecision::Minute)?;
+                match Self::apply_due_relaxation(substate, now, api)? {
+                    Some(substate) => substate,
+                    None => {
+                        api.key_value_entry_remove(kv_store_entry_lock_handle)?;
+                        api.key_value_entry_close(kv_store_entry_lock_handle)?;
+                        return Ok(());
+                    }
+                }
+            }
+            Some(substate) => substate,
+            None => {
+                api.key_value_entry_close(kv_store_entry_lock_handle)?;
+                return Ok(());
+            }
+        };
+
+        let current_period = Self::current_withdrawal_period(substate.limit.period, api)?;
+        if substate.current_period != current_period {
+            substate.current_period = current_period;
+            substate.withdrawn_in_current_period = Decimal::ZERO;
+        }
+
+        let remaining_amount = substate
+            .limit
+            .amount
+            .checked_sub(substate.withdrawn_in_current_period)
+            .filter(|remaining| remaining.is_positive())
+            .unwrap_or(Decimal::ZERO);
+        if amount > remaining_amount {
+            api.key_value_entry_close(kv_store_entry_lock_handle)?;
+            return Err(AccountError::WithdrawalLimitExceeded {
+                resource_address,
+                limit: substate.limit,
+                remaining_amount,
+                requested_amount: amount,
+            }
+            .into());
+        }
+        substate.withdrawn_in_current_period = substate
+            .withdrawn_in_current_period
+            .checked_add(amount)
+            .expect("Can't overflow as it doesn't exceed the limit");
+
+        api.key_value_entry_set_typed(
+            kv_store_entry_lock_handle,
+            &AccountWithdrawalLimitEntryPayload::from_content_source(substate),
+        )?;
+        api.key_value_entry_close(kv_store_entry_lock_handle)?;
+
+        Ok(())
+    }
+
+    /// Applies the pending relaxation of the limit if it's due, returning `None` if the relaxation
+    /// removes the limit.
+    fn apply_due_relaxation<Y>(
+        mut substate: AccountWithdrawalLimitSubstate,
+        now: Instant,
+        api: &mut Y,
+    ) -> Result<Option<AccountWithdrawalLimitSubstate>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let relaxation = match &substate.pending_relaxation {
+            Some(relaxation)
+                if now.compare(relaxation.effective_at, TimeComparisonOperator::Gte) =>
+            {
+                relaxation.clone()
+            }
+            _ => return Ok(Some(substate)),
+        };
+        let limit = match relaxation.limit {
+            Some(limit) => limit,
+            None => return Ok(None),
+        };
+
+        if limit.period != substate.limit.period {
+            substate.current_period = Self::current_withdrawal_period(limit.period, api)?;
+            substate.withdrawn_in_current_period = Decimal::ZERO;
+        }
+        substate.limit = limit;
+        substate.pending_relaxation = None;
+        Ok(Some(substate))
+    }
+
+    /// Whether the new limit allows no more withdrawals than the existing one, in which case it
+    /// can take effect immediately.
+    fn is_at_least_as_strict(new: &WithdrawalLimit, existing: &WithdrawalLimit) -> bool {
+        new.period == existing.period && new.amount <= existing.amount
+    }
+
+    fn relaxation_effective_at(now: Instant) -> Instant {
+        now.add_days(WITHDRAWAL_LIMIT_RELAXATION_DELAY_IN_DAYS)
+            .expect("Current time is far from the maximum instant")
+    }
+
+    fn current_withdrawal_period<Y>(
+        period: WithdrawalLimitPeriod,
+        api: &mut Y,
+    ) -> Result<u64, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        match period {
+            WithdrawalLimitPeriod::Epoch => Ok(Runtime::current_epoch(api)?.number()),
+            WithdrawalLimitPeriod::Day => {
+                let now = Runtime::current_time(api, TimePrecision::Minute)?;
+                Ok(now.seconds_since_unix_epoch.div_euclid(SECONDS_PER_DAY) as u64)
+            }
+        }
+    }
+
     fn get_default_deposit_rule<Y>(api: &mut Y) -> Result<DefaultDepositRule, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
@@ -47,3 +47,17 @@ pub struct AddAuthorizedDepositorEvent {
 pub struct RemoveAuthorizedDepositorEvent {
     pub authorized_depositor_badge: ResourceOrNonFungible,
 }
+
+/// Raising a limit only takes effect after a delay, while lowering it takes effect immediately.
+#[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq)]
+pub struct SetWithdrawalLimitEvent {
+    pub resource_address: ResourceAddress,
+    pub limit: WithdrawalLimit,
+    pub effective_at: Instant,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, PartialEq, Eq)]
+pub struct RemoveWithdrawalLimitEvent {
+    pub resource_address: ResourceAddress,
+    pub effective_at: Instant,
+}
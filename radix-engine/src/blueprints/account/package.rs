@@ -9,12 +9,19 @@ use radix_engine_interface::hooks::OnVirtualizeInput;
 
 pub const ACCOUNT_ON_VIRTUALIZE_EXPORT_NAME: &str = "on_virtualize";
 
+/// The minor version of the Account V1 package. V1.1 introduced per-resource withdrawal limits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Sbor)]
+pub enum AccountV1MinorVersion {
+    Zero,
+    One,
+}
+
 pub struct AccountNativePackage;
 
 impl AccountNativePackage {
-    pub fn definition() -> PackageDefinition {
+    pub fn definition(minor_version: AccountV1MinorVersion) -> PackageDefinition {
         let blueprints = indexmap!(
-            ACCOUNT_BLUEPRINT.to_string() => AccountBlueprint::get_definition()
+            ACCOUNT_BLUEPRINT.to_string() => AccountBlueprint::get_definition(minor_version)
         );
 
         PackageDefinition { blueprints }
@@ -23,6 +30,7 @@ impl AccountNativePackage {
     pub fn invoke_export<Y>(
         export_name: &str,
         input: &IndexedScryptoValue,
+        minor_version: AccountV1MinorVersion,
         api: &mut Y,
     ) -> Result<IndexedScryptoValue, RuntimeError>
     where
@@ -164,7 +172,12 @@ impl AccountNativePackage {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
 
-                let rtn = AccountBlueprint::withdraw(input.resource_address, input.amount, api)?;
+                let rtn = AccountBlueprint::withdraw(
+                    input.resource_address,
+                    input.amount,
+                    minor_version,
+                    api,
+                )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
             ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => {
@@ -174,6 +187,7 @@ impl AccountNativePackage {
                 let rtn = AccountBlueprint::withdraw_non_fungibles(
                     input.resource_address,
                     input.ids,
+                    minor_version,
                     api,
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
@@ -202,6 +216,7 @@ impl AccountNativePackage {
                     input.amount_to_lock,
                     input.resource_address,
                     input.amount,
+                    minor_version,
                     api,
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
@@ -215,6 +230,7 @@ impl AccountNativePackage {
                     input.amount_to_lock,
                     input.resource_address,
                     input.ids,
+                    minor_version,
                     api,
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
@@ -288,6 +304,26 @@ impl AccountNativePackage {
                 let rtn = AccountBlueprint::remove_authorized_depositor(badge, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            ACCOUNT_SET_WITHDRAWAL_LIMIT_IDENT if minor_version >= AccountV1MinorVersion::One => {
+                let AccountSetWithdrawalLimitInput {
+                    resource_address,
+                    limit,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = AccountBlueprint::set_withdrawal_limit(resource_address, limit, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_REMOVE_WITHDRAWAL_LIMIT_IDENT
+                if minor_version >= AccountV1MinorVersion::One =>
+            {
+                let AccountRemoveWithdrawalLimitInput { resource_address } =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = AccountBlueprint::remove_withdrawal_limit(resource_address, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
 
             _ => Err(RuntimeError::ApplicationError(
                 ApplicationError::ExportDoesNotExist(export_name.to_string()),
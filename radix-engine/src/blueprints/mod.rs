@@ -11,6 +11,7 @@ pub mod test_utils;
 pub mod transaction_processor;
 pub mod transaction_tracker;
 pub mod util;
+pub mod vesting;
 
 pub(crate) mod internal_prelude {
     pub use super::models::*;
@@ -31,12 +31,14 @@ use crate::internal_prelude::*;
 ///         kind: Generic,
 ///         ident: BlueprintGenericParameterIdent,
 ///     },
-///     // In future
 ///     {
 ///         kind: StaticMultiVersioned,
-///         previous_versions: [V1, V2],
-///         latest: V3,
-///     }
+///         previous_versions: [
+///             1 => { updates_to: 2 },
+///             2 => { migrates_to: 3 },
+///         ],
+///         latest_version: 3,
+///     },
 /// ```
 ///
 /// Choosing  `StaticSingleVersioned`, which will create a
@@ -50,6 +52,16 @@ use crate::internal_prelude::*;
 /// and generate the following types:
 /// * `<BlueprintIdent><CollectionIdent>` - a type alias for the latest version (V1).
 /// * `Versioned<BlueprintIdent><CollectionIdent>` - the enum wrapper with a single version. This will be the content of `<BlueprintIdent><CollectionIdent>EntryPayload`.
+///
+/// Choosing `StaticMultiVersioned` creates the same types, but with a version of the enum wrapper
+/// for each of the given versions, which assumes the existence of a type for each version, eg
+/// `<BlueprintIdent><FieldIdent>V2`. The alias is for the latest version. The previous versions
+/// are updated as declared in [`sbor::define_versioned`], so that a payload can be read at its
+/// latest version with `try_into_latest_with(migrator)`, or with `into_latest()` if there are no
+/// `migrates_to` updates. Fields without migrations can be read with
+/// [`read_field_at_latest_version`], which also persists the updated payload. Fields with
+/// migrations, and key-value entries, aren't updated in the substate store - a blueprint which
+/// wants to persist the update must write the latest version back itself.
 #[allow(unused)]
 macro_rules! declare_native_blueprint_state {
     (
@@ -610,6 +622,129 @@ mod helper_macros {
                 }
             }
         };
+        // If every previous version updates with a `From` conversion, the payload can also be
+        // read at its latest version without a migrator.
+        (
+            content_trait: $content_trait:ident,
+            payload_trait: $payload_trait:ident,
+            ident_core: $ident_core:ident,
+            $(#[$attributes:meta])*
+            struct $payload_type_name:ident = {
+                kind: StaticMultiVersioned,
+                previous_versions: [
+                    $($version_num:literal => { updates_to: $update_to_version_num:literal }),*
+                    $(,)?
+                ],
+                latest_version: $latest_version:literal
+                $(,)?
+            }$(,)?
+        ) => {
+            generate_content_type!(
+                @multi_versioned
+                content_trait: $content_trait,
+                payload_trait: $payload_trait,
+                ident_core: $ident_core,
+                $(#[$attributes])*
+                struct $payload_type_name = {
+                    previous_versions: [
+                        $($version_num => { updates_to: $update_to_version_num },)*
+                    ],
+                    latest_version: $latest_version,
+                }
+            );
+
+            paste::paste! {
+                impl HasLatestVersion for $payload_type_name
+                {
+                    type Latest = <[<Versioned $ident_core>] as HasLatestVersion>::Latest;
+                    fn into_latest(self) -> Self::Latest {
+                        self.into_content().into_latest()
+                    }
+
+                    fn as_latest_ref(&self) -> Option<&Self::Latest> {
+                        self.as_ref().as_latest_ref()
+                    }
+                }
+            }
+        };
+        (
+            content_trait: $content_trait:ident,
+            payload_trait: $payload_trait:ident,
+            ident_core: $ident_core:ident,
+            $(#[$attributes:meta])*
+            struct $payload_type_name:ident = {
+                kind: StaticMultiVersioned,
+                previous_versions: [
+                    $($version_num:literal => { $update_kind:ident: $update_to_version_num:literal }),*
+                    $(,)?
+                ],
+                latest_version: $latest_version:literal
+                $(,)?
+            }$(,)?
+        ) => {
+            generate_content_type!(
+                @multi_versioned
+                content_trait: $content_trait,
+                payload_trait: $payload_trait,
+                ident_core: $ident_core,
+                $(#[$attributes])*
+                struct $payload_type_name = {
+                    previous_versions: [
+                        $($version_num => { $update_kind: $update_to_version_num },)*
+                    ],
+                    latest_version: $latest_version,
+                }
+            );
+        };
+        (
+            @multi_versioned
+            content_trait: $content_trait:ident,
+            payload_trait: $payload_trait:ident,
+            ident_core: $ident_core:ident,
+            $(#[$attributes:meta])*
+            struct $payload_type_name:ident = {
+                previous_versions: [
+                    $($version_num:literal => { $update_kind:ident: $update_to_version_num:literal },)*
+                ],
+                latest_version: $latest_version:literal,
+            }
+        ) => {
+            paste::paste! {
+                sbor::define_versioned!(
+                    $(#[$attributes])*
+                    pub enum [<Versioned $ident_core>] {
+                        previous_versions: [
+                            $($version_num => [<$ident_core V $version_num>]: { $update_kind: $update_to_version_num },)*
+                        ],
+                        latest_version: {
+                            $latest_version => $ident_core = [<$ident_core V $latest_version>],
+                        },
+                    }
+                );
+                declare_payload_new_type!(
+                    content_trait: $content_trait,
+                    payload_trait: $payload_trait,
+                    ----
+                    $(#[$attributes])*
+                    pub struct $payload_type_name([<Versioned $ident_core>]);
+                );
+
+                impl $payload_type_name {
+                    pub fn try_into_latest_with<M: VersionMigrator<[<Versioned $ident_core>]>>(
+                        self,
+                        migrator: &mut M,
+                    ) -> Result<$ident_core, M::Error> {
+                        self.into_content().try_into_latest_with(migrator)
+                    }
+                }
+
+                impl $content_trait<$payload_type_name> for $ident_core {
+                    fn into_content(self) -> [<Versioned $ident_core>] {
+                        self.into()
+                    }
+                }
+            }
+        };
         (
             content_trait: $content_trait:ident,
             payload_trait: $payload_trait:ident,
@@ -660,7 +795,6 @@ mod helper_macros {
                 impl [<$ident_core ContentMarker>] for RawScryptoValue<'_> {}
             }
         };
-        // TODO - Add support for some kind of StaticMultiVersioned type here
     }
 
     #[allow(unused)]
@@ -681,6 +815,20 @@ mod helper_macros {
                 "A StaticSingleVersioned key is not supported, because keys cannot be lazily updated, because they need to be static"
             );
         };
+        (
+            content_trait: $content_trait:ident,
+            payload_trait: $payload_trait:ident,
+            $(full_key_content: $full_key_content:tt,)?
+            $(#[$attributes:meta])*
+            struct $payload_type_name:ident = {
+                kind: StaticMultiVersioned,
+                $($rest:tt)*
+            }$(,)?
+        ) => {
+            compile_error!(
+                "A StaticMultiVersioned key is not supported, because keys cannot be lazily updated, because they need to be static"
+            );
+        };
         (
             content_trait: $content_trait:ident,
             payload_trait: $payload_trait:ident,
@@ -817,6 +965,17 @@ mod helper_macros {
         ) => {
             TypeRef::Static($aggregator.add_child_type_and_descendents::<$payload_alias>())
         };
+        (
+            $blueprint_ident:ident,
+            $aggregator:ident,
+            {
+                kind: StaticMultiVersioned,
+                $($rest:tt)*
+            },
+            $payload_alias:ident$(,)?
+        ) => {
+            TypeRef::Static($aggregator.add_child_type_and_descendents::<$payload_alias>())
+        };
         (
             $blueprint_ident:ident,
             $aggregator:ident,
@@ -842,7 +1001,7 @@ mod helper_macros {
             paste::paste! {
                 TypeRef::Generic([<$blueprint_ident Generic>]::$generic_ident.generic_index())
             }
-        }; // TODO - Add support for some kind of StaticMultiVersioned type here
+        };
     }
 
     #[allow(unused)]
@@ -958,6 +1117,57 @@ mod tests {
     #[derive(Debug, PartialEq, Eq, Sbor)]
     pub struct TestBlueprintRoyaltyV1;
 
+    #[derive(Debug, PartialEq, Eq, Sbor)]
+    pub struct TestBlueprintStateV1 {
+        pub count: u32,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Sbor)]
+    pub struct TestBlueprintStateV2 {
+        pub count: u64,
+    }
+
+    impl From<TestBlueprintStateV1> for TestBlueprintStateV2 {
+        fn from(value: TestBlueprintStateV1) -> Self {
+            Self {
+                count: value.count.into(),
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq, Eq, Sbor)]
+    pub struct TestBlueprintMigratedStateV1 {
+        pub count: u32,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Sbor)]
+    pub struct TestBlueprintMigratedStateV2 {
+        pub count: u32,
+        pub limit: u32,
+    }
+
+    /// A migration which needs some context - the limit to set on the migrated state.
+    pub struct SetLimit(u32);
+
+    impl VersionMigrator<VersionedTestBlueprintMigratedState> for SetLimit {
+        type Error = String;
+
+        fn migrate(
+            &mut self,
+            value: VersionedTestBlueprintMigratedState,
+        ) -> Result<VersionedTestBlueprintMigratedState, Self::Error> {
+            match value {
+                VersionedTestBlueprintMigratedState::V1(v1) if v1.count <= self.0 => Ok(
+                    VersionedTestBlueprintMigratedState::V2(TestBlueprintMigratedStateV2 {
+                        count: v1.count,
+                        limit: self.0,
+                    }),
+                ),
+                _ => Err("Count is over the limit".to_string()),
+            }
+        }
+    }
+
     #[derive(Debug, PartialEq, Eq, Sbor)]
     pub struct TestBlueprintMyCoolKeyValueStoreV1;
 
@@ -1014,6 +1224,26 @@ mod tests {
                     kind: Generic,
                     ident: Abc,
                 },
+            },
+            state: {
+                ident: State,
+                field_type: {
+                    kind: StaticMultiVersioned,
+                    previous_versions: [
+                        1 => { updates_to: 2 },
+                    ],
+                    latest_version: 2,
+                },
+            },
+            migrated_state: {
+                ident: MigratedState,
+                field_type: {
+                    kind: StaticMultiVersioned,
+                    previous_versions: [
+                        1 => { migrates_to: 2 },
+                    ],
+                    latest_version: 2,
+                },
             }
         },
         collections: {
@@ -1130,6 +1360,102 @@ mod tests {
         );
     }
 
+    #[test]
+    fn validate_multi_versioned_field_payload_is_read_at_latest_version() {
+        let payload = TestBlueprintStateFieldPayload {
+            content: VersionedTestBlueprintState::V1(TestBlueprintStateV1 { count: 3 }),
+        };
+        assert!(payload.as_latest_ref().is_none());
+
+        let latest = payload.into_latest();
+        assert_eq!(latest, TestBlueprintStateV2 { count: 3 });
+
+        let payload: TestBlueprintStateFieldPayload = latest.into_payload();
+        assert_eq!(
+            payload.as_latest_ref(),
+            Some(&TestBlueprintStateV2 { count: 3 })
+        );
+    }
+
+    #[test]
+    fn validate_multi_versioned_field_is_persisted_at_latest_version_when_read() {
+        struct TestFieldApi {
+            fields: IndexMap<FieldHandle, Vec<u8>>,
+            writes: usize,
+        }
+
+        impl ClientFieldApi<()> for TestFieldApi {
+            fn field_read(&mut self, handle: FieldHandle) -> Result<Vec<u8>, ()> {
+                self.fields.get(&handle).cloned().ok_or(())
+            }
+
+            fn field_write(&mut self, handle: FieldHandle, buffer: Vec<u8>) -> Result<(), ()> {
+                self.writes += 1;
+                self.fields.insert(handle, buffer);
+                Ok(())
+            }
+
+            fn field_lock(&mut self, _handle: FieldHandle) -> Result<(), ()> {
+                Ok(())
+            }
+
+            fn field_close(&mut self, _handle: FieldHandle) -> Result<(), ()> {
+                Ok(())
+            }
+        }
+
+        let mut api = TestFieldApi {
+            fields: indexmap!(
+                0 => scrypto_encode(&TestBlueprintStateFieldPayload {
+                    content: VersionedTestBlueprintState::V1(TestBlueprintStateV1 { count: 3 }),
+                })
+                .unwrap()
+            ),
+            writes: 0,
+        };
+
+        let latest =
+            read_field_at_latest_version::<TestBlueprintStateFieldPayload, _, _>(&mut api, 0)
+                .unwrap();
+        assert_eq!(latest, TestBlueprintStateV2 { count: 3 });
+        assert_eq!(api.writes, 1);
+        let stored: TestBlueprintStateFieldPayload = scrypto_decode(&api.fields[&0]).unwrap();
+        assert_eq!(
+            stored.as_latest_ref(),
+            Some(&TestBlueprintStateV2 { count: 3 })
+        );
+
+        // Once persisted at the latest version, it isn't written again
+        let latest =
+            read_field_at_latest_version::<TestBlueprintStateFieldPayload, _, _>(&mut api, 0)
+                .unwrap();
+        assert_eq!(latest, TestBlueprintStateV2 { count: 3 });
+        assert_eq!(api.writes, 1);
+    }
+
+    #[test]
+    fn validate_migrated_field_payload_is_read_at_latest_version_with_migrator() {
+        let payload = TestBlueprintMigratedStateFieldPayload {
+            content: VersionedTestBlueprintMigratedState::V1(TestBlueprintMigratedStateV1 {
+                count: 3,
+            }),
+        };
+        assert_eq!(
+            payload.try_into_latest_with(&mut SetLimit(5)),
+            Ok(TestBlueprintMigratedStateV2 { count: 3, limit: 5 })
+        );
+
+        let payload = TestBlueprintMigratedStateFieldPayload {
+            content: VersionedTestBlueprintMigratedState::V1(TestBlueprintMigratedStateV1 {
+                count: 7,
+            }),
+        };
+        assert_eq!(
+            payload.try_into_latest_with(&mut SetLimit(5)),
+            Err("Count is over the limit".to_string())
+        );
+    }
+
     #[test]
     fn validate_key_value_store_entry_payload_mutability() {
         fn create_payload() -> TestBlueprintMyCoolKeyValueStoreEntryPayload {
@@ -1214,6 +1540,12 @@ mod tests {
 
         let field = TestBlueprintField::GenericField;
         assert_eq!(1, FieldDescriptor::field_index(&field));
+
+        let field = TestBlueprintField::State;
+        assert_eq!(2, FieldDescriptor::field_index(&field));
+
+        let field = TestBlueprintField::MigratedState;
+        assert_eq!(3, FieldDescriptor::field_index(&field));
     }
 
     #[test]
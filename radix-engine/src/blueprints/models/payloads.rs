@@ -124,6 +124,30 @@ pub trait FieldContentSource<Payload: FieldPayload>: Sized {
     }
 }
 
+/// Reads the payload of a versioned field at its latest version.
+///
+/// If the field is stored at a previous version, the updated payload is written back, so that
+/// the update is done only once - the field must therefore be opened as `MUTABLE`. Payloads are
+/// always written at their latest version, by converting the latest content with `into_payload()`.
+pub fn read_field_at_latest_version<P, Y, E>(
+    api: &mut Y,
+    handle: FieldHandle,
+) -> Result<P::Latest, E>
+where
+    P: FieldPayload + HasLatestVersion + ScryptoEncode + ScryptoDecode,
+    P::Latest: FieldContentSource<P>,
+    Y: ClientFieldApi<E>,
+    E: Debug,
+{
+    let payload: P = api.field_read_typed(handle)?;
+    if payload.as_latest_ref().is_some() {
+        return Ok(payload.into_latest());
+    }
+    let payload: P = payload.into_latest().into_payload();
+    api.field_write_typed(handle, &payload)?;
+    Ok(payload.into_latest())
+}
+
 /// This trait is intended to be implemented by an explicit new type for for the given
 /// `{ content: T }` payload of a particular key value collection.
 pub trait KeyValueEntryPayload:
@@ -10,6 +10,7 @@ use crate::blueprints::pool::v1::package::*;
 use crate::blueprints::resource::*;
 use crate::blueprints::transaction_processor::*;
 use crate::blueprints::transaction_tracker::TransactionTrackerNativePackage;
+use crate::blueprints::vesting::VestingNativePackage;
 use crate::system::attached_modules::metadata::*;
 use crate::system::attached_modules::role_assignment::*;
 use crate::system::attached_modules::royalty::*;
@@ -17,8 +18,10 @@ use crate::system::attached_modules::royalty::*;
 lazy_static! {
     pub static ref CONSENSUS_MANAGER_PACKAGE_DEFINITION: PackageDefinition =
         ConsensusManagerNativePackage::definition();
-    pub static ref ACCOUNT_PACKAGE_DEFINITION: PackageDefinition =
-        AccountNativePackage::definition();
+    pub static ref ACCOUNT_PACKAGE_DEFINITION_V1_0: PackageDefinition =
+        AccountNativePackage::definition(AccountV1MinorVersion::Zero);
+    pub static ref ACCOUNT_PACKAGE_DEFINITION_V1_1: PackageDefinition =
+        AccountNativePackage::definition(AccountV1MinorVersion::One);
     pub static ref IDENTITY_PACKAGE_DEFINITION: PackageDefinition =
         IdentityNativePackage::definition();
     pub static ref ACCESS_CONTROLLER_PACKAGE_DEFINITION: PackageDefinition =
@@ -27,6 +30,8 @@ lazy_static! {
         PoolNativePackage::definition(PoolV1MinorVersion::Zero);
     pub static ref POOL_PACKAGE_DEFINITION_V1_1: PackageDefinition =
         PoolNativePackage::definition(PoolV1MinorVersion::One);
+    pub static ref VESTING_PACKAGE_DEFINITION: PackageDefinition =
+        VestingNativePackage::definition();
     pub static ref TRANSACTION_TRACKER_PACKAGE_DEFINITION: PackageDefinition =
         TransactionTrackerNativePackage::definition();
     pub static ref RESOURCE_PACKAGE_DEFINITION: PackageDefinition =
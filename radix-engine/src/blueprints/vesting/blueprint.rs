@@ -0,0 +1,427 @@
+use super::events::*;
+use super::substates::*;
+use crate::internal_prelude::*;
+use native_sdk::modules::metadata::*;
+use native_sdk::modules::role_assignment::*;
+use native_sdk::modules::royalty::*;
+use native_sdk::resource::*;
+use native_sdk::runtime::*;
+use radix_engine_interface::api::*;
+use radix_engine_interface::blueprints::component::*;
+use radix_engine_interface::blueprints::consensus_manager::*;
+use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::blueprints::vesting::*;
+use radix_engine_interface::prelude::*;
+use radix_engine_interface::*;
+
+pub const VESTING_BENEFICIARY_ROLE: &str = "beneficiary_role";
+pub const VESTING_ADMIN_ROLE: &str = "admin_role";
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum VestingError {
+    /// Occurs when the vested tokens are non-fungible - vesting is only defined for fungibles.
+    NonFungibleResourcesAreNotAccepted {
+        resource_address: ResourceAddress,
+    },
+
+    /// Occurs when the schedule does not satisfy `start <= cliff <= end`.
+    InvalidSchedule {
+        schedule: VestingSchedule,
+    },
+
+    /// Occurs when the admin attempts to revoke an already revoked vesting.
+    AlreadyRevoked,
+
+    DecimalOverflowError,
+}
+
+impl From<VestingError> for RuntimeError {
+    fn from(error: VestingError) -> Self {
+        RuntimeError::ApplicationError(ApplicationError::VestingError(error))
+    }
+}
+
+pub struct VestingBlueprint;
+
+impl VestingBlueprint {
+    pub fn definition() -> BlueprintDefinitionInit {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+
+        let feature_set = VestingFeatureSet::all_features();
+        let state = VestingStateSchemaInit::create_schema_init(&mut aggregator);
+
+        let mut functions = index_map_new();
+        functions.insert(
+            VESTING_INSTANTIATE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingInstantiateInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingInstantiateOutput>(),
+                ),
+                export: VESTING_INSTANTIATE_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            VESTING_CLAIM_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingClaimInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingClaimOutput>(),
+                ),
+                export: VESTING_CLAIM_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            VESTING_REVOKE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingRevokeInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingRevokeOutput>(),
+                ),
+                export: VESTING_REVOKE_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            VESTING_GET_VESTED_AMOUNT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingGetVestedAmountInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingGetVestedAmountOutput>(),
+                ),
+                export: VESTING_GET_VESTED_AMOUNT_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            VESTING_GET_CLAIMABLE_AMOUNT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingGetClaimableAmountInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<VestingGetClaimableAmountOutput>(),
+                ),
+                export: VESTING_GET_CLAIMABLE_AMOUNT_EXPORT_NAME.to_string(),
+            },
+        );
+
+        let event_schema = event_schema! {
+            aggregator,
+            [
+                ClaimEvent,
+                RevokeEvent
+            ]
+        };
+
+        let schema = generate_full_schema(aggregator);
+
+        BlueprintDefinitionInit {
+            blueprint_type: BlueprintType::default(),
+            is_transient: false,
+            dependencies: indexset!(),
+            feature_set,
+            schema: BlueprintSchemaInit {
+                generics: vec![],
+                schema,
+                state,
+                events: event_schema,
+                types: BlueprintTypeSchemaInit::default(),
+                functions: BlueprintFunctionsSchemaInit { functions },
+                hooks: BlueprintHooksInit::default(),
+            },
+            royalty_config: PackageRoyaltyConfig::default(),
+            auth_config: AuthConfig {
+                function_auth: FunctionAuth::AllowAll,
+                method_auth: MethodAuthTemplate::StaticRoleDefinition(roles_template! {
+                    roles {
+                        VESTING_BENEFICIARY_ROLE;
+                        VESTING_ADMIN_ROLE;
+                    },
+                    methods {
+                        VESTING_GET_VESTED_AMOUNT_IDENT => MethodAccessibility::Public;
+                        VESTING_GET_CLAIMABLE_AMOUNT_IDENT => MethodAccessibility::Public;
+                        VESTING_CLAIM_IDENT => [VESTING_BENEFICIARY_ROLE];
+                        VESTING_REVOKE_IDENT => [VESTING_ADMIN_ROLE];
+                    }
+                }),
+            },
+        }
+    }
+
+    pub fn instantiate<Y>(
+        owner_role: OwnerRole,
+        beneficiary_rule: AccessRule,
+        admin_rule: AccessRule,
+        bucket: Bucket,
+        schedule: VestingSchedule,
+        address_reservation: Option<GlobalAddressReservation>,
+        api: &mut Y,
+    ) -> Result<VestingInstantiateOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let resource_address = bucket.resource_address(api)?;
+        if let ResourceType::NonFungible { .. } =
+            ResourceManager(resource_address).resource_type(api)?
+        {
+            Err(VestingError::NonFungibleResourcesAreNotAccepted { resource_address })?
+        }
+        if !schedule.is_valid() {
+            Err(VestingError::InvalidSchedule { schedule })?
+        }
+
+        let address_reservation = match address_reservation {
+            Some(address_reservation) => address_reservation,
+            None => {
+                api.allocate_global_address(BlueprintId {
+                    package_address: VESTING_PACKAGE,
+                    blueprint_name: VESTING_BLUEPRINT.to_string(),
+                })?
+                .0
+            }
+        };
+
+        let role_assignment = RoleAssignment::create(
+            owner_role,
+            indexmap! {
+                ModuleId::Main => roles_init! {
+                    RoleKey { key: VESTING_BENEFICIARY_ROLE.to_owned() } => beneficiary_rule;
+                    RoleKey { key: VESTING_ADMIN_ROLE.to_owned() } => admin_rule;
+                }
+            },
+            api,
+        )?
+        .0;
+        let metadata = Metadata::create_with_data(
+            metadata_init! {
+                "vesting_resource" => GlobalAddress::from(resource_address), locked;
+            },
+            api,
+        )?;
+        let royalty = ComponentRoyalty::create(ComponentRoyaltyConfig::default(), api)?;
+        let object_id = {
+            let total_amount = bucket.amount(api)?;
+            let mut vault = Vault::create(resource_address, api)?;
+            vault.put(bucket, api)?;
+            let substate = VestingSubstate {
+                vault,
+                schedule,
+                total_amount,
+                claimed_amount: Decimal::ZERO,
+                revoked_at: None,
+            };
+            api.new_simple_object(
+                VESTING_BLUEPRINT,
+                indexmap! {
+                    VestingField::State.field_index() => FieldValue::new(VestingStateFieldPayload::from_content_source(substate)),
+                },
+            )?
+        };
+
+        let address = api.globalize(
+            object_id,
+            indexmap!(
+                AttachedModuleId::RoleAssignment => role_assignment.0,
+                AttachedModuleId::Metadata => metadata.0,
+                AttachedModuleId::Royalty => royalty.0,
+            ),
+            Some(address_reservation),
+        )?;
+
+        Ok(Global::new(ComponentAddress::new_or_panic(
+            address.as_node_id().0,
+        )))
+    }
+
+    pub fn claim<Y>(api: &mut Y) -> Result<VestingClaimOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let now = Runtime::current_time(api, TimePrecision::Minute)?;
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            VestingField::State.field_index(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut substate = api
+            .field_read_typed::<VestingStateFieldPayload>(handle)?
+            .into_latest();
+
+        let claimable_amount = Self::claimable_amount(&substate, now, api)?;
+        let bucket = substate.vault.take(claimable_amount, api)?;
+        substate.claimed_amount = substate
+            .claimed_amount
+            .checked_add(claimable_amount)
+            .ok_or(VestingError::DecimalOverflowError)?;
+
+        api.field_write_typed(
+            handle,
+            &VestingStateFieldPayload::from_content_source(substate),
+        )?;
+        api.field_close(handle)?;
+
+        Runtime::emit_event(
+            api,
+            ClaimEvent {
+                amount: claimable_amount,
+            },
+        )?;
+
+        Ok(bucket)
+    }
+
+    /// Stops the vesting, returning the tokens which have not vested yet. The tokens which have
+    /// vested by now remain claimable by the beneficiary.
+    pub fn revoke<Y>(api: &mut Y) -> Result<VestingRevokeOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let now = Runtime::current_time(api, TimePrecision::Minute)?;
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            VestingField::State.field_index(),
+            LockFlags::MUTABLE,
+        )?;
+        let mut substate = api
+            .field_read_typed::<VestingStateFieldPayload>(handle)?
+            .into_latest();
+
+        if substate.revoked_at.is_some() {
+            return Err(VestingError::AlreadyRevoked.into());
+        }
+
+        let vested_amount = Self::vested_amount(&substate, now, api)?;
+        let unvested_amount = substate
+            .total_amount
+            .checked_sub(vested_amount)
+            .ok_or(VestingError::DecimalOverflowError)?;
+        let bucket = substate.vault.take(unvested_amount, api)?;
+        substate.total_amount = vested_amount;
+        substate.revoked_at = Some(now);
+
+        api.field_write_typed(
+            handle,
+            &VestingStateFieldPayload::from_content_source(substate),
+        )?;
+        api.field_close(handle)?;
+
+        Runtime::emit_event(
+            api,
+            RevokeEvent {
+                vested_amount,
+                unvested_amount,
+            },
+        )?;
+
+        Ok(bucket)
+    }
+
+    pub fn get_vested_amount<Y>(api: &mut Y) -> Result<VestingGetVestedAmountOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let now = Runtime::current_time(api, TimePrecision::Minute)?;
+        Self::with_state(api, |substate, api| {
+            Self::vested_amount(&substate, now, api)
+        })
+    }
+
+    pub fn get_claimable_amount<Y>(
+        api: &mut Y,
+    ) -> Result<VestingGetClaimableAmountOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let now = Runtime::current_time(api, TimePrecision::Minute)?;
+        Self::with_state(api, |substate, api| {
+            Self::claimable_amount(&substate, now, api)
+        })
+    }
+
+    //===================
+    // Utility Functions
+    //===================
+
+    /// The amount vested at the given time, rounded down to the divisibility of the resource.
+    fn vested_amount<Y>(
+        substate: &VestingSubstate,
+        now: Instant,
+        api: &mut Y,
+    ) -> Result<Decimal, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if substate.revoked_at.is_some() {
+            return Ok(substate.total_amount);
+        }
+
+        let divisibility = substate
+            .vault
+            .resource_address(api)
+            .and_then(|resource_address| ResourceManager(resource_address).resource_type(api))
+            .map(|resource_type| {
+                if let ResourceType::Fungible { divisibility } = resource_type {
+                    divisibility
+                } else {
+                    panic!("Impossible case, we check for this in the constructor.")
+                }
+            })?;
+
+        substate
+            .schedule
+            .vested_amount(substate.total_amount, now)
+            .and_then(|amount| amount.checked_round(divisibility, RoundingMode::ToNegativeInfinity))
+            .ok_or(VestingError::DecimalOverflowError.into())
+    }
+
+    fn claimable_amount<Y>(
+        substate: &VestingSubstate,
+        now: Instant,
+        api: &mut Y,
+    ) -> Result<Decimal, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::vested_amount(substate, now, api)?
+            .checked_sub(substate.claimed_amount)
+            .ok_or(VestingError::DecimalOverflowError.into())
+    }
+
+    /// Opens the substate, executes the callback, and closes the substate.
+    fn with_state<Y, F, O>(api: &mut Y, callback: F) -> Result<O, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+        F: FnOnce(VestingSubstate, &mut Y) -> Result<O, RuntimeError>,
+    {
+        // Open
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            VestingField::State.field_index(),
+            LockFlags::read_only(),
+        )?;
+        let substate = api
+            .field_read_typed::<VestingStateFieldPayload>(handle)?
+            .into_latest();
+
+        // Op
+        let rtn = callback(substate, api);
+
+        // Close
+        if rtn.is_ok() {
+            api.field_close(handle)?;
+        }
+        rtn
+    }
+}
@@ -0,0 +1,12 @@
+use crate::types::*;
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct ClaimEvent {
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct RevokeEvent {
+    pub vested_amount: Decimal,
+    pub unvested_amount: Decimal,
+}
@@ -0,0 +1,9 @@
+mod blueprint;
+mod events;
+mod package;
+mod substates;
+
+pub use blueprint::*;
+pub use events::*;
+pub use package::*;
+pub use substates::*;
@@ -0,0 +1,87 @@
+use super::blueprint::*;
+use crate::internal_prelude::*;
+use radix_engine_interface::api::*;
+use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::blueprints::vesting::*;
+
+pub struct VestingNativePackage;
+
+impl VestingNativePackage {
+    pub fn definition() -> PackageDefinition {
+        let blueprints = indexmap!(
+            VESTING_BLUEPRINT.to_string() => VestingBlueprint::definition()
+        );
+
+        PackageDefinition { blueprints }
+    }
+
+    pub fn invoke_export<Y>(
+        export_name: &str,
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        match export_name {
+            VESTING_INSTANTIATE_EXPORT_NAME => {
+                let VestingInstantiateInput {
+                    owner_role,
+                    beneficiary_rule,
+                    admin_rule,
+                    bucket,
+                    schedule,
+                    address_reservation,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = VestingBlueprint::instantiate(
+                    owner_role,
+                    beneficiary_rule,
+                    admin_rule,
+                    bucket,
+                    schedule,
+                    address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            VESTING_CLAIM_EXPORT_NAME => {
+                let VestingClaimInput {} = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = VestingBlueprint::claim(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            VESTING_REVOKE_EXPORT_NAME => {
+                let VestingRevokeInput {} = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = VestingBlueprint::revoke(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            VESTING_GET_VESTED_AMOUNT_EXPORT_NAME => {
+                let VestingGetVestedAmountInput {} = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = VestingBlueprint::get_vested_amount(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            VESTING_GET_CLAIMABLE_AMOUNT_EXPORT_NAME => {
+                let VestingGetClaimableAmountInput {} = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = VestingBlueprint::get_claimable_amount(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+
+            _ => Err(RuntimeError::ApplicationError(
+                ApplicationError::ExportDoesNotExist(export_name.to_string()),
+            )),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::blueprints::models::*;
+use radix_engine_interface::blueprints::vesting::*;
+use radix_engine_interface::prelude::*;
+
+declare_native_blueprint_state! {
+    blueprint_ident: Vesting,
+    blueprint_snake_case: vesting,
+    features: {
+    },
+    fields: {
+        state:  {
+            ident: State,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::Always,
+        }
+    },
+    collections: {
+    }
+}
+
+pub type VestingStateV1 = VestingSubstate;
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+pub struct VestingSubstate {
+    /// The vault holding the tokens which have not been claimed yet.
+    pub vault: Vault,
+
+    /// The schedule by which the tokens vest.
+    pub schedule: VestingSchedule,
+
+    /// The total amount of tokens which vest over the schedule. Upon revocation, this is lowered
+    /// to the amount which had vested by then.
+    pub total_amount: Decimal,
+
+    /// The amount of tokens which the beneficiary has claimed so far.
+    pub claimed_amount: Decimal,
+
+    /// The time at which the admin revoked the vesting, if they did.
+    pub revoked_at: Option<Instant>,
+}
@@ -13,6 +13,7 @@ use crate::blueprints::resource::{
     VaultError, WorktopError,
 };
 use crate::blueprints::transaction_processor::TransactionProcessorError;
+use crate::blueprints::vesting::VestingError;
 use crate::kernel::call_frame::{
     CallFrameDrainSubstatesError, CallFrameRemoveSubstateError, CallFrameScanKeysError,
     CallFrameScanSortedSubstatesError, CallFrameSetSubstateError, CloseSubstateError,
@@ -469,6 +470,8 @@ pub enum ApplicationError {
     TwoResourcePoolError(TwoResourcePoolError),
 
     MultiResourcePoolError(MultiResourcePoolError),
+
+    VestingError(VestingError),
 }
 
 impl From<TransactionProcessorError> for ApplicationError {
@@ -1,5 +1,7 @@
 use crate::blueprints::access_controller::*;
-use crate::blueprints::account::{AccountNativePackage, AccountOwnerBadgeData};
+use crate::blueprints::account::{
+    AccountNativePackage, AccountOwnerBadgeData, AccountV1MinorVersion,
+};
 use crate::blueprints::consensus_manager::ConsensusManagerNativePackage;
 use crate::blueprints::identity::{IdentityNativePackage, IdentityOwnerBadgeData};
 use crate::blueprints::package::{
@@ -649,6 +651,7 @@ pub fn create_substate_flash_for_genesis() -> FlashReceipt {
             new_resources,
             new_vaults,
             vault_balance_changes: index_map_new(),
+            field_changes: index_map_new(),
         },
         substate_system_structures,
     }
@@ -1021,7 +1024,7 @@ pub fn create_system_bootstrap_transaction(
             function_name: PACKAGE_PUBLISH_NATIVE_IDENT.to_string(),
             args: to_manifest_value_and_unwrap!(&PackagePublishNativeManifestInput {
                 package_address: Some(id_allocator.new_address_reservation_id()),
-                definition: AccountNativePackage::definition(),
+                definition: AccountNativePackage::definition(AccountV1MinorVersion::Zero),
                 native_package_code_id: ACCOUNT_CODE_ID,
                 metadata: metadata_init! {
                     "name" => "Account Package".to_owned(), locked;
@@ -1,7 +1,8 @@
 use radix_engine_common::data::scrypto::ScryptoDecode;
 use radix_engine_common::prelude::{
-    scrypto_decode, scrypto_encode, ScryptoCustomExtension, ScryptoEncode, ScryptoValue,
-    VersionedScryptoSchema,
+    scrypto_decode, scrypto_encode, ScryptoCustomExtension, ScryptoDecoder, ScryptoEncode,
+    ScryptoRawValue, ScryptoValue, VersionedScryptoSchema, SCRYPTO_SBOR_V1_MAX_DEPTH,
+    SCRYPTO_SBOR_V1_PAYLOAD_PREFIX,
 };
 use radix_engine_interface::api::{AttachedModuleId, CollectionIndex, ModuleId};
 use radix_engine_interface::blueprints::package::*;
@@ -11,15 +12,19 @@ use radix_engine_store_interface::db_key_mapper::{
     MappedCommittableSubstateDatabase, SubstateKeyContent,
 };
 use radix_engine_store_interface::interface::{
-    CommittableSubstateDatabase, ListableSubstateDatabase,
+    CommittableSubstateDatabase, DbSubstateValue, ListableSubstateDatabase,
 };
 use radix_engine_store_interface::{
     db_key_mapper::{DatabaseKeyMapper, MappedSubstateDatabase, SpreadPrefixKeyMapper},
     interface::SubstateDatabase,
 };
 use sbor::rust::prelude::*;
+use sbor::traversal::calculate_value_tree_body_byte_length;
 use sbor::LocalTypeId;
-use sbor::{validate_payload_against_schema, HasLatestVersion, LocatedValidationError};
+use sbor::{
+    validate_payload_against_schema, BorrowingDecoder, DecodeError, Decoder, HasLatestVersion,
+    LocatedValidationError, ValueKind,
+};
 
 use crate::blueprints::package::PackageBlueprintVersionDefinitionEntrySubstate;
 use crate::internal_prelude::{IndexEntrySubstate, SortedIndexEntrySubstate};
@@ -99,6 +104,7 @@ pub enum SystemReaderError {
     SchemaDoesNotExist,
     TargetNotSupported,
     BlueprintTypeNotFound(String),
+    InvalidFieldSubstate(DecodeError),
 }
 
 /// A System Layer (Layer 2) abstraction over an underlying substate database
@@ -185,41 +191,22 @@ impl<'a, S: SubstateDatabase> SystemDatabaseReader<'a, S> {
         module_id: ModuleId,
         field_index: u8,
     ) -> Result<(IndexedScryptoValue, PartitionNumber), SystemReaderError> {
-        let blueprint_id = self.get_blueprint_id(node_id, module_id)?;
-        let definition = self.get_blueprint_definition(&blueprint_id)?;
-        let partition_description = &definition
-            .interface
-            .state
-            .fields
-            .as_ref()
-            .ok_or_else(|| SystemReaderError::FieldDoesNotExist)?
-            .0;
-        let partition_number = match partition_description {
-            PartitionDescription::Logical(offset) => {
-                let base_partition = match module_id {
-                    ModuleId::Main => MAIN_BASE_PARTITION,
-                    ModuleId::Metadata => METADATA_BASE_PARTITION,
-                    ModuleId::Royalty => ROYALTY_BASE_PARTITION,
-                    ModuleId::RoleAssignment => ROLE_ASSIGNMENT_BASE_PARTITION,
-                };
-                base_partition.at_offset(*offset).unwrap()
-            }
-            PartitionDescription::Physical(partition_number) => *partition_number,
-        };
-
-        let substate: FieldSubstate<ScryptoValue> = self
-            .substate_db
-            .get_mapped::<SpreadPrefixKeyMapper, _>(
-                node_id,
-                partition_number,
-                &SubstateKey::Field(field_index),
-            )
-            .ok_or_else(|| SystemReaderError::FieldDoesNotExist)?;
-
-        Ok((
-            IndexedScryptoValue::from_scrypto_value(substate.into_payload()),
-            partition_number,
-        ))
+        let partition_number = self.get_field_partition_number(node_id, module_id)?;
+        let substate = self.read_field_substate(node_id, partition_number, field_index)?;
+
+        // The payload is borrowed from the substate and copied as is, rather than decoded into a
+        // `ScryptoValue` only to be encoded again.
+        let payload = borrow_field_substate_payload(&substate)
+            .map_err(SystemReaderError::InvalidFieldSubstate)?;
+        let value_body = payload.value_body_bytes();
+        let mut value_bytes = Vec::with_capacity(2 + value_body.len());
+        value_bytes.push(SCRYPTO_SBOR_V1_PAYLOAD_PREFIX);
+        value_bytes.push(payload.value_kind().as_u8());
+        value_bytes.extend_from_slice(value_body);
+        let value = IndexedScryptoValue::from_vec(value_bytes)
+            .map_err(SystemReaderError::InvalidFieldSubstate)?;
+
+        Ok((value, partition_number))
     }
 
     pub fn read_typed_kv_entry<K: ScryptoEncode, V: ScryptoDecode>(
@@ -243,6 +230,56 @@ impl<'a, S: SubstateDatabase> SystemDatabaseReader<'a, S> {
         module_id: ModuleId,
         field_index: u8,
     ) -> Result<V, SystemReaderError> {
+        let partition_number = self.get_field_partition_number(node_id, module_id)?;
+
+        let substate: FieldSubstate<V> = self
+            .substate_db
+            .get_mapped::<SpreadPrefixKeyMapper, _>(
+                node_id,
+                partition_number,
+                &SubstateKey::Field(field_index),
+            )
+            .ok_or_else(|| SystemReaderError::FieldDoesNotExist)?;
+
+        Ok(substate.into_payload())
+    }
+
+    /// Reads the encoded `FieldSubstate` of an object field, which can be decoded with
+    /// [`scrypto_decode_borrowed`] into a value borrowing from it.
+    ///
+    /// The database hands out owned substate values, so this is the only allocation needed to
+    /// read a field - any strings and byte slices can then be borrowed from the returned value.
+    ///
+    /// [`scrypto_decode_borrowed`]: radix_engine_common::data::scrypto::scrypto_decode_borrowed
+    pub fn read_object_field_bytes(
+        &self,
+        node_id: &NodeId,
+        module_id: ModuleId,
+        field_index: u8,
+    ) -> Result<DbSubstateValue, SystemReaderError> {
+        let partition_number = self.get_field_partition_number(node_id, module_id)?;
+        self.read_field_substate(node_id, partition_number, field_index)
+    }
+
+    fn read_field_substate(
+        &self,
+        node_id: &NodeId,
+        partition_number: PartitionNumber,
+        field_index: u8,
+    ) -> Result<DbSubstateValue, SystemReaderError> {
+        self.substate_db
+            .get_substate(
+                &SpreadPrefixKeyMapper::to_db_partition_key(node_id, partition_number),
+                &SpreadPrefixKeyMapper::to_db_sort_key(&SubstateKey::Field(field_index)),
+            )
+            .ok_or_else(|| SystemReaderError::FieldDoesNotExist)
+    }
+
+    fn get_field_partition_number(
+        &self,
+        node_id: &NodeId,
+        module_id: ModuleId,
+    ) -> Result<PartitionNumber, SystemReaderError> {
         let blueprint_id = self.get_blueprint_id(node_id, module_id)?;
         let definition = self.get_blueprint_definition(&blueprint_id)?;
         let partition_description = &definition
@@ -265,16 +302,7 @@ impl<'a, S: SubstateDatabase> SystemDatabaseReader<'a, S> {
             PartitionDescription::Physical(partition_number) => *partition_number,
         };
 
-        let substate: FieldSubstate<V> = self
-            .substate_db
-            .get_mapped::<SpreadPrefixKeyMapper, _>(
-                node_id,
-                partition_number,
-                &SubstateKey::Field(field_index),
-            )
-            .ok_or_else(|| SystemReaderError::FieldDoesNotExist)?;
-
-        Ok(substate.into_payload())
+        Ok(partition_number)
     }
 
     pub fn get_partition_of_collection(
@@ -1202,3 +1230,27 @@ impl<'a, S: SubstateDatabase + CommittableSubstateDatabase> SystemDatabaseWriter
         Ok(())
     }
 }
+
+/// Borrows the payload of an encoded `FieldSubstate::V1(FieldSubstateV1 { payload, lock_status })`,
+/// without decoding it.
+fn borrow_field_substate_payload(substate: &[u8]) -> Result<ScryptoRawValue<'_>, DecodeError> {
+    let mut decoder = ScryptoDecoder::new(substate, SCRYPTO_SBOR_V1_MAX_DEPTH);
+    decoder.read_and_check_payload_prefix(SCRYPTO_SBOR_V1_PAYLOAD_PREFIX)?;
+    decoder.read_and_check_value_kind(ValueKind::Enum)?;
+    decoder.read_expected_discriminator(0)?;
+    decoder.read_and_check_size(1)?;
+    decoder.read_and_check_value_kind(ValueKind::Tuple)?;
+    decoder.read_and_check_size(2)?;
+    let value_kind = decoder.read_value_kind()?;
+    // The payload sits below the enum and the tuple
+    let length = calculate_value_tree_body_byte_length::<ScryptoCustomExtension>(
+        decoder.peek_remaining(),
+        value_kind,
+        2,
+        decoder.get_depth_limit(),
+    )?;
+    let value_body = decoder.read_slice_from_payload(length)?;
+    Ok(ScryptoRawValue::new_from_valid_value_body_slice(
+        value_kind, value_body,
+    ))
+}
@@ -0,0 +1,103 @@
+use crate::internal_prelude::*;
+use crate::system::system_callback_api::SystemCallbackObject;
+use crate::transaction::*;
+use radix_engine_interface::network::NetworkDefinition;
+use radix_engine_store_interface::interface::SubstateDatabase;
+use transaction::model::PreviewIntentV1;
+
+/// A margin which should comfortably cover the variance between the preview and the actual
+/// execution (including the cost of the added `lock_fee` call itself).
+pub const DEFAULT_FEE_ESTIMATION_MARGIN_PERCENTAGE: u16 = 20;
+
+/// The fee estimated by previewing a transaction (see [`estimate_fee()`]).
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct FeeEstimate {
+    /// The costs observed in the preview.
+    pub fee_summary: TransactionFeeSummary,
+    /// The margin applied on top of each of the observed costs.
+    pub margin_percentage: u16,
+    /// Execution cost in XRD, including the margin.
+    pub execution_cost_in_xrd: Decimal,
+    /// Finalization cost in XRD, including the margin.
+    pub finalization_cost_in_xrd: Decimal,
+    /// Storage cost in XRD, including the margin.
+    pub storage_cost_in_xrd: Decimal,
+    /// Royalty cost in XRD, including the margin.
+    pub royalty_cost_in_xrd: Decimal,
+    /// Tipping cost in XRD, including the margin.
+    pub tipping_cost_in_xrd: Decimal,
+}
+
+impl FeeEstimate {
+    /// The amount of XRD which should be locked by the transaction's `lock_fee` call.
+    pub fn recommended_lock_fee(&self) -> Decimal {
+        self.execution_cost_in_xrd
+            .checked_add(self.finalization_cost_in_xrd)
+            .unwrap()
+            .checked_add(self.storage_cost_in_xrd)
+            .unwrap()
+            .checked_add(self.royalty_cost_in_xrd)
+            .unwrap()
+            .checked_add(self.tipping_cost_in_xrd)
+            .unwrap()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum FeeEstimationError {
+    PreviewError(PreviewError),
+    TransactionRejected(RejectionReason),
+    TransactionAborted(AbortReason),
+    TransactionFailed(RuntimeError),
+}
+
+/// Previews the given intent (whose manifest is expected not to lock any fee) using free credit,
+/// and estimates the fee which it should lock, with the given margin on top of each cost.
+///
+/// The preview must succeed - otherwise the costs of a failing transaction are meaningless and an
+/// error is returned.
+pub fn estimate_fee<S: SubstateDatabase, V: SystemCallbackObject + Clone>(
+    substate_db: &S,
+    vm: V,
+    network: &NetworkDefinition,
+    mut preview_intent: PreviewIntentV1,
+    margin_percentage: u16,
+) -> Result<FeeEstimate, FeeEstimationError> {
+    preview_intent.flags.use_free_credit = true;
+    let receipt = execute_preview(substate_db, vm, network, preview_intent, false)
+        .map_err(FeeEstimationError::PreviewError)?;
+
+    match receipt.result {
+        TransactionResult::Commit(commit) => match commit.outcome {
+            TransactionOutcome::Success(_) => {}
+            TransactionOutcome::Failure(error) => {
+                return Err(FeeEstimationError::TransactionFailed(error))
+            }
+        },
+        TransactionResult::Reject(rejection) => {
+            return Err(FeeEstimationError::TransactionRejected(rejection.reason))
+        }
+        TransactionResult::Abort(abort) => {
+            return Err(FeeEstimationError::TransactionAborted(abort.reason))
+        }
+    }
+
+    let fee_summary = receipt.fee_summary;
+    let margin_multiplier = Decimal::ONE
+        .checked_add(
+            Decimal::ONE_HUNDREDTH
+                .checked_mul(margin_percentage)
+                .unwrap(),
+        )
+        .unwrap();
+    let with_margin = |cost: Decimal| cost.checked_mul(margin_multiplier).unwrap();
+    Ok(FeeEstimate {
+        execution_cost_in_xrd: with_margin(fee_summary.total_execution_cost_in_xrd),
+        finalization_cost_in_xrd: with_margin(fee_summary.total_finalization_cost_in_xrd),
+        storage_cost_in_xrd: with_margin(fee_summary.total_storage_cost_in_xrd),
+        royalty_cost_in_xrd: with_margin(fee_summary.total_royalty_cost_in_xrd),
+        tipping_cost_in_xrd: with_margin(fee_summary.total_tipping_cost_in_xrd),
+        margin_percentage,
+        fee_summary,
+    })
+}
@@ -1,3 +1,4 @@
+mod fee_estimation;
 mod preview_executor;
 mod state_update_summary;
 mod system_structure;
@@ -5,6 +6,7 @@ mod transaction_executor;
 mod transaction_receipt;
 mod transaction_reconciler;
 
+pub use fee_estimation::*;
 pub use preview_executor::*;
 pub use state_update_summary::*;
 pub use system_structure::*;
@@ -2,6 +2,7 @@ use crate::system::system_callback_api::SystemCallbackObject;
 use crate::transaction::TransactionReceipt;
 use crate::transaction::*;
 use radix_engine_interface::network::NetworkDefinition;
+use radix_engine_store_interface::db_key_mapper::SpreadPrefixKeyMapper;
 use radix_engine_store_interface::interface::*;
 use transaction::errors::TransactionValidationError;
 use transaction::model::PreviewIntentV1;
@@ -36,3 +37,30 @@ pub fn execute_preview<S: SubstateDatabase, V: SystemCallbackObject + Clone>(
         &validated.get_executable(),
     ))
 }
+
+/// Executes a preview (see [`execute_preview()`]) and, if the preview resulted in a commit, applies
+/// its state changes to the given database.
+///
+/// This is meant to be used with a disposable database layered on top of the actual state (e.g. an
+/// `OverlaySubstateDatabase`), so that several previews can be chained (each one observing the
+/// results of the previous ones) without affecting the actual state.
+pub fn execute_and_commit_preview<
+    S: SubstateDatabase + CommittableSubstateDatabase,
+    V: SystemCallbackObject + Clone,
+>(
+    substate_db: &mut S,
+    vm: V,
+    network: &NetworkDefinition,
+    preview_intent: PreviewIntentV1,
+    with_kernel_trace: bool,
+) -> Result<TransactionReceipt, PreviewError> {
+    let receipt = execute_preview(substate_db, vm, network, preview_intent, with_kernel_trace)?;
+    if let TransactionResult::Commit(commit) = &receipt.result {
+        substate_db.commit(
+            &commit
+                .state_updates
+                .create_database_updates::<SpreadPrefixKeyMapper>(),
+        );
+    }
+    Ok(receipt)
+}
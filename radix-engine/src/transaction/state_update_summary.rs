@@ -1,8 +1,12 @@
 use crate::blueprints::resource::{FungibleVaultBalanceFieldPayload, FungibleVaultField};
 use crate::internal_prelude::*;
-use crate::system::system_db_reader::SystemDatabaseReader;
+use crate::system::system_db_reader::{
+    ObjectPartitionDescriptor, SystemDatabaseReader, SystemPartitionDescriptor,
+};
+use crate::system::system_type_checker::BlueprintTypeTarget;
 use crate::track::TrackedSubstateValue;
 use crate::track::{TrackedNode, Write};
+use radix_engine_interface::blueprints::package::*;
 use radix_engine_interface::data::scrypto::model::*;
 use radix_engine_interface::math::*;
 use radix_engine_interface::types::*;
@@ -10,6 +14,7 @@ use radix_engine_interface::*;
 use radix_engine_store_interface::{
     db_key_mapper::SpreadPrefixKeyMapper, interface::SubstateDatabase,
 };
+use sbor::representations::*;
 use sbor::rust::prelude::*;
 
 #[derive(Default, Debug, Clone, ScryptoSbor)]
@@ -19,6 +24,13 @@ pub struct StateUpdateSummary {
     pub new_resources: IndexSet<ResourceAddress>,
     pub new_vaults: IndexSet<InternalAddress>,
     pub vault_balance_changes: IndexMap<NodeId, (ResourceAddress, BalanceChange)>,
+    /// The changed fields of existing objects, which are only computed when enabled in the
+    /// `ExecutionConfig`.
+    ///
+    /// These are skipped when encoding, so that the encoding of receipts is unchanged - they are
+    /// empty in a decoded receipt.
+    #[sbor(skip)]
+    pub field_changes: IndexMap<NodeId, Vec<FieldChange>>,
 }
 
 impl StateUpdateSummary {
@@ -56,10 +68,18 @@ impl StateUpdateSummary {
             new_resources,
             new_vaults,
             vault_balance_changes,
+            field_changes: index_map_new(),
         }
     }
 }
 
+#[derive(Debug, Clone, ScryptoSbor, PartialEq, Eq)]
+pub struct FieldChange {
+    pub partition_number: PartitionNumber,
+    pub field_index: u8,
+    pub diff: ValueDiff,
+}
+
 #[derive(Debug, Clone, ScryptoSbor, PartialEq, Eq)]
 pub enum BalanceChange {
     Fungible(Decimal),
@@ -271,3 +291,132 @@ impl<'a, S: SubstateDatabase> BalanceAccounter<'a, S> {
             .map(|(added, removed)| BalanceChange::NonFungible { added, removed })
     }
 }
+
+/// Compares the fields written by a transaction with their previous values, using the schemas of
+/// the objects' blueprints.
+pub struct FieldChangeAccounter<'a, S: SubstateDatabase> {
+    system_reader: SystemDatabaseReader<'a, S>,
+    tracked: &'a IndexMap<NodeId, TrackedNode>,
+    address_encoder: AddressBech32Encoder,
+}
+
+impl<'a, S: SubstateDatabase> FieldChangeAccounter<'a, S> {
+    pub fn new(
+        substate_db: &'a S,
+        tracked: &'a IndexMap<NodeId, TrackedNode>,
+        network_definition: &NetworkDefinition,
+    ) -> Self {
+        Self {
+            system_reader: SystemDatabaseReader::new_with_overlay(substate_db, tracked),
+            tracked,
+            address_encoder: AddressBech32Encoder::new(network_definition),
+        }
+    }
+
+    pub fn run(&self) -> IndexMap<NodeId, Vec<FieldChange>> {
+        self.tracked
+            .iter()
+            .filter(|(_, tracked_node)| !tracked_node.is_new)
+            .map(|(node_id, _)| (*node_id, self.calculate_field_changes(node_id)))
+            .filter(|(_, changes)| !changes.is_empty())
+            .collect()
+    }
+
+    fn calculate_field_changes(&self, node_id: &NodeId) -> Vec<FieldChange> {
+        let mut changes = vec![];
+        for (partition_number, tracked_partition) in &self.tracked[node_id].tracked_partitions {
+            let Ok(partition_descriptors) = self
+                .system_reader
+                .get_partition_descriptors(node_id, partition_number)
+            else {
+                continue;
+            };
+            let Some(SystemPartitionDescriptor::Object(
+                module_id,
+                ObjectPartitionDescriptor::Fields,
+            )) = partition_descriptors.first()
+            else {
+                continue;
+            };
+            let Ok(target) = self
+                .system_reader
+                .get_blueprint_type_target(node_id, *module_id)
+            else {
+                continue;
+            };
+
+            for tracked_substate in tracked_partition.substates.values() {
+                match &tracked_substate.substate_value {
+                    TrackedSubstateValue::ReadExistAndWrite(_, _)
+                    | TrackedSubstateValue::WriteOnly(_) => {}
+                    TrackedSubstateValue::New(_)
+                    | TrackedSubstateValue::ReadNonExistAndWrite(_)
+                    | TrackedSubstateValue::ReadOnly(_)
+                    | TrackedSubstateValue::Garbage => continue,
+                }
+                let SubstateKey::Field(field_index) = tracked_substate.substate_key else {
+                    continue;
+                };
+                let Some(diff) =
+                    self.calculate_field_diff(node_id, *partition_number, field_index, &target)
+                else {
+                    continue;
+                };
+                if !diff.is_empty() {
+                    changes.push(FieldChange {
+                        partition_number: *partition_number,
+                        field_index,
+                        diff,
+                    });
+                }
+            }
+        }
+        changes
+    }
+
+    fn calculate_field_diff(
+        &self,
+        node_id: &NodeId,
+        partition_number: PartitionNumber,
+        field_index: u8,
+        target: &BlueprintTypeTarget,
+    ) -> Option<ValueDiff> {
+        let substate_key = SubstateKey::Field(field_index);
+        let old_payload = self
+            .system_reader
+            .fetch_substate_from_database::<SpreadPrefixKeyMapper, FieldSubstate<ScryptoValue>>(
+                node_id,
+                partition_number,
+                &substate_key,
+            )
+            .map(|substate| scrypto_encode(substate.payload()).unwrap())?;
+        let new_payload = self
+            .system_reader
+            .fetch_substate_from_state_updates::<SpreadPrefixKeyMapper, FieldSubstate<ScryptoValue>>(
+                node_id,
+                partition_number,
+                &substate_key,
+            )
+            .map(|substate| scrypto_encode(substate.payload()).unwrap())?;
+        let payload_schema = self
+            .system_reader
+            .get_blueprint_payload_schema(target, &BlueprintPayloadIdentifier::Field(field_index))
+            .ok()?;
+
+        let context = RustLikeDisplayContext::<ScryptoCustomExtension> {
+            schema: payload_schema.schema.v1(),
+            custom_context: ScryptoValueDisplayContext::with_optional_bech32(Some(
+                &self.address_encoder,
+            )),
+            print_mode: PrintMode::SingleLine,
+        };
+        diff_payloads(
+            &context,
+            &old_payload,
+            &new_payload,
+            payload_schema.type_id,
+            SCRYPTO_SBOR_V1_MAX_DEPTH,
+        )
+        .ok()
+    }
+}
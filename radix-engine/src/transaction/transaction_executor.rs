@@ -100,6 +100,7 @@ pub struct ExecutionConfig {
     pub enabled_modules: EnabledModules,
     pub abort_when_loan_repaid: bool,
     pub enable_cost_breakdown: bool,
+    pub enable_field_change_diffs: bool,
     pub max_execution_trace_depth: usize,
     pub max_call_depth: usize,
     pub max_heap_substate_total_bytes: usize,
@@ -124,6 +125,7 @@ impl ExecutionConfig {
             enabled_modules: EnabledModules::for_notarized_transaction(),
             abort_when_loan_repaid: false,
             enable_cost_breakdown: false,
+            enable_field_change_diffs: false,
             max_execution_trace_depth: MAX_EXECUTION_TRACE_DEPTH,
             max_call_depth: MAX_CALL_DEPTH,
             max_heap_substate_total_bytes: MAX_HEAP_SUBSTATE_TOTAL_BYTES,
@@ -195,6 +197,13 @@ impl ExecutionConfig {
         self
     }
 
+    /// Enables diffing the fields written by the transaction against their previous values, to
+    /// report them in the `StateUpdateSummary` of the receipt.
+    pub fn with_field_change_diffs(mut self, enabled: bool) -> Self {
+        self.enable_field_change_diffs = enabled;
+        self
+    }
+
     pub fn up_to_loan_repayment(mut self, enabled: bool) -> Self {
         self.abort_when_loan_repaid = enabled;
         self
@@ -412,8 +421,16 @@ where
                             &application_events,
                         );
 
-                        let state_update_summary =
+                        let mut state_update_summary =
                             StateUpdateSummary::new(self.substate_db, &tracked_nodes);
+                        if execution_config.enable_field_change_diffs {
+                            state_update_summary.field_changes = FieldChangeAccounter::new(
+                                self.substate_db,
+                                &tracked_nodes,
+                                &execution_config.network_definition,
+                            )
+                            .run();
+                        }
 
                         let system_reader = SystemDatabaseReader::new_with_overlay(
                             self.substate_db,
@@ -1,4 +1,4 @@
-use super::{BalanceChange, CostingParameters, StateUpdateSummary};
+use super::{BalanceChange, CostingParameters, FieldChange, StateUpdateSummary};
 use crate::blueprints::consensus_manager::EpochChangeEvent;
 use crate::errors::*;
 use crate::internal_prelude::*;
@@ -235,6 +235,10 @@ impl CommitResult {
         &self.state_update_summary.vault_balance_changes
     }
 
+    pub fn field_changes(&self) -> &IndexMap<NodeId, Vec<FieldChange>> {
+        &self.state_update_summary.field_changes
+    }
+
     pub fn output<T: ScryptoDecode>(&self, nth: usize) -> T {
         match &self.outcome {
             TransactionOutcome::Success(o) => match o.get(nth) {
@@ -816,6 +820,34 @@ impl<'a> ContextualDisplay<TransactionReceiptDisplayContext<'a>> for Transaction
                 )?;
             }
 
+            // Field changes are only computed when enabled in the execution config
+            let field_changes = c.field_changes();
+            if !field_changes.is_empty() {
+                write!(
+                    f,
+                    "\n{} {}",
+                    "Field Changes:".bold().green(),
+                    field_changes.len()
+                )?;
+                for (i, (node_id, changes)) in field_changes.iter().enumerate() {
+                    write!(
+                        f,
+                        "\n{} Entity: {}",
+                        prefix!(i, field_changes),
+                        node_id.display(address_display_context)
+                    )?;
+                    for change in changes {
+                        for value_change in change.diff.changes.iter() {
+                            write!(
+                                f,
+                                "\n   Partition {} Field {}: {}",
+                                change.partition_number.0, change.field_index, value_change
+                            )?;
+                        }
+                    }
+                }
+            }
+
             write!(
                 f,
                 "\n{} {}",
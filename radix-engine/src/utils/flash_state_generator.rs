@@ -1,7 +1,9 @@
+use crate::blueprints::account::{AccountNativePackage, AccountV1MinorVersion};
 use crate::blueprints::consensus_manager::*;
 use crate::blueprints::models::KeyValueEntryContentSource;
 use crate::blueprints::package::*;
 use crate::blueprints::pool::v1::constants::*;
+use crate::blueprints::vesting::VestingNativePackage;
 use crate::internal_prelude::*;
 use crate::system::system_db_reader::{ObjectCollectionKey, SystemDatabaseReader};
 use crate::track::{NodeStateUpdates, PartitionStateUpdates, StateUpdates};
@@ -13,6 +15,7 @@ use radix_engine_common::prelude::ScopedTypeId;
 use radix_engine_common::prelude::{scrypto_encode, ScryptoCustomTypeKind};
 use radix_engine_common::types::SubstateKey;
 use radix_engine_interface::api::ObjectModuleId;
+use radix_engine_interface::blueprints::account::ACCOUNT_BLUEPRINT;
 use radix_engine_interface::blueprints::consensus_manager::*;
 use radix_engine_interface::prelude::*;
 use radix_engine_interface::types::CollectionDescriptor;
@@ -383,3 +386,189 @@ pub fn generate_validator_fee_fix_state_updates<S: SubstateDatabase>(db: &S) ->
         ),
     }
 }
+
+/// Generates the state updates required to introduce the native vesting package. The package is
+/// flashed in the same way as the native packages flashed at genesis, i.e. without a royalty vault
+/// and with a locked name and description.
+pub fn generate_vesting_package_state_updates() -> StateUpdates {
+    let package_structure = PackageNativePackage::validate_and_build_package_structure(
+        VestingNativePackage::definition(),
+        VmType::Native,
+        VESTING_CODE_ID.to_be_bytes().to_vec(),
+        btreemap!(),
+        &VmVersion::default(),
+    )
+    .unwrap_or_else(|err| panic!("Invalid flashed Vesting package definition: {:?}", err));
+
+    let partitions = create_package_partition_substates(
+        package_structure,
+        metadata_init! {
+            "name" => "Vesting Package".to_owned(), locked;
+            "description" => "A native package that defines the logic of vesting components, which release tokens to a beneficiary over time.".to_owned(), locked;
+        },
+        None,
+    );
+
+    StateUpdates {
+        by_node: indexmap!(
+            VESTING_PACKAGE.into_node_id() => NodeStateUpdates::Delta {
+                by_partition: partitions
+                    .into_iter()
+                    .map(|(partition_num, substates)| {
+                        (
+                            partition_num,
+                            PartitionStateUpdates::Delta {
+                                by_substate: substates
+                                    .into_iter()
+                                    .map(|(key, value)| (key, DatabaseUpdate::Set(value.into())))
+                                    .collect(),
+                            },
+                        )
+                    })
+                    .collect(),
+            }
+        ),
+    }
+}
+
+/// Generates the state updates required to update the account package from the v1.0 to the v1.1
+/// logic, which adds per-resource withdrawal limits. It produces the following updates:
+///
+/// * Removes the old code_hash => vm_type substate.
+/// * Adds a new code_hash => vm_type substate.
+/// * Removes the old code_hash => original_code substate.
+/// * Adds a new code_hash => original_code substate.
+/// * Adds the schema of the v1.1 blueprint definition.
+/// * Updates the blueprint definition, dependencies and auth config of the account blueprint.
+///
+/// The v1.1 definition appends the withdrawal limit collection after the existing collections,
+/// so accounts created before the update keep their partition layout and simply see an empty
+/// withdrawal limit collection.
+pub fn generate_account_v1_1_state_updates<S: SubstateDatabase>(db: &S) -> StateUpdates {
+    let reader = SystemDatabaseReader::new(db);
+
+    let account_package_node_id = ACCOUNT_PACKAGE.into_node_id();
+
+    // The old and new code hashes
+    let old_code_id = ACCOUNT_CODE_ID;
+    let new_code_id = ACCOUNT_V1_1_CODE_ID;
+
+    let old_code = old_code_id.to_be_bytes().to_vec();
+    let new_code = new_code_id.to_be_bytes().to_vec();
+
+    let old_code_hash = CodeHash::from_hash(hash(&old_code));
+    let new_code_hash = CodeHash::from_hash(hash(&new_code));
+
+    // The package structure of the v1.1 definition, from which all of the new substates are taken.
+    let mut package_structure = PackageNativePackage::validate_and_build_package_structure(
+        AccountNativePackage::definition(AccountV1MinorVersion::One),
+        VmType::Native,
+        new_code,
+        btreemap!(),
+        &VmVersion::default(),
+    )
+    .unwrap_or_else(|err| panic!("Invalid flashed Account v1.1 package definition: {:?}", err));
+
+    let new_code_substate = package_structure
+        .original_code
+        .swap_remove(&new_code_hash)
+        .unwrap()
+        .into_locked_substate();
+    let new_vm_type_substate = package_structure
+        .vm_type
+        .swap_remove(&new_code_hash)
+        .unwrap()
+        .into_locked_substate();
+    let new_schema_substates = package_structure
+        .schemas
+        .into_iter()
+        .map(|(schema_hash, schema)| {
+            (
+                SubstateKey::Map(scrypto_encode(&schema_hash).unwrap()),
+                DatabaseUpdate::Set(scrypto_encode(&schema.into_locked_substate()).unwrap()),
+            )
+        })
+        .collect();
+
+    let account_blueprint_key = BlueprintVersionKey::new_default(ACCOUNT_BLUEPRINT);
+    let account_blueprint_definition = package_structure
+        .definitions
+        .swap_remove(ACCOUNT_BLUEPRINT)
+        .unwrap()
+        .into_locked_substate();
+    let account_blueprint_dependencies = package_structure
+        .dependencies
+        .swap_remove(ACCOUNT_BLUEPRINT)
+        .unwrap()
+        .into_locked_substate();
+    let account_blueprint_auth_config = package_structure
+        .auth_configs
+        .swap_remove(ACCOUNT_BLUEPRINT)
+        .unwrap()
+        .into_locked_substate();
+
+    let [original_code_partition_number, code_vm_type_partition_number, schema_partition_number, blueprint_definition_partition_number, blueprint_dependencies_partition_number, blueprint_auth_config_partition_number] =
+        [
+            PackageCollection::CodeOriginalCodeKeyValue,
+            PackageCollection::CodeVmTypeKeyValue,
+            PackageCollection::SchemaKeyValue,
+            PackageCollection::BlueprintVersionDefinitionKeyValue,
+            PackageCollection::BlueprintVersionDependenciesKeyValue,
+            PackageCollection::BlueprintVersionAuthConfigKeyValue,
+        ]
+        .map(|collection| {
+            reader
+                .get_partition_of_collection(
+                    &account_package_node_id,
+                    ObjectModuleId::Main,
+                    collection.collection_index(),
+                )
+                .unwrap()
+        });
+
+    StateUpdates {
+        by_node: indexmap! {
+            account_package_node_id => NodeStateUpdates::Delta {
+                by_partition: indexmap! {
+                    original_code_partition_number => PartitionStateUpdates::Delta {
+                        by_substate: indexmap! {
+                            SubstateKey::Map(scrypto_encode(&old_code_hash).unwrap())
+                                => DatabaseUpdate::Delete,
+                            SubstateKey::Map(scrypto_encode(&new_code_hash).unwrap())
+                                => DatabaseUpdate::Set(scrypto_encode(&new_code_substate).unwrap()),
+                        }
+                    },
+                    code_vm_type_partition_number => PartitionStateUpdates::Delta {
+                        by_substate: indexmap! {
+                            SubstateKey::Map(scrypto_encode(&old_code_hash).unwrap())
+                                => DatabaseUpdate::Delete,
+                            SubstateKey::Map(scrypto_encode(&new_code_hash).unwrap())
+                                => DatabaseUpdate::Set(scrypto_encode(&new_vm_type_substate).unwrap()),
+                        }
+                    },
+                    schema_partition_number => PartitionStateUpdates::Delta {
+                        by_substate: new_schema_substates,
+                    },
+                    blueprint_definition_partition_number => PartitionStateUpdates::Delta {
+                        by_substate: indexmap! {
+                            SubstateKey::Map(scrypto_encode(&account_blueprint_key).unwrap())
+                                => DatabaseUpdate::Set(scrypto_encode(&account_blueprint_definition).unwrap()),
+                        }
+                    },
+                    blueprint_dependencies_partition_number => PartitionStateUpdates::Delta {
+                        by_substate: indexmap! {
+                            SubstateKey::Map(scrypto_encode(&account_blueprint_key).unwrap())
+                                => DatabaseUpdate::Set(scrypto_encode(&account_blueprint_dependencies).unwrap()),
+                        }
+                    },
+                    blueprint_auth_config_partition_number => PartitionStateUpdates::Delta {
+                        by_substate: indexmap! {
+                            SubstateKey::Map(scrypto_encode(&account_blueprint_key).unwrap())
+                                => DatabaseUpdate::Set(scrypto_encode(&account_blueprint_auth_config).unwrap()),
+                        }
+                    },
+                }
+            }
+        },
+    }
+}
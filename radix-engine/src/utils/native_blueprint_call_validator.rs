@@ -138,7 +138,7 @@ fn get_arguments_schema<'s>(
                 .map(Some)?
         }
         Invocation::Function(package_address @ ACCOUNT_PACKAGE, ref blueprint, _) => {
-            get_blueprint_schema(&ACCOUNT_PACKAGE_DEFINITION, package_address, blueprint)
+            get_blueprint_schema(&ACCOUNT_PACKAGE_DEFINITION_V1_1, package_address, blueprint)
                 .map(Some)?
         }
         Invocation::Function(package_address @ IDENTITY_PACKAGE, ref blueprint, _) => {
@@ -165,6 +165,10 @@ fn get_arguments_schema<'s>(
             get_blueprint_schema(&POOL_PACKAGE_DEFINITION_V1_0, package_address, blueprint)
                 .map(Some)?
         }
+        Invocation::Function(package_address @ VESTING_PACKAGE, ref blueprint, _) => {
+            get_blueprint_schema(&VESTING_PACKAGE_DEFINITION, package_address, blueprint)
+                .map(Some)?
+        }
         Invocation::Function(package_address @ TRANSACTION_PROCESSOR_PACKAGE, ref blueprint, _) => {
             get_blueprint_schema(
                 &TRANSACTION_PROCESSOR_PACKAGE_DEFINITION,
@@ -208,7 +212,7 @@ fn get_arguments_schema<'s>(
                 EntityType::GlobalAccount
                 | EntityType::GlobalVirtualEd25519Account
                 | EntityType::GlobalVirtualSecp256k1Account => {
-                    ACCOUNT_PACKAGE_DEFINITION.blueprints.get(ACCOUNT_BLUEPRINT)
+                    ACCOUNT_PACKAGE_DEFINITION_V1_1.blueprints.get(ACCOUNT_BLUEPRINT)
                 }
 
                 EntityType::GlobalIdentity
@@ -1,5 +1,5 @@
 use crate::blueprints::access_controller::AccessControllerNativePackage;
-use crate::blueprints::account::AccountNativePackage;
+use crate::blueprints::account::{AccountNativePackage, AccountV1MinorVersion};
 use crate::blueprints::consensus_manager::{
     ConsensusManagerNativePackage, ConsensusManagerSecondsPrecisionNativeCode,
 };
@@ -10,6 +10,7 @@ use crate::blueprints::resource::ResourceNativePackage;
 use crate::blueprints::test_utils::TestUtilsNativePackage;
 use crate::blueprints::transaction_processor::TransactionProcessorNativePackage;
 use crate::blueprints::transaction_tracker::TransactionTrackerNativePackage;
+use crate::blueprints::vesting::VestingNativePackage;
 use crate::errors::{NativeRuntimeError, RuntimeError, VmError};
 use crate::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
 use crate::system::attached_modules::metadata::MetadataNativePackage;
@@ -133,7 +134,18 @@ impl<I: VmInvoke> VmInvoke for NativeVmInstance<I> {
                     IDENTITY_CODE_ID => {
                         IdentityNativePackage::invoke_export(export_name, input, api)
                     }
-                    ACCOUNT_CODE_ID => AccountNativePackage::invoke_export(export_name, input, api),
+                    ACCOUNT_CODE_ID => AccountNativePackage::invoke_export(
+                        export_name,
+                        input,
+                        AccountV1MinorVersion::Zero,
+                        api,
+                    ),
+                    ACCOUNT_V1_1_CODE_ID => AccountNativePackage::invoke_export(
+                        export_name,
+                        input,
+                        AccountV1MinorVersion::One,
+                        api,
+                    ),
                     ACCESS_CONTROLLER_CODE_ID => {
                         AccessControllerNativePackage::invoke_export(export_name, input, api)
                     }
@@ -159,6 +171,7 @@ impl<I: VmInvoke> VmInvoke for NativeVmInstance<I> {
                         PoolV1MinorVersion::One,
                         api,
                     ),
+                    VESTING_CODE_ID => VestingNativePackage::invoke_export(export_name, input, api),
                     TRANSACTION_TRACKER_CODE_ID => {
                         TransactionTrackerNativePackage::invoke_export(export_name, input, api)
                     }
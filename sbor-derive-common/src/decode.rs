@@ -363,6 +363,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decode_struct_with_borrowed_fields() {
+        let input = TokenStream::from_str(
+            "#[sbor(borrow = \"'a\")] struct Test<'a> {a: &'a str, b: &'a [u8]}",
+        )
+        .unwrap();
+        let output = handle_decode(input, None).unwrap();
+
+        assert_code_eq(
+            output,
+            quote! {
+                impl <'a, D: ::sbor::BorrowingDecoder<'a, X>, X: ::sbor::CustomValueKind > ::sbor::Decode<X, D> for Test<'a> {
+                    #[inline]
+                    fn decode_body_with_value_kind(decoder: &mut D, value_kind: ::sbor::ValueKind<X>) -> Result<Self, ::sbor::DecodeError> {
+                        use ::sbor::{self, Decode};
+                        decoder.check_preloaded_value_kind(value_kind, ::sbor::ValueKind::Tuple)?;
+                        decoder.read_and_check_size(2)?;
+                        Ok(Self {
+                            a: decoder.decode::<&'a str>()?,
+                            b: decoder.decode::<&'a [u8]>()?,
+                        })
+                    }
+                }
+            },
+        );
+    }
+
     #[test]
     fn test_decode_enum() {
         let input = TokenStream::from_str("enum Test {A, B (u32), C {x: u8}}").unwrap();
@@ -338,6 +338,19 @@ pub fn get_custom_value_kind(attributes: &[Attribute]) -> Result<Option<String>>
     extract_sbor_typed_attributes(attributes)?.get_string_value("custom_value_kind")
 }
 
+/// Returns the lifetime given by `#[sbor(borrow = "'a")]`, which the decoded value may borrow
+/// from the payload for.
+pub fn get_borrow_lifetime(attributes: &[Attribute]) -> Result<Option<Lifetime>> {
+    let attributes = extract_sbor_typed_attributes(attributes)?;
+    let Some(lifetime) = attributes.get_string_value("borrow")? else {
+        return Ok(None);
+    };
+    let span = attributes.get("borrow").unwrap().span();
+    parse_str(&lifetime)
+        .map(Some)
+        .map_err(|_| Error::new(span, "Expected a lifetime, such as \"'a\""))
+}
+
 pub fn get_custom_type_kind(attributes: &[Attribute]) -> Result<Option<String>> {
     extract_sbor_typed_attributes(attributes)?.get_string_value("custom_type_kind")
 }
@@ -604,9 +617,15 @@ pub fn build_decode_generics<'a>(
         where_clause = Some(new_where_clause);
     }
 
-    impl_generics
-        .params
-        .push(parse_quote!(#decoder_generic: ::sbor::Decoder<#custom_value_kind_generic>));
+    if let Some(borrow_lifetime) = get_borrow_lifetime(attributes)? {
+        impl_generics.params.push(
+            parse_quote!(#decoder_generic: ::sbor::BorrowingDecoder<#borrow_lifetime, #custom_value_kind_generic>),
+        );
+    } else {
+        impl_generics
+            .params
+            .push(parse_quote!(#decoder_generic: ::sbor::Decoder<#custom_value_kind_generic>));
+    }
 
     if need_to_add_cvk_generic {
         impl_generics
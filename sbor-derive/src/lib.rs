@@ -17,6 +17,9 @@ pub fn encode(input: TokenStream) -> TokenStream {
 }
 
 /// Derive code that decodes this data structure from a byte array.
+///
+/// Types with fields such as `&'a str` or `&'a [u8]` can borrow from the byte array by
+/// declaring the lifetime with `#[sbor(borrow = "'a")]`.
 #[proc_macro_derive(Decode, attributes(sbor))]
 pub fn decode(input: TokenStream) -> TokenStream {
     sbor_derive_common::decode::handle_decode(proc_macro2::TokenStream::from(input), None)
@@ -0,0 +1,100 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sbor::rust::prelude::*;
+use sbor::*;
+
+#[derive(Categorize, Encode, Decode, Debug, PartialEq)]
+#[sbor(borrow = "'a")]
+pub struct BorrowedStruct<'a> {
+    pub name: &'a str,
+    pub data: &'a [u8],
+    pub inner: BorrowedInner<'a>,
+}
+
+#[derive(Categorize, Encode, Decode, Debug, PartialEq)]
+#[sbor(borrow = "'a")]
+pub enum BorrowedInner<'a> {
+    Empty,
+    Tagged(&'a str, Option<&'a [u8]>),
+}
+
+#[derive(Categorize, Encode, Decode, Debug, PartialEq)]
+pub struct OwnedStruct {
+    pub name: String,
+    pub data: Vec<u8>,
+    pub inner: OwnedInner,
+}
+
+#[derive(Categorize, Encode, Decode, Debug, PartialEq)]
+pub enum OwnedInner {
+    Empty,
+    Tagged(String, Option<Vec<u8>>),
+}
+
+#[test]
+fn can_decode_borrowed_values_from_owned_encoding() {
+    let payload = basic_encode(&OwnedStruct {
+        name: "hello".to_string(),
+        data: vec![1, 2, 3],
+        inner: OwnedInner::Tagged("world".to_string(), Some(vec![4, 5])),
+    })
+    .unwrap();
+
+    let decoded: BorrowedStruct = basic_decode_borrowed(&payload).unwrap();
+    assert_eq!(
+        decoded,
+        BorrowedStruct {
+            name: "hello",
+            data: &[1, 2, 3],
+            inner: BorrowedInner::Tagged("world", Some(&[4, 5])),
+        }
+    );
+
+    // The borrowed values point into the payload rather than being copied out of it
+    let payload_range = payload.as_ptr_range();
+    assert!(payload_range.contains(&decoded.name.as_ptr()));
+    assert!(payload_range.contains(&decoded.data.as_ptr()));
+}
+
+#[test]
+fn borrowed_encoding_matches_owned_encoding() {
+    let borrowed = BorrowedStruct {
+        name: "hello",
+        data: &[1, 2, 3],
+        inner: BorrowedInner::Empty,
+    };
+    let owned = OwnedStruct {
+        name: "hello".to_string(),
+        data: vec![1, 2, 3],
+        inner: OwnedInner::Empty,
+    };
+
+    assert_eq!(basic_encode(&borrowed), basic_encode(&owned));
+}
+
+#[test]
+fn decoding_borrowed_values_checks_their_encoding() {
+    let payload = basic_encode(&vec![1u16, 2u16]).unwrap();
+    assert_eq!(
+        basic_decode_borrowed::<&[u8]>(&payload),
+        Err(DecodeError::UnexpectedValueKind {
+            expected: ValueKind::<NoCustomValueKind>::U8.as_u8(),
+            actual: ValueKind::<NoCustomValueKind>::U16.as_u8(),
+        })
+    );
+
+    let payload = vec![
+        BASIC_SBOR_V1_PAYLOAD_PREFIX,
+        VALUE_KIND_STRING,
+        2,
+        0xff,
+        0xfe,
+    ];
+    assert_eq!(
+        basic_decode_borrowed::<&str>(&payload),
+        Err(DecodeError::InvalidUtf8)
+    );
+
+    let payload = basic_encode("\u{2603}").unwrap();
+    assert_eq!(basic_decode_borrowed::<&str>(&payload), Ok("\u{2603}"));
+}
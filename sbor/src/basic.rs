@@ -28,6 +28,7 @@ impl CustomValue<NoCustomValueKind> for NoCustomValue {
 pub type BasicEncoder<'a> = VecEncoder<'a, NoCustomValueKind>;
 pub type BasicDecoder<'a> = VecDecoder<'a, NoCustomValueKind>;
 pub type BasicTraverser<'a> = VecTraverser<'a, NoCustomTraversal>;
+pub type BasicStreamingTraverser = StreamingTraverser<NoCustomTraversal>;
 pub type BasicValue = Value<NoCustomValueKind, NoCustomValue>;
 pub type BasicValueKind = ValueKind<NoCustomValueKind>;
 
@@ -58,6 +59,10 @@ impl<T: SborTuple<NoCustomValueKind> + ?Sized> BasicSborTuple for T {}
 pub trait BasicDecode: for<'a> Decode<NoCustomValueKind, BasicDecoder<'a>> {}
 impl<T: for<'a> Decode<NoCustomValueKind, BasicDecoder<'a>>> BasicDecode for T {}
 
+/// A value which can be decoded from a payload living for `'a`, and which may borrow from it.
+pub trait BasicBorrowedDecode<'a>: Decode<NoCustomValueKind, BasicDecoder<'a>> {}
+impl<'a, T: Decode<NoCustomValueKind, BasicDecoder<'a>>> BasicBorrowedDecode<'a> for T {}
+
 pub trait BasicEncode: for<'a> Encode<NoCustomValueKind, BasicEncoder<'a>> {}
 impl<T: for<'a> Encode<NoCustomValueKind, BasicEncoder<'a>> + ?Sized> BasicEncode for T {}
 
@@ -94,6 +99,20 @@ pub fn basic_decode_with_depth_limit<T: BasicDecode>(
     BasicDecoder::new(buf, depth_limit).decode_payload(BASIC_SBOR_V1_PAYLOAD_PREFIX)
 }
 
+/// Decode an instance of `T` which may borrow strings and byte slices from the payload.
+pub fn basic_decode_borrowed<'a, T: BasicBorrowedDecode<'a>>(
+    buf: &'a [u8],
+) -> Result<T, DecodeError> {
+    basic_decode_borrowed_with_depth_limit(buf, BASIC_SBOR_V1_MAX_DEPTH)
+}
+
+pub fn basic_decode_borrowed_with_depth_limit<'a, T: BasicBorrowedDecode<'a>>(
+    buf: &'a [u8],
+    depth_limit: usize,
+) -> Result<T, DecodeError> {
+    BasicDecoder::new(buf, depth_limit).decode_payload(BASIC_SBOR_V1_PAYLOAD_PREFIX)
+}
+
 impl CustomValueKind for NoCustomValueKind {
     fn as_u8(&self) -> u8 {
         panic!("No custom type")
@@ -311,6 +330,23 @@ impl ValidatableCustomExtension<()> for NoCustomExtension {
     }
 }
 
+impl ParseableCustomExtension for NoCustomExtension {
+    type CustomValue = NoCustomValue;
+    type CustomParsingContext<'a> = ();
+
+    fn parse_custom_value_kind(_: &str) -> Option<Self::CustomValueKind> {
+        None
+    }
+
+    fn parse_custom_value(
+        _: &Self::CustomParsingContext<'_>,
+        _: Self::CustomValueKind,
+        _: &str,
+    ) -> Result<Self::CustomValue, String> {
+        unreachable!("No custom values exist")
+    }
+}
+
 #[cfg(feature = "serde")]
 mod serde_serialization {
     use super::*;
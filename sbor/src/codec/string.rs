@@ -44,6 +44,19 @@ impl<X: CustomValueKind, D: Decoder<X>> Decode<X, D> for String {
     }
 }
 
+impl<'de, X: CustomValueKind, D: BorrowingDecoder<'de, X>> Decode<X, D> for &'de str {
+    #[inline]
+    fn decode_body_with_value_kind(
+        decoder: &mut D,
+        value_kind: ValueKind<X>,
+    ) -> Result<Self, DecodeError> {
+        decoder.check_preloaded_value_kind(value_kind, ValueKind::String)?;
+        let len = decoder.read_size()?;
+        let slice = decoder.read_slice_from_payload(len)?;
+        core::str::from_utf8(slice).map_err(|_| DecodeError::InvalidUtf8)
+    }
+}
+
 mod schema {
     use super::*;
 
@@ -97,7 +97,7 @@ pub mod prelude {
     pub use crate::schema::prelude::*;
     pub use crate::value::{CustomValue as SborCustomValue, Value as SborValue};
     pub use crate::value_kind::*;
-    pub use crate::versioned::{CloneIntoLatest, HasLatestVersion, UpdateResult};
+    pub use crate::versioned::{CloneIntoLatest, HasLatestVersion, UpdateResult, VersionMigrator};
     pub use crate::{
         basic_decode, basic_encode, BasicCategorize, BasicDecode, BasicDescribe, BasicEncode,
         BasicSbor,
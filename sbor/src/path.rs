@@ -34,7 +34,7 @@ impl From<SborPathBuf> for SborPath {
 }
 
 /// A series of indexes which describes some value in the sbor tree
-#[derive(Eq, PartialEq, Clone, Debug)]
+#[derive(Eq, PartialEq, Clone, Debug, Sbor)]
 pub struct SborPath(Vec<usize>);
 
 impl SborPath {
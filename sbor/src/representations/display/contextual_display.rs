@@ -23,6 +23,8 @@ pub enum DisplayMode {
     ///   - Array: `[value1, value2]`
     ///   - Map: `{ key1 => value1 }`
     ///   - Enum: `Name::Variant`, `Name::Variant(value1)`, `Name::Variant { field1: value1 }`
+    ///
+    /// Values in this format can be parsed back with [`parse_rustlike_value`] / [`parse_rustlike_payload`].
     RustLike,
     /// ==RustLike is recommended over NestedString. This may be deprecated soon==
     /// NestedString - is somewhat like the Manifest format, eg:
@@ -1,7 +1,11 @@
 mod contextual_display;
 mod nested_string;
+mod rustlike_parser;
 mod rustlike_string;
+mod value_diff;
 
 pub use contextual_display::*;
 pub use nested_string::*;
+pub use rustlike_parser::*;
 pub use rustlike_string::*;
+pub use value_diff::*;
@@ -0,0 +1,1320 @@
+//! Parsing of values from the text format output by the [`DisplayMode::RustLike`] printer.
+//!
+//! The format is:
+//! * Booleans: `true`, `false`
+//! * Integers: a decimal literal with the type as a suffix, eg `1u8` or `-3i32`. The suffix can be
+//!   omitted where a schema gives the type.
+//! * Strings: `"hello"`, with Rust escapes (`\"`, `\\`, `\n`, `\r`, `\t`, `\0`, `\u{..}`)
+//! * Byte arrays: `hex("0102ff")`
+//! * Arrays: `[value1, value2]`
+//! * Maps: `{ key1 => value1, key2 => value2 }`
+//! * Tuples: `Unit`, `Tuple(value1, value2)`, or with names from the schema: `TypeName`,
+//!   `TypeName(value1, value2)` or `TypeName { field1: value1, field2: value2 }`
+//! * Enums: `Enum::[1](value1)`, or with names from the schema: `TypeName::Variant`,
+//!   `TypeName::Variant(value1)` or `TypeName::Variant { field1: value1 }`
+//! * Custom values: `CustomValueKind("<value_content>")`, eg `Decimal("1.5")`, where the content
+//!   is parsed by the [`ParseableCustomExtension`].
+//! * Other values of a named type may be wrapped in the type name, eg `MyId(1u64)`.
+//!
+//! Trailing commas and `//` line comments are allowed, so both single-line and multi-line output
+//! can be parsed.
+//!
+//! Without a schema (or where the schema gives the type `Any`), the kind of each value has to be
+//! evident from the text alone: integers need their suffix, enum variants need their id, and arrays
+//! and maps can't be empty.
+
+use crate::representations::*;
+use crate::rust::prelude::*;
+use crate::*;
+
+pub enum RustLikeParsingParameters<'s, 'a, E: ParseableCustomExtension> {
+    Schemaless {
+        custom_context: E::CustomParsingContext<'a>,
+        depth_limit: usize,
+    },
+    /// Names in the text are resolved against the given type, and the parsed value is validated
+    /// against it.
+    Annotated {
+        custom_context: E::CustomParsingContext<'a>,
+        schema: &'s Schema<E::CustomSchema>,
+        type_id: LocalTypeId,
+        depth_limit: usize,
+    },
+}
+
+impl<'s, 'a, E: ParseableCustomExtension> RustLikeParsingParameters<'s, 'a, E> {
+    pub fn custom_context(&self) -> &E::CustomParsingContext<'a> {
+        match self {
+            Self::Schemaless { custom_context, .. } | Self::Annotated { custom_context, .. } => {
+                custom_context
+            }
+        }
+    }
+
+    pub fn depth_limit(&self) -> usize {
+        match self {
+            Self::Schemaless { depth_limit, .. } | Self::Annotated { depth_limit, .. } => {
+                *depth_limit
+            }
+        }
+    }
+
+    fn schema_and_type_id(&self) -> (&'s Schema<E::CustomSchema>, LocalTypeId) {
+        match self {
+            Self::Schemaless { .. } => (E::CustomSchema::empty_schema(), LocalTypeId::any()),
+            Self::Annotated {
+                schema, type_id, ..
+            } => (schema, *type_id),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RustLikeParsingError {
+    /// The text isn't well-formed at the given byte offset.
+    SyntaxError {
+        offset: usize,
+        message: String,
+    },
+    /// The value at the given byte offset can't be interpreted as the expected type.
+    InvalidValue {
+        offset: usize,
+        message: String,
+    },
+    EncodeError(EncodeError),
+    /// The encoded value doesn't match the schema.
+    ValidationError(String),
+}
+
+pub type ParsedValue<E> =
+    Value<<E as CustomExtension>::CustomValueKind, <E as ParseableCustomExtension>::CustomValue>;
+
+/// Parses a value from the [`DisplayMode::RustLike`] text format.
+///
+/// If a schema is provided, the value is checked to match the given type.
+pub fn parse_rustlike_value<E>(
+    text: &str,
+    params: &RustLikeParsingParameters<'_, '_, E>,
+) -> Result<ParsedValue<E>, RustLikeParsingError>
+where
+    E: ParseableCustomExtension + ValidatableCustomExtension<()>,
+{
+    let value = parse_unvalidated_value(text, params)?;
+    if let RustLikeParsingParameters::Annotated { .. } = params {
+        encode_and_validate(&value, params)?;
+    }
+    Ok(value)
+}
+
+/// Parses a value from the [`DisplayMode::RustLike`] text format, and encodes it as a full payload
+/// (including the payload prefix of the custom extension).
+///
+/// If a schema is provided, the payload is checked to match the given type.
+pub fn parse_rustlike_payload<E>(
+    text: &str,
+    params: &RustLikeParsingParameters<'_, '_, E>,
+) -> Result<Vec<u8>, RustLikeParsingError>
+where
+    E: ParseableCustomExtension + ValidatableCustomExtension<()>,
+{
+    let value = parse_unvalidated_value(text, params)?;
+    encode_and_validate(&value, params)
+}
+
+fn parse_unvalidated_value<E: ParseableCustomExtension>(
+    text: &str,
+    params: &RustLikeParsingParameters<'_, '_, E>,
+) -> Result<ParsedValue<E>, RustLikeParsingError> {
+    let node = SyntaxParser {
+        tokens: tokenize(text)?,
+        position: 0,
+        end_offset: text.len(),
+        depth_limit: params.depth_limit(),
+    }
+    .parse_root()?;
+    let (schema, type_id) = params.schema_and_type_id();
+    ValueConverter::<E> {
+        schema,
+        custom_context: params.custom_context(),
+    }
+    .convert(&node, type_id)
+}
+
+fn encode_and_validate<E: ParseableCustomExtension + ValidatableCustomExtension<()>>(
+    value: &ParsedValue<E>,
+    params: &RustLikeParsingParameters<'_, '_, E>,
+) -> Result<Vec<u8>, RustLikeParsingError> {
+    let mut payload = Vec::new();
+    VecEncoder::<E::CustomValueKind>::new(&mut payload, params.depth_limit())
+        .encode_payload(value, E::PAYLOAD_PREFIX)
+        .map_err(RustLikeParsingError::EncodeError)?;
+    if let RustLikeParsingParameters::Annotated {
+        schema,
+        type_id,
+        depth_limit,
+        ..
+    } = params
+    {
+        validate_payload_against_schema::<E, _>(&payload, schema, *type_id, &(), *depth_limit)
+            .map_err(|error| RustLikeParsingError::ValidationError(error.error_message(schema)))?;
+    }
+    Ok(payload)
+}
+
+fn syntax_error(offset: usize, message: String) -> RustLikeParsingError {
+    RustLikeParsingError::SyntaxError { offset, message }
+}
+
+fn invalid_value(offset: usize, message: String) -> RustLikeParsingError {
+    RustLikeParsingError::InvalidValue { offset, message }
+}
+
+//=======================
+// Tokenizing
+//=======================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token<'t> {
+    Identifier(&'t str),
+    /// An integer literal, including any sign and type suffix.
+    Integer(&'t str),
+    String(String),
+    OpenParenthesis,
+    CloseParenthesis,
+    OpenBracket,
+    CloseBracket,
+    OpenBrace,
+    CloseBrace,
+    Comma,
+    Colon,
+    DoubleColon,
+    FatArrow,
+}
+
+fn tokenize(text: &str) -> Result<Vec<(usize, Token<'_>)>, RustLikeParsingError> {
+    let mut lexer = Lexer { text, offset: 0 };
+    let mut tokens = Vec::new();
+    while let Some(c) = lexer.peek_char() {
+        let offset = lexer.offset;
+        if c.is_whitespace() {
+            lexer.next_char();
+            continue;
+        }
+        if lexer.text[offset..].starts_with("//") {
+            lexer.take_while(|c| c != '\n');
+            continue;
+        }
+        let token = match c {
+            '-' | '0'..='9' => {
+                lexer.next_char();
+                lexer.take_while(|c| c.is_ascii_alphanumeric() || c == '_');
+                Token::Integer(&text[offset..lexer.offset])
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                Token::Identifier(lexer.take_while(|c| c.is_alphanumeric() || c == '_'))
+            }
+            '"' => {
+                lexer.next_char();
+                Token::String(lexer.read_string_content()?)
+            }
+            _ => {
+                lexer.next_char();
+                match c {
+                    '(' => Token::OpenParenthesis,
+                    ')' => Token::CloseParenthesis,
+                    '[' => Token::OpenBracket,
+                    ']' => Token::CloseBracket,
+                    '{' => Token::OpenBrace,
+                    '}' => Token::CloseBrace,
+                    ',' => Token::Comma,
+                    ':' if lexer.peek_char() == Some(':') => {
+                        lexer.next_char();
+                        Token::DoubleColon
+                    }
+                    ':' => Token::Colon,
+                    '=' if lexer.peek_char() == Some('>') => {
+                        lexer.next_char();
+                        Token::FatArrow
+                    }
+                    _ => return Err(syntax_error(offset, format!("Unexpected character: {}", c))),
+                }
+            }
+        };
+        tokens.push((offset, token));
+    }
+    Ok(tokens)
+}
+
+struct Lexer<'t> {
+    text: &'t str,
+    offset: usize,
+}
+
+impl<'t> Lexer<'t> {
+    fn peek_char(&self) -> Option<char> {
+        self.text[self.offset..].chars().next()
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.peek_char()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    /// Returns the text from the current offset (inclusive) while the predicate holds.
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'t str {
+        let start = self.offset;
+        while let Some(c) = self.peek_char() {
+            if !predicate(c) {
+                break;
+            }
+            self.offset += c.len_utf8();
+        }
+        &self.text[start..self.offset]
+    }
+
+    /// Reads up to and including the closing quote, unescaping the content.
+    fn read_string_content(&mut self) -> Result<String, RustLikeParsingError> {
+        let start = self.offset - 1;
+        let mut value = String::new();
+        loop {
+            let offset = self.offset;
+            let c = match self.next_char() {
+                Some('"') => return Ok(value),
+                Some('\\') => match self.next_char() {
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some('0') => '\0',
+                    Some('\\') => '\\',
+                    Some('"') => '"',
+                    Some('\'') => '\'',
+                    Some('u') => self.read_unicode_escape(offset)?,
+                    Some(_) => {
+                        return Err(syntax_error(offset, "Invalid escape sequence".to_string()))
+                    }
+                    None => break,
+                },
+                Some(c) => c,
+                None => break,
+            };
+            value.push(c);
+        }
+        Err(syntax_error(start, "Unterminated string".to_string()))
+    }
+
+    /// Reads the `{..}` part of a `\u{..}` escape.
+    fn read_unicode_escape(&mut self, offset: usize) -> Result<char, RustLikeParsingError> {
+        if self.next_char() != Some('{') {
+            return Err(syntax_error(offset, "Invalid unicode escape".to_string()));
+        }
+        let digits = self.take_while(|c| c.is_ascii_hexdigit());
+        if self.next_char() != Some('}') {
+            return Err(syntax_error(offset, "Invalid unicode escape".to_string()));
+        }
+        u32::from_str_radix(digits, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| syntax_error(offset, format!("Invalid unicode escape: {}", digits)))
+    }
+}
+
+//=======================
+// Syntax tree
+//=======================
+
+struct Node<'t> {
+    offset: usize,
+    kind: NodeKind<'t>,
+}
+
+enum NodeKind<'t> {
+    Bool(bool),
+    Integer(&'t str),
+    String(String),
+    Bytes(Vec<u8>),
+    Array(Vec<Node<'t>>),
+    Map(Vec<(Node<'t>, Node<'t>)>),
+    /// A tuple, a custom value, or a value wrapped in its type name.
+    Named {
+        name: &'t str,
+        body: Body<'t>,
+    },
+    EnumVariant {
+        enum_name: &'t str,
+        variant: VariantReference<'t>,
+        body: Body<'t>,
+    },
+}
+
+enum VariantReference<'t> {
+    Id(u8),
+    Name(&'t str),
+}
+
+enum Body<'t> {
+    None,
+    Positional(Vec<Node<'t>>),
+    Fields(Vec<(&'t str, Node<'t>)>),
+}
+
+struct SyntaxParser<'t> {
+    tokens: Vec<(usize, Token<'t>)>,
+    position: usize,
+    end_offset: usize,
+    depth_limit: usize,
+}
+
+impl<'t> SyntaxParser<'t> {
+    fn parse_root(mut self) -> Result<Node<'t>, RustLikeParsingError> {
+        let node = self.parse_node(1)?;
+        if let Some((offset, token)) = self.tokens.get(self.position) {
+            return Err(syntax_error(
+                *offset,
+                format!("Expected the end of the value, but found {:?}", token),
+            ));
+        }
+        Ok(node)
+    }
+
+    fn parse_node(&mut self, depth: usize) -> Result<Node<'t>, RustLikeParsingError> {
+        let (offset, token) = self.next_token()?;
+        if depth > self.depth_limit {
+            return Err(syntax_error(offset, "Exceeded the depth limit".to_string()));
+        }
+        let kind = match token {
+            Token::String(value) => NodeKind::String(value),
+            Token::Integer(text) => NodeKind::Integer(text),
+            Token::OpenBracket => NodeKind::Array(
+                self.parse_list(Token::CloseBracket, |parser| parser.parse_node(depth + 1))?,
+            ),
+            Token::OpenBrace => NodeKind::Map(self.parse_list(Token::CloseBrace, |parser| {
+                let key = parser.parse_node(depth + 1)?;
+                parser.expect(Token::FatArrow)?;
+                let value = parser.parse_node(depth + 1)?;
+                Ok((key, value))
+            })?),
+            Token::Identifier("true") => NodeKind::Bool(true),
+            Token::Identifier("false") => NodeKind::Bool(false),
+            Token::Identifier("hex") if self.eat(&Token::OpenParenthesis) => {
+                let (hex_offset, token) = self.next_token()?;
+                let Token::String(hex) = token else {
+                    return Err(syntax_error(
+                        hex_offset,
+                        format!("Expected a hex string, but found {:?}", token),
+                    ));
+                };
+                let bytes = hex::decode(&hex)
+                    .map_err(|_| syntax_error(hex_offset, format!("Invalid hex: {}", hex)))?;
+                self.expect(Token::CloseParenthesis)?;
+                NodeKind::Bytes(bytes)
+            }
+            Token::Identifier(name) if self.eat(&Token::DoubleColon) => {
+                let variant = if self.eat(&Token::OpenBracket) {
+                    let (id_offset, token) = self.next_token()?;
+                    let id = match &token {
+                        Token::Integer(text) => text.parse::<u8>().ok(),
+                        _ => None,
+                    }
+                    .ok_or_else(|| {
+                        syntax_error(
+                            id_offset,
+                            format!("Expected a variant id, but found {:?}", token),
+                        )
+                    })?;
+                    self.expect(Token::CloseBracket)?;
+                    VariantReference::Id(id)
+                } else {
+                    VariantReference::Name(self.expect_identifier()?)
+                };
+                NodeKind::EnumVariant {
+                    enum_name: name,
+                    variant,
+                    body: self.parse_body(depth)?,
+                }
+            }
+            Token::Identifier(name) => NodeKind::Named {
+                name,
+                body: self.parse_body(depth)?,
+            },
+            token => {
+                return Err(syntax_error(
+                    offset,
+                    format!("Expected a value, but found {:?}", token),
+                ))
+            }
+        };
+        Ok(Node { offset, kind })
+    }
+
+    fn parse_body(&mut self, depth: usize) -> Result<Body<'t>, RustLikeParsingError> {
+        let body = if self.eat(&Token::OpenParenthesis) {
+            Body::Positional(self.parse_list(Token::CloseParenthesis, |parser| {
+                parser.parse_node(depth + 1)
+            })?)
+        } else if self.eat(&Token::OpenBrace) {
+            Body::Fields(self.parse_list(Token::CloseBrace, |parser| {
+                let field_name = parser.expect_identifier()?;
+                parser.expect(Token::Colon)?;
+                Ok((field_name, parser.parse_node(depth + 1)?))
+            })?)
+        } else {
+            Body::None
+        };
+        Ok(body)
+    }
+
+    /// Parses comma separated items up to the closing token, allowing a trailing comma.
+    fn parse_list<T>(
+        &mut self,
+        close: Token<'t>,
+        mut parse_item: impl FnMut(&mut Self) -> Result<T, RustLikeParsingError>,
+    ) -> Result<Vec<T>, RustLikeParsingError> {
+        let mut items = Vec::new();
+        loop {
+            if self.eat(&close) {
+                return Ok(items);
+            }
+            items.push(parse_item(self)?);
+            if !self.eat(&Token::Comma) {
+                self.expect(close)?;
+                return Ok(items);
+            }
+        }
+    }
+
+    fn next_token(&mut self) -> Result<(usize, Token<'t>), RustLikeParsingError> {
+        let (offset, token) =
+            self.tokens.get(self.position).cloned().ok_or_else(|| {
+                syntax_error(self.end_offset, "Unexpected end of input".to_string())
+            })?;
+        self.position += 1;
+        Ok((offset, token))
+    }
+
+    fn eat(&mut self, expected: &Token<'t>) -> bool {
+        match self.tokens.get(self.position) {
+            Some((_, token)) if token == expected => {
+                self.position += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn expect(&mut self, expected: Token<'t>) -> Result<(), RustLikeParsingError> {
+        let (offset, token) = self.next_token()?;
+        if token != expected {
+            return Err(syntax_error(
+                offset,
+                format!("Expected {:?}, but found {:?}", expected, token),
+            ));
+        }
+        Ok(())
+    }
+
+    fn expect_identifier(&mut self) -> Result<&'t str, RustLikeParsingError> {
+        match self.next_token()? {
+            (_, Token::Identifier(name)) => Ok(name),
+            (offset, token) => Err(syntax_error(
+                offset,
+                format!("Expected a name, but found {:?}", token),
+            )),
+        }
+    }
+}
+
+//=======================
+// Conversion to a value
+//=======================
+
+struct ValueConverter<'s, 'c, 'a, E: ParseableCustomExtension> {
+    schema: &'s Schema<E::CustomSchema>,
+    custom_context: &'c E::CustomParsingContext<'a>,
+}
+
+impl<'s, 'c, 'a, E: ParseableCustomExtension> ValueConverter<'s, 'c, 'a, E> {
+    fn convert(
+        &self,
+        node: &Node,
+        type_id: LocalTypeId,
+    ) -> Result<ParsedValue<E>, RustLikeParsingError> {
+        let type_kind = self.schema.resolve_type_kind(type_id).ok_or_else(|| {
+            invalid_value(
+                node.offset,
+                format!("The type {:?} is missing from the schema", type_id),
+            )
+        })?;
+        let type_metadata = self.schema.resolve_type_metadata(type_id);
+        let type_name = type_metadata.and_then(|m| m.get_name());
+
+        match type_kind {
+            TypeKind::Tuple { field_types } => {
+                return self.convert_tuple(node, type_metadata, field_types)
+            }
+            TypeKind::Enum { variants } => return self.convert_enum(node, type_metadata, variants),
+            _ => {}
+        }
+
+        if let Some(value) = self.convert_custom_value(node)? {
+            let value_kind = value.get_custom_value_kind();
+            if !matches!(type_kind, TypeKind::Any)
+                && !E::custom_value_kind_matches_type_kind(self.schema, value_kind, type_kind)
+            {
+                return Err(invalid_value(
+                    node.offset,
+                    format!("A {:?} value doesn't match the expected type", value_kind),
+                ));
+            }
+            return Ok(Value::Custom { value });
+        }
+
+        // Unwrap the "new-type-like struct" the printer wraps named values in
+        if let NodeKind::Named {
+            name,
+            body: Body::Positional(children),
+        } = &node.kind
+        {
+            if Some(*name) == type_name && children.len() == 1 {
+                return self.convert(&children[0], type_id);
+            }
+        }
+
+        let value = match type_kind {
+            TypeKind::Bool => Value::Bool {
+                value: expect_bool(node)?,
+            },
+            TypeKind::I8 => Value::I8 {
+                value: parse_integer(node, "i8")?,
+            },
+            TypeKind::I16 => Value::I16 {
+                value: parse_integer(node, "i16")?,
+            },
+            TypeKind::I32 => Value::I32 {
+                value: parse_integer(node, "i32")?,
+            },
+            TypeKind::I64 => Value::I64 {
+                value: parse_integer(node, "i64")?,
+            },
+            TypeKind::I128 => Value::I128 {
+                value: parse_integer(node, "i128")?,
+            },
+            TypeKind::U8 => Value::U8 {
+                value: parse_integer(node, "u8")?,
+            },
+            TypeKind::U16 => Value::U16 {
+                value: parse_integer(node, "u16")?,
+            },
+            TypeKind::U32 => Value::U32 {
+                value: parse_integer(node, "u32")?,
+            },
+            TypeKind::U64 => Value::U64 {
+                value: parse_integer(node, "u64")?,
+            },
+            TypeKind::U128 => Value::U128 {
+                value: parse_integer(node, "u128")?,
+            },
+            TypeKind::String => Value::String {
+                value: expect_string(node)?,
+            },
+            TypeKind::Array { element_type } => self.convert_array(node, *element_type)?,
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => self.convert_map(node, *key_type, *value_type)?,
+            // Custom type kinds can also match non-custom values, which is checked on validation
+            TypeKind::Any | TypeKind::Custom(_) => self.convert_any(node)?,
+            TypeKind::Tuple { .. } | TypeKind::Enum { .. } => unreachable!(),
+        };
+        Ok(value)
+    }
+
+    /// Converts a value whose kind has to be evident from the text alone.
+    fn convert_any(&self, node: &Node) -> Result<ParsedValue<E>, RustLikeParsingError> {
+        let any = LocalTypeId::any();
+        let value = match &node.kind {
+            NodeKind::Bool(value) => Value::Bool { value: *value },
+            NodeKind::Integer(text) => {
+                let suffix = text
+                    .find(|c: char| c.is_ascii_alphabetic())
+                    .map(|index| &text[index..])
+                    .unwrap_or_default();
+                match suffix {
+                    "i8" => Value::I8 {
+                        value: parse_integer(node, suffix)?,
+                    },
+                    "i16" => Value::I16 {
+                        value: parse_integer(node, suffix)?,
+                    },
+                    "i32" => Value::I32 {
+                        value: parse_integer(node, suffix)?,
+                    },
+                    "i64" => Value::I64 {
+                        value: parse_integer(node, suffix)?,
+                    },
+                    "i128" => Value::I128 {
+                        value: parse_integer(node, suffix)?,
+                    },
+                    "u8" => Value::U8 {
+                        value: parse_integer(node, suffix)?,
+                    },
+                    "u16" => Value::U16 {
+                        value: parse_integer(node, suffix)?,
+                    },
+                    "u32" => Value::U32 {
+                        value: parse_integer(node, suffix)?,
+                    },
+                    "u64" => Value::U64 {
+                        value: parse_integer(node, suffix)?,
+                    },
+                    "u128" => Value::U128 {
+                        value: parse_integer(node, suffix)?,
+                    },
+                    _ => {
+                        return Err(invalid_value(
+                            node.offset,
+                            format!(
+                                "Integers need a type suffix (eg 1u32) where their type isn't known, but found: {}",
+                                text
+                            ),
+                        ))
+                    }
+                }
+            }
+            NodeKind::String(value) => Value::String {
+                value: value.clone(),
+            },
+            NodeKind::Bytes(_) | NodeKind::Array(_) => self.convert_array(node, any)?,
+            NodeKind::Map(_) => self.convert_map(node, any, any)?,
+            NodeKind::Named { name, body } => {
+                if let Some(value) = self.convert_custom_value(node)? {
+                    return Ok(Value::Custom { value });
+                }
+                match (*name, body) {
+                    ("Unit", Body::None) | ("Tuple", Body::Positional(_)) | (_, Body::Fields(_)) => {
+                        Value::Tuple {
+                            fields: self.convert_fields(node, body, None, None)?,
+                        }
+                    }
+                    _ => {
+                        return Err(invalid_value(
+                            node.offset,
+                            format!(
+                                "Can't resolve {} where its type isn't known - use Unit, Tuple(..) or a custom value kind instead",
+                                name
+                            ),
+                        ))
+                    }
+                }
+            }
+            NodeKind::EnumVariant {
+                variant: VariantReference::Id(discriminator),
+                body,
+                ..
+            } => Value::Enum {
+                discriminator: *discriminator,
+                fields: self.convert_fields(node, body, None, None)?,
+            },
+            NodeKind::EnumVariant {
+                variant: VariantReference::Name(variant_name),
+                ..
+            } => {
+                return Err(invalid_value(
+                    node.offset,
+                    format!(
+                        "Can't resolve the variant {} where its type isn't known - use Enum::[<id>] instead",
+                        variant_name
+                    ),
+                ))
+            }
+        };
+        Ok(value)
+    }
+
+    /// Converts a `CustomValueKind("<value_content>")` node, if the node is one.
+    fn convert_custom_value(
+        &self,
+        node: &Node,
+    ) -> Result<Option<<E as ParseableCustomExtension>::CustomValue>, RustLikeParsingError> {
+        let NodeKind::Named {
+            name,
+            body: Body::Positional(children),
+        } = &node.kind
+        else {
+            return Ok(None);
+        };
+        let (Some(value_kind), [child]) = (E::parse_custom_value_kind(name), children.as_slice())
+        else {
+            return Ok(None);
+        };
+        let NodeKind::String(content) = &child.kind else {
+            return Ok(None);
+        };
+        E::parse_custom_value(self.custom_context, value_kind, content)
+            .map(Some)
+            .map_err(|message| invalid_value(child.offset, message))
+    }
+
+    fn convert_tuple(
+        &self,
+        node: &Node,
+        type_metadata: Option<&TypeMetadata>,
+        field_types: &[LocalTypeId],
+    ) -> Result<ParsedValue<E>, RustLikeParsingError> {
+        let type_name = type_metadata.and_then(|m| m.get_name());
+        let NodeKind::Named { name, body } = &node.kind else {
+            return Err(invalid_value(
+                node.offset,
+                format!("Expected {}", type_name.unwrap_or("a tuple")),
+            ));
+        };
+        if Some(*name) != type_name && !matches!(*name, "Unit" | "Tuple" | "Struct") {
+            return Err(invalid_value(
+                node.offset,
+                format!(
+                    "Expected {}, but found {}",
+                    type_name.unwrap_or("a tuple"),
+                    name
+                ),
+            ));
+        }
+        let field_names = type_metadata.and_then(|m| m.get_field_names());
+        Ok(Value::Tuple {
+            fields: self.convert_fields(node, body, Some(field_types), field_names)?,
+        })
+    }
+
+    fn convert_enum(
+        &self,
+        node: &Node,
+        type_metadata: Option<&TypeMetadata>,
+        variants: &IndexMap<u8, Vec<LocalTypeId>>,
+    ) -> Result<ParsedValue<E>, RustLikeParsingError> {
+        let type_name = type_metadata.and_then(|m| m.get_name());
+        let NodeKind::EnumVariant {
+            enum_name,
+            variant,
+            body,
+        } = &node.kind
+        else {
+            return Err(invalid_value(
+                node.offset,
+                format!("Expected a variant of {}", type_name.unwrap_or("an enum")),
+            ));
+        };
+        if Some(*enum_name) != type_name && *enum_name != "Enum" {
+            return Err(invalid_value(
+                node.offset,
+                format!(
+                    "Expected a variant of {}, but found {}",
+                    type_name.unwrap_or("an enum"),
+                    enum_name
+                ),
+            ));
+        }
+        let variant_metadata = match type_metadata.and_then(|m| m.child_names.as_ref()) {
+            Some(ChildNames::EnumVariants(variant_metadata)) => Some(variant_metadata),
+            _ => None,
+        };
+        let discriminator = match variant {
+            VariantReference::Id(discriminator) => *discriminator,
+            VariantReference::Name(variant_name) => variant_metadata
+                .and_then(|variant_metadata| {
+                    variant_metadata
+                        .iter()
+                        .find(|(_, m)| m.get_name() == Some(*variant_name))
+                })
+                .map(|(discriminator, _)| *discriminator)
+                .ok_or_else(|| {
+                    invalid_value(node.offset, format!("Unknown variant: {}", variant_name))
+                })?,
+        };
+        let field_types = variants.get(&discriminator).ok_or_else(|| {
+            invalid_value(
+                node.offset,
+                format!("Unknown variant id: {}", discriminator),
+            )
+        })?;
+        let field_names = variant_metadata
+            .and_then(|variant_metadata| variant_metadata.get(&discriminator))
+            .and_then(|m| m.get_field_names());
+        Ok(Value::Enum {
+            discriminator,
+            fields: self.convert_fields(node, body, Some(field_types), field_names)?,
+        })
+    }
+
+    /// Converts the fields of a tuple or enum variant. If the field types aren't known, the fields
+    /// are converted in the given order.
+    fn convert_fields(
+        &self,
+        node: &Node,
+        body: &Body,
+        field_types: Option<&[LocalTypeId]>,
+        field_names: Option<&[Cow<'static, str>]>,
+    ) -> Result<Vec<ParsedValue<E>>, RustLikeParsingError> {
+        let children: Vec<&Node> = match (body, field_types) {
+            (Body::None, _) => vec![],
+            (Body::Positional(children), _) => children.iter().collect(),
+            (Body::Fields(fields), None) => fields.iter().map(|(_, child)| child).collect(),
+            (Body::Fields(fields), Some(_)) => {
+                let field_names = field_names.ok_or_else(|| {
+                    invalid_value(
+                        node.offset,
+                        "The type doesn't have named fields".to_string(),
+                    )
+                })?;
+                if fields.len() != field_names.len() {
+                    return Err(invalid_value(
+                        node.offset,
+                        format!(
+                            "Expected the fields {:?}, but found {:?}",
+                            field_names,
+                            fields.iter().map(|(name, _)| name).collect::<Vec<_>>()
+                        ),
+                    ));
+                }
+                field_names
+                    .iter()
+                    .map(|field_name| {
+                        fields
+                            .iter()
+                            .find(|(name, _)| name == field_name)
+                            .map(|(_, child)| child)
+                            .ok_or_else(|| {
+                                invalid_value(node.offset, format!("Missing field: {}", field_name))
+                            })
+                    })
+                    .collect::<Result<_, _>>()?
+            }
+        };
+        match field_types {
+            Some(field_types) => {
+                if children.len() != field_types.len() {
+                    return Err(invalid_value(
+                        node.offset,
+                        format!(
+                            "Expected {} fields, but found {}",
+                            field_types.len(),
+                            children.len()
+                        ),
+                    ));
+                }
+                children
+                    .into_iter()
+                    .zip(field_types)
+                    .map(|(child, field_type)| self.convert(child, *field_type))
+                    .collect()
+            }
+            None => children
+                .into_iter()
+                .map(|child| self.convert(child, LocalTypeId::any()))
+                .collect(),
+        }
+    }
+
+    fn convert_array(
+        &self,
+        node: &Node,
+        element_type: LocalTypeId,
+    ) -> Result<ParsedValue<E>, RustLikeParsingError> {
+        let (element_value_kind, elements) = match &node.kind {
+            NodeKind::Bytes(bytes) => (
+                ValueKind::U8,
+                bytes
+                    .iter()
+                    .map(|value| Value::U8 { value: *value })
+                    .collect(),
+            ),
+            NodeKind::Array(children) => {
+                let elements = children
+                    .iter()
+                    .map(|child| self.convert(child, element_type))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let element_value_kind = match elements.first() {
+                    Some(element) => element.get_value_kind(),
+                    None => self.value_kind_of(element_type).ok_or_else(|| {
+                        invalid_value(
+                            node.offset,
+                            "Can't determine the element kind of an empty array".to_string(),
+                        )
+                    })?,
+                };
+                (element_value_kind, elements)
+            }
+            _ => return Err(invalid_value(node.offset, "Expected an array".to_string())),
+        };
+        check_value_kinds(node, element_value_kind, elements.iter())?;
+        Ok(Value::Array {
+            element_value_kind,
+            elements,
+        })
+    }
+
+    fn convert_map(
+        &self,
+        node: &Node,
+        key_type: LocalTypeId,
+        value_type: LocalTypeId,
+    ) -> Result<ParsedValue<E>, RustLikeParsingError> {
+        let NodeKind::Map(children) = &node.kind else {
+            return Err(invalid_value(node.offset, "Expected a map".to_string()));
+        };
+        let entries = children
+            .iter()
+            .map(|(key, value)| {
+                Ok((
+                    self.convert(key, key_type)?,
+                    self.convert(value, value_type)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, RustLikeParsingError>>()?;
+        let (key_value_kind, value_value_kind) = match entries.first() {
+            Some((key, value)) => (key.get_value_kind(), value.get_value_kind()),
+            None => self
+                .value_kind_of(key_type)
+                .zip(self.value_kind_of(value_type))
+                .ok_or_else(|| {
+                    invalid_value(
+                        node.offset,
+                        "Can't determine the key and value kinds of an empty map".to_string(),
+                    )
+                })?,
+        };
+        check_value_kinds(node, key_value_kind, entries.iter().map(|(key, _)| key))?;
+        check_value_kinds(
+            node,
+            value_value_kind,
+            entries.iter().map(|(_, value)| value),
+        )?;
+        Ok(Value::Map {
+            key_value_kind,
+            value_value_kind,
+            entries,
+        })
+    }
+
+    /// Returns the value kind of the type, if it's determined by the type alone.
+    fn value_kind_of(&self, type_id: LocalTypeId) -> Option<ValueKind<E::CustomValueKind>> {
+        let value_kind = match self.schema.resolve_type_kind(type_id)? {
+            TypeKind::Any => return None,
+            TypeKind::Bool => ValueKind::Bool,
+            TypeKind::I8 => ValueKind::I8,
+            TypeKind::I16 => ValueKind::I16,
+            TypeKind::I32 => ValueKind::I32,
+            TypeKind::I64 => ValueKind::I64,
+            TypeKind::I128 => ValueKind::I128,
+            TypeKind::U8 => ValueKind::U8,
+            TypeKind::U16 => ValueKind::U16,
+            TypeKind::U32 => ValueKind::U32,
+            TypeKind::U64 => ValueKind::U64,
+            TypeKind::U128 => ValueKind::U128,
+            TypeKind::String => ValueKind::String,
+            TypeKind::Array { .. } => ValueKind::Array,
+            TypeKind::Tuple { .. } => ValueKind::Tuple,
+            TypeKind::Enum { .. } => ValueKind::Enum,
+            TypeKind::Map { .. } => ValueKind::Map,
+            type_kind @ TypeKind::Custom(_) => {
+                // Custom value kinds can't be enumerated directly, so each possible id is tried
+                let mut matching_value_kinds = (0..=u8::MAX)
+                    .filter_map(E::CustomValueKind::from_u8)
+                    .filter(|value_kind| {
+                        E::custom_value_kind_matches_type_kind(self.schema, *value_kind, type_kind)
+                    });
+                let value_kind = matching_value_kinds.next()?;
+                if matching_value_kinds.next().is_some() {
+                    return None;
+                }
+                ValueKind::Custom(value_kind)
+            }
+        };
+        Some(value_kind)
+    }
+}
+
+fn check_value_kinds<'v, X: CustomValueKind + 'v, Y: CustomValue<X> + 'v>(
+    node: &Node,
+    expected: ValueKind<X>,
+    values: impl Iterator<Item = &'v Value<X, Y>>,
+) -> Result<(), RustLikeParsingError> {
+    for value in values {
+        let actual = value.get_value_kind();
+        if actual != expected {
+            return Err(invalid_value(
+                node.offset,
+                format!(
+                    "Expected child value kind {}, but found {}",
+                    expected, actual
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn expect_bool(node: &Node) -> Result<bool, RustLikeParsingError> {
+    match &node.kind {
+        NodeKind::Bool(value) => Ok(*value),
+        _ => Err(invalid_value(node.offset, "Expected a bool".to_string())),
+    }
+}
+
+fn expect_string(node: &Node) -> Result<String, RustLikeParsingError> {
+    match &node.kind {
+        NodeKind::String(value) => Ok(value.clone()),
+        _ => Err(invalid_value(node.offset, "Expected a string".to_string())),
+    }
+}
+
+/// Parses an integer literal, with an optional suffix, as the type with the given suffix.
+fn parse_integer<T: FromStr>(node: &Node, suffix: &str) -> Result<T, RustLikeParsingError> {
+    let NodeKind::Integer(text) = &node.kind else {
+        return Err(invalid_value(
+            node.offset,
+            format!("Expected a {} integer", suffix),
+        ));
+    };
+    text.strip_suffix(suffix)
+        .unwrap_or(text)
+        .parse()
+        .map_err(|_| {
+            invalid_value(
+                node.offset,
+                format!("Invalid or out of range {} integer: {}", suffix, text),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::*;
+
+    #[derive(Sbor, Debug, PartialEq, Eq, Hash)]
+    enum TestEnum {
+        UnitVariant,
+        SingleFieldVariant { field: u8 },
+        TupleVariant(i64, bool),
+    }
+
+    #[derive(Sbor, Debug, PartialEq, Eq)]
+    struct MyUnitStruct;
+
+    #[derive(Sbor, Debug, PartialEq, Eq)]
+    struct MyId(u64);
+
+    #[derive(Sbor, Debug, PartialEq, Eq)]
+    struct MyFieldStruct {
+        field1: u64,
+        field2: Vec<String>,
+    }
+
+    #[derive(BasicSbor, Debug, PartialEq, Eq)]
+    struct MyComplexTupleStruct(
+        Vec<u16>,
+        Vec<u16>,
+        Vec<u8>,
+        IndexMap<TestEnum, MyFieldStruct>,
+        BTreeMap<String, MyUnitStruct>,
+        TestEnum,
+        TestEnum,
+        Option<MyId>,
+        Vec<MyUnitStruct>,
+        BasicValue,
+    );
+
+    fn complex_value() -> MyComplexTupleStruct {
+        MyComplexTupleStruct(
+            vec![1, 2, 3],
+            vec![],
+            vec![1, 2, 3],
+            indexmap! {
+                TestEnum::UnitVariant => MyFieldStruct { field1: 1, field2: vec!["hello".to_string()] },
+                TestEnum::SingleFieldVariant { field: 1 } => MyFieldStruct { field1: 2, field2: vec![] },
+            },
+            btreemap! {
+                "quote \" and\nnewline".to_string() => MyUnitStruct,
+            },
+            TestEnum::TupleVariant(-5, true),
+            TestEnum::UnitVariant,
+            Some(MyId(7)),
+            vec![MyUnitStruct],
+            Value::Tuple {
+                fields: vec![
+                    Value::Enum {
+                        discriminator: 32,
+                        fields: vec![],
+                    },
+                    Value::Array {
+                        element_value_kind: ValueKind::I32,
+                        elements: vec![Value::I32 { value: -3 }],
+                    },
+                ],
+            },
+        )
+    }
+
+    fn annotated_params(
+        schema: &Schema<NoCustomSchema>,
+        type_id: LocalTypeId,
+    ) -> RustLikeParsingParameters<'_, 'static, NoCustomExtension> {
+        RustLikeParsingParameters::Annotated {
+            custom_context: (),
+            schema,
+            type_id,
+            depth_limit: 64,
+        }
+    }
+
+    #[test]
+    fn annotated_output_round_trips() {
+        let (type_id, schema) =
+            generate_full_schema_from_single_type::<MyComplexTupleStruct, NoCustomSchema>();
+        let payload = basic_encode(&complex_value()).unwrap();
+        let raw_payload = BasicRawPayload::new_from_valid_slice_with_checks(&payload).unwrap();
+
+        for print_mode in [
+            PrintMode::SingleLine,
+            PrintMode::MultiLine {
+                indent_size: 4,
+                base_indent: 0,
+                first_line_indent: 0,
+            },
+        ] {
+            let text = raw_payload.to_string(ValueDisplayParameters::Annotated {
+                display_mode: DisplayMode::RustLike,
+                print_mode,
+                schema: schema.v1(),
+                custom_context: Default::default(),
+                type_id,
+                depth_limit: 64,
+            });
+            let parsed_payload =
+                parse_rustlike_payload(&text, &annotated_params(schema.v1(), type_id)).unwrap();
+            assert_eq!(parsed_payload, payload, "Failed to round trip: {}", text);
+        }
+    }
+
+    #[test]
+    fn schemaless_output_round_trips() {
+        let value = BasicValue::Tuple {
+            fields: vec![
+                BasicValue::String {
+                    value: "tab\t \u{1f600}".to_string(),
+                },
+                BasicValue::Enum {
+                    discriminator: 1,
+                    fields: vec![BasicValue::Map {
+                        key_value_kind: ValueKind::U8,
+                        value_value_kind: ValueKind::Array,
+                        entries: vec![(
+                            BasicValue::U8 { value: 1 },
+                            BasicValue::Array {
+                                element_value_kind: ValueKind::U8,
+                                elements: vec![BasicValue::U8 { value: 255 }],
+                            },
+                        )],
+                    }],
+                },
+                BasicValue::Tuple { fields: vec![] },
+                BasicValue::I128 { value: i128::MIN },
+            ],
+        };
+        let payload = basic_encode(&value).unwrap();
+        let text = BasicRawPayload::new_from_valid_slice_with_checks(&payload)
+            .unwrap()
+            .to_string(ValueDisplayParameters::Schemaless {
+                display_mode: DisplayMode::RustLike,
+                print_mode: PrintMode::SingleLine,
+                custom_context: Default::default(),
+                depth_limit: 64,
+            });
+        let params = RustLikeParsingParameters::Schemaless::<NoCustomExtension> {
+            custom_context: (),
+            depth_limit: 64,
+        };
+
+        assert_eq!(parse_rustlike_value(&text, &params).unwrap(), value);
+        assert_eq!(parse_rustlike_payload(&text, &params).unwrap(), payload);
+    }
+
+    #[test]
+    fn hand_written_text_is_resolved_against_the_schema() {
+        let (type_id, schema) =
+            generate_full_schema_from_single_type::<MyComplexTupleStruct, NoCustomSchema>();
+        let text = r#"
+            // Field order, suffixes and name wrappers are optional against a schema
+            MyComplexTupleStruct(
+                [1, 2, 3u16],
+                [],
+                [1u8, 2, 3],
+                {
+                    TestEnum::UnitVariant => MyFieldStruct { field2: ["hello"], field1: 1 },
+                    TestEnum::[1] { field: 1 } => MyFieldStruct { field1: 2, field2: [] },
+                },
+                { "quote \" and\nnewline" => MyUnitStruct },
+                TestEnum::TupleVariant(-5, true),
+                Enum::UnitVariant,
+                Option::Some(MyId(7)),
+                [Unit],
+                Tuple(Enum::[32], [-3i32]),
+            )
+        "#;
+
+        let payload =
+            parse_rustlike_payload(text, &annotated_params(schema.v1(), type_id)).unwrap();
+
+        assert_eq!(
+            basic_decode::<MyComplexTupleStruct>(&payload).unwrap(),
+            complex_value()
+        );
+    }
+
+    #[test]
+    fn invalid_text_is_rejected() {
+        let (type_id, schema) = generate_full_schema_from_single_type::<TestEnum, NoCustomSchema>();
+        let params = annotated_params(schema.v1(), type_id);
+
+        assert_eq!(
+            parse_rustlike_value("TestEnum::Missing", &params),
+            Err(RustLikeParsingError::InvalidValue {
+                offset: 0,
+                message: "Unknown variant: Missing".to_string()
+            })
+        );
+        assert_eq!(
+            parse_rustlike_value("TestEnum::SingleFieldVariant { field: 256 }", &params),
+            Err(RustLikeParsingError::InvalidValue {
+                offset: 38,
+                message: "Invalid or out of range u8 integer: 256".to_string()
+            })
+        );
+        assert_eq!(
+            parse_rustlike_value("TestEnum::UnitVariant,,", &params),
+            Err(RustLikeParsingError::SyntaxError {
+                offset: 21,
+                message: "Expected the end of the value, but found Comma".to_string()
+            })
+        );
+        assert!(matches!(
+            parse_rustlike_value(
+                "[]",
+                &RustLikeParsingParameters::Schemaless::<NoCustomExtension> {
+                    custom_context: (),
+                    depth_limit: 64,
+                }
+            ),
+            Err(RustLikeParsingError::InvalidValue { offset: 0, .. })
+        ));
+        assert!(matches!(
+            parse_rustlike_value(
+                "[[[1u8]]]",
+                &RustLikeParsingParameters::Schemaless::<NoCustomExtension> {
+                    custom_context: (),
+                    depth_limit: 3,
+                }
+            ),
+            Err(RustLikeParsingError::SyntaxError { offset: 3, .. })
+        ));
+    }
+}
@@ -469,7 +469,7 @@ fn format_map<F: fmt::Write, E: FormattableCustomExtension>(
     Ok(())
 }
 
-fn format_terminal_value<F: fmt::Write, E: FormattableCustomExtension>(
+pub(crate) fn format_terminal_value<F: fmt::Write, E: FormattableCustomExtension>(
     f: &mut F,
     context: &RustLikeDisplayContext<'_, '_, E>,
     type_id: LocalTypeId,
@@ -498,12 +498,11 @@ fn format_terminal_value<F: fmt::Write, E: FormattableCustomExtension>(
         TerminalValueRef::U32(value) => write!(f, "{}u32", value)?,
         TerminalValueRef::U64(value) => write!(f, "{}u64", value)?,
         TerminalValueRef::U128(value) => write!(f, "{}u128", value)?,
-        TerminalValueRef::String(value) => write!(f, "\"{}\"", value)?,
+        TerminalValueRef::String(value) => write!(f, "{:?}", value)?,
         TerminalValueRef::Custom(ref value) => {
             write!(f, "{}(", value_ref.value_kind())?;
             E::display_string_content(f, &context.custom_context, value)?;
             write!(f, ")")?;
-            return Ok(());
         }
     }
     if type_name.is_some() {
@@ -692,4 +691,37 @@ mod tests {
             expected_annotated_multi_line,
         );
     }
+
+    #[test]
+    fn string_formatting() {
+        let value = BasicValue::Array {
+            element_value_kind: ValueKind::String,
+            elements: vec![
+                BasicValue::String {
+                    value: "hello world!".to_string(),
+                },
+                BasicValue::String {
+                    value: "quote \" backslash \\ newline\n tab\t".to_string(),
+                },
+            ],
+        };
+        let payload = basic_encode(&value).unwrap();
+
+        let actual = BasicRawPayload::new_from_valid_slice_with_checks(&payload)
+            .unwrap()
+            .to_string(ValueDisplayParameters::Schemaless {
+                display_mode: DisplayMode::RustLike,
+                print_mode: PrintMode::SingleLine,
+                custom_context: Default::default(),
+                depth_limit: 64,
+            });
+
+        // Strings without special characters are printed as before. Other strings are now escaped
+        // like Rust string literals, so they can be parsed back - they used to be printed verbatim
+        // between quotes, eg: `"quote " backslash \ newline<LF> tab<TAB>"`.
+        assert_eq!(
+            actual,
+            r###"["hello world!", "quote \" backslash \\ newline\n tab\t"]"###
+        );
+    }
 }
@@ -0,0 +1,325 @@
+use super::*;
+use crate::representations::*;
+use crate::rust::prelude::*;
+use crate::traversal::*;
+use crate::*;
+use TypedTraversalEvent::*;
+
+/// Compares two payloads of the same type, and reports the values which differ between them,
+/// located by a path built from the type's field and variant names, eg `MyStruct.field::Variant.0`.
+///
+/// Changes are reported at the level of terminal values, byte arrays and enum variants, which are
+/// displayed in the rust-like representation. Array elements and map entries are compared by
+/// index.
+pub fn diff_payloads<E: FormattableCustomExtension>(
+    context: &RustLikeDisplayContext<'_, '_, E>,
+    old_payload: &[u8],
+    new_payload: &[u8],
+    type_id: LocalTypeId,
+    depth_limit: usize,
+) -> Result<ValueDiff, FormattingError> {
+    let context = RustLikeDisplayContext {
+        print_mode: PrintMode::SingleLine,
+        ..*context
+    };
+    let mut old_values = flatten_payload(&context, old_payload, type_id, depth_limit)?;
+    let new_values = flatten_payload(&context, new_payload, type_id, depth_limit)?;
+
+    let mut changes = vec![];
+    for (path, (sbor_path, new)) in new_values.into_iter() {
+        match old_values.swap_remove(&path) {
+            Some((_, old)) if old == new => {}
+            old => changes.push(ValueChange {
+                path,
+                sbor_path,
+                old: old.map(|(_, old)| old),
+                new: Some(new),
+            }),
+        }
+    }
+    for (path, (sbor_path, old)) in old_values.into_iter() {
+        changes.push(ValueChange {
+            path,
+            sbor_path,
+            old: Some(old),
+            new: None,
+        });
+    }
+
+    Ok(ValueDiff { changes })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Sbor)]
+pub struct ValueDiff {
+    pub changes: Vec<ValueChange>,
+}
+
+impl ValueDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for ValueDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return write!(f, "No changes");
+        }
+        for (i, change) in self.changes.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// A value which differs between the compared payloads. The value is `None` in the payload
+/// where it isn't present.
+#[derive(Debug, Clone, PartialEq, Eq, Sbor)]
+pub struct ValueChange {
+    pub path: String,
+    pub sbor_path: SborPath,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+impl fmt::Display for ValueChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.path,
+            self.old.as_deref().unwrap_or("(none)"),
+            self.new.as_deref().unwrap_or("(none)")
+        )
+    }
+}
+
+/// Maps the path of each terminal value, byte array and enum variant of the payload to its
+/// location and display.
+fn flatten_payload<E: FormattableCustomExtension>(
+    context: &RustLikeDisplayContext<'_, '_, E>,
+    payload: &[u8],
+    type_id: LocalTypeId,
+    depth_limit: usize,
+) -> Result<IndexMap<String, (SborPath, String)>, FormattingError> {
+    let mut traverser =
+        traverse_payload_with_types::<E>(payload, context.schema, type_id, depth_limit);
+    let mut values = index_map_new();
+    // The path of each container entered, including the variant of enums.
+    let mut container_paths: Vec<(String, SborPathBuf)> = vec![];
+
+    loop {
+        let typed_event = traverser.next_event();
+        match typed_event.event {
+            ContainerStart(type_id, container_header) => {
+                let (mut path, sbor_path) =
+                    value_path(context, &typed_event.location, &container_paths, type_id);
+                if let ContainerHeader::EnumVariant(header) = container_header {
+                    let enum_data = context.schema.resolve_matching_enum_metadata(
+                        type_id,
+                        header.variant,
+                        header.length,
+                    );
+                    let variant = match enum_data.variant_name {
+                        Some(variant_name) => variant_name.to_string(),
+                        None => format!("[{}]", header.variant),
+                    };
+                    values.insert(
+                        path.clone(),
+                        (
+                            sbor_path.clone().into(),
+                            format!("{}::{}", enum_data.enum_name.unwrap_or("Enum"), variant),
+                        ),
+                    );
+                    path.push_str(&format!("::{}", variant));
+                }
+                container_paths.push((path, sbor_path));
+            }
+            ContainerEnd(_, _) => {
+                container_paths.pop();
+            }
+            TerminalValue(type_id, value_ref) => {
+                let (path, sbor_path) =
+                    value_path(context, &typed_event.location, &container_paths, type_id);
+                let mut display = String::new();
+                format_terminal_value(&mut display, context, type_id, value_ref)?;
+                values.insert(path, (sbor_path.into(), display));
+            }
+            TerminalValueBatch(_, TerminalValueBatchRef::U8(bytes)) => {
+                let (path, sbor_path) = container_paths
+                    .last()
+                    .cloned()
+                    .expect("Value batches are always in an array");
+                values.insert(
+                    path,
+                    (sbor_path.into(), format!("hex(\"{}\")", hex::encode(bytes))),
+                );
+            }
+            End => return Ok(values),
+            Error(_) => {
+                return Err(FormattingError::Sbor(
+                    typed_event.display_as_unexpected_event(
+                        "ContainerStart | ContainerEnd | TerminalValue | End",
+                        context.schema,
+                    ),
+                ))
+            }
+        }
+    }
+}
+
+/// Gives the path of the value at the given location, from the path of its parent container.
+fn value_path<E: FormattableCustomExtension>(
+    context: &RustLikeDisplayContext<'_, '_, E>,
+    location: &TypedLocation<'_, '_, E::CustomTraversal>,
+    container_paths: &[(String, SborPathBuf)],
+    type_id: LocalTypeId,
+) -> (String, SborPathBuf) {
+    // On container starts, the typed ancestor path already includes the started container, so the
+    // type of the parent is found from the depth of the untyped ancestor path.
+    let parent_depth = location.location.ancestor_path.len();
+    let (Some(parent), Some(parent_type), Some((parent_path, parent_sbor_path))) = (
+        location.location.ancestor_path.last(),
+        parent_depth
+            .checked_sub(1)
+            .and_then(|index| location.typed_ancestor_path.get(index)),
+        container_paths.last(),
+    ) else {
+        let root_path = context
+            .schema
+            .resolve_type_name_from_metadata(type_id)
+            .unwrap_or("<root>")
+            .to_string();
+        return (root_path, SborPathBuf::new());
+    };
+
+    let child_index = parent
+        .current_child_index
+        .expect("Child values are only visited when the parent has a current child");
+    let mut path = parent_path.clone();
+    let mut sbor_path = parent_sbor_path.clone();
+    let field_names = match parent.container_header {
+        ContainerHeader::Tuple(header) => {
+            context
+                .schema
+                .resolve_matching_tuple_metadata(parent_type.self_type(), header.length)
+                .field_names
+        }
+        ContainerHeader::EnumVariant(header) => {
+            context
+                .schema
+                .resolve_matching_enum_metadata(
+                    parent_type.self_type(),
+                    header.variant,
+                    header.length,
+                )
+                .field_names
+        }
+        ContainerHeader::Array(_) => {
+            path.push_str(&format!("[{}]", child_index));
+            sbor_path.push(child_index);
+            return (path, sbor_path);
+        }
+        ContainerHeader::Map(_) => {
+            let key_or_value = if child_index % 2 == 0 { "key" } else { "value" };
+            path.push_str(&format!("[{}]{{{}}}", child_index / 2, key_or_value));
+            sbor_path.push(child_index / 2);
+            sbor_path.push(child_index % 2);
+            return (path, sbor_path);
+        }
+    };
+    match field_names.and_then(|names| names.get(child_index)) {
+        Some(field_name) => path.push_str(&format!(".{}", field_name)),
+        None => path.push_str(&format!(".{}", child_index)),
+    }
+    sbor_path.push(child_index);
+    (path, sbor_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Sbor)]
+    struct Account {
+        balance: u64,
+        status: Status,
+        tags: Vec<String>,
+        payload: Vec<u8>,
+    }
+
+    #[derive(Sbor)]
+    enum Status {
+        Active { since: u32 },
+        Frozen(String),
+    }
+
+    fn diff(old: &Account, new: &Account) -> ValueDiff {
+        let (type_id, schema) = generate_full_schema_from_single_type::<Account, NoCustomSchema>();
+        let context = RustLikeDisplayContext::<NoCustomExtension> {
+            schema: schema.v1(),
+            custom_context: Default::default(),
+            print_mode: PrintMode::SingleLine,
+        };
+        diff_payloads(
+            &context,
+            &basic_encode(old).unwrap(),
+            &basic_encode(new).unwrap(),
+            type_id,
+            64,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn changes_are_reported_with_named_paths() {
+        let old = Account {
+            balance: 5,
+            status: Status::Active { since: 1 },
+            tags: vec!["a".to_string(), "b".to_string()],
+            payload: vec![1, 2],
+        };
+        let new = Account {
+            balance: 7,
+            status: Status::Active { since: 1 },
+            tags: vec!["a".to_string()],
+            payload: vec![1, 3],
+        };
+
+        assert_eq!(
+            diff(&old, &new).to_string(),
+            "Account.balance: 5u64 -> 7u64\n\
+            Account.payload: hex(\"0102\") -> hex(\"0103\")\n\
+            Account.tags[1]: \"b\" -> (none)"
+        );
+        assert!(diff(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn variant_changes_are_reported_with_their_fields() {
+        let old = Account {
+            balance: 5,
+            status: Status::Active { since: 1 },
+            tags: vec![],
+            payload: vec![],
+        };
+        let new = Account {
+            balance: 5,
+            status: Status::Frozen("audit".to_string()),
+            tags: vec![],
+            payload: vec![],
+        };
+
+        let diff = diff(&old, &new);
+        assert_eq!(
+            diff.to_string(),
+            "Account.status: Status::Active -> Status::Frozen\n\
+            Account.status::Frozen.0: (none) -> \"audit\"\n\
+            Account.status::Active.since: 1u32 -> (none)"
+        );
+        assert_eq!(diff.changes[1].sbor_path, SborPath::new(vec![1, 0]));
+    }
+}
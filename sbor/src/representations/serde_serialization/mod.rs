@@ -21,14 +21,20 @@
 //!     // efficient in some cases.
 //!     let json = serde_json::to_string(&serializable).unwrap();
 //! ```
+//!
+//! JSON in the `Programmatic` mode can be turned back into a value or payload with
+//! [`deserialize_programmatic_value`] / [`deserialize_programmatic_payload`], for custom extensions
+//! implementing [`DeserializableCustomExtension`].
 
 // Imports and Exports
 mod contextual_serialize;
+mod serde_deserializer;
 mod serde_serializer;
 mod traits;
 mod value_map_aggregator;
 
 pub use contextual_serialize::*;
+pub use serde_deserializer::*;
 pub use serde_serializer::*;
 pub use traits::*;
 pub use value_map_aggregator::*;
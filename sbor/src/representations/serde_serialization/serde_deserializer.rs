@@ -0,0 +1,600 @@
+use super::*;
+use crate::representations::*;
+use crate::rust::prelude::*;
+use crate::*;
+use serde::de::{self, Deserialize, Deserializer, Error as _, MapAccess, SeqAccess, Visitor};
+
+/// Custom extensions which support deserializing values from their
+/// [`SerializationMode::Programmatic`] representation.
+pub trait DeserializableCustomExtension:
+    SerializableCustomExtension + ParseableCustomExtension
+{
+}
+
+impl<E: SerializableCustomExtension + ParseableCustomExtension> DeserializableCustomExtension
+    for E
+{
+}
+
+pub enum DeserializationParameters<'s, 'a, E: DeserializableCustomExtension> {
+    Schemaless {
+        custom_context: E::CustomParsingContext<'a>,
+        depth_limit: usize,
+    },
+    /// The deserialized payload is additionally validated against the given type.
+    WithSchema {
+        custom_context: E::CustomParsingContext<'a>,
+        schema: &'s Schema<E::CustomSchema>,
+        type_id: LocalTypeId,
+        depth_limit: usize,
+    },
+}
+
+impl<'s, 'a, E: DeserializableCustomExtension> DeserializationParameters<'s, 'a, E> {
+    pub fn custom_context(&self) -> &E::CustomParsingContext<'a> {
+        match self {
+            Self::Schemaless { custom_context, .. } | Self::WithSchema { custom_context, .. } => {
+                custom_context
+            }
+        }
+    }
+
+    pub fn depth_limit(&self) -> usize {
+        match self {
+            Self::Schemaless { depth_limit, .. } | Self::WithSchema { depth_limit, .. } => {
+                *depth_limit
+            }
+        }
+    }
+}
+
+pub type DeserializedValue<E> =
+    Value<<E as CustomExtension>::CustomValueKind, <E as ParseableCustomExtension>::CustomValue>;
+
+/// Deserializes a value from its [`SerializationMode::Programmatic`] representation.
+///
+/// Annotations (type names, field names and variant names) are ignored. If a schema is provided,
+/// the value is checked to match the given type.
+pub fn deserialize_programmatic_value<'de, D, E>(
+    deserializer: D,
+    params: &DeserializationParameters<'_, '_, E>,
+) -> Result<DeserializedValue<E>, D::Error>
+where
+    D: Deserializer<'de>,
+    E: DeserializableCustomExtension + ValidatableCustomExtension<()>,
+{
+    let tree = SerdeTree::deserialize(deserializer)?;
+    let value = ProgrammaticValueParser::<E> {
+        custom_context: params.custom_context(),
+        depth_limit: params.depth_limit(),
+    }
+    .parse_value(&tree, 0)
+    .map_err(D::Error::custom)?;
+    if let DeserializationParameters::WithSchema { .. } = params {
+        encode_and_validate(&value, params).map_err(D::Error::custom)?;
+    }
+    Ok(value)
+}
+
+/// Deserializes a value from its [`SerializationMode::Programmatic`] representation, and encodes
+/// it as a full payload (including the payload prefix of the custom extension).
+///
+/// If a schema is provided, the payload is checked to match the given type.
+pub fn deserialize_programmatic_payload<'de, D, E>(
+    deserializer: D,
+    params: &DeserializationParameters<'_, '_, E>,
+) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+    E: DeserializableCustomExtension + ValidatableCustomExtension<()>,
+{
+    let tree = SerdeTree::deserialize(deserializer)?;
+    let value = ProgrammaticValueParser::<E> {
+        custom_context: params.custom_context(),
+        depth_limit: params.depth_limit(),
+    }
+    .parse_value(&tree, 0)
+    .map_err(D::Error::custom)?;
+    encode_and_validate(&value, params).map_err(D::Error::custom)
+}
+
+fn encode_and_validate<E: DeserializableCustomExtension + ValidatableCustomExtension<()>>(
+    value: &DeserializedValue<E>,
+    params: &DeserializationParameters<'_, '_, E>,
+) -> Result<Vec<u8>, String> {
+    let mut payload = Vec::new();
+    VecEncoder::<E::CustomValueKind>::new(&mut payload, params.depth_limit())
+        .encode_payload(value, E::PAYLOAD_PREFIX)
+        .map_err(|error| format!("Failed to encode the value: {:?}", error))?;
+    if let DeserializationParameters::WithSchema {
+        schema,
+        type_id,
+        depth_limit,
+        ..
+    } = params
+    {
+        validate_payload_against_schema::<E, _>(&payload, schema, *type_id, &(), *depth_limit)
+            .map_err(|error| {
+                format!(
+                    "The value doesn't match the schema: {}",
+                    error.error_message(schema)
+                )
+            })?;
+    }
+    Ok(payload)
+}
+
+struct ProgrammaticValueParser<'c, 'a, E: DeserializableCustomExtension> {
+    custom_context: &'c E::CustomParsingContext<'a>,
+    depth_limit: usize,
+}
+
+impl<'c, 'a, E: DeserializableCustomExtension> ProgrammaticValueParser<'c, 'a, E> {
+    fn parse_value(&self, tree: &SerdeTree, depth: usize) -> Result<DeserializedValue<E>, String> {
+        if depth >= self.depth_limit {
+            return Err(format!("Depth limit of {} exceeded", self.depth_limit));
+        }
+        let object = tree.as_object()?;
+        let kind = get_field(object, "kind")?.as_str()?;
+        if kind == "Bytes" {
+            let hex = get_field(object, "hex")?.as_str()?;
+            let bytes = hex::decode(hex).map_err(|_| format!("Invalid hex: {}", hex))?;
+            return Ok(Value::Array {
+                element_value_kind: ValueKind::U8,
+                elements: bytes.into_iter().map(|value| Value::U8 { value }).collect(),
+            });
+        }
+
+        let value = match parse_value_kind::<E>(kind)? {
+            ValueKind::Bool => Value::Bool {
+                value: get_field(object, "value")?.as_bool()?,
+            },
+            ValueKind::I8 => Value::I8 {
+                value: parse_integer(get_field(object, "value")?)?,
+            },
+            ValueKind::I16 => Value::I16 {
+                value: parse_integer(get_field(object, "value")?)?,
+            },
+            ValueKind::I32 => Value::I32 {
+                value: parse_integer(get_field(object, "value")?)?,
+            },
+            ValueKind::I64 => Value::I64 {
+                value: parse_integer(get_field(object, "value")?)?,
+            },
+            ValueKind::I128 => Value::I128 {
+                value: parse_integer(get_field(object, "value")?)?,
+            },
+            ValueKind::U8 => Value::U8 {
+                value: parse_integer(get_field(object, "value")?)?,
+            },
+            ValueKind::U16 => Value::U16 {
+                value: parse_integer(get_field(object, "value")?)?,
+            },
+            ValueKind::U32 => Value::U32 {
+                value: parse_integer(get_field(object, "value")?)?,
+            },
+            ValueKind::U64 => Value::U64 {
+                value: parse_integer(get_field(object, "value")?)?,
+            },
+            ValueKind::U128 => Value::U128 {
+                value: parse_integer(get_field(object, "value")?)?,
+            },
+            ValueKind::String => Value::String {
+                value: get_field(object, "value")?.as_str()?.to_string(),
+            },
+            ValueKind::Tuple => Value::Tuple {
+                fields: self.parse_values(get_field(object, "fields")?, depth)?,
+            },
+            ValueKind::Enum => Value::Enum {
+                discriminator: parse_integer(get_field(object, "variant_id")?)?,
+                fields: self.parse_values(get_field(object, "fields")?, depth)?,
+            },
+            ValueKind::Array => {
+                let element_value_kind =
+                    parse_value_kind::<E>(get_field(object, "element_kind")?.as_str()?)?;
+                let elements = self.parse_values(get_field(object, "elements")?, depth)?;
+                check_value_kinds(element_value_kind, elements.iter())?;
+                Value::Array {
+                    element_value_kind,
+                    elements,
+                }
+            }
+            ValueKind::Map => {
+                let key_value_kind =
+                    parse_value_kind::<E>(get_field(object, "key_kind")?.as_str()?)?;
+                let value_value_kind =
+                    parse_value_kind::<E>(get_field(object, "value_kind")?.as_str()?)?;
+                let entries = get_field(object, "entries")?
+                    .as_array()?
+                    .iter()
+                    .map(|entry| {
+                        let entry = entry.as_object()?;
+                        Ok((
+                            self.parse_value(get_field(entry, "key")?, depth + 1)?,
+                            self.parse_value(get_field(entry, "value")?, depth + 1)?,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                check_value_kinds(key_value_kind, entries.iter().map(|(key, _)| key))?;
+                check_value_kinds(value_value_kind, entries.iter().map(|(_, value)| value))?;
+                Value::Map {
+                    key_value_kind,
+                    value_value_kind,
+                    entries,
+                }
+            }
+            ValueKind::Custom(custom_value_kind) => Value::Custom {
+                value: E::parse_custom_value(
+                    self.custom_context,
+                    custom_value_kind,
+                    get_field(object, "value")?.as_str()?,
+                )?,
+            },
+        };
+        Ok(value)
+    }
+
+    fn parse_values(
+        &self,
+        tree: &SerdeTree,
+        depth: usize,
+    ) -> Result<Vec<DeserializedValue<E>>, String> {
+        tree.as_array()?
+            .iter()
+            .map(|child| self.parse_value(child, depth + 1))
+            .collect()
+    }
+}
+
+fn parse_value_kind<E: DeserializableCustomExtension>(
+    name: &str,
+) -> Result<ValueKind<E::CustomValueKind>, String> {
+    let value_kind = match name {
+        "Bool" => ValueKind::Bool,
+        "I8" => ValueKind::I8,
+        "I16" => ValueKind::I16,
+        "I32" => ValueKind::I32,
+        "I64" => ValueKind::I64,
+        "I128" => ValueKind::I128,
+        "U8" => ValueKind::U8,
+        "U16" => ValueKind::U16,
+        "U32" => ValueKind::U32,
+        "U64" => ValueKind::U64,
+        "U128" => ValueKind::U128,
+        "String" => ValueKind::String,
+        "Tuple" => ValueKind::Tuple,
+        "Enum" => ValueKind::Enum,
+        "Array" => ValueKind::Array,
+        "Map" => ValueKind::Map,
+        _ => ValueKind::Custom(
+            E::parse_custom_value_kind(name)
+                .ok_or_else(|| format!("Unknown value kind: {}", name))?,
+        ),
+    };
+    Ok(value_kind)
+}
+
+fn check_value_kinds<'v, X: CustomValueKind + 'v, Y: CustomValue<X> + 'v>(
+    expected: ValueKind<X>,
+    values: impl Iterator<Item = &'v Value<X, Y>>,
+) -> Result<(), String> {
+    for value in values {
+        let actual = value.get_value_kind();
+        if actual != expected {
+            return Err(format!(
+                "Expected child value kind {}, but found {}",
+                expected, actual
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Integers are output as strings in the programmatic mode, but JSON numbers are also accepted.
+fn parse_integer<T: FromStr>(tree: &SerdeTree) -> Result<T, String> {
+    let text = match tree {
+        SerdeTree::String(text) | SerdeTree::Integer(text) => text,
+        _ => {
+            return Err(format!(
+                "Expected an integer, but found {}",
+                tree.describe()
+            ))
+        }
+    };
+    text.parse()
+        .map_err(|_| format!("Invalid or out of range integer: {}", text))
+}
+
+fn get_field<'t>(object: &'t [(String, SerdeTree)], name: &str) -> Result<&'t SerdeTree, String> {
+    object
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value)
+        .ok_or_else(|| format!("Missing field: {}", name))
+}
+
+/// A self-describing tree of the deserialized data, from which the SBOR value is then parsed.
+///
+/// This intermediate step is needed as object fields can come in any order, and the "kind" field
+/// determines how the others are interpreted.
+enum SerdeTree {
+    Null,
+    Bool(bool),
+    Integer(String),
+    Float,
+    String(String),
+    Array(Vec<SerdeTree>),
+    Object(Vec<(String, SerdeTree)>),
+}
+
+impl SerdeTree {
+    fn describe(&self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Bool(_) => "a boolean",
+            Self::Integer(_) => "an integer",
+            Self::Float => "a float",
+            Self::String(_) => "a string",
+            Self::Array(_) => "an array",
+            Self::Object(_) => "an object",
+        }
+    }
+
+    fn as_bool(&self) -> Result<bool, String> {
+        match self {
+            Self::Bool(value) => Ok(*value),
+            _ => Err(format!("Expected a boolean, but found {}", self.describe())),
+        }
+    }
+
+    fn as_str(&self) -> Result<&str, String> {
+        match self {
+            Self::String(value) => Ok(value),
+            _ => Err(format!("Expected a string, but found {}", self.describe())),
+        }
+    }
+
+    fn as_array(&self) -> Result<&[SerdeTree], String> {
+        match self {
+            Self::Array(value) => Ok(value),
+            _ => Err(format!("Expected an array, but found {}", self.describe())),
+        }
+    }
+
+    fn as_object(&self) -> Result<&[(String, SerdeTree)], String> {
+        match self {
+            Self::Object(value) => Ok(value),
+            _ => Err(format!("Expected an object, but found {}", self.describe())),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SerdeTree {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(SerdeTreeVisitor)
+    }
+}
+
+struct SerdeTreeVisitor;
+
+impl<'de> Visitor<'de> for SerdeTreeVisitor {
+    type Value = SerdeTree;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a programmatic SBOR value")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(SerdeTree::Null)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(SerdeTree::Null)
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(SerdeTree::Bool(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(SerdeTree::Integer(value.to_string()))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(SerdeTree::Integer(value.to_string()))
+    }
+
+    fn visit_f64<E: de::Error>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(SerdeTree::Float)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(SerdeTree::String(value.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+        Ok(SerdeTree::String(value))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut elements = Vec::new();
+        while let Some(element) = seq.next_element()? {
+            elements.push(element);
+        }
+        Ok(SerdeTree::Array(elements))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = Vec::new();
+        while let Some(entry) = map.next_entry::<String, SerdeTree>()? {
+            entries.push(entry);
+        }
+        Ok(SerdeTree::Object(entries))
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")] // Ensures that VS Code runs this module with the features serde tag!
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use utils::*;
+
+    #[derive(Sbor, Debug, PartialEq, Eq)]
+    enum TestEnum {
+        UnitVariant,
+        SingleFieldVariant { field: u8 },
+    }
+
+    #[derive(Sbor, Debug, PartialEq, Eq)]
+    struct TestStruct {
+        field1: u64,
+        field2: Vec<String>,
+        field3: Vec<u8>,
+        field4: BTreeMap<String, TestEnum>,
+        field5: TestEnum,
+        field6: (i128, bool),
+    }
+
+    fn test_value() -> TestStruct {
+        TestStruct {
+            field1: u64::MAX,
+            field2: vec!["hello".to_string(), "world".to_string()],
+            field3: vec![1, 2, 3],
+            field4: btreemap! {
+                "a".to_string() => TestEnum::UnitVariant,
+                "b".to_string() => TestEnum::SingleFieldVariant { field: 5 },
+            },
+            field5: TestEnum::SingleFieldVariant { field: 7 },
+            field6: (-12, true),
+        }
+    }
+
+    fn to_programmatic_json(payload: &[u8], schema: Option<(&BasicSchema, LocalTypeId)>) -> String {
+        let raw_payload = BasicRawPayload::new_from_valid_slice_with_checks(payload).unwrap();
+        let serializable = match schema {
+            Some((schema, type_id)) => {
+                raw_payload.serializable(SerializationParameters::WithSchema {
+                    mode: SerializationMode::Programmatic,
+                    custom_context: (),
+                    schema,
+                    type_id,
+                    depth_limit: 64,
+                })
+            }
+            None => raw_payload.serializable(SerializationParameters::Schemaless {
+                mode: SerializationMode::Programmatic,
+                custom_context: (),
+                depth_limit: 64,
+            }),
+        };
+        serde_json::to_string(&serializable).unwrap()
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn programmatic_json_round_trips_with_and_without_annotations() {
+        let (type_id, schema) =
+            generate_full_schema_from_single_type::<TestStruct, NoCustomSchema>();
+        let payload = basic_encode(&test_value()).unwrap();
+
+        for json in [
+            to_programmatic_json(&payload, None),
+            to_programmatic_json(&payload, Some((schema.v1(), type_id))),
+        ] {
+            let deserialized = deserialize_programmatic_payload(
+                &mut serde_json::Deserializer::from_str(&json),
+                &DeserializationParameters::WithSchema::<NoCustomExtension> {
+                    custom_context: (),
+                    schema: schema.v1(),
+                    type_id,
+                    depth_limit: 64,
+                },
+            )
+            .unwrap();
+            assert_eq!(deserialized, payload);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn json_numbers_are_accepted_for_integers() {
+        let json = json!({
+            "kind": "Tuple",
+            "fields": [
+                { "kind": "U8", "value": 5 },
+                { "kind": "I64", "value": "-5" },
+                { "kind": "Enum", "variant_id": 1, "fields": [] },
+            ]
+        });
+
+        let value = deserialize_programmatic_value(
+            json,
+            &DeserializationParameters::Schemaless::<NoCustomExtension> {
+                custom_context: (),
+                depth_limit: 64,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            value,
+            BasicValue::Tuple {
+                fields: vec![
+                    Value::U8 { value: 5 },
+                    Value::I64 { value: -5 },
+                    Value::Enum {
+                        discriminator: 1,
+                        fields: vec![],
+                    },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn value_not_matching_schema_is_rejected() {
+        let (type_id, schema) = generate_full_schema_from_single_type::<TestEnum, NoCustomSchema>();
+        let json = json!({
+            "kind": "Enum",
+            "variant_id": "1",
+            "fields": [{ "kind": "U16", "value": "5" }]
+        });
+
+        let result = deserialize_programmatic_payload(
+            json,
+            &DeserializationParameters::WithSchema::<NoCustomExtension> {
+                custom_context: (),
+                schema: schema.v1(),
+                type_id,
+                depth_limit: 64,
+            },
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")] // Workaround for VS Code "Run Test" feature
+    fn out_of_range_integers_and_mismatching_elements_are_rejected() {
+        let params = DeserializationParameters::Schemaless::<NoCustomExtension> {
+            custom_context: (),
+            depth_limit: 64,
+        };
+
+        assert!(
+            deserialize_programmatic_value(json!({ "kind": "U8", "value": "256" }), &params)
+                .is_err()
+        );
+        assert!(deserialize_programmatic_value(
+            json!({
+                "kind": "Array",
+                "element_kind": "U16",
+                "elements": [{ "kind": "U32", "value": "1" }]
+            }),
+            &params
+        )
+        .is_err());
+    }
+}
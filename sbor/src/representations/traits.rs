@@ -20,3 +20,22 @@ pub trait FormattableCustomExtension: CustomExtension + Copy {
         value: &<Self::CustomTraversal as CustomTraversal>::CustomTerminalValueRef<'de>,
     ) -> Result<(), fmt::Error>;
 }
+
+/// Custom extensions whose custom values can be parsed back from the string content output by
+/// their representations (for example, the `"value"` field of the programmatic JSON, or the
+/// content of `CustomValueKind("<value_content>")` in the rust-like text format).
+pub trait ParseableCustomExtension: CustomExtension {
+    type CustomValue: CustomValue<Self::CustomValueKind>
+        + for<'b> Encode<Self::CustomValueKind, VecEncoder<'b, Self::CustomValueKind>>;
+    type CustomParsingContext<'a>: Copy;
+
+    /// Parses the name of a custom value kind, as output in its representations.
+    fn parse_custom_value_kind(name: &str) -> Option<Self::CustomValueKind>;
+
+    /// Parses the string content of a custom value.
+    fn parse_custom_value(
+        context: &Self::CustomParsingContext<'_>,
+        value_kind: Self::CustomValueKind,
+        value: &str,
+    ) -> Result<Self::CustomValue, String>;
+}
@@ -0,0 +1,782 @@
+use crate::rust::prelude::*;
+use crate::*;
+
+/// Compares a type in an old schema with a type in a new schema, and reports whether every payload
+/// valid for the old type is also valid for the new type.
+///
+/// Changes which don't affect the encoding (such as renamed types, fields and variants, added
+/// enum variants or relaxed validations) are reported, but don't break compatibility.
+pub fn check_schema_compatibility<S: CustomSchema>(
+    old_schema: &VersionedSchema<S>,
+    old_type_id: LocalTypeId,
+    new_schema: &VersionedSchema<S>,
+    new_type_id: LocalTypeId,
+) -> SchemaCompatibilityReport {
+    let mut checker = SchemaCompatibilityChecker {
+        old_schema: old_schema.v1(),
+        new_schema: new_schema.v1(),
+        visited: index_set_new(),
+        changes: vec![],
+    };
+    let root_path = old_schema
+        .v1()
+        .resolve_type_name_from_metadata(old_type_id)
+        .unwrap_or("<root>")
+        .to_string();
+    checker.compare_types(&root_path, old_type_id, new_type_id);
+    SchemaCompatibilityReport {
+        changes: checker.changes,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaCompatibilityReport {
+    pub changes: Vec<SchemaChange>,
+}
+
+impl SchemaCompatibilityReport {
+    pub fn is_compatible(&self) -> bool {
+        self.breaking_changes().next().is_none()
+    }
+
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &SchemaChange> {
+        self.changes
+            .iter()
+            .filter(|change| change.kind.is_breaking())
+    }
+}
+
+impl fmt::Display for SchemaCompatibilityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.changes.is_empty() {
+            return write!(f, "No changes");
+        }
+        for (i, change) in self.changes.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// A change found at a given path of the compared type, eg `MyStruct.field::Variant.0`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaChange {
+    pub path: String,
+    pub kind: SchemaChangeKind,
+}
+
+impl fmt::Display for SchemaChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tag = if self.kind.is_breaking() {
+            "BREAKING"
+        } else {
+            "compatible"
+        };
+        write!(f, "[{}] {}: {}", tag, self.path, self.kind)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaChangeKind {
+    // Breaking changes
+    TypeKindChanged {
+        old: String,
+        new: String,
+    },
+    TypeNotFound,
+    TupleFieldCountChanged {
+        old: usize,
+        new: usize,
+    },
+    EnumVariantRemoved {
+        discriminator: u8,
+        name: Option<String>,
+    },
+    EnumVariantFieldCountChanged {
+        discriminator: u8,
+        old: usize,
+        new: usize,
+    },
+    ValidationTightened {
+        old: String,
+        new: String,
+    },
+    // Compatible changes
+    TypeBecameAny,
+    TypeRenamed {
+        old: String,
+        new: String,
+    },
+    FieldRenamed {
+        index: usize,
+        old: String,
+        new: String,
+    },
+    EnumVariantAdded {
+        discriminator: u8,
+        name: Option<String>,
+    },
+    EnumVariantRenamed {
+        discriminator: u8,
+        old: String,
+        new: String,
+    },
+    ValidationRelaxed {
+        old: String,
+        new: String,
+    },
+}
+
+impl SchemaChangeKind {
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            Self::TypeKindChanged { .. }
+            | Self::TypeNotFound
+            | Self::TupleFieldCountChanged { .. }
+            | Self::EnumVariantRemoved { .. }
+            | Self::EnumVariantFieldCountChanged { .. }
+            | Self::ValidationTightened { .. } => true,
+            Self::TypeBecameAny
+            | Self::TypeRenamed { .. }
+            | Self::FieldRenamed { .. }
+            | Self::EnumVariantAdded { .. }
+            | Self::EnumVariantRenamed { .. }
+            | Self::ValidationRelaxed { .. } => false,
+        }
+    }
+}
+
+impl fmt::Display for SchemaChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TypeKindChanged { old, new } => {
+                write!(f, "type kind changed from {} to {}", old, new)
+            }
+            Self::TypeNotFound => write!(f, "type could not be resolved in the schema"),
+            Self::TupleFieldCountChanged { old, new } => {
+                write!(f, "field count changed from {} to {}", old, new)
+            }
+            Self::EnumVariantRemoved {
+                discriminator,
+                name,
+            } => write!(
+                f,
+                "variant {} was removed",
+                variant_label(*discriminator, name.as_deref())
+            ),
+            Self::EnumVariantFieldCountChanged {
+                discriminator,
+                old,
+                new,
+            } => write!(
+                f,
+                "field count of variant {} changed from {} to {}",
+                discriminator, old, new
+            ),
+            Self::ValidationTightened { old, new } => {
+                write!(f, "validation tightened from {} to {}", old, new)
+            }
+            Self::TypeBecameAny => write!(f, "type became Any"),
+            Self::TypeRenamed { old, new } => write!(f, "type renamed from {} to {}", old, new),
+            Self::FieldRenamed { index, old, new } => {
+                write!(f, "field {} renamed from {} to {}", index, old, new)
+            }
+            Self::EnumVariantAdded {
+                discriminator,
+                name,
+            } => write!(
+                f,
+                "variant {} was added",
+                variant_label(*discriminator, name.as_deref())
+            ),
+            Self::EnumVariantRenamed {
+                discriminator,
+                old,
+                new,
+            } => write!(
+                f,
+                "variant {} renamed from {} to {}",
+                discriminator, old, new
+            ),
+            Self::ValidationRelaxed { old, new } => {
+                write!(f, "validation relaxed from {} to {}", old, new)
+            }
+        }
+    }
+}
+
+fn variant_label(discriminator: u8, name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("{} ({})", name, discriminator),
+        None => discriminator.to_string(),
+    }
+}
+
+struct SchemaCompatibilityChecker<'s, S: CustomSchema> {
+    old_schema: &'s Schema<S>,
+    new_schema: &'s Schema<S>,
+    /// Types can be recursive, so each pair of types is only compared once.
+    visited: IndexSet<(LocalTypeId, LocalTypeId)>,
+    changes: Vec<SchemaChange>,
+}
+
+impl<'s, S: CustomSchema> SchemaCompatibilityChecker<'s, S> {
+    fn compare_types(&mut self, path: &str, old_type_id: LocalTypeId, new_type_id: LocalTypeId) {
+        if let (LocalTypeId::WellKnown(old_id), LocalTypeId::WellKnown(new_id)) =
+            (old_type_id, new_type_id)
+        {
+            if old_id == new_id {
+                return;
+            }
+        }
+        if !self.visited.insert((old_type_id, new_type_id)) {
+            return;
+        }
+        let old_schema = self.old_schema;
+        let new_schema = self.new_schema;
+        let (Some(old_kind), Some(new_kind)) = (
+            old_schema.resolve_type_kind(old_type_id),
+            new_schema.resolve_type_kind(new_type_id),
+        ) else {
+            self.push(path, SchemaChangeKind::TypeNotFound);
+            return;
+        };
+
+        if let (Some(old_name), Some(new_name)) = (
+            old_schema.resolve_type_name_from_metadata(old_type_id),
+            new_schema.resolve_type_name_from_metadata(new_type_id),
+        ) {
+            if old_name != new_name {
+                self.push(
+                    path,
+                    SchemaChangeKind::TypeRenamed {
+                        old: old_name.to_string(),
+                        new: new_name.to_string(),
+                    },
+                );
+            }
+        }
+
+        match (old_kind, new_kind) {
+            (TypeKind::Any, TypeKind::Any) => {}
+            (_, TypeKind::Any) => {
+                self.push(path, SchemaChangeKind::TypeBecameAny);
+                return;
+            }
+            (
+                TypeKind::Tuple {
+                    field_types: old_field_types,
+                },
+                TypeKind::Tuple {
+                    field_types: new_field_types,
+                },
+            ) => {
+                if old_field_types.len() != new_field_types.len() {
+                    self.push(
+                        path,
+                        SchemaChangeKind::TupleFieldCountChanged {
+                            old: old_field_types.len(),
+                            new: new_field_types.len(),
+                        },
+                    );
+                    return;
+                }
+                self.compare_fields(
+                    path,
+                    old_schema
+                        .resolve_type_metadata(old_type_id)
+                        .and_then(|m| m.get_field_names()),
+                    old_field_types,
+                    new_schema
+                        .resolve_type_metadata(new_type_id)
+                        .and_then(|m| m.get_field_names()),
+                    new_field_types,
+                );
+            }
+            (
+                TypeKind::Enum {
+                    variants: old_variants,
+                },
+                TypeKind::Enum {
+                    variants: new_variants,
+                },
+            ) => {
+                self.compare_variants(path, old_type_id, old_variants, new_type_id, new_variants);
+            }
+            (
+                TypeKind::Array {
+                    element_type: old_element_type,
+                },
+                TypeKind::Array {
+                    element_type: new_element_type,
+                },
+            ) => {
+                self.compare_types(
+                    &format!("{}[*]", path),
+                    *old_element_type,
+                    *new_element_type,
+                );
+            }
+            (
+                TypeKind::Map {
+                    key_type: old_key_type,
+                    value_type: old_value_type,
+                },
+                TypeKind::Map {
+                    key_type: new_key_type,
+                    value_type: new_value_type,
+                },
+            ) => {
+                self.compare_types(&format!("{}{{key}}", path), *old_key_type, *new_key_type);
+                self.compare_types(
+                    &format!("{}{{value}}", path),
+                    *old_value_type,
+                    *new_value_type,
+                );
+            }
+            (TypeKind::Custom(old_custom_kind), TypeKind::Custom(new_custom_kind))
+                if old_custom_kind == new_custom_kind => {}
+            (old_kind, new_kind)
+                if !matches!(old_kind, TypeKind::Custom(_))
+                    && core::mem::discriminant(old_kind) == core::mem::discriminant(new_kind) => {}
+            (old_kind, new_kind) => {
+                self.push(
+                    path,
+                    SchemaChangeKind::TypeKindChanged {
+                        old: type_kind_name::<S>(old_kind),
+                        new: type_kind_name::<S>(new_kind),
+                    },
+                );
+                return;
+            }
+        }
+
+        self.compare_validations(path, old_type_id, new_type_id);
+    }
+
+    fn compare_fields(
+        &mut self,
+        path: &str,
+        old_field_names: Option<&[Cow<'static, str>]>,
+        old_field_types: &[LocalTypeId],
+        new_field_names: Option<&[Cow<'static, str>]>,
+        new_field_types: &[LocalTypeId],
+    ) {
+        for (index, (old_field_type, new_field_type)) in old_field_types
+            .iter()
+            .zip(new_field_types.iter())
+            .enumerate()
+        {
+            let old_field_name = old_field_names.and_then(|names| names.get(index));
+            let new_field_name = new_field_names.and_then(|names| names.get(index));
+            if let (Some(old_name), Some(new_name)) = (old_field_name, new_field_name) {
+                if old_name != new_name {
+                    self.push(
+                        path,
+                        SchemaChangeKind::FieldRenamed {
+                            index,
+                            old: old_name.to_string(),
+                            new: new_name.to_string(),
+                        },
+                    );
+                }
+            }
+            let field_path = match old_field_name {
+                Some(name) => format!("{}.{}", path, name),
+                None => format!("{}.{}", path, index),
+            };
+            self.compare_types(&field_path, *old_field_type, *new_field_type);
+        }
+    }
+
+    fn compare_variants(
+        &mut self,
+        path: &str,
+        old_type_id: LocalTypeId,
+        old_variants: &IndexMap<u8, Vec<LocalTypeId>>,
+        new_type_id: LocalTypeId,
+        new_variants: &IndexMap<u8, Vec<LocalTypeId>>,
+    ) {
+        let old_schema = self.old_schema;
+        let new_schema = self.new_schema;
+        for (discriminator, old_field_types) in old_variants {
+            let old_metadata = variant_metadata(old_schema, old_type_id, *discriminator);
+            let old_name = old_metadata.and_then(|m| m.get_name());
+            let Some(new_field_types) = new_variants.get(discriminator) else {
+                self.push(
+                    path,
+                    SchemaChangeKind::EnumVariantRemoved {
+                        discriminator: *discriminator,
+                        name: old_name.map(|name| name.to_string()),
+                    },
+                );
+                continue;
+            };
+            let new_metadata = variant_metadata(new_schema, new_type_id, *discriminator);
+            let new_name = new_metadata.and_then(|m| m.get_name());
+            if let (Some(old_name), Some(new_name)) = (old_name, new_name) {
+                if old_name != new_name {
+                    self.push(
+                        path,
+                        SchemaChangeKind::EnumVariantRenamed {
+                            discriminator: *discriminator,
+                            old: old_name.to_string(),
+                            new: new_name.to_string(),
+                        },
+                    );
+                }
+            }
+            if old_field_types.len() != new_field_types.len() {
+                self.push(
+                    path,
+                    SchemaChangeKind::EnumVariantFieldCountChanged {
+                        discriminator: *discriminator,
+                        old: old_field_types.len(),
+                        new: new_field_types.len(),
+                    },
+                );
+                continue;
+            }
+            let variant_path = match old_name {
+                Some(name) => format!("{}::{}", path, name),
+                None => format!("{}::{}", path, discriminator),
+            };
+            self.compare_fields(
+                &variant_path,
+                old_metadata.and_then(|m| m.get_field_names()),
+                old_field_types,
+                new_metadata.and_then(|m| m.get_field_names()),
+                new_field_types,
+            );
+        }
+        for discriminator in new_variants.keys() {
+            if !old_variants.contains_key(discriminator) {
+                self.push(
+                    path,
+                    SchemaChangeKind::EnumVariantAdded {
+                        discriminator: *discriminator,
+                        name: variant_metadata(new_schema, new_type_id, *discriminator)
+                            .and_then(|m| m.get_name())
+                            .map(|name| name.to_string()),
+                    },
+                );
+            }
+        }
+    }
+
+    fn compare_validations(
+        &mut self,
+        path: &str,
+        old_type_id: LocalTypeId,
+        new_type_id: LocalTypeId,
+    ) {
+        let none = TypeValidation::None;
+        let old_validation = self
+            .old_schema
+            .resolve_type_validation(old_type_id)
+            .unwrap_or(&none);
+        let new_validation = self
+            .new_schema
+            .resolve_type_validation(new_type_id)
+            .unwrap_or(&none);
+        if old_validation == new_validation {
+            return;
+        }
+        let is_relaxed = match (old_validation, new_validation) {
+            (_, TypeValidation::None) => true,
+            (TypeValidation::I8(old), TypeValidation::I8(new)) => numeric_range_includes(new, old),
+            (TypeValidation::I16(old), TypeValidation::I16(new)) => {
+                numeric_range_includes(new, old)
+            }
+            (TypeValidation::I32(old), TypeValidation::I32(new)) => {
+                numeric_range_includes(new, old)
+            }
+            (TypeValidation::I64(old), TypeValidation::I64(new)) => {
+                numeric_range_includes(new, old)
+            }
+            (TypeValidation::I128(old), TypeValidation::I128(new)) => {
+                numeric_range_includes(new, old)
+            }
+            (TypeValidation::U8(old), TypeValidation::U8(new)) => numeric_range_includes(new, old),
+            (TypeValidation::U16(old), TypeValidation::U16(new)) => {
+                numeric_range_includes(new, old)
+            }
+            (TypeValidation::U32(old), TypeValidation::U32(new)) => {
+                numeric_range_includes(new, old)
+            }
+            (TypeValidation::U64(old), TypeValidation::U64(new)) => {
+                numeric_range_includes(new, old)
+            }
+            (TypeValidation::U128(old), TypeValidation::U128(new)) => {
+                numeric_range_includes(new, old)
+            }
+            (TypeValidation::String(old), TypeValidation::String(new))
+            | (TypeValidation::Array(old), TypeValidation::Array(new))
+            | (TypeValidation::Map(old), TypeValidation::Map(new)) => {
+                length_range_includes(new, old)
+            }
+            // Custom validations can't be compared generically, so any change is treated as breaking
+            _ => false,
+        };
+        let old = format!("{:?}", old_validation);
+        let new = format!("{:?}", new_validation);
+        if is_relaxed {
+            self.push(path, SchemaChangeKind::ValidationRelaxed { old, new });
+        } else {
+            self.push(path, SchemaChangeKind::ValidationTightened { old, new });
+        }
+    }
+
+    fn push(&mut self, path: &str, kind: SchemaChangeKind) {
+        self.changes.push(SchemaChange {
+            path: path.to_string(),
+            kind,
+        });
+    }
+}
+
+fn variant_metadata<S: CustomSchema>(
+    schema: &Schema<S>,
+    type_id: LocalTypeId,
+    discriminator: u8,
+) -> Option<&TypeMetadata> {
+    match &schema.resolve_type_metadata(type_id)?.child_names {
+        Some(ChildNames::EnumVariants(variants)) => variants.get(&discriminator),
+        _ => None,
+    }
+}
+
+fn numeric_range_includes<T: Ord>(new: &NumericValidation<T>, old: &NumericValidation<T>) -> bool {
+    let min_included = match (&new.min, &old.min) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(new_min), Some(old_min)) => new_min <= old_min,
+    };
+    let max_included = match (&new.max, &old.max) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(new_max), Some(old_max)) => new_max >= old_max,
+    };
+    min_included && max_included
+}
+
+fn length_range_includes(new: &LengthValidation, old: &LengthValidation) -> bool {
+    new.min.unwrap_or(0) <= old.min.unwrap_or(0)
+        && new.max.unwrap_or(u32::MAX) >= old.max.unwrap_or(u32::MAX)
+}
+
+fn type_kind_name<S: CustomSchema>(type_kind: &SchemaTypeKind<S>) -> String {
+    match type_kind {
+        TypeKind::Any => "Any".to_string(),
+        TypeKind::Bool => "Bool".to_string(),
+        TypeKind::I8 => "I8".to_string(),
+        TypeKind::I16 => "I16".to_string(),
+        TypeKind::I32 => "I32".to_string(),
+        TypeKind::I64 => "I64".to_string(),
+        TypeKind::I128 => "I128".to_string(),
+        TypeKind::U8 => "U8".to_string(),
+        TypeKind::U16 => "U16".to_string(),
+        TypeKind::U32 => "U32".to_string(),
+        TypeKind::U64 => "U64".to_string(),
+        TypeKind::U128 => "U128".to_string(),
+        TypeKind::String => "String".to_string(),
+        TypeKind::Array { .. } => "Array".to_string(),
+        TypeKind::Tuple { .. } => "Tuple".to_string(),
+        TypeKind::Enum { .. } => "Enum".to_string(),
+        TypeKind::Map { .. } => "Map".to_string(),
+        TypeKind::Custom(custom_type_kind) => format!("{:?}", custom_type_kind),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod v1 {
+        use super::*;
+
+        #[derive(Sbor)]
+        pub struct Data {
+            pub amount: u32,
+            pub status: Status,
+            pub tags: Vec<String>,
+        }
+
+        #[derive(Sbor)]
+        pub enum Status {
+            Active,
+            Paused { until: u64 },
+        }
+    }
+
+    mod v2_compatible {
+        use super::*;
+
+        #[derive(Sbor)]
+        #[sbor(type_name = "Data")]
+        pub struct DataRenamedField {
+            pub quantity: u32,
+            pub status: Status,
+            pub tags: Vec<String>,
+        }
+
+        #[derive(Sbor)]
+        pub enum Status {
+            Active,
+            Paused { until: u64 },
+            Closed,
+        }
+    }
+
+    mod v2_incompatible {
+        use super::*;
+
+        #[derive(Sbor)]
+        pub struct Data {
+            pub amount: u64,
+            pub status: Status,
+            pub tags: Vec<String>,
+        }
+
+        #[derive(Sbor)]
+        pub enum Status {
+            Active,
+        }
+    }
+
+    fn compare<Old: Describe<NoCustomTypeKind>, New: Describe<NoCustomTypeKind>>(
+    ) -> SchemaCompatibilityReport {
+        let (old_type_id, old_schema) =
+            generate_full_schema_from_single_type::<Old, NoCustomSchema>();
+        let (new_type_id, new_schema) =
+            generate_full_schema_from_single_type::<New, NoCustomSchema>();
+        check_schema_compatibility(&old_schema, old_type_id, &new_schema, new_type_id)
+    }
+
+    #[test]
+    fn identical_types_are_compatible_without_changes() {
+        let report = compare::<v1::Data, v1::Data>();
+
+        assert!(report.is_compatible());
+        assert_eq!(report.changes, vec![]);
+    }
+
+    #[test]
+    fn renamed_fields_and_added_variants_are_compatible() {
+        let report = compare::<v1::Data, v2_compatible::DataRenamedField>();
+
+        assert!(report.is_compatible());
+        assert_eq!(
+            report.changes,
+            vec![
+                SchemaChange {
+                    path: "Data".to_string(),
+                    kind: SchemaChangeKind::FieldRenamed {
+                        index: 0,
+                        old: "amount".to_string(),
+                        new: "quantity".to_string(),
+                    },
+                },
+                SchemaChange {
+                    path: "Data.status".to_string(),
+                    kind: SchemaChangeKind::EnumVariantAdded {
+                        discriminator: 2,
+                        name: Some("Closed".to_string()),
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn changed_integer_widths_and_removed_variants_are_breaking() {
+        let report = compare::<v1::Data, v2_incompatible::Data>();
+
+        assert!(!report.is_compatible());
+        assert_eq!(
+            report.changes,
+            vec![
+                SchemaChange {
+                    path: "Data.amount".to_string(),
+                    kind: SchemaChangeKind::TypeKindChanged {
+                        old: "U32".to_string(),
+                        new: "U64".to_string(),
+                    },
+                },
+                SchemaChange {
+                    path: "Data.status".to_string(),
+                    kind: SchemaChangeKind::EnumVariantRemoved {
+                        discriminator: 1,
+                        name: Some("Paused".to_string()),
+                    },
+                },
+            ]
+        );
+        assert_eq!(
+            report.to_string(),
+            "[BREAKING] Data.amount: type kind changed from U32 to U64\n\
+            [BREAKING] Data.status: variant Paused (1) was removed"
+        );
+    }
+
+    #[test]
+    fn added_fields_are_breaking() {
+        let report = compare::<(u8, String), (u8, String, bool)>();
+
+        assert!(!report.is_compatible());
+        assert_eq!(
+            report.changes,
+            vec![SchemaChange {
+                path: "<root>".to_string(),
+                kind: SchemaChangeKind::TupleFieldCountChanged { old: 2, new: 3 },
+            }]
+        );
+    }
+
+    #[test]
+    fn recursive_types_are_compared() {
+        #[derive(Sbor)]
+        pub struct Node {
+            pub children: Vec<Node>,
+        }
+
+        let report = compare::<Node, Node>();
+
+        assert!(report.is_compatible());
+    }
+
+    #[test]
+    fn tightened_validations_are_breaking() {
+        let old_schema: VersionedSchema<NoCustomSchema> = Schema {
+            type_kinds: vec![TypeKind::U8],
+            type_metadata: vec![TypeMetadata::unnamed()],
+            type_validations: vec![TypeValidation::U8(NumericValidation {
+                min: Some(1),
+                max: Some(10),
+            })],
+        }
+        .into();
+        let mut relaxed_schema = old_schema.clone();
+        relaxed_schema.v1_mut().type_validations[0] = TypeValidation::U8(NumericValidation {
+            min: None,
+            max: Some(20),
+        });
+        let mut tightened_schema = old_schema.clone();
+        tightened_schema.v1_mut().type_validations[0] = TypeValidation::U8(NumericValidation {
+            min: Some(2),
+            max: Some(10),
+        });
+        let type_id = LocalTypeId::SchemaLocalIndex(0);
+
+        let relaxed_report =
+            check_schema_compatibility(&old_schema, type_id, &relaxed_schema, type_id);
+        let tightened_report =
+            check_schema_compatibility(&old_schema, type_id, &tightened_schema, type_id);
+
+        assert!(relaxed_report.is_compatible());
+        assert!(!tightened_report.is_compatible());
+    }
+}
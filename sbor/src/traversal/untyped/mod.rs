@@ -1,5 +1,7 @@
 mod events;
+mod streaming_traverser;
 mod traverser;
 
 pub use events::*;
+pub use streaming_traverser::*;
 pub use traverser::*;
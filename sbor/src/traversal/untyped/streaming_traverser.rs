@@ -0,0 +1,779 @@
+use super::*;
+use crate::decoder::*;
+use crate::rust::prelude::*;
+use crate::value_kind::*;
+use crate::*;
+
+/// The `StreamingTraverser` is an incremental variant of the [`VecTraverser`], for payloads
+/// which arrive in pieces or are too large to hold in memory at once.
+///
+/// Bytes are provided with [`push_bytes`](Self::push_bytes), and [`next_event`](Self::next_event)
+/// returns `None` until enough of the payload has been pushed to output the next event. Once the
+/// whole payload has been pushed, [`finish_input`](Self::finish_input) must be called, so that
+/// a truncated payload can be reported as an error rather than waiting for more input.
+///
+/// The events are as output by the [`VecTraverser`] for the same payload, except that a byte
+/// array may be output as several [`TerminalValueBatch`](TraversalEvent::TerminalValueBatch)
+/// events, as its bytes arrive.
+///
+/// Consumed bytes are released as more bytes are pushed, so only the terminal value or container
+/// header currently being read needs to be buffered. This is bounded by `max_buffer_size`: if a
+/// single string or custom value doesn't fit, the traverser reports
+/// [`NextEventStatus::BufferLimitExceeded`].
+///
+/// The caller is responsible for stopping calling `next_event` after an Error or End event.
+pub struct StreamingTraverser<C: CustomTraversal> {
+    max_depth: usize,
+    check_exact_end: bool,
+    max_buffer_size: usize,
+    buffer: Vec<u8>,
+    /// The offset in the payload of the start of `buffer`
+    buffer_offset: usize,
+    /// The index in `buffer` of the first byte not yet consumed
+    position: usize,
+    input_complete: bool,
+    container_stack: Vec<ContainerState<C>>,
+    next_event_override: NextEventOverride<C::CustomValueKind>,
+    prepared_event: Option<PreparedEvent<C>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NextEventStatus {
+    /// The next call to `next_event` will return an event.
+    Ready,
+    /// More bytes need to be pushed before the next event can be output.
+    NeedsMoreInput,
+    /// The next value doesn't fit within the maximum buffer size.
+    BufferLimitExceeded { limit: usize },
+}
+
+/// The next event, as determined from the buffer before it's output.
+#[derive(Debug, Clone)]
+enum PreparedEvent<C: CustomTraversal> {
+    ContainerStart {
+        start_offset: usize,
+        header: ContainerHeader<C>,
+        length: usize,
+    },
+    TerminalValue {
+        start_offset: usize,
+        value_kind: ValueKind<C::CustomValueKind>,
+        body_start: usize,
+        length: usize,
+    },
+    TerminalValueBatch {
+        length: usize,
+    },
+    ContainerEnd,
+    End,
+    Error {
+        start_offset: usize,
+        error: DecodeError,
+    },
+}
+
+impl<C: CustomTraversal> StreamingTraverser<C> {
+    pub fn new(
+        max_depth: usize,
+        expected_start: ExpectedStart<C::CustomValueKind>,
+        check_exact_end: bool,
+        max_buffer_size: usize,
+    ) -> Self {
+        Self {
+            max_depth,
+            check_exact_end,
+            max_buffer_size,
+            buffer: Vec::new(),
+            buffer_offset: 0,
+            position: 0,
+            input_complete: false,
+            container_stack: Vec::with_capacity(max_depth),
+            next_event_override: match expected_start {
+                ExpectedStart::PayloadPrefix(prefix) => NextEventOverride::ReadPrefix(prefix),
+                ExpectedStart::Value => NextEventOverride::ReadRootValue,
+                ExpectedStart::ValueBody(value_kind) => {
+                    NextEventOverride::ReadRootValueWithValueKind(value_kind)
+                }
+            },
+            prepared_event: None,
+        }
+    }
+
+    /// Appends the next bytes of the payload.
+    pub fn push_bytes(&mut self, bytes: &[u8]) {
+        // The consumed bytes are only dropped once they're at least half of the buffer, so that
+        // the unconsumed bytes aren't moved on every push.
+        if self.position > 0 && self.position >= self.buffered_len() {
+            self.buffer.drain(..self.position);
+            self.buffer_offset += self.position;
+            self.position = 0;
+        }
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Marks that the whole payload has been pushed.
+    pub fn finish_input(&mut self) {
+        self.input_complete = true;
+    }
+
+    /// The number of bytes which have been pushed but not yet consumed.
+    pub fn buffered_len(&self) -> usize {
+        self.buffer.len() - self.position
+    }
+
+    /// The offset in the payload of the first byte not yet consumed.
+    pub fn get_offset(&self) -> usize {
+        self.buffer_offset + self.position
+    }
+
+    /// Checks whether the next event can be output from the bytes pushed so far.
+    pub fn poll_next_event(&mut self) -> NextEventStatus {
+        if self.prepared_event.is_none() {
+            match self.prepare_next_event() {
+                Ok(prepared_event) => self.prepared_event = Some(prepared_event),
+                Err(status) => return status,
+            }
+        }
+        NextEventStatus::Ready
+    }
+
+    /// Returns the next event, or `None` if more bytes need to be pushed first.
+    pub fn next_event<'t>(&'t mut self) -> Option<LocatedTraversalEvent<'t, 't, C>> {
+        if self.poll_next_event() != NextEventStatus::Ready {
+            return None;
+        }
+        let event = match self.prepared_event.take().unwrap() {
+            PreparedEvent::ContainerStart {
+                start_offset,
+                header,
+                length,
+            } => {
+                self.consume_value(length);
+                if let ContainerHeader::Array(ArrayHeader {
+                    element_value_kind: ValueKind::U8,
+                    length,
+                }) = header
+                {
+                    if length > 0 {
+                        self.next_event_override = NextEventOverride::ReadBytes(length);
+                    }
+                }
+                self.enter_container(start_offset, header)
+            }
+            PreparedEvent::TerminalValue {
+                start_offset,
+                value_kind,
+                body_start,
+                length,
+            } => {
+                let body_index = self.position + body_start;
+                let value_end = self.position + length;
+                self.consume_value(length);
+                let mut decoder =
+                    VecDecoder::new(&self.buffer[body_index..value_end], self.max_depth);
+                let event = match decode_terminal_value_body::<C>(&mut decoder, value_kind) {
+                    Ok(value) => TraversalEvent::TerminalValue(value),
+                    Err(error) => TraversalEvent::DecodeError(error),
+                };
+                LocatedTraversalEvent {
+                    event,
+                    location: Location {
+                        start_offset,
+                        end_offset: self.buffer_offset + self.position,
+                        ancestor_path: &self.container_stack,
+                    },
+                }
+            }
+            PreparedEvent::TerminalValueBatch { length } => {
+                let start_index = self.position;
+                let start_offset = self.get_offset();
+                self.position += length;
+                let parent = self.container_stack.last_mut().unwrap();
+                parent.advance_current_child_index_by(length);
+                self.next_event_override = match self.next_event_override {
+                    NextEventOverride::ReadBytes(size) if size > length => {
+                        NextEventOverride::ReadBytes(size - length)
+                    }
+                    _ => NextEventOverride::None,
+                };
+                LocatedTraversalEvent {
+                    event: TraversalEvent::TerminalValueBatch(TerminalValueBatchRef::U8(
+                        &self.buffer[start_index..self.position],
+                    )),
+                    location: Location {
+                        start_offset,
+                        end_offset: self.buffer_offset + self.position,
+                        ancestor_path: &self.container_stack,
+                    },
+                }
+            }
+            PreparedEvent::ContainerEnd => {
+                let container = self.container_stack.pop().unwrap();
+                LocatedTraversalEvent {
+                    event: TraversalEvent::ContainerEnd(container.container_header),
+                    location: Location {
+                        start_offset: container.container_start_offset,
+                        end_offset: self.get_offset(),
+                        ancestor_path: &self.container_stack,
+                    },
+                }
+            }
+            PreparedEvent::End => {
+                let offset = self.get_offset();
+                LocatedTraversalEvent {
+                    event: TraversalEvent::End,
+                    location: Location {
+                        start_offset: offset,
+                        end_offset: offset,
+                        ancestor_path: &self.container_stack,
+                    },
+                }
+            }
+            PreparedEvent::Error {
+                start_offset,
+                error,
+            } => LocatedTraversalEvent {
+                event: TraversalEvent::DecodeError(error),
+                location: Location {
+                    start_offset,
+                    end_offset: self.get_offset(),
+                    ancestor_path: &self.container_stack,
+                },
+            },
+        };
+        Some(event)
+    }
+
+    /// Consumes the bytes of the next value (or container header), and records that it has been
+    /// read by the parent container.
+    fn consume_value(&mut self, length: usize) {
+        self.position += length;
+        match self.next_event_override {
+            NextEventOverride::ReadPrefix(_)
+            | NextEventOverride::ReadRootValue
+            | NextEventOverride::ReadRootValueWithValueKind(_) => {
+                self.next_event_override = NextEventOverride::None;
+            }
+            NextEventOverride::ReadBytes(_) | NextEventOverride::None => {
+                self.container_stack
+                    .last_mut()
+                    .unwrap()
+                    .advance_current_child_index();
+            }
+        }
+    }
+
+    fn enter_container<'t>(
+        &'t mut self,
+        start_offset: usize,
+        container_header: ContainerHeader<C>,
+    ) -> LocatedTraversalEvent<'t, 't, C> {
+        let child_count = container_header.get_child_count();
+
+        self.container_stack.push(ContainerState {
+            container_header,
+            container_start_offset: start_offset,
+            container_child_count: child_count,
+            current_child_index: None,
+        });
+
+        // Check depth: either container stack overflows or children of this container will overflow.
+        if self.container_stack.len() > self.max_depth
+            || self.container_stack.len() == self.max_depth && child_count > 0
+        {
+            return LocatedTraversalEvent {
+                event: TraversalEvent::DecodeError(DecodeError::MaxDepthExceeded(self.max_depth)),
+                location: Location {
+                    start_offset,
+                    end_offset: self.get_offset(),
+                    ancestor_path: &self.container_stack,
+                },
+            };
+        }
+
+        LocatedTraversalEvent {
+            event: TraversalEvent::ContainerStart(container_header),
+            location: Location {
+                start_offset,
+                end_offset: self.get_offset(),
+                ancestor_path: &self.container_stack[0..self.container_stack.len() - 1],
+            },
+        }
+    }
+
+    fn prepare_next_event(&self) -> Result<PreparedEvent<C>, NextEventStatus> {
+        let (expected_prefix, value_kind) = match self.next_event_override {
+            NextEventOverride::ReadPrefix(prefix) => (Some(prefix), None),
+            NextEventOverride::ReadRootValue => (None, None),
+            NextEventOverride::ReadRootValueWithValueKind(value_kind) => (None, Some(value_kind)),
+            NextEventOverride::ReadBytes(size) => {
+                return match self.buffered_len() {
+                    0 if self.input_complete => Ok(PreparedEvent::Error {
+                        start_offset: self.get_offset(),
+                        error: DecodeError::BufferUnderflow {
+                            required: size,
+                            remaining: 0,
+                        },
+                    }),
+                    0 => Err(NextEventStatus::NeedsMoreInput),
+                    buffered_len => Ok(PreparedEvent::TerminalValueBatch {
+                        length: buffered_len.min(size),
+                    }),
+                };
+            }
+            NextEventOverride::None => match self.container_stack.last() {
+                Some(parent) if parent.is_complete() => return Ok(PreparedEvent::ContainerEnd),
+                Some(parent) => {
+                    let child_index = parent.current_child_index.map_or(0, |index| index + 1);
+                    let value_kind = parent
+                        .container_header
+                        .get_implicit_child_value_kind(child_index);
+                    (None, value_kind)
+                }
+                None => return self.prepare_end(),
+            },
+        };
+
+        let mut decoder = VecDecoder::new(&self.buffer[self.position..], self.max_depth);
+        match read_value_header::<C>(&mut decoder, expected_prefix, value_kind) {
+            Ok((value_start, value_header)) => {
+                let start_offset = self.get_offset() + value_start;
+                let length = decoder.get_offset();
+                Ok(match value_header {
+                    ValueHeader::Container(header) => PreparedEvent::ContainerStart {
+                        start_offset,
+                        header,
+                        length,
+                    },
+                    ValueHeader::Terminal {
+                        value_kind,
+                        body_start,
+                    } => PreparedEvent::TerminalValue {
+                        start_offset,
+                        value_kind,
+                        body_start,
+                        length,
+                    },
+                })
+            }
+            Err(DecodeError::BufferUnderflow { .. }) if !self.input_complete => {
+                Err(self.insufficient_input_status())
+            }
+            Err(error) => Ok(PreparedEvent::Error {
+                start_offset: self.get_offset() + decoder.get_offset(),
+                error,
+            }),
+        }
+    }
+
+    fn prepare_end(&self) -> Result<PreparedEvent<C>, NextEventStatus> {
+        if !self.check_exact_end {
+            return Ok(PreparedEvent::End);
+        }
+        match self.buffered_len() {
+            0 if self.input_complete => Ok(PreparedEvent::End),
+            0 => Err(NextEventStatus::NeedsMoreInput),
+            buffered_len => Ok(PreparedEvent::Error {
+                start_offset: self.get_offset(),
+                error: DecodeError::ExtraTrailingBytes(buffered_len),
+            }),
+        }
+    }
+
+    fn insufficient_input_status(&self) -> NextEventStatus {
+        if self.buffered_len() >= self.max_buffer_size {
+            NextEventStatus::BufferLimitExceeded {
+                limit: self.max_buffer_size,
+            }
+        } else {
+            NextEventStatus::NeedsMoreInput
+        }
+    }
+}
+
+enum ValueHeader<C: CustomTraversal> {
+    Container(ContainerHeader<C>),
+    Terminal {
+        value_kind: ValueKind<C::CustomValueKind>,
+        body_start: usize,
+    },
+}
+
+/// Reads the value kind and container header of the next value, or the whole body if it's a
+/// terminal value. Returns the offset of the start of the value, after any payload prefix.
+fn read_value_header<'de, C: CustomTraversal>(
+    decoder: &mut VecDecoder<'de, C::CustomValueKind>,
+    expected_prefix: Option<u8>,
+    value_kind: Option<ValueKind<C::CustomValueKind>>,
+) -> Result<(usize, ValueHeader<C>), DecodeError> {
+    if let Some(expected_prefix) = expected_prefix {
+        decoder.read_and_check_payload_prefix(expected_prefix)?;
+    }
+    let value_start = decoder.get_offset();
+    let value_kind = match value_kind {
+        Some(value_kind) => value_kind,
+        None => decoder.read_value_kind()?,
+    };
+    let value_header = match value_kind {
+        ValueKind::Array => {
+            let element_value_kind = decoder.read_value_kind()?;
+            let length = decoder.read_size()?;
+            ValueHeader::Container(ContainerHeader::Array(ArrayHeader {
+                element_value_kind,
+                length,
+            }))
+        }
+        ValueKind::Map => {
+            let key_value_kind = decoder.read_value_kind()?;
+            let value_value_kind = decoder.read_value_kind()?;
+            let length = decoder.read_size()?;
+            ValueHeader::Container(ContainerHeader::Map(MapHeader {
+                key_value_kind,
+                value_value_kind,
+                length,
+            }))
+        }
+        ValueKind::Enum => {
+            let variant = decoder.read_byte()?;
+            let length = decoder.read_size()?;
+            ValueHeader::Container(ContainerHeader::EnumVariant(EnumVariantHeader {
+                variant,
+                length,
+            }))
+        }
+        ValueKind::Tuple => {
+            let length = decoder.read_size()?;
+            ValueHeader::Container(ContainerHeader::Tuple(TupleHeader { length }))
+        }
+        _ => {
+            let body_start = decoder.get_offset();
+            decode_terminal_value_body::<C>(decoder, value_kind)?;
+            ValueHeader::Terminal {
+                value_kind,
+                body_start,
+            }
+        }
+    };
+    Ok((value_start, value_header))
+}
+
+fn decode_terminal_value_body<'de, C: CustomTraversal>(
+    decoder: &mut VecDecoder<'de, C::CustomValueKind>,
+    value_kind: ValueKind<C::CustomValueKind>,
+) -> Result<TerminalValueRef<'de, C>, DecodeError> {
+    Ok(match value_kind {
+        ValueKind::Bool => {
+            TerminalValueRef::Bool(bool::decode_body_with_value_kind(decoder, value_kind)?)
+        }
+        ValueKind::I8 => {
+            TerminalValueRef::I8(i8::decode_body_with_value_kind(decoder, value_kind)?)
+        }
+        ValueKind::I16 => {
+            TerminalValueRef::I16(i16::decode_body_with_value_kind(decoder, value_kind)?)
+        }
+        ValueKind::I32 => {
+            TerminalValueRef::I32(i32::decode_body_with_value_kind(decoder, value_kind)?)
+        }
+        ValueKind::I64 => {
+            TerminalValueRef::I64(i64::decode_body_with_value_kind(decoder, value_kind)?)
+        }
+        ValueKind::I128 => {
+            TerminalValueRef::I128(i128::decode_body_with_value_kind(decoder, value_kind)?)
+        }
+        ValueKind::U8 => {
+            TerminalValueRef::U8(u8::decode_body_with_value_kind(decoder, value_kind)?)
+        }
+        ValueKind::U16 => {
+            TerminalValueRef::U16(u16::decode_body_with_value_kind(decoder, value_kind)?)
+        }
+        ValueKind::U32 => {
+            TerminalValueRef::U32(u32::decode_body_with_value_kind(decoder, value_kind)?)
+        }
+        ValueKind::U64 => {
+            TerminalValueRef::U64(u64::decode_body_with_value_kind(decoder, value_kind)?)
+        }
+        ValueKind::U128 => {
+            TerminalValueRef::U128(u128::decode_body_with_value_kind(decoder, value_kind)?)
+        }
+        ValueKind::String => {
+            let size = decoder.read_size()?;
+            let bytes = decoder.read_slice_from_payload(size)?;
+            TerminalValueRef::String(
+                sbor::rust::str::from_utf8(bytes).map_err(|_| DecodeError::InvalidUtf8)?,
+            )
+        }
+        ValueKind::Custom(custom_value_kind) => {
+            TerminalValueRef::Custom(C::decode_custom_value_body(custom_value_kind, decoder)?)
+        }
+        ValueKind::Array | ValueKind::Map | ValueKind::Enum | ValueKind::Tuple => {
+            unreachable!("Containers are not terminal values")
+        }
+    })
+}
+
+#[cfg(feature = "std")]
+pub use reader::*;
+
+#[cfg(feature = "std")]
+mod reader {
+    use super::*;
+    use std::io::{ErrorKind, Read};
+
+    pub const DEFAULT_READ_CHUNK_SIZE: usize = 64 * 1024;
+
+    #[derive(Debug)]
+    pub enum ReaderTraversalError {
+        IoError(std::io::Error),
+        BufferLimitExceeded { limit: usize },
+    }
+
+    /// Traverses a payload from a [`Read`], reading it in chunks as the events are consumed.
+    ///
+    /// See [`StreamingTraverser`] for details of the events and memory usage.
+    pub struct ReaderTraverser<R: Read, C: CustomTraversal> {
+        reader: R,
+        traverser: StreamingTraverser<C>,
+        read_chunk: Vec<u8>,
+    }
+
+    impl<R: Read, C: CustomTraversal> ReaderTraverser<R, C> {
+        pub fn new(reader: R, traverser: StreamingTraverser<C>, read_chunk_size: usize) -> Self {
+            Self {
+                reader,
+                traverser,
+                read_chunk: vec![0; read_chunk_size],
+            }
+        }
+
+        pub fn next_event<'t>(
+            &'t mut self,
+        ) -> Result<LocatedTraversalEvent<'t, 't, C>, ReaderTraversalError> {
+            loop {
+                match self.traverser.poll_next_event() {
+                    NextEventStatus::Ready => break,
+                    NextEventStatus::NeedsMoreInput => self.read_chunk()?,
+                    NextEventStatus::BufferLimitExceeded { limit } => {
+                        return Err(ReaderTraversalError::BufferLimitExceeded { limit })
+                    }
+                }
+            }
+            Ok(self.traverser.next_event().unwrap())
+        }
+
+        pub fn into_reader(self) -> R {
+            self.reader
+        }
+
+        fn read_chunk(&mut self) -> Result<(), ReaderTraversalError> {
+            loop {
+                match self.reader.read(&mut self.read_chunk) {
+                    Ok(0) => {
+                        self.traverser.finish_input();
+                        return Ok(());
+                    }
+                    Ok(n) => {
+                        self.traverser.push_bytes(&self.read_chunk[..n]);
+                        return Ok(());
+                    }
+                    Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                    Err(error) => return Err(ReaderTraversalError::IoError(error)),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rust::prelude::*;
+
+    fn vec_traverser_events(payload: &[u8]) -> Vec<String> {
+        let mut traverser = basic_payload_traverser(payload);
+        let mut events = vec![];
+        loop {
+            let event = traverser.next_event();
+            let is_end = matches!(
+                event.event,
+                TraversalEvent::End | TraversalEvent::DecodeError(_)
+            );
+            events.push(format!("{:?}", event));
+            if is_end {
+                return events;
+            }
+        }
+    }
+
+    fn basic_streaming_traverser(max_buffer_size: usize) -> StreamingTraverser<NoCustomTraversal> {
+        StreamingTraverser::new(
+            BASIC_SBOR_V1_MAX_DEPTH,
+            ExpectedStart::PayloadPrefix(BASIC_SBOR_V1_PAYLOAD_PREFIX),
+            true,
+            max_buffer_size,
+        )
+    }
+
+    fn example_payload() -> Vec<u8> {
+        basic_encode(&(
+            2u8,
+            vec![3u8, 7u8, 9u8],
+            (3u32, indexmap!(16u8 => "hello".to_string())),
+            Some(-4i64),
+            Vec::<u8>::new(),
+            vec![vec![(true, 1u128)]],
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    pub fn events_match_vec_traverser_when_given_whole_payload() {
+        let payload = example_payload();
+        let mut traverser = basic_streaming_traverser(1024);
+        traverser.push_bytes(&payload);
+        traverser.finish_input();
+
+        let mut events = vec![];
+        loop {
+            let event = traverser.next_event().unwrap();
+            let is_end = matches!(
+                event.event,
+                TraversalEvent::End | TraversalEvent::DecodeError(_)
+            );
+            events.push(format!("{:?}", event));
+            if is_end {
+                break;
+            }
+        }
+
+        assert_eq!(events, vec_traverser_events(&payload));
+    }
+
+    #[test]
+    pub fn events_are_output_as_bytes_arrive() {
+        let payload = example_payload();
+        let mut traverser = basic_streaming_traverser(16);
+        let mut bytes = payload.iter();
+
+        let mut events = vec![];
+        let mut byte_batches = vec![];
+        loop {
+            let Some(event) = traverser.next_event() else {
+                match bytes.next() {
+                    Some(byte) => traverser.push_bytes(&[*byte]),
+                    None => traverser.finish_input(),
+                }
+                continue;
+            };
+            match event.event {
+                TraversalEvent::TerminalValueBatch(TerminalValueBatchRef::U8(batch)) => {
+                    byte_batches.push(batch.to_vec());
+                }
+                TraversalEvent::End => break,
+                TraversalEvent::DecodeError(error) => panic!("Unexpected error: {:?}", error),
+                _ => events.push(format!("{:?}", event.event)),
+            }
+            assert!(traverser.buffered_len() <= 16);
+        }
+
+        let expected_events = {
+            let mut traverser = basic_payload_traverser(&payload);
+            let mut expected_events = vec![];
+            loop {
+                let event = traverser.next_event();
+                match event.event {
+                    TraversalEvent::TerminalValueBatch(_) => {}
+                    TraversalEvent::End => break,
+                    _ => expected_events.push(format!("{:?}", event.event)),
+                }
+            }
+            expected_events
+        };
+        assert_eq!(events, expected_events);
+        assert_eq!(byte_batches, vec![vec![3u8], vec![7u8], vec![9u8]]);
+    }
+
+    #[test]
+    pub fn consumed_bytes_are_released_without_affecting_offsets() {
+        let payload = basic_encode(&(vec![5u8; 1000], "end".to_string())).unwrap();
+        let mut traverser = basic_streaming_traverser(16);
+        let mut chunks = payload.chunks(10);
+
+        let mut events = vec![];
+        loop {
+            let Some(event) = traverser.next_event() else {
+                match chunks.next() {
+                    Some(chunk) => traverser.push_bytes(chunk),
+                    None => traverser.finish_input(),
+                }
+                assert!(traverser.buffer.len() <= 2 * 16 + 10);
+                continue;
+            };
+            let is_end = matches!(event.event, TraversalEvent::End);
+            if !matches!(event.event, TraversalEvent::TerminalValueBatch(_)) {
+                events.push(format!("{:?}", event));
+            }
+            if is_end {
+                break;
+            }
+        }
+
+        let expected_events: Vec<_> = vec_traverser_events(&payload)
+            .into_iter()
+            .filter(|event| !event.contains("TerminalValueBatch"))
+            .collect();
+        assert_eq!(events, expected_events);
+    }
+
+    #[test]
+    pub fn truncated_payload_is_reported_once_input_is_finished() {
+        let payload = example_payload();
+        let mut traverser = basic_streaming_traverser(1024);
+        traverser.push_bytes(&payload[..payload.len() - 1]);
+
+        loop {
+            match traverser.next_event() {
+                Some(event) => assert!(!event.event.is_error()),
+                None => break,
+            }
+        }
+        traverser.finish_input();
+
+        assert!(matches!(
+            traverser.next_event().unwrap().event,
+            TraversalEvent::DecodeError(DecodeError::BufferUnderflow { .. })
+        ));
+    }
+
+    #[test]
+    pub fn values_larger_than_the_buffer_limit_are_rejected() {
+        let payload = basic_encode(&"a long string which doesn't fit".to_string()).unwrap();
+        let mut traverser = basic_streaming_traverser(8);
+        traverser.push_bytes(&payload[..8]);
+
+        assert_eq!(
+            traverser.poll_next_event(),
+            NextEventStatus::BufferLimitExceeded { limit: 8 }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    pub fn reader_traverser_reads_payload_in_chunks() {
+        let payload = example_payload();
+        let mut traverser =
+            ReaderTraverser::new(payload.as_slice(), basic_streaming_traverser(64), 3);
+
+        let mut events = vec![];
+        loop {
+            let event = traverser.next_event().unwrap();
+            match event.event {
+                TraversalEvent::TerminalValueBatch(_) => {}
+                TraversalEvent::End => break,
+                _ => events.push(format!("{:?}", event.event)),
+            }
+        }
+
+        assert!(events.len() > 10);
+        assert!(!events.iter().any(|event| event.contains("DecodeError")));
+    }
+}
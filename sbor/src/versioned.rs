@@ -3,6 +3,17 @@ pub enum UpdateResult<T> {
     AtLatest(T),
 }
 
+/// Performs the updates of a versioned type which are declared with `migrates_to` in
+/// [`crate::define_versioned`], rather than being `From` conversions. This allows an update
+/// to fail, or to make use of some context, eg a lookup of other state.
+pub trait VersionMigrator<V> {
+    type Error;
+
+    /// Migrates a value at a version declared with `migrates_to` to a later version, which
+    /// should be the version it migrates to.
+    fn migrate(&mut self, value: V) -> Result<V, Self::Error>;
+}
+
 /// A marker trait to indicate that the type is versioned.
 /// This can be used for type bounds for requiring that types are versioned.
 pub trait HasLatestVersion {
@@ -57,12 +68,22 @@ macro_rules! define_single_versioned {
 /// This is useful for creating an SBOR data model which can be updated in future.
 /// In future, enum variants can be added, and automatically mapped to.
 ///
-/// NOTE: A circular version update chain will be an infinite loop at runtime. Be careful.
+/// Each previous version either `updates_to` a later version with a `From` conversion, or
+/// `migrates_to` it with a [`VersionMigrator`], when the update can fail or needs some context.
+///
+/// The `try_*_with` updates are created for every versioned type. The infallible updates,
+/// including the [`HasLatestVersion`] implementation, are only created if every previous version
+/// `updates_to` a later one, so the values of a type with migrations can't be updated without
+/// a migrator.
+///
+/// Each version must update to a later version, which is checked at compile time.
 ///
 /// In the future, this may become a programmatic macro to support better error handling /
 /// edge case detection, and opting into more explicit SBOR handling.
 #[macro_export]
 macro_rules! define_versioned {
+    // If every previous version updates with a `From` conversion, the infallible updates are
+    // also created.
     (
         $(#[$attributes:meta])*
         $vis:vis enum $name:ident
@@ -82,6 +103,68 @@ macro_rules! define_versioned {
             }
             $(,)? // Optional trailing comma
         }
+    ) => {
+        $crate::define_versioned!(
+            @versioned [infallible_updates]
+            $(#[$attributes])*
+            $vis enum $name
+            $(< $( $lt $( : $clt $(+ $dlt )* )? $( = $deflt)? ),+ >)?
+            {
+                previous_versions: [
+                    $($($version_num => $version_type: { updates_to: $update_to_version_num },)*)?
+                ],
+                latest_version: {
+                    $latest_version => $latest_version_alias = $latest_version_type
+                },
+            }
+        );
+    };
+    (
+        $(#[$attributes:meta])*
+        $vis:vis enum $name:ident
+        $(< $( $lt:tt $( : $clt:tt $(+ $dlt:tt )* )? $( = $deflt:tt)? ),+ >)?
+        {
+            $(
+                previous_versions: [
+                    $($version_num:expr => $version_type:ty: { $update_kind:ident: $update_to_version_num:expr }),*
+                    $(,)? // Optional trailing comma
+                ],
+            )?
+            latest_version: {
+                $latest_version:expr => $latest_version_alias:ty = $latest_version_type:ty
+                $(,)? // Optional trailing comma
+            }
+            $(,)? // Optional trailing comma
+        }
+    ) => {
+        $crate::define_versioned!(
+            @versioned []
+            $(#[$attributes])*
+            $vis enum $name
+            $(< $( $lt $( : $clt $(+ $dlt )* )? $( = $deflt)? ),+ >)?
+            {
+                previous_versions: [
+                    $($($version_num => $version_type: { $update_kind: $update_to_version_num },)*)?
+                ],
+                latest_version: {
+                    $latest_version => $latest_version_alias = $latest_version_type
+                },
+            }
+        );
+    };
+    (
+        @versioned [$($infallible_updates:ident)?]
+        $(#[$attributes:meta])*
+        $vis:vis enum $name:ident
+        $(< $( $lt:tt $( : $clt:tt $(+ $dlt:tt )* )? $( = $deflt:tt)? ),+ >)?
+        {
+            previous_versions: [
+                $($version_num:expr => $version_type:ty: { $update_kind:ident: $update_to_version_num:expr },)*
+            ],
+            latest_version: {
+                $latest_version:expr => $latest_version_alias:ty = $latest_version_type:ty
+            },
+        }
     ) => {
         $crate::paste::paste! {
             // Create inline sub-macros to handle the type generics nested inside
@@ -101,6 +184,27 @@ macro_rules! define_versioned {
                 };
             }
 
+            // Creates the update of a single previous version, depending on how it's declared
+            #[allow(unused_macros)]
+            macro_rules! [<$name _update_step>] {
+                (updates_to, $value:ident, $from:path, $to:path, $migrator:ident) => {
+                    Ok($to($value.into()))
+                };
+                (migrates_to, $value:ident, $from:path, $to:path, $migrator:ident) => {
+                    $crate::VersionMigrator::migrate($migrator, $from($value))
+                };
+            }
+
+            $(
+                const _: () = assert!(
+                    $update_to_version_num > $version_num,
+                    concat!(
+                        "Version ", stringify!($version_num), " of ", stringify!($name),
+                        " must update to a later version",
+                    ),
+                );
+            )*
+
             #[allow(dead_code)]
             $vis type $latest_version_alias = $latest_version_type;
 
@@ -110,9 +214,9 @@ macro_rules! define_versioned {
             #[repr(u8)]
             $vis enum $name $(< $( $lt $( : $clt $(+ $dlt )* )? $( = $deflt)? ),+ >)?
             {
-                $($(
+                $(
                     [<V $version_num>]($version_type) = $version_num,
-                )*)?
+                )*
                 [<V $latest_version>]($latest_version_type) = $latest_version,
             }
 
@@ -126,60 +230,146 @@ macro_rules! define_versioned {
                     Self::[<V $latest_version>](value)
                 }
 
-                pub fn update_once(self) -> $crate::UpdateResult<Self> {
+                pub fn version_num(&self) -> u8 {
+                    match self {
+                    $(
+                        Self::[<V $version_num>](_) => $version_num,
+                    )*
+                        Self::[<V $latest_version>](_) => $latest_version,
+                    }
+                }
+
+                // The migrator is unused if there are no migrations
+                #[allow(unused_variables)]
+                pub fn try_update_once_with<M: $crate::VersionMigrator<Self>>(
+                    self,
+                    migrator: &mut M,
+                ) -> Result<$crate::UpdateResult<Self>, M::Error> {
                     match self {
-                    $($(
-                        Self::[<V $version_num>](value) => $crate::UpdateResult::Updated(Self::[<V $update_to_version_num>](value.into())),
-                    )*)?
-                        Self::[<V $latest_version>](value) => $crate::UpdateResult::AtLatest(Self::[<V $latest_version>](value)),
+                    $(
+                        Self::[<V $version_num>](value) => {
+                            let updated: Result<Self, M::Error> = [<$name _update_step>]!(
+                                $update_kind,
+                                value,
+                                Self::[<V $version_num>],
+                                Self::[<V $update_to_version_num>],
+                                migrator
+                            );
+                            Ok($crate::UpdateResult::Updated(updated?))
+                        }
+                    )*
+                        Self::[<V $latest_version>](value) => Ok($crate::UpdateResult::AtLatest(Self::[<V $latest_version>](value))),
                     }
                 }
 
-                pub fn update_to_latest(mut self) -> Self {
+                pub fn try_update_to_latest_with<M: $crate::VersionMigrator<Self>>(
+                    mut self,
+                    migrator: &mut M,
+                ) -> Result<Self, M::Error> {
                     loop {
-                        match self.update_once() {
+                        let version_num = self.version_num();
+                        match self.try_update_once_with(migrator)? {
                             $crate::UpdateResult::Updated(new) => {
+                                // A migrator could return any version, so we check that the
+                                // updates can't loop forever
+                                if new.version_num() <= version_num {
+                                    panic!(
+                                        "{} version {} was updated to version {}, which isn't a later version",
+                                        stringify!($name),
+                                        version_num,
+                                        new.version_num(),
+                                    );
+                                }
                                 self = new;
                             }
                             $crate::UpdateResult::AtLatest(latest) => {
-                                return latest;
+                                return Ok(latest);
                             }
                         }
                     }
                 }
+
+                #[allow(irrefutable_let_patterns)]
+                pub fn try_into_latest_with<M: $crate::VersionMigrator<Self>>(
+                    self,
+                    migrator: &mut M,
+                ) -> Result<$latest_version_type, M::Error> {
+                    let Self::[<V $latest_version>](latest) = self.try_update_to_latest_with(migrator)? else {
+                        panic!("Invalid resolved latest version not equal to latest type")
+                    };
+                    Ok(latest)
+                }
             }
 
-            [<$name _trait_impl>]!(
-                $crate::HasLatestVersion,
-                {
-                    type Latest = $latest_version_type;
-
-                    #[allow(irrefutable_let_patterns)]
-                    fn into_latest(self) -> Self::Latest {
-                        let Self::[<V $latest_version>](latest) = self.update_to_latest() else {
-                            panic!("Invalid resolved latest version not equal to latest type")
-                        };
-                        return latest;
-                    }
+            // Creates the updates which can only be used if there are no migrations
+            #[allow(unused_macros)]
+            macro_rules! [<$name _infallible_updates>] {
+                () => {};
+                (infallible_updates) => {
+                    #[allow(dead_code)]
+                    impl
+                    $(< $( $lt $( : $clt $(+ $dlt )* )? ),+ >)?
+                    $name
+                    $(< $( $lt ),+ >)?
+                    {
+                        pub fn update_once(self) -> $crate::UpdateResult<Self> {
+                            match self {
+                            $(
+                                Self::[<V $version_num>](value) => $crate::UpdateResult::Updated(Self::[<V $update_to_version_num>](value.into())),
+                            )*
+                                Self::[<V $latest_version>](value) => $crate::UpdateResult::AtLatest(Self::[<V $latest_version>](value)),
+                            }
+                        }
 
-                    #[allow(unreachable_patterns)]
-                    fn as_latest_ref(&self) -> Option<&Self::Latest> {
-                        match self {
-                            Self::[<V $latest_version>](latest) => Some(latest),
-                            _ => None,
+                        pub fn update_to_latest(mut self) -> Self {
+                            loop {
+                                match self.update_once() {
+                                    $crate::UpdateResult::Updated(new) => {
+                                        self = new;
+                                    }
+                                    $crate::UpdateResult::AtLatest(latest) => {
+                                        return latest;
+                                    }
+                                }
+                            }
                         }
                     }
-                }
-            );
 
-            $($([<$name _trait_impl>]!(
+                    [<$name _trait_impl>]!(
+                        $crate::HasLatestVersion,
+                        {
+                            type Latest = $latest_version_type;
+
+                            #[allow(irrefutable_let_patterns)]
+                            fn into_latest(self) -> Self::Latest {
+                                let Self::[<V $latest_version>](latest) = self.update_to_latest() else {
+                                    panic!("Invalid resolved latest version not equal to latest type")
+                                };
+                                return latest;
+                            }
+
+                            #[allow(unreachable_patterns)]
+                            fn as_latest_ref(&self) -> Option<&Self::Latest> {
+                                match self {
+                                    Self::[<V $latest_version>](latest) => Some(latest),
+                                    _ => None,
+                                }
+                            }
+                        }
+                    );
+                };
+            }
+
+            [<$name _infallible_updates>]!($($infallible_updates)?);
+
+            $([<$name _trait_impl>]!(
                 From<$version_type>,
                 {
                     fn from(value: $version_type) -> Self {
                         Self::[<V $version_num>](value)
                     }
                 }
-            );)*)?
+            );)*
 
             [<$name _trait_impl>]!(
                 From<$latest_version_type>,
@@ -212,7 +402,7 @@ macro_rules! define_versioned {
                 };
             }
 
-            $($([<$name _versionable_impl>]!($version_type);)*)?
+            $([<$name _versionable_impl>]!($version_type);)*
             [<$name _versionable_impl>]!($latest_version_type);
         }
     };
@@ -221,6 +411,8 @@ macro_rules! define_versioned {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::prelude::indexmap;
+    use crate::rust::prelude::*;
     use crate::*;
 
     crate::define_versioned!(
@@ -297,6 +489,144 @@ mod tests {
         assert_eq!(versioned_actual.into_latest(), expected,);
     }
 
+    crate::define_versioned!(
+        #[derive(Debug, Clone, PartialEq, Eq, Sbor)]
+        enum VersionedAccountState {
+            previous_versions: [
+                1 => AccountStateV1: { updates_to: 2 },
+                2 => AccountStateV2: { migrates_to: 3 },
+            ],
+            latest_version: {
+                3 => AccountState = AccountStateV3,
+            },
+        }
+    );
+
+    type AccountStateV1 = u32;
+
+    #[derive(Debug, Clone, PartialEq, Eq, Sbor)]
+    struct AccountStateV2 {
+        owner_id: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Eq, Sbor)]
+    struct AccountStateV3 {
+        owner_name: String,
+    }
+
+    impl From<AccountStateV1> for AccountStateV2 {
+        fn from(value: AccountStateV1) -> Self {
+            Self { owner_id: value }
+        }
+    }
+
+    /// A migration of owner ids to owner names, which requires a lookup.
+    struct OwnerLookup {
+        owner_names: IndexMap<u32, String>,
+        migrations: usize,
+    }
+
+    impl VersionMigrator<VersionedAccountState> for OwnerLookup {
+        type Error = String;
+
+        fn migrate(
+            &mut self,
+            value: VersionedAccountState,
+        ) -> Result<VersionedAccountState, Self::Error> {
+            let VersionedAccountState::V2(v2) = value else {
+                return Err("Only V2 migrates".to_string());
+            };
+            self.migrations += 1;
+            let owner_name = self
+                .owner_names
+                .get(&v2.owner_id)
+                .ok_or_else(|| format!("Unknown owner {}", v2.owner_id))?;
+            Ok(VersionedAccountState::V3(AccountStateV3 {
+                owner_name: owner_name.clone(),
+            }))
+        }
+    }
+
+    #[test]
+    pub fn migrations_are_performed_with_the_migrator() {
+        let mut lookup = OwnerLookup {
+            owner_names: indexmap!(7 => "alice".to_string()),
+            migrations: 0,
+        };
+
+        let versioned = VersionedAccountState::from(7u32);
+        assert_eq!(
+            versioned.try_into_latest_with(&mut lookup),
+            Ok(AccountStateV3 {
+                owner_name: "alice".to_string()
+            })
+        );
+        assert_eq!(lookup.migrations, 1);
+
+        // Values at the latest version aren't migrated
+        let latest = AccountStateV3 {
+            owner_name: "bob".to_string(),
+        };
+        assert_eq!(
+            latest
+                .clone()
+                .into_versioned()
+                .try_into_latest_with(&mut lookup),
+            Ok(latest)
+        );
+        assert_eq!(lookup.migrations, 1);
+    }
+
+    #[test]
+    pub fn failed_migrations_return_the_migrator_error() {
+        let mut lookup = OwnerLookup {
+            owner_names: indexmap!(),
+            migrations: 0,
+        };
+
+        let versioned = VersionedAccountState::from(AccountStateV2 { owner_id: 8 });
+        assert_eq!(
+            versioned.try_update_to_latest_with(&mut lookup),
+            Err("Unknown owner 8".to_string())
+        );
+        assert_eq!(lookup.migrations, 1);
+
+        // Updates declared with `updates_to` don't use the migrator
+        let versioned = VersionedAccountState::from(8u32);
+        assert!(matches!(
+            versioned.try_update_once_with(&mut lookup),
+            Ok(UpdateResult::Updated(VersionedAccountState::V2(
+                AccountStateV2 { owner_id: 8 }
+            )))
+        ));
+        assert_eq!(lookup.migrations, 1);
+    }
+
+    /// A migration which incorrectly returns an earlier version.
+    struct RevertOwner;
+
+    impl VersionMigrator<VersionedAccountState> for RevertOwner {
+        type Error = String;
+
+        fn migrate(
+            &mut self,
+            value: VersionedAccountState,
+        ) -> Result<VersionedAccountState, Self::Error> {
+            let VersionedAccountState::V2(v2) = value else {
+                return Err("Only V2 migrates".to_string());
+            };
+            Ok(VersionedAccountState::V1(v2.owner_id))
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "VersionedAccountState version 2 was updated to version 1")]
+    pub fn migrations_to_an_earlier_version_panic_rather_than_loop() {
+        let versioned = VersionedAccountState::from(AccountStateV2 { owner_id: 8 });
+        assert_eq!(versioned.version_num(), 2);
+        let _ = versioned.try_update_to_latest_with(&mut RevertOwner);
+    }
+
     #[derive(Debug, Clone, PartialEq, Eq)]
     struct GenericModelV1<T>(T);
 
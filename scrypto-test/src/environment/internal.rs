@@ -59,6 +59,14 @@ impl EncapsulatedRadixEngine {
             let state_updates = generate_pools_v1_1_state_updates(&substate_db);
             let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
             substate_db.commit(&db_updates);
+
+            let state_updates = generate_vesting_package_state_updates();
+            let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
+            substate_db.commit(&db_updates);
+
+            let state_updates = generate_account_v1_1_state_updates(&substate_db);
+            let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
+            substate_db.commit(&db_updates);
         }
 
         // Create the Id allocator we will be using throughout this test
@@ -16,9 +16,9 @@ use radix_engine::system::system_db_reader::{
 use radix_engine::system::system_substates::FieldSubstate;
 use radix_engine::system::type_info::TypeInfoSubstate;
 use radix_engine::transaction::{
-    execute_preview, execute_transaction_with_system, BalanceChange, CommitResult,
-    CostingParameters, ExecutionConfig, PreviewError, TransactionReceipt, TransactionResult,
-    WrappedSystem,
+    estimate_fee, execute_and_commit_preview, execute_preview, execute_transaction_with_system,
+    BalanceChange, CommitResult, CostingParameters, ExecutionConfig, FeeEstimate,
+    FeeEstimationError, PreviewError, TransactionReceipt, TransactionResult, WrappedSystem,
 };
 use radix_engine::types::*;
 use radix_engine::utils::*;
@@ -54,11 +54,13 @@ use radix_engine_store_interface::interface::{
 };
 use radix_engine_stores::hash_tree_support::HashTreeUpdatingDatabase;
 use radix_engine_stores::memory_db::InMemorySubstateDatabase;
+use sbor::representations::*;
 use scrypto::prelude::*;
 use transaction::prelude::*;
 use transaction::validation::{
     NotarizedTransactionValidator, TransactionValidator, ValidationConfig,
 };
+use transaction_scenarios::recording::{RecordedProtocolUpdates, RecordedScenario, RecordedStep};
 
 pub struct Compile;
 
@@ -345,12 +347,18 @@ pub struct TestRunnerBuilder<E, D> {
     custom_extension: E,
     custom_database: D,
     trace: bool,
+    field_change_diffs: bool,
     skip_receipt_check: bool,
+    recording: Option<String>,
 
     // The following are protocol updates on mainnet
     with_seconds_precision_update: bool,
     with_crypto_utils_update: bool,
     with_pools_v1_1: bool,
+
+    // The following are protocol updates not yet enacted on mainnet
+    with_vesting_package: bool,
+    with_account_v1_1: bool,
 }
 
 impl TestRunnerBuilder<NoExtension, InMemorySubstateDatabase> {
@@ -360,10 +368,14 @@ impl TestRunnerBuilder<NoExtension, InMemorySubstateDatabase> {
             custom_extension: NoExtension,
             custom_database: InMemorySubstateDatabase::standard(),
             trace: true,
+            field_change_diffs: true,
             skip_receipt_check: false,
+            recording: None,
             with_seconds_precision_update: true,
             with_crypto_utils_update: true,
             with_pools_v1_1: true,
+            with_vesting_package: true,
+            with_account_v1_1: false,
         }
     }
 }
@@ -374,16 +386,27 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
         self
     }
 
+    /// Stops the built runner from diffing the fields written by each transaction, which are
+    /// otherwise reported in the receipts (see `ExecutionConfig::with_field_change_diffs`).
+    pub fn without_field_change_diffs(mut self) -> Self {
+        self.field_change_diffs = false;
+        self
+    }
+
     pub fn with_state_hashing(self) -> TestRunnerBuilder<E, HashTreeUpdatingDatabase<D>> {
         TestRunnerBuilder {
             custom_genesis: self.custom_genesis,
             custom_extension: self.custom_extension,
             custom_database: HashTreeUpdatingDatabase::new(self.custom_database),
             trace: self.trace,
+            field_change_diffs: self.field_change_diffs,
             skip_receipt_check: false,
+            recording: self.recording,
             with_seconds_precision_update: self.with_seconds_precision_update,
             with_crypto_utils_update: self.with_crypto_utils_update,
             with_pools_v1_1: self.with_pools_v1_1,
+            with_vesting_package: self.with_vesting_package,
+            with_account_v1_1: self.with_account_v1_1,
         }
     }
 
@@ -397,6 +420,14 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
         self
     }
 
+    /// Records every transaction executed by the built runner (along with epoch changes) into a
+    /// scenario, which can be replayed by the `transaction-scenarios` runner.
+    /// Only supported with the default genesis.
+    pub fn with_recording(mut self, logical_name: impl Into<String>) -> Self {
+        self.recording = Some(logical_name.into());
+        self
+    }
+
     pub fn with_custom_extension<NE: NativeVmExtension>(
         self,
         extension: NE,
@@ -406,10 +437,14 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             custom_extension: extension,
             custom_database: self.custom_database,
             trace: self.trace,
+            field_change_diffs: self.field_change_diffs,
             skip_receipt_check: self.skip_receipt_check,
+            recording: self.recording,
             with_seconds_precision_update: self.with_seconds_precision_update,
             with_crypto_utils_update: self.with_crypto_utils_update,
             with_pools_v1_1: self.with_pools_v1_1,
+            with_vesting_package: self.with_vesting_package,
+            with_account_v1_1: self.with_account_v1_1,
         }
     }
 
@@ -419,10 +454,14 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             custom_extension: self.custom_extension,
             custom_database: database,
             trace: self.trace,
+            field_change_diffs: self.field_change_diffs,
             skip_receipt_check: self.skip_receipt_check,
+            recording: self.recording,
             with_seconds_precision_update: self.with_seconds_precision_update,
             with_crypto_utils_update: self.with_crypto_utils_update,
             with_pools_v1_1: self.with_pools_v1_1,
+            with_vesting_package: self.with_vesting_package,
+            with_account_v1_1: self.with_account_v1_1,
         }
     }
 
@@ -441,6 +480,18 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
         self
     }
 
+    pub fn without_vesting_package(mut self) -> Self {
+        self.with_vesting_package = false;
+        self
+    }
+
+    /// Updates the account package to v1.1, which adds withdrawal limits. Unlike the other
+    /// protocol updates this is opt-in, as it changes the cost of every account withdrawal.
+    pub fn with_account_v1_1(mut self) -> Self {
+        self.with_account_v1_1 = true;
+        self
+    }
+
     pub fn build_from_snapshot(
         self,
         snapshot: TestRunnerSnapshot,
@@ -459,9 +510,11 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             next_private_key: snapshot.next_private_key,
             next_transaction_nonce: snapshot.next_transaction_nonce,
             trace,
+            field_change_diffs: self.field_change_diffs,
             collected_events: snapshot.collected_events,
             xrd_free_credits_used: snapshot.xrd_free_credits_used,
             skip_receipt_check: snapshot.skip_receipt_check,
+            recorded_scenario: snapshot.recorded_scenario,
         }
     }
 
@@ -482,6 +535,9 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
         let native_vm = NativeVm::new_with_extension(self.custom_extension);
         let vm = Vm::new(&scrypto_vm, native_vm.clone());
         let mut substate_db = self.custom_database;
+        if self.recording.is_some() && self.custom_genesis.is_some() {
+            panic!("Recording is only supported with the default genesis");
+        }
         let mut bootstrapper = Bootstrapper::new(
             NetworkDefinition::simulator(),
             &mut substate_db,
@@ -557,6 +613,32 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             substate_db.commit(&db_updates);
         }
 
+        if self.with_vesting_package {
+            let state_updates = generate_vesting_package_state_updates();
+            let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
+            substate_db.commit(&db_updates);
+        }
+
+        if self.with_account_v1_1 {
+            let state_updates = generate_account_v1_1_state_updates(&substate_db);
+            let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
+            substate_db.commit(&db_updates);
+        }
+
+        let recorded_scenario = self.recording.map(|logical_name| {
+            RecordedScenario::new(
+                logical_name,
+                RecordedProtocolUpdates {
+                    seconds_precision: self.with_seconds_precision_update,
+                    crypto_utils: self.with_crypto_utils_update,
+                    validator_fee_fix: true,
+                    pools_v1_1: self.with_pools_v1_1,
+                    vesting_package: self.with_vesting_package,
+                    account_v1_1: self.with_account_v1_1,
+                },
+            )
+        });
+
         let runner = TestRunner {
             scrypto_vm,
             native_vm,
@@ -564,9 +646,11 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             next_private_key,
             next_transaction_nonce,
             trace,
+            field_change_diffs: self.field_change_diffs,
             collected_events: events,
             xrd_free_credits_used: false,
             skip_receipt_check: self.skip_receipt_check,
+            recorded_scenario,
         };
 
         let next_epoch = wrap_up_receipt
@@ -588,9 +672,11 @@ pub struct TestRunner<E: NativeVmExtension, D: TestDatabase> {
     next_private_key: u64,
     next_transaction_nonce: u32,
     trace: bool,
+    field_change_diffs: bool,
     collected_events: Vec<Vec<(EventTypeIdentifier, Vec<u8>)>>,
     xrd_free_credits_used: bool,
     skip_receipt_check: bool,
+    recorded_scenario: Option<RecordedScenario>,
 }
 
 #[cfg(feature = "post_run_db_check")]
@@ -608,6 +694,7 @@ pub struct TestRunnerSnapshot {
     collected_events: Vec<Vec<(EventTypeIdentifier, Vec<u8>)>>,
     xrd_free_credits_used: bool,
     skip_receipt_check: bool,
+    recorded_scenario: Option<RecordedScenario>,
 }
 
 impl<E: NativeVmExtension> TestRunner<E, InMemorySubstateDatabase> {
@@ -619,6 +706,7 @@ impl<E: NativeVmExtension> TestRunner<E, InMemorySubstateDatabase> {
             collected_events: self.collected_events.clone(),
             xrd_free_credits_used: self.xrd_free_credits_used,
             skip_receipt_check: self.skip_receipt_check,
+            recorded_scenario: self.recorded_scenario.clone(),
         }
     }
 
@@ -629,6 +717,7 @@ impl<E: NativeVmExtension> TestRunner<E, InMemorySubstateDatabase> {
         self.collected_events = snapshot.collected_events;
         self.xrd_free_credits_used = snapshot.xrd_free_credits_used;
         self.skip_receipt_check = snapshot.skip_receipt_check;
+        self.recorded_scenario = snapshot.recorded_scenario;
     }
 }
 
@@ -649,6 +738,17 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         self.collected_events.as_ref()
     }
 
+    /// The scenario recorded so far, if enabled by [`TestRunnerBuilder::with_recording()`].
+    pub fn recorded_scenario(&self) -> Option<&RecordedScenario> {
+        self.recorded_scenario.as_ref()
+    }
+
+    fn record(&mut self, create_step: impl FnOnce() -> RecordedStep) {
+        if let Some(recorded_scenario) = &mut self.recorded_scenario {
+            recorded_scenario.record(create_step());
+        }
+    }
+
     pub fn next_private_key(&mut self) -> u64 {
         self.next_private_key += 1;
         self.next_private_key - 1
@@ -958,6 +1058,51 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         component_state.unwrap().into_payload()
     }
 
+    /// Asserts that the state of the component is equal to the expected state, or panics with the
+    /// values which differ between them, located by their field names.
+    pub fn assert_component_state_eq<T: ScryptoEncode>(
+        &self,
+        component_address: ComponentAddress,
+        expected: &T,
+    ) {
+        let node_id: &NodeId = component_address.as_node_id();
+        let reader = SystemDatabaseReader::new(self.substate_db());
+        let actual = reader
+            .read_object_field(node_id, ModuleId::Main, ComponentField::State0.into())
+            .expect("Component state should exist");
+        let target = reader
+            .get_blueprint_type_target(node_id, ModuleId::Main)
+            .unwrap();
+        let payload_schema = reader
+            .get_blueprint_payload_schema(
+                &target,
+                &BlueprintPayloadIdentifier::Field(ComponentField::State0.into()),
+            )
+            .unwrap();
+
+        let address_encoder = AddressBech32Encoder::for_simulator();
+        let context = RustLikeDisplayContext::<ScryptoCustomExtension> {
+            schema: payload_schema.schema.v1(),
+            custom_context: ScryptoValueDisplayContext::with_optional_bech32(Some(
+                &address_encoder,
+            )),
+            print_mode: PrintMode::SingleLine,
+        };
+        let diff = diff_payloads(
+            &context,
+            actual.as_slice(),
+            &scrypto_encode(expected).unwrap(),
+            payload_schema.type_id,
+            SCRYPTO_SBOR_V1_MAX_DEPTH,
+        )
+        .expect("Expected state should match the component state schema");
+        assert!(
+            diff.is_empty(),
+            "Component state differs from the expected state (actual -> expected):\n{}",
+            diff
+        );
+    }
+
     pub fn get_non_fungible_data<T: NonFungibleData>(
         &self,
         resource: ResourceAddress,
@@ -1295,7 +1440,7 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         let code_hash = hash(&code);
         let nonce = self.next_transaction_nonce();
 
-        let receipt = self.execute_transaction(
+        let receipt = self.execute_system_transaction_v1(
             SystemTransactionV1 {
                 instructions: InstructionsV1(vec![InstructionV1::CallFunction {
                     package_address: PACKAGE_PACKAGE.into(),
@@ -1317,12 +1462,8 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
                     blueprint_id: BlueprintId::new(&PACKAGE_PACKAGE, PACKAGE_BLUEPRINT),
                     address: address.into(),
                 }],
-            }
-            .prepare()
-            .expect("expected transaction to be preparable")
-            .get_executable(btreeset!(AuthAddresses::system_role())),
-            CostingParameters::default(),
-            ExecutionConfig::for_system_transaction(NetworkDefinition::simulator()),
+            },
+            btreeset!(AuthAddresses::system_role()),
         );
 
         receipt.expect_commit_success();
@@ -1539,7 +1680,11 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         let validated = validator
             .validate_from_raw(&raw_transaction)
             .expect("Expected raw transaction to be valid");
-        self.execute_transaction(
+        self.execute_recorded_transaction(
+            || RecordedStep::NotarizedTransaction {
+                raw: raw_transaction.clone(),
+                network: network.clone(),
+            },
             validated.get_executable(),
             CostingParameters::default(),
             ExecutionConfig::for_notarized_transaction(network.clone()),
@@ -1571,11 +1716,13 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         T: IntoIterator<Item = NonFungibleGlobalId>,
     {
         let nonce = self.next_transaction_nonce();
+        let transaction = TestTransaction::new_from_nonce(manifest, nonce);
+        let initial_proofs = initial_proofs.into_iter().collect::<BTreeSet<_>>();
         self.execute_transaction_with_system::<R>(
-            TestTransaction::new_from_nonce(manifest, nonce)
+            transaction
                 .prepare()
                 .expect("expected transaction to be preparable")
-                .get_executable(initial_proofs.into_iter().collect()),
+                .get_executable(initial_proofs),
             CostingParameters::default(),
             ExecutionConfig::for_test_transaction(),
             init,
@@ -1592,13 +1739,11 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         T: IntoIterator<Item = NonFungibleGlobalId>,
     {
         let nonce = self.next_transaction_nonce();
-        self.execute_transaction(
-            TestTransaction::new_from_nonce(manifest, nonce)
-                .prepare()
-                .expect("expected transaction to be preparable")
-                .get_executable(initial_proofs.into_iter().collect()),
+        let transaction = TestTransaction::new_from_nonce(manifest, nonce);
+        self.execute_test_transaction(
+            transaction,
+            initial_proofs.into_iter().collect(),
             costing_parameters,
-            ExecutionConfig::for_test_transaction(),
         )
     }
 
@@ -1612,39 +1757,105 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         T: IntoIterator<Item = NonFungibleGlobalId>,
     {
         let nonce = self.next_transaction_nonce();
-        self.execute_transaction(
-            TestTransaction::new_from_nonce(manifest, nonce)
-                .prepare()
-                .expect("expected transaction to be preparable")
-                .get_executable(initial_proofs.into_iter().collect()),
-            CostingParameters::default().with_execution_cost_unit_limit(execution_cost_unit_limit),
+        let transaction = TestTransaction::new_from_nonce(manifest, nonce);
+        let costing_parameters =
+            CostingParameters::default().with_execution_cost_unit_limit(execution_cost_unit_limit);
+        self.execute_test_transaction(
+            transaction,
+            initial_proofs.into_iter().collect(),
+            costing_parameters,
+        )
+    }
+
+    fn execute_test_transaction(
+        &mut self,
+        transaction: TestTransaction,
+        initial_proofs: BTreeSet<NonFungibleGlobalId>,
+        costing_parameters: CostingParameters,
+    ) -> TransactionReceipt {
+        // Preparing consumes the transaction, so its step is created beforehand (if recording)
+        let recorded_step = self.recorded_scenario.as_ref().map(|_| {
+            RecordedStep::test_transaction(&transaction, &initial_proofs, &costing_parameters)
+        });
+        let prepared = transaction
+            .prepare()
+            .expect("expected transaction to be preparable");
+        let executable = prepared.get_executable(initial_proofs);
+        self.execute_recorded_transaction(
+            || recorded_step.expect("The step is created when recording"),
+            executable,
+            costing_parameters,
             ExecutionConfig::for_test_transaction(),
         )
     }
 
+    /// Note: the executable doesn't carry the transaction's payload, so if recording is enabled,
+    /// it's recorded as a [`RecordedStep::Unreplayable`] step.
     pub fn execute_transaction(
         &mut self,
         executable: Executable,
         costing_parameters: CostingParameters,
         execution_config: ExecutionConfig,
     ) -> TransactionReceipt {
-        self.execute_transaction_with_system::<SystemConfig<Vm<'_, DefaultWasmEngine, E>>>(
+        self.execute_recorded_transaction(
+            || RecordedStep::unreplayable("executed from a bare executable"),
             executable,
             costing_parameters,
             execution_config,
-            (),
         )
     }
 
+    /// Note: replaying can't reproduce the effects of a custom system, so if recording is enabled,
+    /// the transaction is recorded as a [`RecordedStep::Unreplayable`] step.
     pub fn execute_transaction_with_system<'a, T: WrappedSystem<Vm<'a, DefaultWasmEngine, E>>>(
         &'a mut self,
         executable: Executable,
         costing_parameters: CostingParameters,
+        execution_config: ExecutionConfig,
+        init: T::Init,
+    ) -> TransactionReceipt {
+        self.execute_transaction_internal::<T>(
+            || RecordedStep::unreplayable("executed with a custom system"),
+            executable,
+            costing_parameters,
+            execution_config,
+            init,
+        )
+    }
+
+    fn execute_recorded_transaction(
+        &mut self,
+        create_recorded_step: impl FnOnce() -> RecordedStep,
+        executable: Executable,
+        costing_parameters: CostingParameters,
+        execution_config: ExecutionConfig,
+    ) -> TransactionReceipt {
+        self.execute_transaction_internal::<SystemConfig<Vm<'_, DefaultWasmEngine, E>>>(
+            create_recorded_step,
+            executable,
+            costing_parameters,
+            execution_config,
+            (),
+        )
+    }
+
+    /// The single point through which all transactions are executed, so that each one is recorded
+    /// (if enabled), as the step created by `create_recorded_step`.
+    fn execute_transaction_internal<'a, T: WrappedSystem<Vm<'a, DefaultWasmEngine, E>>>(
+        &'a mut self,
+        create_recorded_step: impl FnOnce() -> RecordedStep,
+        executable: Executable,
+        costing_parameters: CostingParameters,
         mut execution_config: ExecutionConfig,
         init: T::Init,
     ) -> TransactionReceipt {
+        self.record(create_recorded_step);
+
         // Override the kernel trace config
         execution_config = execution_config.with_kernel_trace(self.trace);
+        if self.field_change_diffs {
+            execution_config = execution_config.with_field_change_diffs(true);
+        }
 
         if executable
             .costing_parameters()
@@ -1711,31 +1922,107 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
             &mut self.database,
             vm,
             &NetworkDefinition::simulator(),
-            PreviewIntentV1 {
-                intent: IntentV1 {
-                    header: TransactionHeaderV1 {
-                        network_id: NetworkDefinition::simulator().id,
-                        start_epoch_inclusive: epoch,
-                        end_epoch_exclusive: epoch.after(10).unwrap(),
-                        nonce: 0,
-                        notary_public_key: PublicKey::Secp256k1(Secp256k1PublicKey([0u8; 33])),
-                        notary_is_signatory: false,
-                        tip_percentage,
-                    },
-                    instructions: InstructionsV1(manifest.instructions),
-                    blobs: BlobsV1 {
-                        blobs: manifest.blobs.values().map(|x| BlobV1(x.clone())).collect(),
-                    },
-                    message: MessageV1::default(),
-                },
+            Self::create_preview_intent(epoch, manifest, signer_public_keys, tip_percentage, flags),
+            self.trace,
+        )
+        .unwrap()
+    }
+
+    /// Previews the manifest (which should not lock any fee) and estimates the fee it should lock,
+    /// with the given margin on top of each cost.
+    pub fn estimate_fee(
+        &mut self,
+        manifest: TransactionManifestV1,
+        signer_public_keys: Vec<PublicKey>,
+        margin_percentage: u16,
+    ) -> Result<FeeEstimate, FeeEstimationError> {
+        let epoch = self.get_current_epoch();
+        let vm = Vm {
+            scrypto_vm: &self.scrypto_vm,
+            native_vm: self.native_vm.clone(),
+        };
+        estimate_fee(
+            &self.database,
+            vm,
+            &NetworkDefinition::simulator(),
+            Self::create_preview_intent(
+                epoch,
+                manifest,
                 signer_public_keys,
-                flags,
-            },
+                0,
+                PreviewFlags::default(),
+            ),
+            margin_percentage,
+        )
+    }
+
+    /// Previews the manifest against the given database (instead of this runner's one) and, if
+    /// the preview resulted in a commit, applies its state changes to that database.
+    ///
+    /// This is meant to be used with an
+    /// [`OverlaySubstateDatabase`](radix_engine_stores::overlay_db::OverlaySubstateDatabase) on top
+    /// of this runner's database, in order to chain several previews (each one observing the results of the
+    /// previous ones) and then discard them.
+    pub fn preview_manifest_onto<S: SubstateDatabase + CommittableSubstateDatabase>(
+        &self,
+        database: &mut S,
+        manifest: TransactionManifestV1,
+        signer_public_keys: Vec<PublicKey>,
+        tip_percentage: u16,
+        flags: PreviewFlags,
+    ) -> TransactionReceipt {
+        let epoch = SystemDatabaseReader::new(&*database)
+            .read_typed_object_field::<ConsensusManagerStateFieldPayload>(
+                CONSENSUS_MANAGER.as_node_id(),
+                ModuleId::Main,
+                ConsensusManagerField::State.field_index(),
+            )
+            .unwrap()
+            .into_latest()
+            .epoch;
+        let vm = Vm {
+            scrypto_vm: &self.scrypto_vm,
+            native_vm: self.native_vm.clone(),
+        };
+        execute_and_commit_preview(
+            database,
+            vm,
+            &NetworkDefinition::simulator(),
+            Self::create_preview_intent(epoch, manifest, signer_public_keys, tip_percentage, flags),
             self.trace,
         )
         .unwrap()
     }
 
+    fn create_preview_intent(
+        epoch: Epoch,
+        manifest: TransactionManifestV1,
+        signer_public_keys: Vec<PublicKey>,
+        tip_percentage: u16,
+        flags: PreviewFlags,
+    ) -> PreviewIntentV1 {
+        PreviewIntentV1 {
+            intent: IntentV1 {
+                header: TransactionHeaderV1 {
+                    network_id: NetworkDefinition::simulator().id,
+                    start_epoch_inclusive: epoch,
+                    end_epoch_exclusive: epoch.after(10).unwrap(),
+                    nonce: 0,
+                    notary_public_key: PublicKey::Secp256k1(Secp256k1PublicKey([0u8; 33])),
+                    notary_is_signatory: false,
+                    tip_percentage,
+                },
+                instructions: InstructionsV1(manifest.instructions),
+                blobs: BlobsV1 {
+                    blobs: manifest.blobs.values().map(|x| BlobV1(x.clone())).collect(),
+                },
+                message: MessageV1::default(),
+            },
+            signer_public_keys,
+            flags,
+        }
+    }
+
     /// Calls a package blueprint function with the given arguments, paying the fee from the faucet.
     ///
     /// The arguments should be one of:
@@ -2290,6 +2577,7 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
     }
 
     pub fn set_current_epoch(&mut self, epoch: Epoch) {
+        self.record(|| RecordedStep::SetCurrentEpoch(epoch));
         let reader = SystemDatabaseReader::new(&self.database);
         let mut substate = reader
             .read_typed_object_field::<ConsensusManagerStateFieldPayload>(
@@ -2333,18 +2621,14 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
     ) -> TransactionReceipt {
         let nonce = self.next_transaction_nonce();
 
-        self.execute_transaction(
+        self.execute_system_transaction_v1(
             SystemTransactionV1 {
                 instructions: InstructionsV1(instructions),
                 blobs: BlobsV1 { blobs: vec![] },
                 hash_for_execution: hash(format!("Test runner txn: {}", nonce)),
                 pre_allocated_addresses,
-            }
-            .prepare()
-            .expect("expected transaction to be preparable")
-            .get_executable(proofs),
-            CostingParameters::default(),
-            ExecutionConfig::for_system_transaction(NetworkDefinition::simulator()),
+            },
+            proofs,
         )
     }
 
@@ -2363,18 +2647,14 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
     ) -> TransactionReceipt {
         let nonce = self.next_transaction_nonce();
         proofs.insert(AuthAddresses::system_role());
-        self.execute_transaction(
+        self.execute_system_transaction_v1(
             SystemTransactionV1 {
                 instructions: InstructionsV1(instructions),
                 blobs: BlobsV1 { blobs: vec![] },
                 hash_for_execution: hash(format!("Test runner txn: {}", nonce)),
                 pre_allocated_addresses,
-            }
-            .prepare()
-            .expect("expected transaction to be preparable")
-            .get_executable(proofs),
-            CostingParameters::default(),
-            ExecutionConfig::for_system_transaction(NetworkDefinition::simulator()),
+            },
+            proofs,
         )
     }
 
@@ -2385,16 +2665,29 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
     ) -> TransactionReceipt {
         let nonce = self.next_transaction_nonce();
 
-        self.execute_transaction(
+        self.execute_system_transaction_v1(
             SystemTransactionV1 {
                 instructions: InstructionsV1(instructions),
                 blobs: BlobsV1 { blobs: vec![] },
                 hash_for_execution: hash(format!("Test runner txn: {}", nonce)),
                 pre_allocated_addresses: vec![],
-            }
+            },
+            proofs,
+        )
+    }
+
+    fn execute_system_transaction_v1(
+        &mut self,
+        transaction: SystemTransactionV1,
+        proofs: BTreeSet<NonFungibleGlobalId>,
+    ) -> TransactionReceipt {
+        let prepared = transaction
             .prepare()
-            .expect("expected transaction to be preparable")
-            .get_executable(proofs),
+            .expect("expected transaction to be preparable");
+        let executable = prepared.get_executable(proofs.clone());
+        self.execute_recorded_transaction(
+            || RecordedStep::system_transaction(&transaction, &proofs),
+            executable,
             CostingParameters::default(),
             ExecutionConfig::for_system_transaction(NetworkDefinition::simulator()),
         )
@@ -28,6 +28,20 @@ extern_blueprint_internal! {
         fn lock_fee(&mut self, amount: Decimal);
     }
 }
+#[allow(dead_code, unused_imports)]
+pub mod faucet_view {
+    use super::*;
+    use scrypto::prelude::*;
+    pub const BLUEPRINT_NAME: &str = "Faucet";
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct Faucet {
+        pub vault: Vault,
+        pub transactions: Own,
+    }
+    pub type Epoch = u64;
+    pub type Hash = Vec<u8>;
+    pub type Field0 = Faucet;
+}
 
 extern_blueprint_internal! {
     PackageAddress::new_or_panic([
@@ -73,6 +87,122 @@ extern_blueprint_internal! {
         ) -> (Global<Validator>, Bucket, Bucket);
     }
 }
+#[allow(dead_code, unused_imports)]
+pub mod consensus_manager_view {
+    use super::*;
+    use scrypto::prelude::*;
+    pub const BLUEPRINT_NAME: &str = "ConsensusManager";
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum ConsensusManagerConfigurationFieldPayload {
+        #[sbor(discriminator(0u8))]
+        V1(ConsensusManagerConfigSubstate),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ConsensusManagerConfigSubstate {
+        pub config: ConsensusManagerConfig,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ConsensusManagerConfig {
+        pub max_validators: u32,
+        pub epoch_change_condition: EpochChangeCondition,
+        pub num_unstake_epochs: u64,
+        pub total_emission_xrd_per_epoch: Decimal,
+        pub min_validator_reliability: Decimal,
+        pub num_owner_stake_units_unlock_epochs: u64,
+        pub num_fee_increase_delay_epochs: u64,
+        pub validator_creation_usd_cost: Decimal,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct EpochChangeCondition {
+        pub min_round_count: u64,
+        pub max_round_count: u64,
+        pub target_duration_millis: u64,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum ConsensusManagerStateFieldPayload {
+        #[sbor(discriminator(0u8))]
+        V1(ConsensusManagerSubstate),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ConsensusManagerSubstate {
+        pub started: bool,
+        pub epoch: Epoch,
+        pub effective_epoch_start_milli: i64,
+        pub actual_epoch_start_milli: i64,
+        pub round: Round,
+        pub current_leader: Option<u8>,
+    }
+    pub type Epoch = u64;
+    pub type Round = u64;
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum ConsensusManagerValidatorRewardsFieldPayload {
+        #[sbor(discriminator(0u8))]
+        V1(ValidatorRewardsSubstate),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ValidatorRewardsSubstate {
+        pub proposer_rewards: IndexMap<u8, Decimal>,
+        pub rewards_vault: Vault,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum ConsensusManagerCurrentValidatorSetFieldPayload {
+        #[sbor(discriminator(0u8))]
+        V1(CurrentValidatorSetSubstate),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct CurrentValidatorSetSubstate {
+        pub validator_set: ActiveValidatorSet,
+    }
+    pub type ActiveValidatorSet = IndexMap<ComponentAddress, Validator>;
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct Validator {
+        pub key: Secp256k1PublicKey,
+        pub stake: Decimal,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum ConsensusManagerCurrentProposalStatisticFieldPayload {
+        #[sbor(discriminator(0u8))]
+        V1(CurrentProposalStatisticSubstate),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct CurrentProposalStatisticSubstate {
+        pub validator_statistics: Vec<ProposalStatistic>,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ProposalStatistic {
+        pub made: u64,
+        pub missed: u64,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum ConsensusManagerProposerMinuteTimestampFieldPayload {
+        #[sbor(discriminator(0u8))]
+        V1(ProposerMinuteTimestampSubstate),
+    }
+    pub type ProposerMinuteTimestampSubstate = i32;
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum ConsensusManagerProposerMilliTimestampFieldPayload {
+        #[sbor(discriminator(0u8))]
+        V1(ProposerMilliTimestampSubstate),
+    }
+    pub type ProposerMilliTimestampSubstate = i64;
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct RoundChangeEvent {
+        pub round: Round,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct EpochChangeEvent {
+        pub epoch: Epoch,
+        pub validator_set: ActiveValidatorSet,
+        pub significant_protocol_update_readiness: IndexMap<String, Decimal>,
+    }
+    pub type Field0 = ConsensusManagerConfigurationFieldPayload;
+    pub type Field1 = ConsensusManagerStateFieldPayload;
+    pub type Field2 = ConsensusManagerValidatorRewardsFieldPayload;
+    pub type Field3 = ConsensusManagerCurrentValidatorSetFieldPayload;
+    pub type Field4 = ConsensusManagerCurrentProposalStatisticFieldPayload;
+    pub type Field5 = ConsensusManagerProposerMinuteTimestampFieldPayload;
+    pub type Field6 = ConsensusManagerProposerMilliTimestampFieldPayload;
+}
 extern_blueprint_internal! {
     PackageAddress::new_or_panic([
         13u8, 144u8, 99u8, 24u8, 198u8, 49u8, 140u8, 108u8, 78u8, 27u8, 64u8, 204u8, 99u8, 24u8,
@@ -115,6 +245,93 @@ extern_blueprint_internal! {
         fn apply_reward(&mut self, xrd_bucket: Bucket, epoch: Epoch);
     }
 }
+#[allow(dead_code, unused_imports)]
+pub mod validator_view {
+    use super::*;
+    use scrypto::prelude::*;
+    pub const BLUEPRINT_NAME: &str = "Validator";
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum ValidatorStateFieldPayload {
+        #[sbor(discriminator(0u8))]
+        V1(ValidatorSubstate),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ValidatorSubstate {
+        pub sorted_key: Option<(Vec<u8>, Vec<u8>)>,
+        pub key: Secp256k1PublicKey,
+        pub is_registered: bool,
+        pub accepts_delegated_stake: bool,
+        pub validator_fee_factor: Decimal,
+        pub validator_fee_change_request: Option<ValidatorFeeChangeRequest>,
+        pub stake_unit_resource: ResourceAddress,
+        pub stake_xrd_vault_id: Own,
+        pub claim_nft: ResourceAddress,
+        pub pending_xrd_withdraw_vault_id: Own,
+        pub locked_owner_stake_unit_vault_id: Own,
+        pub pending_owner_stake_unit_unlock_vault_id: Own,
+        pub pending_owner_stake_unit_withdrawals: IndexMap<Epoch, Decimal>,
+        pub already_unlocked_owner_stake_unit_amount: Decimal,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ValidatorFeeChangeRequest {
+        pub epoch_effective: Epoch,
+        pub new_fee_factor: Decimal,
+    }
+    pub type Epoch = u64;
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum ValidatorProtocolUpdateReadinessSignalFieldPayload {
+        #[sbor(discriminator(0u8))]
+        V1(ValidatorProtocolUpdateReadinessSignalSubstate),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum ValidatorProtocolUpdateReadinessSignalSubstate {
+        #[sbor(discriminator(0u8))]
+        None,
+        #[sbor(discriminator(1u8))]
+        Some(String),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct RegisterValidatorEvent();
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct UnregisterValidatorEvent();
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct StakeEvent {
+        pub xrd_staked: Decimal,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct UnstakeEvent {
+        pub stake_units: Decimal,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ClaimXrdEvent {
+        pub claimed_xrd: Decimal,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ProtocolUpdateReadinessSignalEvent {
+        pub protocol_version_name: String,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct UpdateAcceptingStakeDelegationStateEvent {
+        pub accepts_delegation: bool,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ValidatorEmissionAppliedEvent {
+        pub epoch: Epoch,
+        pub starting_stake_pool_xrd: Decimal,
+        pub stake_pool_added_xrd: Decimal,
+        pub total_stake_unit_supply: Decimal,
+        pub validator_fee_xrd: Decimal,
+        pub proposals_made: u64,
+        pub proposals_missed: u64,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ValidatorRewardAppliedEvent {
+        pub epoch: Epoch,
+        pub amount: Decimal,
+    }
+    pub type Field0 = ValidatorStateFieldPayload;
+    pub type Field1 = ValidatorProtocolUpdateReadinessSignalFieldPayload;
+}
 
 extern_blueprint_internal! {
     PackageAddress::new_or_panic([
@@ -134,6 +351,12 @@ extern_blueprint_internal! {
         fn securify(&mut self) -> Bucket;
     }
 }
+#[allow(dead_code, unused_imports)]
+pub mod identity_view {
+    use super::*;
+    use scrypto::prelude::*;
+    pub const BLUEPRINT_NAME: &str = "Identity";
+}
 
 extern_blueprint_internal! {
     PackageAddress::new_or_panic([
@@ -217,8 +440,107 @@ extern_blueprint_internal! {
         );
         fn add_authorized_depositor(&mut self, badge: ResourceOrNonFungible);
         fn remove_authorized_depositor(&mut self, badge: ResourceOrNonFungible);
+        fn set_withdrawal_limit(&mut self, resource_address: ResourceAddress, limit: WithdrawalLimit);
+        fn remove_withdrawal_limit(&mut self, resource_address: ResourceAddress);
     }
 }
+#[allow(dead_code, unused_imports)]
+pub mod account_view {
+    use super::*;
+    use scrypto::prelude::*;
+    pub const BLUEPRINT_NAME: &str = "Account";
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum AccountDepositRuleFieldPayload {
+        #[sbor(discriminator(0u8))]
+        V1(AccountSubstate),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct AccountSubstate {
+        pub default_deposit_rule: DefaultDepositRule,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum DefaultDepositRule {
+        #[sbor(discriminator(0u8))]
+        Accept,
+        #[sbor(discriminator(1u8))]
+        Reject,
+        #[sbor(discriminator(2u8))]
+        AllowExisting,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum ResourcePreference {
+        #[sbor(discriminator(0u8))]
+        Allowed,
+        #[sbor(discriminator(1u8))]
+        Disallowed,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct WithdrawalLimit {
+        pub amount: Decimal,
+        pub period: WithdrawalLimitPeriod,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum WithdrawalLimitPeriod {
+        #[sbor(discriminator(0u8))]
+        Epoch,
+        #[sbor(discriminator(1u8))]
+        Day,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum WithdrawEvent {
+        #[sbor(discriminator(0u8))]
+        Fungible(ResourceAddress, Decimal),
+        #[sbor(discriminator(1u8))]
+        NonFungible(ResourceAddress, Vec<NonFungibleLocalId>),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum DepositEvent {
+        #[sbor(discriminator(0u8))]
+        Fungible(ResourceAddress, Decimal),
+        #[sbor(discriminator(1u8))]
+        NonFungible(ResourceAddress, Vec<NonFungibleLocalId>),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum RejectedDepositEvent {
+        #[sbor(discriminator(0u8))]
+        Fungible(ResourceAddress, Decimal),
+        #[sbor(discriminator(1u8))]
+        NonFungible(ResourceAddress, Vec<NonFungibleLocalId>),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct SetResourcePreferenceEvent {
+        pub resource_address: ResourceAddress,
+        pub preference: ResourcePreference,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct RemoveResourcePreferenceEvent {
+        pub resource_address: ResourceAddress,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct SetDefaultDepositRuleEvent {
+        pub default_deposit_rule: DefaultDepositRule,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct AddAuthorizedDepositorEvent {
+        pub authorized_depositor_badge: ResourceOrNonFungible,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct RemoveAuthorizedDepositorEvent {
+        pub authorized_depositor_badge: ResourceOrNonFungible,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct SetWithdrawalLimitEvent {
+        pub resource_address: ResourceAddress,
+        pub limit: WithdrawalLimit,
+        pub effective_at: Instant,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct RemoveWithdrawalLimitEvent {
+        pub resource_address: ResourceAddress,
+        pub effective_at: Instant,
+    }
+    pub type Field0 = AccountDepositRuleFieldPayload;
+}
 
 extern_blueprint_internal! {
     PackageAddress::new_or_panic([
@@ -255,6 +577,45 @@ extern_blueprint_internal! {
         fn get_vault_amounts(&self) -> IndexMap<ResourceAddress, Decimal>;
     }
 }
+#[allow(dead_code, unused_imports)]
+pub mod multi_resource_pool_view {
+    use super::*;
+    use scrypto::prelude::*;
+    pub const BLUEPRINT_NAME: &str = "MultiResourcePool";
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum MultiResourcePoolStateFieldPayload {
+        #[sbor(discriminator(0u8))]
+        V1(MultiResourcePoolSubstate),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct MultiResourcePoolSubstate {
+        pub vaults: IndexMap<ResourceAddress, Vault>,
+        pub pool_unit_resource_manager: ResourceManager,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ResourceManager(pub ResourceAddress);
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ContributionEvent {
+        pub contributed_resources: IndexMap<ResourceAddress, Decimal>,
+        pub pool_units_minted: Decimal,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct RedemptionEvent {
+        pub pool_unit_tokens_redeemed: Decimal,
+        pub redeemed_resources: IndexMap<ResourceAddress, Decimal>,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct WithdrawEvent {
+        pub resource_address: ResourceAddress,
+        pub amount: Decimal,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct DepositEvent {
+        pub resource_address: ResourceAddress,
+        pub amount: Decimal,
+    }
+    pub type Field0 = MultiResourcePoolStateFieldPayload;
+}
 extern_blueprint_internal! {
     PackageAddress::new_or_panic([
         13u8, 144u8, 99u8, 24u8, 198u8, 49u8, 140u8, 96u8, 252u8, 198u8, 49u8, 140u8, 99u8, 24u8,
@@ -286,6 +647,43 @@ extern_blueprint_internal! {
         fn get_vault_amount(&self) -> Decimal;
     }
 }
+#[allow(dead_code, unused_imports)]
+pub mod one_resource_pool_view {
+    use super::*;
+    use scrypto::prelude::*;
+    pub const BLUEPRINT_NAME: &str = "OneResourcePool";
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum OneResourcePoolStateFieldPayload {
+        #[sbor(discriminator(0u8))]
+        V1(OneResourcePoolSubstate),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct OneResourcePoolSubstate {
+        pub vault: Vault,
+        pub pool_unit_resource_manager: ResourceManager,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ResourceManager(pub ResourceAddress);
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ContributionEvent {
+        pub amount_of_resources_contributed: Decimal,
+        pub pool_units_minted: Decimal,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct RedemptionEvent {
+        pub pool_unit_tokens_redeemed: Decimal,
+        pub redeemed_amount: Decimal,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct WithdrawEvent {
+        pub amount: Decimal,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct DepositEvent {
+        pub amount: Decimal,
+    }
+    pub type Field0 = OneResourcePoolStateFieldPayload;
+}
 extern_blueprint_internal! {
     PackageAddress::new_or_panic([
         13u8, 144u8, 99u8, 24u8, 198u8, 49u8, 140u8, 96u8, 252u8, 198u8, 49u8, 140u8, 99u8, 24u8,
@@ -321,6 +719,45 @@ extern_blueprint_internal! {
         fn get_vault_amounts(&self) -> IndexMap<ResourceAddress, Decimal>;
     }
 }
+#[allow(dead_code, unused_imports)]
+pub mod two_resource_pool_view {
+    use super::*;
+    use scrypto::prelude::*;
+    pub const BLUEPRINT_NAME: &str = "TwoResourcePool";
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum TwoResourcePoolStateFieldPayload {
+        #[sbor(discriminator(0u8))]
+        V1(TwoResourcePoolSubstate),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct TwoResourcePoolSubstate {
+        pub vaults: Vec<(ResourceAddress, Vault)>,
+        pub pool_unit_resource_manager: ResourceManager,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ResourceManager(pub ResourceAddress);
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct ContributionEvent {
+        pub contributed_resources: IndexMap<ResourceAddress, Decimal>,
+        pub pool_units_minted: Decimal,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct RedemptionEvent {
+        pub pool_unit_tokens_redeemed: Decimal,
+        pub redeemed_resources: IndexMap<ResourceAddress, Decimal>,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct WithdrawEvent {
+        pub resource_address: ResourceAddress,
+        pub amount: Decimal,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct DepositEvent {
+        pub resource_address: ResourceAddress,
+        pub amount: Decimal,
+    }
+    pub type Field0 = TwoResourcePoolStateFieldPayload;
+}
 
 extern_blueprint_internal! {
     PackageAddress::new_or_panic([
@@ -385,3 +822,123 @@ extern_blueprint_internal! {
         fn mint_recovery_badges(&mut self, non_fungible_local_ids: Vec<NonFungibleLocalId>) -> Bucket;
     }
 }
+#[allow(dead_code, unused_imports)]
+pub mod access_controller_view {
+    use super::*;
+    use scrypto::prelude::*;
+    pub const BLUEPRINT_NAME: &str = "AccessController";
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum AccessControllerStateFieldPayload {
+        #[sbor(discriminator(0u8))]
+        V1(AccessControllerSubstate),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct AccessControllerSubstate {
+        pub controlled_asset: Vault,
+        pub timed_recovery_delay_in_minutes: Option<u32>,
+        pub recovery_badge: ResourceAddress,
+        pub state: (
+            PrimaryRoleLockingState,
+            PrimaryRoleRecoveryAttemptState,
+            PrimaryRoleBadgeWithdrawAttemptState,
+            RecoveryRoleRecoveryAttemptState,
+            RecoveryRoleBadgeWithdrawAttemptState,
+        ),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum PrimaryRoleLockingState {
+        #[sbor(discriminator(0u8))]
+        Unlocked,
+        #[sbor(discriminator(1u8))]
+        Locked,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum PrimaryRoleRecoveryAttemptState {
+        #[sbor(discriminator(0u8))]
+        NoRecoveryAttempt,
+        #[sbor(discriminator(1u8))]
+        RecoveryAttempt(RecoveryProposal),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct RecoveryProposal {
+        pub rule_set: RuleSet,
+        pub timed_recovery_delay_in_minutes: Option<u32>,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct RuleSet {
+        pub primary_role: AccessRule,
+        pub recovery_role: AccessRule,
+        pub confirmation_role: AccessRule,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum PrimaryRoleBadgeWithdrawAttemptState {
+        #[sbor(discriminator(0u8))]
+        NoBadgeWithdrawAttempt,
+        #[sbor(discriminator(1u8))]
+        BadgeWithdrawAttempt,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum RecoveryRoleRecoveryAttemptState {
+        #[sbor(discriminator(0u8))]
+        NoRecoveryAttempt,
+        #[sbor(discriminator(1u8))]
+        RecoveryAttempt(RecoveryRoleRecoveryState),
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum RecoveryRoleRecoveryState {
+        #[sbor(discriminator(0u8))]
+        UntimedRecovery(RecoveryProposal),
+        #[sbor(discriminator(1u8))]
+        TimedRecovery {
+            proposal: RecoveryProposal,
+            timed_recovery_allowed_after: Instant,
+        },
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum RecoveryRoleBadgeWithdrawAttemptState {
+        #[sbor(discriminator(0u8))]
+        NoBadgeWithdrawAttempt,
+        #[sbor(discriminator(1u8))]
+        BadgeWithdrawAttempt,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct InitiateRecoveryEvent {
+        pub proposer: Proposer,
+        pub proposal: RecoveryProposal,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub enum Proposer {
+        #[sbor(discriminator(0u8))]
+        Primary,
+        #[sbor(discriminator(1u8))]
+        Recovery,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct RuleSetUpdateEvent {
+        pub proposer: Proposer,
+        pub proposal: RecoveryProposal,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct CancelRecoveryProposalEvent {
+        pub proposer: Proposer,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct LockPrimaryRoleEvent();
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct UnlockPrimaryRoleEvent();
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct StopTimedRecoveryEvent();
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct InitiateBadgeWithdrawAttemptEvent {
+        pub proposer: Proposer,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct BadgeWithdrawEvent {
+        pub proposer: Proposer,
+    }
+    #[derive(:: scrypto :: prelude :: ScryptoSbor)]
+    pub struct CancelBadgeWithdrawAttemptEvent {
+        pub proposer: Proposer,
+    }
+    pub type Field0 = AccessControllerStateFieldPayload;
+}
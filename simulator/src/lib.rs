@@ -6,6 +6,8 @@ pub mod resim;
 pub mod rtmc;
 /// Radix transaction manifest decompiler CLI.
 pub mod rtmd;
+/// JSON Schema and TypeScript generation from package schemas.
+pub mod schema_export;
 /// Scrypto CLI.
 pub mod scrypto;
 /// Stubs Generator CLI.
@@ -1,14 +1,13 @@
 use radix_engine::types::{
-    AddressBech32Decoder, AddressBech32Encoder, ComponentAddress, NonFungibleGlobalId,
-    PackageAddress, ResourceAddress,
+    AddressBech32Decoder, ComponentAddress, NonFungibleGlobalId, PackageAddress, ResourceAddress,
 };
 use radix_engine_interface::{
     blueprints::resource::{require, AccessRule, ParseNonFungibleGlobalIdError},
     rule,
 };
 use sbor::rust::fmt;
+use std::convert::Infallible;
 use std::str::FromStr;
-use utils::ContextualDisplay;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AddressError {
@@ -24,42 +23,36 @@ impl fmt::Display for AddressError {
     }
 }
 
+/// A package address given on the command line, as hex or Bech32m, which is decoded on the network of the
+/// command once it's resolved.
 #[derive(Clone)]
-pub struct SimulatorPackageAddress(pub PackageAddress);
-
-impl From<SimulatorPackageAddress> for PackageAddress {
-    fn from(simulator_address: SimulatorPackageAddress) -> Self {
-        simulator_address.0
-    }
-}
-
-impl From<PackageAddress> for SimulatorPackageAddress {
-    fn from(address: PackageAddress) -> Self {
-        Self(address)
+pub struct SimulatorPackageAddress(pub String);
+
+impl SimulatorPackageAddress {
+    pub fn decode(
+        &self,
+        address_bech32_decoder: &AddressBech32Decoder,
+    ) -> Result<PackageAddress, AddressError> {
+        PackageAddress::try_from_hex(&self.0)
+            .or(PackageAddress::try_from_bech32(
+                address_bech32_decoder,
+                &self.0,
+            ))
+            .ok_or(AddressError::InvalidAddress(self.0.clone()))
     }
 }
 
 impl FromStr for SimulatorPackageAddress {
-    type Err = AddressError;
+    type Err = Infallible;
 
     fn from_str(address: &str) -> Result<Self, Self::Err> {
-        PackageAddress::try_from_hex(address)
-            .or(PackageAddress::try_from_bech32(
-                &AddressBech32Decoder::for_simulator(),
-                address,
-            ))
-            .ok_or(AddressError::InvalidAddress(address.to_string()))
-            .map(|x| Self(x))
+        Ok(Self(address.to_string()))
     }
 }
 
 impl fmt::Display for SimulatorPackageAddress {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        write!(
-            f,
-            "{}",
-            self.0.display(&AddressBech32Encoder::for_simulator())
-        )
+        write!(f, "{}", self.0)
     }
 }
 
@@ -69,42 +62,36 @@ impl fmt::Debug for SimulatorPackageAddress {
     }
 }
 
+/// A resource address given on the command line, as hex or Bech32m, which is decoded on the network of the
+/// command once it's resolved.
 #[derive(Clone)]
-pub struct SimulatorResourceAddress(pub ResourceAddress);
-
-impl From<SimulatorResourceAddress> for ResourceAddress {
-    fn from(simulator_address: SimulatorResourceAddress) -> Self {
-        simulator_address.0
-    }
-}
-
-impl From<ResourceAddress> for SimulatorResourceAddress {
-    fn from(address: ResourceAddress) -> Self {
-        Self(address)
+pub struct SimulatorResourceAddress(pub String);
+
+impl SimulatorResourceAddress {
+    pub fn decode(
+        &self,
+        address_bech32_decoder: &AddressBech32Decoder,
+    ) -> Result<ResourceAddress, AddressError> {
+        ResourceAddress::try_from_hex(&self.0)
+            .or(ResourceAddress::try_from_bech32(
+                address_bech32_decoder,
+                &self.0,
+            ))
+            .ok_or(AddressError::InvalidAddress(self.0.clone()))
     }
 }
 
 impl FromStr for SimulatorResourceAddress {
-    type Err = AddressError;
+    type Err = Infallible;
 
     fn from_str(address: &str) -> Result<Self, Self::Err> {
-        ResourceAddress::try_from_hex(address)
-            .or(ResourceAddress::try_from_bech32(
-                &AddressBech32Decoder::for_simulator(),
-                address,
-            ))
-            .ok_or(AddressError::InvalidAddress(address.to_string()))
-            .map(|x| Self(x))
+        Ok(Self(address.to_string()))
     }
 }
 
 impl fmt::Display for SimulatorResourceAddress {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        write!(
-            f,
-            "{}",
-            self.0.display(&AddressBech32Encoder::for_simulator())
-        )
+        write!(f, "{}", self.0)
     }
 }
 
@@ -114,42 +101,36 @@ impl fmt::Debug for SimulatorResourceAddress {
     }
 }
 
+/// A component address given on the command line, as hex or Bech32m, which is decoded on the network of the
+/// command once it's resolved.
 #[derive(Clone)]
-pub struct SimulatorComponentAddress(pub ComponentAddress);
-
-impl From<SimulatorComponentAddress> for ComponentAddress {
-    fn from(simulator_address: SimulatorComponentAddress) -> Self {
-        simulator_address.0
-    }
-}
-
-impl From<ComponentAddress> for SimulatorComponentAddress {
-    fn from(address: ComponentAddress) -> Self {
-        Self(address)
+pub struct SimulatorComponentAddress(pub String);
+
+impl SimulatorComponentAddress {
+    pub fn decode(
+        &self,
+        address_bech32_decoder: &AddressBech32Decoder,
+    ) -> Result<ComponentAddress, AddressError> {
+        ComponentAddress::try_from_hex(&self.0)
+            .or(ComponentAddress::try_from_bech32(
+                address_bech32_decoder,
+                &self.0,
+            ))
+            .ok_or(AddressError::InvalidAddress(self.0.clone()))
     }
 }
 
 impl FromStr for SimulatorComponentAddress {
-    type Err = AddressError;
+    type Err = Infallible;
 
     fn from_str(address: &str) -> Result<Self, Self::Err> {
-        ComponentAddress::try_from_hex(address)
-            .or(ComponentAddress::try_from_bech32(
-                &AddressBech32Decoder::for_simulator(),
-                address,
-            ))
-            .ok_or(AddressError::InvalidAddress(address.to_string()))
-            .map(|x| Self(x))
+        Ok(Self(address.to_string()))
     }
 }
 
 impl fmt::Display for SimulatorComponentAddress {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        write!(
-            f,
-            "{}",
-            self.0.display(&AddressBech32Encoder::for_simulator())
-        )
+        write!(f, "{}", self.0)
     }
 }
 
@@ -159,41 +140,31 @@ impl fmt::Debug for SimulatorComponentAddress {
     }
 }
 
+/// A non-fungible global id given on the command line, in its canonical string form, which is
+/// decoded on the network of the command once it's resolved.
 #[derive(Clone)]
-pub struct SimulatorNonFungibleGlobalId(pub NonFungibleGlobalId);
-
-impl From<SimulatorNonFungibleGlobalId> for NonFungibleGlobalId {
-    fn from(global_id: SimulatorNonFungibleGlobalId) -> Self {
-        global_id.0
-    }
-}
-
-impl From<NonFungibleGlobalId> for SimulatorNonFungibleGlobalId {
-    fn from(address: NonFungibleGlobalId) -> Self {
-        Self(address)
+pub struct SimulatorNonFungibleGlobalId(pub String);
+
+impl SimulatorNonFungibleGlobalId {
+    pub fn decode(
+        &self,
+        address_bech32_decoder: &AddressBech32Decoder,
+    ) -> Result<NonFungibleGlobalId, ParseNonFungibleGlobalIdError> {
+        NonFungibleGlobalId::try_from_canonical_string(address_bech32_decoder, &self.0)
     }
 }
 
 impl FromStr for SimulatorNonFungibleGlobalId {
-    type Err = ParseNonFungibleGlobalIdError;
+    type Err = Infallible;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let global_id = NonFungibleGlobalId::try_from_canonical_string(
-            &AddressBech32Decoder::for_simulator(),
-            s,
-        )?;
-        Ok(Self(global_id))
+        Ok(Self(s.to_string()))
     }
 }
 
 impl fmt::Display for SimulatorNonFungibleGlobalId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            self.0
-                .to_canonical_string(&AddressBech32Encoder::for_simulator())
-        )
+        write!(f, "{}", self.0)
     }
 }
 
@@ -209,43 +180,34 @@ pub enum SimulatorResourceOrNonFungibleGlobalId {
     NonFungibleGlobalId(SimulatorNonFungibleGlobalId),
 }
 
-impl From<SimulatorResourceAddress> for SimulatorResourceOrNonFungibleGlobalId {
-    fn from(address: SimulatorResourceAddress) -> Self {
-        Self::ResourceAddress(address)
-    }
-}
-
-impl From<SimulatorNonFungibleGlobalId> for SimulatorResourceOrNonFungibleGlobalId {
-    fn from(address: SimulatorNonFungibleGlobalId) -> Self {
-        Self::NonFungibleGlobalId(address)
-    }
-}
-
-impl From<SimulatorResourceOrNonFungibleGlobalId> for AccessRule {
-    fn from(address: SimulatorResourceOrNonFungibleGlobalId) -> Self {
-        match address {
-            SimulatorResourceOrNonFungibleGlobalId::ResourceAddress(resource_address) => {
-                rule!(require(resource_address.0))
+impl SimulatorResourceOrNonFungibleGlobalId {
+    /// Decodes the resource address or non-fungible global id, into a rule requiring it.
+    pub fn to_access_rule(
+        &self,
+        address_bech32_decoder: &AddressBech32Decoder,
+    ) -> Result<AccessRule, ParseSimulatorResourceOrNonFungibleGlobalIdError> {
+        match self {
+            Self::ResourceAddress(resource_address) => {
+                let resource_address = resource_address.decode(address_bech32_decoder)?;
+                Ok(rule!(require(resource_address)))
             }
-            SimulatorResourceOrNonFungibleGlobalId::NonFungibleGlobalId(non_fungible_global_id) => {
-                rule!(require(non_fungible_global_id.0))
+            Self::NonFungibleGlobalId(non_fungible_global_id) => {
+                let non_fungible_global_id =
+                    non_fungible_global_id.decode(address_bech32_decoder)?;
+                Ok(rule!(require(non_fungible_global_id)))
             }
         }
     }
 }
 
 impl FromStr for SimulatorResourceOrNonFungibleGlobalId {
-    type Err = ParseSimulatorResourceOrNonFungibleGlobalIdError;
+    type Err = Infallible;
 
     fn from_str(address: &str) -> Result<Self, Self::Err> {
         if address.contains(':') {
-            SimulatorNonFungibleGlobalId::from_str(address)
-                .map_err(ParseSimulatorResourceOrNonFungibleGlobalIdError::from)
-                .map(Self::NonFungibleGlobalId)
+            SimulatorNonFungibleGlobalId::from_str(address).map(Self::NonFungibleGlobalId)
         } else {
-            SimulatorResourceAddress::from_str(address)
-                .map_err(ParseSimulatorResourceOrNonFungibleGlobalIdError::from)
-                .map(Self::ResourceAddress)
+            SimulatorResourceAddress::from_str(address).map(Self::ResourceAddress)
         }
     }
 }
@@ -42,8 +42,12 @@ pub struct CallFunction {
 }
 
 impl CallFunction {
-    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
-        let address_bech32_decoder = AddressBech32Decoder::for_simulator();
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
+        let address_bech32_decoder = AddressBech32Decoder::new(network);
 
         let default_account = get_default_account()?;
         let proofs = self.proofs.clone().unwrap_or_default();
@@ -59,11 +63,15 @@ impl CallFunction {
             )
             .map_err(Error::FailedToBuildArguments)?;
         }
+        let package_address = self
+            .package_address
+            .decode(&address_bech32_decoder)
+            .map_err(Error::AddressError)?;
         let manifest = self
             .add_call_function_instruction_with_schema(
                 builder,
                 &address_bech32_decoder,
-                self.package_address.0,
+                package_address,
                 self.blueprint_name.clone(),
                 self.function_name.clone(),
                 self.arguments.clone(),
@@ -74,7 +82,7 @@ impl CallFunction {
         handle_manifest(
             manifest,
             &self.signing_keys,
-            &self.network,
+            network,
             &self.manifest,
             self.trace,
             true,
@@ -40,8 +40,12 @@ pub struct CallMethod {
 }
 
 impl CallMethod {
-    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
-        let address_bech32_decoder = AddressBech32Decoder::for_simulator();
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
+        let address_bech32_decoder = AddressBech32Decoder::new(network);
 
         let default_account = get_default_account()?;
         let proofs = self.proofs.clone().unwrap_or_default();
@@ -57,11 +61,15 @@ impl CallMethod {
             .map_err(Error::FailedToBuildArguments)?
         }
 
+        let component_address = self
+            .component_address
+            .decode(&address_bech32_decoder)
+            .map_err(Error::AddressError)?;
         let manifest = self
             .add_call_method_instruction_with_schema(
                 builder,
                 &address_bech32_decoder,
-                self.component_address.0,
+                component_address,
                 self.method_name.clone(),
                 self.arguments.clone(),
                 Some(default_account),
@@ -71,7 +79,7 @@ impl CallMethod {
         handle_manifest(
             manifest,
             &self.signing_keys,
-            &self.network,
+            network,
             &self.manifest,
             self.trace,
             true,
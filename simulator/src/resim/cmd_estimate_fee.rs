@@ -0,0 +1,131 @@
+use clap::Parser;
+use colored::*;
+use radix_engine::transaction::{
+    estimate_fee, FeeEstimationError, PreviewError, DEFAULT_FEE_ESTIMATION_MARGIN_PERCENTAGE,
+};
+use std::path::PathBuf;
+use transaction::manifest::BlobProvider;
+
+use crate::resim::*;
+
+/// Previews a transaction manifest (which does not lock any fee) and estimates the fee it should lock
+#[derive(Parser, Debug)]
+pub struct EstimateFee {
+    /// The path to a transaction manifest file
+    pub path: PathBuf,
+
+    /// The network to use when compiling manifest, [simulator | adapanet | nebunet | mainnet]
+    #[clap(short, long)]
+    pub network: Option<String>,
+
+    /// The paths to blobs
+    #[clap(short, long, multiple = true)]
+    pub blobs: Option<Vec<String>>,
+
+    /// The private keys of the signers, separated by comma
+    #[clap(short, long)]
+    pub signing_keys: Option<String>,
+
+    /// The margin to add on top of each of the previewed costs, in percent
+    #[clap(short, long, default_value_t = DEFAULT_FEE_ESTIMATION_MARGIN_PERCENTAGE)]
+    pub margin: u16,
+}
+
+impl EstimateFee {
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
+        let manifest = std::fs::read_to_string(&self.path).map_err(Error::IOError)?;
+        let pre_processed_manifest = Run::pre_process_manifest(&manifest);
+        let mut blobs = Vec::new();
+        if let Some(paths) = &self.blobs {
+            for path in paths {
+                blobs.push(std::fs::read(path).map_err(Error::IOError)?);
+            }
+        }
+        let compiled_manifest = transaction::manifest::compile(
+            &pre_processed_manifest,
+            network,
+            BlobProvider::new_with_blobs(blobs),
+        )
+        .map_err(Error::CompileError)?;
+
+        let signer_public_keys = get_signing_keys(&self.signing_keys)?
+            .into_iter()
+            .map(|private_key| private_key.public_key())
+            .collect();
+        let preview_intent = PreviewIntentV1 {
+            intent: IntentV1 {
+                header: TransactionHeaderV1 {
+                    network_id: network.id,
+                    start_epoch_inclusive: Epoch::zero(),
+                    end_epoch_exclusive: Epoch::of(1),
+                    nonce: get_nonce()?,
+                    notary_public_key: PublicKey::Secp256k1(Secp256k1PublicKey([0u8; 33])),
+                    notary_is_signatory: false,
+                    tip_percentage: 0,
+                },
+                instructions: InstructionsV1(compiled_manifest.instructions),
+                blobs: BlobsV1 {
+                    blobs: compiled_manifest
+                        .blobs
+                        .values()
+                        .map(|blob| BlobV1(blob.clone()))
+                        .collect(),
+                },
+                message: MessageV1::default(),
+            },
+            signer_public_keys,
+            flags: PreviewFlags {
+                use_free_credit: true,
+                assume_all_signature_proofs: false,
+                skip_epoch_check: true,
+            },
+        };
+
+        let SimulatorEnvironment {
+            db,
+            scrypto_vm,
+            native_vm,
+        } = SimulatorEnvironment::new()?;
+        let vm = Vm::new(&scrypto_vm, native_vm);
+
+        let estimate = estimate_fee(&db, vm, network, preview_intent, self.margin).map_err(
+            |error| match error {
+                FeeEstimationError::PreviewError(PreviewError::TransactionValidationError(
+                    error,
+                )) => Error::TransactionValidationError(error),
+                FeeEstimationError::TransactionRejected(reason) => {
+                    Error::TransactionRejected(reason)
+                }
+                FeeEstimationError::TransactionAborted(reason) => Error::TransactionAborted(reason),
+                FeeEstimationError::TransactionFailed(error) => Error::TransactionFailed(error),
+            },
+        )?;
+
+        writeln!(
+            out,
+            "Previewed costs, with {}% margin:",
+            estimate.margin_percentage
+        )
+        .map_err(Error::IOError)?;
+        for (name, cost) in [
+            ("Execution", estimate.execution_cost_in_xrd),
+            ("Finalization", estimate.finalization_cost_in_xrd),
+            ("Storage", estimate.storage_cost_in_xrd),
+            ("Royalty", estimate.royalty_cost_in_xrd),
+            ("Tipping", estimate.tipping_cost_in_xrd),
+        ] {
+            writeln!(out, "├─ {}: {} XRD", name, cost).map_err(Error::IOError)?;
+        }
+        writeln!(
+            out,
+            "Recommended lock fee: {}",
+            estimate.recommended_lock_fee().to_string().green()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
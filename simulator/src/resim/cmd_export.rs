@@ -0,0 +1,52 @@
+use clap::Parser;
+use radix_engine::types::*;
+use radix_engine_store_interface::interface::{
+    DbPartitionKey, ListableSubstateDatabase, PartitionEntry,
+};
+use std::path::PathBuf;
+
+use crate::resim::*;
+
+/// A portable snapshot of the simulator's ledger (i.e. all substates of all partitions) and its
+/// configs.
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct LedgerSnapshot {
+    pub configs: Configs,
+    pub partitions: Vec<(DbPartitionKey, Vec<PartitionEntry>)>,
+}
+
+/// Export the ledger and configs of this simulator into a snapshot file
+#[derive(Parser, Debug)]
+pub struct Export {
+    /// The path to write the snapshot to
+    pub path: PathBuf,
+}
+
+impl Export {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let SimulatorEnvironment { db, .. } = SimulatorEnvironment::new()?;
+
+        let partitions = db
+            .list_partition_keys()
+            .map(|partition_key| {
+                let entries = db.list_entries(&partition_key).collect();
+                (partition_key, entries)
+            })
+            .collect::<Vec<_>>();
+        let snapshot = LedgerSnapshot {
+            configs: get_configs()?,
+            partitions,
+        };
+        let bytes = scrypto_encode(&snapshot).map_err(Error::SborEncodeError)?;
+        std::fs::write(&self.path, bytes).map_err(Error::IOError)?;
+
+        writeln!(
+            out,
+            "Exported {} partitions to {}",
+            snapshot.partitions.len(),
+            self.path.display()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
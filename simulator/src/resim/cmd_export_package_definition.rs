@@ -18,8 +18,16 @@ pub struct ExportPackageDefinition {
 }
 
 impl ExportPackageDefinition {
-    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
-        match export_package_schema(self.package_address.0) {
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
+        let package_address = self
+            .package_address
+            .decode(&AddressBech32Decoder::new(network))
+            .map_err(Error::AddressError)?;
+        match export_package_schema(package_address) {
             Ok(schema) => {
                 fs::write(
                     &self.output,
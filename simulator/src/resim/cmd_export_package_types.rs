@@ -0,0 +1,71 @@
+use clap::Parser;
+use radix_engine::system::system_db_reader::SystemDatabaseReader;
+use radix_engine::types::*;
+
+use crate::resim::*;
+use crate::schema_export::json_schema::blueprint_json_schema;
+use crate::schema_export::package_types_from_package_definition;
+use crate::schema_export::typescript::package_typescript;
+use crate::scrypto_bindgen::SchemaResolver;
+
+/// Export the types of a package as JSON Schema and TypeScript
+#[derive(Parser, Debug)]
+pub struct ExportPackageTypes {
+    /// The package ID
+    pub package_address: SimulatorPackageAddress,
+
+    /// The output directory
+    pub output: PathBuf,
+
+    /// Turn on tracing.
+    #[clap(short, long)]
+    pub trace: bool,
+}
+
+impl ExportPackageTypes {
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
+        let package_address = self
+            .package_address
+            .decode(&AddressBech32Decoder::new(network))
+            .map_err(Error::AddressError)?;
+        let SimulatorEnvironment { db, .. } = SimulatorEnvironment::new()?;
+
+        let package_definition =
+            SystemDatabaseReader::new(&db).get_package_definition(package_address);
+        let schema_resolver = SchemaResolver::new(package_address, &db);
+        let package_types =
+            package_types_from_package_definition(package_definition, &schema_resolver)
+                .map_err(Error::SchemaExportError)?;
+
+        fs::create_dir_all(&self.output).map_err(Error::IOError)?;
+        for (blueprint_name, blueprint_types) in package_types.blueprints.iter() {
+            let document = blueprint_json_schema(
+                blueprint_name,
+                blueprint_types,
+                &package_types.definitions,
+                &schema_resolver,
+            )
+            .map_err(Error::SchemaExportError)?;
+            fs::write(
+                self.output.join(format!("{blueprint_name}.schema.json")),
+                serde_json::to_string_pretty(&document).unwrap(),
+            )
+            .map_err(Error::IOError)?;
+        }
+        let typescript = package_typescript(&package_types, &schema_resolver)
+            .map_err(Error::SchemaExportError)?;
+        fs::write(self.output.join("types.ts"), typescript).map_err(Error::IOError)?;
+
+        writeln!(
+            out,
+            "Package types exported to {}",
+            self.output.to_str().unwrap()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
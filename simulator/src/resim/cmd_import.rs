@@ -0,0 +1,70 @@
+use clap::Parser;
+use radix_engine::types::*;
+use radix_engine_store_interface::interface::{
+    CommittableSubstateDatabase, DatabaseUpdates, ListableSubstateDatabase,
+    PartitionDatabaseUpdates,
+};
+use std::path::PathBuf;
+use utils::prelude::index_map_new;
+
+use crate::resim::*;
+
+/// Replace the ledger and configs of this simulator with the ones from a snapshot file
+#[derive(Parser, Debug)]
+pub struct Import {
+    /// The path to a snapshot file, as created by `resim export`
+    pub path: PathBuf,
+}
+
+impl Import {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let bytes = std::fs::read(&self.path).map_err(Error::IOError)?;
+        let snapshot: LedgerSnapshot = scrypto_decode(&bytes).map_err(Error::SborDecodeError)?;
+
+        let SimulatorEnvironment { mut db, .. } = SimulatorEnvironment::new()?.reset()?;
+
+        // Partitions which are not part of the snapshot (e.g. created by a newer bootstrap logic)
+        // are cleared, so that the resulting ledger matches the snapshot exactly.
+        let mut database_updates = DatabaseUpdates::default();
+        for partition_key in db.list_partition_keys() {
+            database_updates
+                .node_updates
+                .entry(partition_key.node_key)
+                .or_default()
+                .partition_updates
+                .insert(
+                    partition_key.partition_num,
+                    PartitionDatabaseUpdates::Reset {
+                        new_substate_values: index_map_new(),
+                    },
+                );
+        }
+        let partition_count = snapshot.partitions.len();
+        for (partition_key, entries) in snapshot.partitions {
+            database_updates
+                .node_updates
+                .entry(partition_key.node_key)
+                .or_default()
+                .partition_updates
+                .insert(
+                    partition_key.partition_num,
+                    PartitionDatabaseUpdates::Reset {
+                        new_substate_values: entries.into_iter().collect(),
+                    },
+                );
+        }
+        db.commit(&database_updates);
+        drop(db);
+
+        set_configs(&snapshot.configs)?;
+
+        writeln!(
+            out,
+            "Imported {} partitions from {}",
+            partition_count,
+            self.path.display()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
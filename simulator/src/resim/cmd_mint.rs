@@ -35,8 +35,12 @@ pub struct Mint {
 }
 
 impl Mint {
-    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
-        let address_bech32_decoder = AddressBech32Decoder::for_simulator();
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
+        let address_bech32_decoder = AddressBech32Decoder::new(network);
 
         let default_account = get_default_account()?;
         let proofs = self.proofs.clone().unwrap_or_default();
@@ -51,14 +55,18 @@ impl Mint {
             )
             .map_err(Error::FailedToBuildArguments)?;
         }
+        let resource_address = self
+            .resource_address
+            .decode(&address_bech32_decoder)
+            .map_err(Error::AddressError)?;
         let manifest = builder
-            .mint_fungible(self.resource_address.0, self.amount)
+            .mint_fungible(resource_address, self.amount)
             .try_deposit_entire_worktop_or_refund(default_account, None)
             .build();
         handle_manifest(
             manifest,
             &self.signing_keys,
-            &self.network,
+            network,
             &self.manifest,
             self.trace,
             true,
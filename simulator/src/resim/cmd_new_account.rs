@@ -31,7 +31,11 @@ pub struct NewAccount {
 struct EmptyStruct;
 
 impl NewAccount {
-    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
         let secret = rand::thread_rng().gen::<[u8; 32]>();
         let private_key = Secp256k1PrivateKey::from_bytes(&secret).unwrap();
         let public_key = private_key.public_key();
@@ -45,14 +49,14 @@ impl NewAccount {
         let receipt = handle_manifest(
             manifest,
             &Some("".to_string()), // explicit empty signer public keys
-            &self.network,
+            network,
             &self.manifest,
             self.trace,
             false,
             out,
         )?;
 
-        let address_bech32_encoder = AddressBech32Encoder::new(&NetworkDefinition::simulator());
+        let address_bech32_encoder = AddressBech32Encoder::new(network);
 
         if let Some(ref receipt) = receipt {
             let commit_result = receipt.expect_commit(true);
@@ -83,7 +87,7 @@ impl NewAccount {
             let receipt = handle_manifest(
                 manifest,
                 &Some("".to_string()), // explicit empty signer public keys
-                &self.network,
+                network,
                 &None,
                 self.trace,
                 false,
@@ -113,7 +117,7 @@ impl NewAccount {
                 out,
                 "Owner badge: {}",
                 owner_badge
-                    .to_canonical_string(&AddressBech32Encoder::for_simulator())
+                    .to_canonical_string(&address_bech32_encoder)
                     .green()
             )
             .map_err(Error::IOError)?;
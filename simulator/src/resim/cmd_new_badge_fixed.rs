@@ -49,7 +49,11 @@ pub struct NewBadgeFixed {
 }
 
 impl NewBadgeFixed {
-    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
         let default_account = get_default_account()?;
         let mut metadata = BTreeMap::new();
         if let Some(symbol) = self.symbol.clone() {
@@ -90,7 +94,7 @@ impl NewBadgeFixed {
         handle_manifest(
             manifest,
             &self.signing_keys,
-            &self.network,
+            network,
             &self.manifest,
             self.trace,
             true,
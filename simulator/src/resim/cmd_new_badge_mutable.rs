@@ -49,7 +49,11 @@ pub struct NewBadgeMutable {
 }
 
 impl NewBadgeMutable {
-    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
         let mut metadata = BTreeMap::new();
         if let Some(symbol) = self.symbol.clone() {
             metadata.insert("symbol".to_string(), MetadataValue::String(symbol));
@@ -81,14 +85,18 @@ impl NewBadgeMutable {
             roles: RoleAssignmentInit::default(),
         };
 
+        let minter_rule = self
+            .minter_badge
+            .to_access_rule(&AddressBech32Decoder::new(network))
+            .map_err(Error::ResourceOrNonFungibleGlobalIdError)?;
         let manifest = ManifestBuilder::new()
             .lock_fee_from_faucet()
-            .new_badge_mutable(metadata, self.minter_badge.clone().into())
+            .new_badge_mutable(metadata, minter_rule)
             .build();
         handle_manifest(
             manifest,
             &self.signing_keys,
-            &self.network,
+            network,
             &self.manifest,
             self.trace,
             true,
@@ -54,9 +54,9 @@ pub struct NewSimpleBadge {
 impl NewSimpleBadge {
     pub fn run<O: std::io::Write>(
         &self,
+        network: &NetworkDefinition,
         out: &mut O,
     ) -> Result<Option<NonFungibleGlobalId>, Error> {
-        let network_definition = NetworkDefinition::simulator();
         let default_account = get_default_account()?;
         let mut metadata = MetadataInit::new();
         if let Some(symbol) = self.symbol.clone() {
@@ -95,7 +95,7 @@ impl NewSimpleBadge {
         let receipt = handle_manifest(
             manifest,
             &self.signing_keys,
-            &self.network,
+            network,
             &self.manifest,
             self.trace,
             false,
@@ -106,7 +106,7 @@ impl NewSimpleBadge {
         if let Some(receipt) = receipt {
             let resource_address = receipt.expect_commit(true).new_resource_addresses()[0];
 
-            let address_bech32_encoder = AddressBech32Encoder::new(&network_definition);
+            let address_bech32_encoder = AddressBech32Encoder::new(network);
             writeln!(
                 out,
                 "NonFungibleGlobalId: {}",
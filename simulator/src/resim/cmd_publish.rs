@@ -42,6 +42,10 @@ pub struct Publish {
     #[clap(short, long)]
     pub manifest: Option<PathBuf>,
 
+    /// The private keys used for signing, separated by comma
+    #[clap(short, long)]
+    pub signing_keys: Option<String>,
+
     /// Turn on tracing
     #[clap(short, long)]
     pub trace: bool,
@@ -57,7 +61,11 @@ pub struct Publish {
 }
 
 impl Publish {
-    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
         // Load wasm code
         let (code_path, definition_path) = if self.path.extension() != Some(OsStr::new("wasm")) {
             build_package(
@@ -82,10 +90,14 @@ impl Publish {
         )
         .map_err(Error::SborDecodeError)?;
 
-        if let Some(package_address) = self.package_address.clone() {
+        let address_bech32_decoder = AddressBech32Decoder::new(network);
+        if let Some(package_address) = &self.package_address {
+            let package_address = package_address
+                .decode(&address_bech32_decoder)
+                .map_err(Error::AddressError)?;
             let SimulatorEnvironment { mut db, .. } = SimulatorEnvironment::new()?;
 
-            let node_id: NodeId = package_address.0.into();
+            let node_id: NodeId = package_address.into();
 
             let code_hash = CodeHash::from(hash(&code));
             let blueprints_partition_key = SpreadPrefixKeyMapper::to_db_partition_key(
@@ -308,11 +320,12 @@ impl Publish {
 
             writeln!(out, "Package updated!").map_err(Error::IOError)?;
         } else {
-            let owner_badge_non_fungible_global_id = self
-                .owner_badge
-                .clone()
-                .map(|owner_badge| owner_badge.0)
-                .unwrap_or(get_default_owner_badge()?);
+            let owner_badge_non_fungible_global_id = match &self.owner_badge {
+                Some(owner_badge) => owner_badge
+                    .decode(&address_bech32_decoder)
+                    .map_err(Error::NonFungibleGlobalIdError)?,
+                None => get_default_owner_badge()?,
+            };
 
             let manifest = ManifestBuilder::new()
                 .lock_fee_from_faucet()
@@ -325,8 +338,8 @@ impl Publish {
 
             let receipt = handle_manifest(
                 manifest,
-                &None,
-                &self.network,
+                &self.signing_keys,
+                network,
                 &self.manifest,
                 self.trace,
                 false,
@@ -337,7 +350,7 @@ impl Publish {
                     out,
                     "Success! New Package: {}",
                     receipt.expect_commit(true).new_package_addresses()[0]
-                        .display(&AddressBech32Encoder::for_simulator())
+                        .display(&AddressBech32Encoder::new(network))
                         .to_string()
                         .green()
                 )
@@ -39,13 +39,13 @@ impl Run {
         .into()
     }
 
-    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
         let manifest = std::fs::read_to_string(&self.path).map_err(Error::IOError)?;
         let pre_processed_manifest = Self::pre_process_manifest(&manifest);
-        let network = match &self.network {
-            Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
-            None => NetworkDefinition::simulator(),
-        };
         let mut blobs = Vec::new();
         if let Some(paths) = &self.blobs {
             for path in paths {
@@ -54,7 +54,7 @@ impl Run {
         }
         let compiled_manifest = transaction::manifest::compile(
             &pre_processed_manifest,
-            &network,
+            network,
             BlobProvider::new_with_blobs(blobs),
         )
         .map_err(Error::CompileError)?;
@@ -65,7 +65,7 @@ impl Run {
         handle_manifest(
             compiled_manifest,
             &self.signing_keys,
-            &self.network,
+            network,
             &None,
             self.trace,
             true,
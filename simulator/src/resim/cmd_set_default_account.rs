@@ -17,7 +17,11 @@ pub struct SetDefaultAccount {
 }
 
 impl SetDefaultAccount {
-    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
         let mut configs = get_configs()?;
         let private_key = parse_private_key_from_str(&self.private_key).map_err(|e| {
             if Secp256k1PublicKey::from_str(&self.private_key).is_ok() {
@@ -26,9 +30,18 @@ impl SetDefaultAccount {
                 e
             }
         })?;
-        configs.default_account = Some(self.component_address.0);
+        let address_bech32_decoder = AddressBech32Decoder::new(network);
+        configs.default_account = Some(
+            self.component_address
+                .decode(&address_bech32_decoder)
+                .map_err(Error::AddressError)?,
+        );
         configs.default_private_key = Some(private_key.to_hex());
-        configs.default_owner_badge = Some(self.owner_badge.clone().0);
+        configs.default_owner_badge = Some(
+            self.owner_badge
+                .decode(&address_bech32_decoder)
+                .map_err(Error::NonFungibleGlobalIdError)?,
+        );
         set_configs(&configs)?;
 
         writeln!(out, "Default account updated!").map_err(Error::IOError)?;
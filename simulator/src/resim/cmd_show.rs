@@ -11,17 +11,32 @@ pub struct Show {
 }
 
 impl Show {
-    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
         let SimulatorEnvironment { db, .. } = SimulatorEnvironment::new()?;
+        let address_bech32_decoder = AddressBech32Decoder::new(network);
+        let address_bech32_encoder = AddressBech32Encoder::new(network);
 
         match &self.address {
             Some(address) => {
-                if let Ok(a) = SimulatorPackageAddress::from_str(address) {
-                    dump_package(a.0, &db, out).map_err(Error::LedgerDumpError)
-                } else if let Ok(a) = SimulatorComponentAddress::from_str(address) {
-                    dump_component(a.0, &db, out).map_err(Error::LedgerDumpError)
-                } else if let Ok(a) = SimulatorResourceAddress::from_str(address) {
-                    dump_resource_manager(a.0, &db, out).map_err(Error::LedgerDumpError)
+                if let Ok(a) =
+                    SimulatorPackageAddress(address.clone()).decode(&address_bech32_decoder)
+                {
+                    dump_package(a, &db, &address_bech32_encoder, out)
+                        .map_err(Error::LedgerDumpError)
+                } else if let Ok(a) =
+                    SimulatorComponentAddress(address.clone()).decode(&address_bech32_decoder)
+                {
+                    dump_component(a, &db, &address_bech32_encoder, out)
+                        .map_err(Error::LedgerDumpError)
+                } else if let Ok(a) =
+                    SimulatorResourceAddress(address.clone()).decode(&address_bech32_decoder)
+                {
+                    dump_resource_manager(a, &db, &address_bech32_encoder, out)
+                        .map_err(Error::LedgerDumpError)
                 } else {
                     Err(Error::InvalidId(address.clone()))
                 }
@@ -32,7 +47,10 @@ impl Show {
                         EntityDumpError::NoAddressProvidedAndNotDefaultAccountSet,
                     ))
                 })
-                .and_then(|x| dump_component(x, &db, out).map_err(Error::LedgerDumpError)),
+                .and_then(|x| {
+                    dump_component(x, &db, &address_bech32_encoder, out)
+                        .map_err(Error::LedgerDumpError)
+                }),
         }
     }
 }
@@ -9,17 +9,19 @@ use crate::resim::*;
 pub struct ShowConfigs {}
 
 impl ShowConfigs {
-    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
         let configs = get_configs()?;
+        let address_bech32_encoder = AddressBech32Encoder::new(network);
         writeln!(
             out,
             "{}: {}",
             "Account Address".green().bold(),
             match configs.default_account {
-                Some(component) => format!(
-                    "{}",
-                    component.display(&AddressBech32Encoder::for_simulator()),
-                ),
+                Some(component) => format!("{}", component.display(&address_bech32_encoder),),
                 None => "None".to_owned(),
             }
         )
@@ -39,10 +41,7 @@ impl ShowConfigs {
             "{}: {}",
             "Account Owner Badge".green().bold(),
             match configs.default_owner_badge {
-                Some(owner_badge) => format!(
-                    "{}",
-                    owner_badge.display(&AddressBech32Encoder::for_simulator())
-                ),
+                Some(owner_badge) => format!("{}", owner_badge.display(&address_bech32_encoder)),
                 None => "None".to_owned(),
             }
         )
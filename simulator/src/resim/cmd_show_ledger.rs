@@ -18,13 +18,17 @@ use crate::resim::*;
 pub struct ShowLedger {}
 
 impl ShowLedger {
-    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
         {
             let SimulatorEnvironment { db, .. } = SimulatorEnvironment::new()?;
-            Self::list_entries(out, &db)?;
+            Self::list_entries(out, &db, network)?;
         }
 
-        let current_epoch = Self::get_current_epoch(out)?;
+        let current_epoch = Self::get_current_epoch(network, out)?;
         writeln!(
             out,
             "{}: {}",
@@ -33,7 +37,7 @@ impl ShowLedger {
         )
         .map_err(Error::IOError)?;
 
-        let instant = Self::get_current_time(out, TimePrecisionV1::Minute)?;
+        let instant = Self::get_current_time(network, out, TimePrecisionV1::Minute)?;
         let date_time = UtcDateTime::from_instant(&instant).unwrap();
         writeln!(
             out,
@@ -49,8 +53,9 @@ impl ShowLedger {
     pub fn list_entries<O: std::io::Write>(
         out: &mut O,
         substate_db: &RocksdbSubstateStore,
+        network: &NetworkDefinition,
     ) -> Result<(), Error> {
-        let address_bech32_encoder = AddressBech32Encoder::new(&NetworkDefinition::simulator());
+        let address_bech32_encoder = AddressBech32Encoder::new(network);
         let mut packages: Vec<PackageAddress> = vec![];
         let mut components: Vec<ComponentAddress> = vec![];
         let mut resources: Vec<ResourceAddress> = vec![];
@@ -105,7 +110,10 @@ impl ShowLedger {
         Ok(())
     }
 
-    pub fn get_current_epoch<O: std::io::Write>(out: &mut O) -> Result<Epoch, Error> {
+    pub fn get_current_epoch<O: std::io::Write>(
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<Epoch, Error> {
         let instructions = vec![InstructionV1::CallMethod {
             address: CONSENSUS_MANAGER.into(),
             method_name: CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT.to_string(),
@@ -113,12 +121,20 @@ impl ShowLedger {
         }];
         let blobs = vec![];
         let initial_proofs = btreeset![];
-        let receipt =
-            handle_system_transaction(instructions, blobs, initial_proofs, false, false, out)?;
+        let receipt = handle_system_transaction(
+            instructions,
+            blobs,
+            initial_proofs,
+            network,
+            false,
+            false,
+            out,
+        )?;
         Ok(receipt.expect_commit(true).output(0))
     }
 
     pub fn get_current_time<O: std::io::Write>(
+        network: &NetworkDefinition,
         out: &mut O,
         precision: TimePrecisionV1,
     ) -> Result<Instant, Error> {
@@ -131,8 +147,15 @@ impl ShowLedger {
         }];
         let blobs = vec![];
         let initial_proofs = btreeset![];
-        let receipt =
-            handle_system_transaction(instructions, blobs, initial_proofs, false, false, out)?;
+        let receipt = handle_system_transaction(
+            instructions,
+            blobs,
+            initial_proofs,
+            network,
+            false,
+            false,
+            out,
+        )?;
         Ok(receipt.expect_commit(true).output(0))
     }
 }
@@ -0,0 +1,120 @@
+use clap::Parser;
+use colored::*;
+use radix_engine::utils::validate_call_arguments_to_native_components;
+use std::path::PathBuf;
+use transaction::manifest::BlobProvider;
+
+use crate::resim::*;
+
+/// Compiles a transaction manifest into a notarized transaction, signed by the given keys, which
+/// can be executed later with `resim submit`
+#[derive(Parser, Debug)]
+pub struct Sign {
+    /// The path to a transaction manifest file
+    pub path: PathBuf,
+
+    /// The path to write the notarized transaction to
+    #[clap(short, long)]
+    pub output: PathBuf,
+
+    /// The network to use when compiling manifest, [simulator | adapanet | nebunet | mainnet]
+    #[clap(short, long)]
+    pub network: Option<String>,
+
+    /// The paths to blobs
+    #[clap(short, long, multiple = true)]
+    pub blobs: Option<Vec<String>>,
+
+    /// The private keys used for signing, separated by comma
+    #[clap(short, long)]
+    pub signing_keys: Option<String>,
+
+    /// The private key of the notary, which defaults to the default account's key
+    #[clap(long)]
+    pub notary_key: Option<String>,
+
+    /// Whether the notary should be counted as a signatory
+    #[clap(long)]
+    pub notary_is_signatory: bool,
+
+    /// The number of epochs, starting from the current one, in which the transaction is valid
+    #[clap(long, default_value_t = 10)]
+    pub valid_for_epochs: u64,
+}
+
+impl Sign {
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
+        let manifest = std::fs::read_to_string(&self.path).map_err(Error::IOError)?;
+        let pre_processed_manifest = Run::pre_process_manifest(&manifest);
+        let mut blobs = Vec::new();
+        if let Some(paths) = &self.blobs {
+            for path in paths {
+                blobs.push(std::fs::read(path).map_err(Error::IOError)?);
+            }
+        }
+        let compiled_manifest = transaction::manifest::compile(
+            &pre_processed_manifest,
+            network,
+            BlobProvider::new_with_blobs(blobs),
+        )
+        .map_err(Error::CompileError)?;
+
+        validate_call_arguments_to_native_components(&compiled_manifest.instructions)
+            .map_err(Error::InstructionSchemaValidationError)?;
+
+        let signing_keys = get_signing_keys(&self.signing_keys)?;
+        let notary_key = match &self.notary_key {
+            Some(key) => parse_signing_key_from_str(key.trim())?,
+            None => get_default_private_key()?.into(),
+        };
+        let current_epoch = ShowLedger::get_current_epoch(network, out)?;
+        let header = TransactionHeaderV1 {
+            network_id: network.id,
+            start_epoch_inclusive: current_epoch,
+            end_epoch_exclusive: Epoch::of(
+                current_epoch.number().saturating_add(self.valid_for_epochs),
+            ),
+            nonce: get_nonce()?,
+            notary_public_key: notary_key.public_key(),
+            notary_is_signatory: self.notary_is_signatory,
+            tip_percentage: 0,
+        };
+
+        let transaction = TransactionBuilder::new()
+            .header(header)
+            .manifest(compiled_manifest)
+            .multi_sign(&signing_keys.iter().collect::<Vec<_>>())
+            .notarize(&notary_key)
+            .build();
+        let payload = transaction
+            .to_payload_bytes()
+            .map_err(Error::SborEncodeError)?;
+        std::fs::write(&self.output, payload).map_err(Error::IOError)?;
+
+        let intent_hash = transaction
+            .prepare()
+            .map_err(Error::TransactionPrepareError)?
+            .intent_hash();
+        writeln!(
+            out,
+            "Transaction intent hash: {}",
+            TransactionHashBech32Encoder::new(network)
+                .encode(&intent_hash)
+                .unwrap()
+                .green()
+        )
+        .map_err(Error::IOError)?;
+        writeln!(
+            out,
+            "Notarized transaction, signed by {} key(s), written to {}",
+            signing_keys.len(),
+            self.output.display()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
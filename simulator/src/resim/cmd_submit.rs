@@ -0,0 +1,63 @@
+use clap::Parser;
+use std::path::PathBuf;
+use transaction::validation::{
+    NotarizedTransactionValidator, TransactionValidator, ValidationConfig,
+};
+
+use crate::resim::*;
+
+/// Validates and executes a notarized transaction, as created by `resim sign`
+#[derive(Parser, Debug)]
+pub struct Submit {
+    /// The path to a notarized transaction file
+    pub path: PathBuf,
+
+    /// The network the transaction has been created for, [simulator | adapanet | nebunet | mainnet]
+    #[clap(short, long)]
+    pub network: Option<String>,
+
+    /// Turn on tracing
+    #[clap(short, long)]
+    pub trace: bool,
+}
+
+impl Submit {
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
+        let payload = std::fs::read(&self.path).map_err(Error::IOError)?;
+        let validated = NotarizedTransactionValidator::new(ValidationConfig::default(network.id))
+            .validate_from_payload_bytes(&payload)
+            .map_err(Error::TransactionValidationError)?;
+
+        let SimulatorEnvironment {
+            mut db,
+            scrypto_vm,
+            native_vm,
+        } = SimulatorEnvironment::new()?;
+        let vm = Vm::new(&scrypto_vm, native_vm);
+
+        let receipt = execute_and_commit_transaction(
+            &mut db,
+            vm,
+            &CostingParameters::default(),
+            &ExecutionConfig::for_notarized_transaction(network.clone())
+                .with_kernel_trace(self.trace),
+            &validated.get_executable(),
+        );
+
+        let encoder = AddressBech32Encoder::new(network);
+        let display_context = TransactionReceiptDisplayContextBuilder::new()
+            .encoder(&encoder)
+            .schema_lookup_callback(|event_type_identifier: &EventTypeIdentifier| {
+                get_event_schema(&db, event_type_identifier)
+            })
+            .build();
+        writeln!(out, "{}", receipt.display(display_context)).map_err(Error::IOError)?;
+        drop(db);
+
+        process_receipt(receipt).map(|_| ())
+    }
+}
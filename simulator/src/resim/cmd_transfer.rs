@@ -35,8 +35,12 @@ pub struct Transfer {
 }
 
 impl Transfer {
-    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
-        let address_bech32_decoder = AddressBech32Decoder::for_simulator();
+    pub fn run<O: std::io::Write>(
+        &self,
+        network: &NetworkDefinition,
+        out: &mut O,
+    ) -> Result<(), Error> {
+        let address_bech32_decoder = AddressBech32Decoder::new(network);
 
         let default_account = get_default_account()?;
         let proofs = self.proofs.clone().unwrap_or_default();
@@ -65,12 +69,17 @@ impl Transfer {
             }
         };
         let manifest = builder
-            .try_deposit_entire_worktop_or_refund(self.recipient.0, None)
+            .try_deposit_entire_worktop_or_refund(
+                self.recipient
+                    .decode(&address_bech32_decoder)
+                    .map_err(Error::AddressError)?,
+                None,
+            )
             .build();
         handle_manifest(
             manifest,
             &self.signing_keys,
-            &self.network,
+            network,
             &self.manifest,
             self.trace,
             true,
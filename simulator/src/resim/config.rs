@@ -7,6 +7,7 @@ use radix_engine_store_interface::db_key_mapper::*;
 use radix_engine_store_interface::interface::*;
 
 use crate::resim::*;
+use crate::utils::resolve_network;
 use std::env;
 
 /// The environment that the simulator runs in.
@@ -70,6 +71,16 @@ impl SimulatorEnvironment {
             let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
             self.db.commit(&db_updates);
         }
+        {
+            let state_updates = generate_vesting_package_state_updates();
+            let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
+            self.db.commit(&db_updates);
+        }
+        {
+            let state_updates = generate_account_v1_1_state_updates(&self.db);
+            let db_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
+            self.db.commit(&db_updates);
+        }
     }
 }
 
@@ -139,3 +150,17 @@ pub fn get_default_owner_badge() -> Result<NonFungibleGlobalId, Error> {
 pub fn get_nonce() -> Result<u32, Error> {
     Ok(get_configs()?.nonce)
 }
+
+/// The network given by the `--network` of a command, or else the custom network of the global
+/// `--network-config`, or else that of the `NETWORK_CONFIG` environment variable, or else the
+/// simulator network.
+pub fn get_network(
+    network: &Option<String>,
+    network_config: &Option<PathBuf>,
+) -> Result<NetworkDefinition, Error> {
+    match network {
+        Some(_) => resolve_network(network, &None),
+        None => resolve_network(&None, network_config),
+    }
+    .map_err(Error::NetworkConfigError)
+}
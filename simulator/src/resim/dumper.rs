@@ -5,7 +5,6 @@ use radix_engine::blueprints::resource::*;
 use radix_engine::system::system_db_reader::SystemDatabaseReader;
 use radix_engine::types::*;
 use radix_engine_interface::api::ModuleId;
-use radix_engine_interface::network::NetworkDefinition;
 use radix_engine_queries::query::ResourceAccounter;
 use radix_engine_queries::typed_substate_layout::*;
 use radix_engine_store_interface::{
@@ -29,9 +28,9 @@ pub enum EntityDumpError {
 pub fn dump_package<T: SubstateDatabase, O: std::io::Write>(
     package_address: PackageAddress,
     substate_db: &T,
+    address_bech32_encoder: &AddressBech32Encoder,
     output: &mut O,
 ) -> Result<(), EntityDumpError> {
-    let address_bech32_encoder = AddressBech32Encoder::new(&NetworkDefinition::simulator());
     let (_, substate) = substate_db
         .list_mapped::<SpreadPrefixKeyMapper, PackageCodeOriginalCodeEntrySubstate, MapKey>(
             package_address.as_node_id(),
@@ -44,7 +43,7 @@ pub fn dump_package<T: SubstateDatabase, O: std::io::Write>(
         output,
         "{}: {}",
         "Package Address".green().bold(),
-        package_address.display(&address_bech32_encoder)
+        package_address.display(address_bech32_encoder)
     );
     writeln!(
         output,
@@ -66,10 +65,9 @@ pub fn dump_package<T: SubstateDatabase, O: std::io::Write>(
 pub fn dump_component<T: SubstateDatabase, O: std::io::Write>(
     component_address: ComponentAddress,
     substate_db: &T,
+    address_bech32_encoder: &AddressBech32Encoder,
     output: &mut O,
 ) -> Result<(), EntityDumpError> {
-    let address_bech32_encoder = AddressBech32Encoder::new(&NetworkDefinition::simulator());
-
     let reader = SystemDatabaseReader::new(substate_db);
 
     let (package_address, blueprint_name, resources) = {
@@ -93,14 +91,14 @@ pub fn dump_component<T: SubstateDatabase, O: std::io::Write>(
         output,
         "{}: {}",
         "Component Address".green().bold(),
-        component_address.display(&address_bech32_encoder),
+        component_address.display(address_bech32_encoder),
     );
 
     writeln!(
         output,
         "{}: {{ package_address: {}, blueprint_name: \"{}\" }}",
         "Blueprint ID".green().bold(),
-        package_address.display(&address_bech32_encoder),
+        package_address.display(address_bech32_encoder),
         blueprint_name
     );
 
@@ -126,7 +124,7 @@ pub fn dump_component<T: SubstateDatabase, O: std::io::Write>(
             output,
             "{} {}: {} {}{}",
             list_item_prefix(last),
-            resource_address.display(&address_bech32_encoder),
+            resource_address.display(address_bech32_encoder),
             amount,
             name,
             symbol_text,
@@ -155,7 +153,7 @@ pub fn dump_component<T: SubstateDatabase, O: std::io::Write>(
             output,
             "{} {}: {} {}{}",
             list_item_prefix(last),
-            resource_address.display(&address_bech32_encoder),
+            resource_address.display(address_bech32_encoder),
             ids.len(),
             name,
             symbol_text,
@@ -178,15 +176,14 @@ pub fn dump_component<T: SubstateDatabase, O: std::io::Write>(
 pub fn dump_resource_manager<T: SubstateDatabase, O: std::io::Write>(
     resource_address: ResourceAddress,
     substate_db: &T,
+    address_bech32_encoder: &AddressBech32Encoder,
     output: &mut O,
 ) -> Result<(), EntityDumpError> {
-    let address_bech32_encoder = AddressBech32Encoder::new(&NetworkDefinition::simulator());
-
     writeln!(
         output,
         "{}: {}",
         "Resource Address".green().bold(),
-        resource_address.display(&address_bech32_encoder)
+        resource_address.display(address_bech32_encoder)
     );
 
     let reader = SystemDatabaseReader::new(substate_db);
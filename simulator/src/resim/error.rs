@@ -13,7 +13,9 @@ use sbor::*;
 use transaction::errors::*;
 use transaction::model::PrepareError as TransactionPrepareError;
 
-use crate::resim::EntityDumpError;
+use crate::resim::{
+    AddressError, EntityDumpError, ParseSimulatorResourceOrNonFungibleGlobalIdError,
+};
 use crate::utils::*;
 
 /// Represents a resim error.
@@ -65,6 +67,8 @@ pub enum Error {
 
     InvalidId(String),
 
+    AddressError(AddressError),
+
     InvalidPrivateKey,
 
     /// e.g. if you accidentally pass in a public key in `set_default_account` command.
@@ -72,10 +76,14 @@ pub enum Error {
 
     NonFungibleGlobalIdError(ParseNonFungibleGlobalIdError),
 
+    ResourceOrNonFungibleGlobalIdError(ParseSimulatorResourceOrNonFungibleGlobalIdError),
+
     FailedToBuildArguments(BuildCallArgumentError),
 
     ParseNetworkError(ParseNetworkError),
 
+    NetworkConfigError(NetworkConfigError),
+
     OwnerBadgeNotSpecified,
 
     InstructionSchemaValidationError(radix_engine::utils::LocatedInstructionSchemaValidationError),
@@ -83,4 +91,6 @@ pub enum Error {
     InvalidResourceSpecifier(String),
 
     RemoteGenericSubstitutionNotSupported,
+
+    SchemaExportError(crate::scrypto_bindgen::schema::SchemaError),
 }
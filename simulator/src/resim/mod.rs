@@ -1,8 +1,12 @@
 mod addressing;
 mod cmd_call_function;
 mod cmd_call_method;
+mod cmd_estimate_fee;
+mod cmd_export;
 mod cmd_export_package_definition;
+mod cmd_export_package_types;
 mod cmd_generate_key_pair;
+mod cmd_import;
 mod cmd_mint;
 mod cmd_new_account;
 mod cmd_new_badge_fixed;
@@ -19,6 +23,8 @@ mod cmd_set_default_account;
 mod cmd_show;
 mod cmd_show_configs;
 mod cmd_show_ledger;
+mod cmd_sign;
+mod cmd_submit;
 mod cmd_transfer;
 mod config;
 mod dumper;
@@ -27,8 +33,12 @@ mod error;
 pub use addressing::*;
 pub use cmd_call_function::*;
 pub use cmd_call_method::*;
+pub use cmd_estimate_fee::*;
+pub use cmd_export::*;
 pub use cmd_export_package_definition::*;
+pub use cmd_export_package_types::*;
 pub use cmd_generate_key_pair::*;
+pub use cmd_import::*;
 pub use cmd_new_account::*;
 pub use cmd_new_badge_fixed::*;
 pub use cmd_new_badge_mutable::*;
@@ -44,6 +54,8 @@ pub use cmd_set_default_account::*;
 pub use cmd_show::*;
 pub use cmd_show_configs::*;
 pub use cmd_show_ledger::*;
+pub use cmd_sign::*;
+pub use cmd_submit::*;
 pub use cmd_transfer::*;
 pub use config::*;
 pub use dumper::*;
@@ -52,6 +64,7 @@ pub use error::*;
 pub const DEFAULT_SCRYPTO_DIR_UNDER_HOME: &'static str = ".scrypto";
 pub const ENV_DATA_DIR: &'static str = "DATA_DIR";
 pub const ENV_DISABLE_MANIFEST_OUTPUT: &'static str = "DISABLE_MANIFEST_OUTPUT";
+pub const ED25519_SIGNING_KEY_PREFIX: &'static str = "ed25519:";
 
 use clap::{Parser, Subcommand};
 use radix_engine::blueprints::consensus_manager::{
@@ -76,7 +89,7 @@ use radix_engine_interface::blueprints::package::{
     BlueprintDefinition, BlueprintInterface, BlueprintPayloadDef, BlueprintVersionKey,
 };
 use radix_engine_interface::blueprints::resource::FromPublicKey;
-use radix_engine_interface::crypto::{hash, Secp256k1PrivateKey};
+use radix_engine_interface::crypto::{hash, Ed25519PrivateKey, Secp256k1PrivateKey};
 use radix_engine_interface::network::NetworkDefinition;
 use radix_engine_queries::typed_substate_layout::*;
 use radix_engine_store_interface::interface::SubstateDatabase;
@@ -97,20 +110,34 @@ use utils::ContextualDisplay;
 pub struct ResimCli {
     #[clap(subcommand)]
     pub(crate) command: Command,
+
+    /// Path to a JSON file defining a custom network, with its `id`, `logical_name` and
+    /// `hrp_suffix`, which is used by commands unless they're given a `--network`
+    #[clap(long, global = true)]
+    pub(crate) network_config: Option<PathBuf>,
 }
 
 impl ResimCli {
     pub fn get_command(&self) -> &Command {
         &self.command
     }
+
+    /// Resolves the network of the command, from its `--network` and the global `--network-config`.
+    pub fn get_network(&self) -> Result<NetworkDefinition, Error> {
+        get_network(&self.command.network_arg(), &self.network_config)
+    }
 }
 
 #[derive(Subcommand, Debug)]
 pub enum Command {
     CallFunction(CallFunction),
     CallMethod(CallMethod),
+    EstimateFee(EstimateFee),
+    Export(Export),
     ExportPackageDefinition(ExportPackageDefinition),
+    ExportPackageTypes(ExportPackageTypes),
     GenerateKeyPair(GenerateKeyPair),
+    Import(Import),
     Mint(crate::resim::cmd_mint::Mint),
     NewAccount(NewAccount),
     NewSimpleBadge(NewSimpleBadge),
@@ -127,36 +154,80 @@ pub enum Command {
     ShowConfigs(ShowConfigs),
     ShowLedger(ShowLedger),
     Show(Show),
+    Sign(Sign),
+    Submit(Submit),
     Transfer(Transfer),
 }
 
+impl Command {
+    /// The `--network` argument of the command, if it takes one.
+    fn network_arg(&self) -> Option<String> {
+        match self {
+            Command::CallFunction(cmd) => cmd.network.clone(),
+            Command::CallMethod(cmd) => cmd.network.clone(),
+            Command::EstimateFee(cmd) => cmd.network.clone(),
+            Command::Mint(cmd) => cmd.network.clone(),
+            Command::NewAccount(cmd) => cmd.network.clone(),
+            Command::NewSimpleBadge(cmd) => cmd.network.clone(),
+            Command::NewBadgeFixed(cmd) => cmd.network.clone(),
+            Command::NewBadgeMutable(cmd) => cmd.network.clone(),
+            Command::NewTokenFixed(cmd) => cmd.network.clone(),
+            Command::NewTokenMutable(cmd) => cmd.network.clone(),
+            Command::Publish(cmd) => cmd.network.clone(),
+            Command::Run(cmd) => cmd.network.clone(),
+            Command::Sign(cmd) => cmd.network.clone(),
+            Command::Submit(cmd) => cmd.network.clone(),
+            Command::Transfer(cmd) => cmd.network.clone(),
+            Command::Export(_)
+            | Command::ExportPackageDefinition(_)
+            | Command::ExportPackageTypes(_)
+            | Command::GenerateKeyPair(_)
+            | Command::Import(_)
+            | Command::Reset(_)
+            | Command::SetCurrentEpoch(_)
+            | Command::SetCurrentTime(_)
+            | Command::SetDefaultAccount(_)
+            | Command::ShowConfigs(_)
+            | Command::ShowLedger(_)
+            | Command::Show(_) => None,
+        }
+    }
+}
+
 pub fn run() -> Result<(), Error> {
     let cli = ResimCli::parse();
+    let network = cli.get_network()?;
 
     let mut out = std::io::stdout();
 
     match cli.command {
-        Command::CallFunction(cmd) => cmd.run(&mut out),
-        Command::CallMethod(cmd) => cmd.run(&mut out),
-        Command::ExportPackageDefinition(cmd) => cmd.run(&mut out),
+        Command::CallFunction(cmd) => cmd.run(&network, &mut out),
+        Command::CallMethod(cmd) => cmd.run(&network, &mut out),
+        Command::EstimateFee(cmd) => cmd.run(&network, &mut out),
+        Command::Export(cmd) => cmd.run(&mut out),
+        Command::ExportPackageDefinition(cmd) => cmd.run(&network, &mut out),
+        Command::ExportPackageTypes(cmd) => cmd.run(&network, &mut out),
         Command::GenerateKeyPair(cmd) => cmd.run(&mut out),
-        Command::Mint(cmd) => cmd.run(&mut out),
-        Command::NewAccount(cmd) => cmd.run(&mut out),
-        Command::NewSimpleBadge(cmd) => cmd.run(&mut out).map(|_| ()),
-        Command::NewBadgeFixed(cmd) => cmd.run(&mut out),
-        Command::NewBadgeMutable(cmd) => cmd.run(&mut out),
-        Command::NewTokenFixed(cmd) => cmd.run(&mut out),
-        Command::NewTokenMutable(cmd) => cmd.run(&mut out),
-        Command::Publish(cmd) => cmd.run(&mut out),
+        Command::Import(cmd) => cmd.run(&mut out),
+        Command::Mint(cmd) => cmd.run(&network, &mut out),
+        Command::NewAccount(cmd) => cmd.run(&network, &mut out),
+        Command::NewSimpleBadge(cmd) => cmd.run(&network, &mut out).map(|_| ()),
+        Command::NewBadgeFixed(cmd) => cmd.run(&network, &mut out),
+        Command::NewBadgeMutable(cmd) => cmd.run(&network, &mut out),
+        Command::NewTokenFixed(cmd) => cmd.run(&network, &mut out),
+        Command::NewTokenMutable(cmd) => cmd.run(&network, &mut out),
+        Command::Publish(cmd) => cmd.run(&network, &mut out),
         Command::Reset(cmd) => cmd.run(&mut out),
-        Command::Run(cmd) => cmd.run(&mut out),
+        Command::Run(cmd) => cmd.run(&network, &mut out),
         Command::SetCurrentEpoch(cmd) => cmd.run(&mut out),
         Command::SetCurrentTime(cmd) => cmd.run(&mut out),
-        Command::SetDefaultAccount(cmd) => cmd.run(&mut out),
-        Command::ShowConfigs(cmd) => cmd.run(&mut out),
-        Command::ShowLedger(cmd) => cmd.run(&mut out),
-        Command::Show(cmd) => cmd.run(&mut out),
-        Command::Transfer(cmd) => cmd.run(&mut out),
+        Command::SetDefaultAccount(cmd) => cmd.run(&network, &mut out),
+        Command::ShowConfigs(cmd) => cmd.run(&network, &mut out),
+        Command::ShowLedger(cmd) => cmd.run(&network, &mut out),
+        Command::Show(cmd) => cmd.run(&network, &mut out),
+        Command::Sign(cmd) => cmd.run(&network, &mut out),
+        Command::Submit(cmd) => cmd.run(&network, &mut out),
+        Command::Transfer(cmd) => cmd.run(&network, &mut out),
     }
 }
 
@@ -164,6 +235,7 @@ pub fn handle_system_transaction<O: std::io::Write>(
     instructions: Vec<InstructionV1>,
     blobs: Vec<Vec<u8>>,
     initial_proofs: BTreeSet<NonFungibleGlobalId>,
+    network: &NetworkDefinition,
     trace: bool,
     print_receipt: bool,
     out: &mut O,
@@ -189,8 +261,7 @@ pub fn handle_system_transaction<O: std::io::Write>(
         &mut db,
         vm,
         &CostingParameters::default(),
-        &ExecutionConfig::for_system_transaction(NetworkDefinition::simulator())
-            .with_kernel_trace(trace),
+        &ExecutionConfig::for_system_transaction(network.clone()).with_kernel_trace(trace),
         &transaction
             .prepare()
             .map_err(Error::TransactionPrepareError)?
@@ -198,7 +269,7 @@ pub fn handle_system_transaction<O: std::io::Write>(
     );
 
     if print_receipt {
-        let encoder = AddressBech32Encoder::for_simulator();
+        let encoder = AddressBech32Encoder::new(network);
         let display_context = TransactionReceiptDisplayContextBuilder::new()
             .encoder(&encoder)
             .schema_lookup_callback(|event_type_identifier: &EventTypeIdentifier| {
@@ -215,21 +286,17 @@ pub fn handle_system_transaction<O: std::io::Write>(
 pub fn handle_manifest<O: std::io::Write>(
     manifest: TransactionManifestV1,
     signing_keys: &Option<String>,
-    network: &Option<String>,
+    network: &NetworkDefinition,
     write_manifest: &Option<PathBuf>,
     trace: bool,
     print_receipt: bool,
     out: &mut O,
 ) -> Result<Option<TransactionReceipt>, Error> {
-    let network = match network {
-        Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
-        None => NetworkDefinition::simulator(),
-    };
     match write_manifest {
         Some(path) => {
             if !env::var(ENV_DISABLE_MANIFEST_OUTPUT).is_ok() {
                 let manifest_str =
-                    decompile(&manifest.instructions, &network).map_err(Error::DecompileError)?;
+                    decompile(&manifest.instructions, network).map_err(Error::DecompileError)?;
                 fs::write(path, manifest_str).map_err(Error::IOError)?;
                 for blob in manifest.blobs.values() {
                     let blob_hash = hash(&blob);
@@ -271,7 +338,7 @@ pub fn handle_manifest<O: std::io::Write>(
             );
 
             if print_receipt {
-                let encoder = AddressBech32Encoder::for_simulator();
+                let encoder = AddressBech32Encoder::new(network);
                 let display_context = TransactionReceiptDisplayContextBuilder::new()
                     .encoder(&encoder)
                     .schema_lookup_callback(|event_type_identifier: &EventTypeIdentifier| {
@@ -316,15 +383,29 @@ pub fn parse_private_key_from_str(key: &str) -> Result<Secp256k1PrivateKey, Erro
         .and_then(|bytes| parse_private_key_from_bytes(&bytes))
 }
 
-pub fn get_signing_keys(signing_keys: &Option<String>) -> Result<Vec<Secp256k1PrivateKey>, Error> {
+/// Parses a signing key, which is either a hex-encoded Secp256k1 private key, or a hex-encoded
+/// Ed25519 private key prefixed with `ed25519:`.
+pub fn parse_signing_key_from_str(key: &str) -> Result<PrivateKey, Error> {
+    match key.strip_prefix(ED25519_SIGNING_KEY_PREFIX) {
+        Some(key) => hex::decode(key)
+            .map_err(|_| Error::InvalidPrivateKey)
+            .and_then(|bytes| {
+                Ed25519PrivateKey::from_bytes(&bytes).map_err(|_| Error::InvalidPrivateKey)
+            })
+            .map(PrivateKey::Ed25519),
+        None => parse_private_key_from_str(key).map(PrivateKey::Secp256k1),
+    }
+}
+
+pub fn get_signing_keys(signing_keys: &Option<String>) -> Result<Vec<PrivateKey>, Error> {
     let private_keys = if let Some(keys) = signing_keys {
         keys.split(",")
             .map(str::trim)
             .filter(|s: &&str| !s.is_empty())
-            .map(parse_private_key_from_str)
-            .collect::<Result<Vec<Secp256k1PrivateKey>, Error>>()?
+            .map(parse_signing_key_from_str)
+            .collect::<Result<Vec<PrivateKey>, Error>>()?
     } else {
-        vec![get_default_private_key()?]
+        vec![get_default_private_key()?.into()]
     };
 
     Ok(private_keys)
@@ -527,9 +608,10 @@ mod tests {
             manifest: None,
             trace: false,
         };
-        assert!(new_account.run(&mut out).is_ok());
+        let network = NetworkDefinition::simulator();
+        assert!(new_account.run(&network, &mut out).is_ok());
         let cmd = Show { address: None };
-        assert!(cmd.run(&mut out).is_ok());
+        assert!(cmd.run(&network, &mut out).is_ok());
     }
 
     fn test_pre_process_manifest() {
@@ -574,8 +656,46 @@ mod tests {
             };
         };
 
-        assert!(make_cmd(private_key.to_hex()).run(&mut out).is_ok());
-        assert!(make_cmd(public_key.to_string()).run(&mut out).is_err());
+        let network = NetworkDefinition::simulator();
+        assert!(make_cmd(private_key.to_hex())
+            .run(&network, &mut out)
+            .is_ok());
+        assert!(make_cmd(public_key.to_string())
+            .run(&network, &mut out)
+            .is_err());
+    }
+
+    #[test]
+    fn network_config_applies_to_commands_without_a_network() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("network.json");
+        std::fs::write(
+            &path,
+            r#"{ "id": 240, "logical_name": "privatenet", "hrp_suffix": "tdx_f0_" }"#,
+        )
+        .unwrap();
+        let path = path.to_str().unwrap();
+
+        let cli = ResimCli::parse_from(["resim", "--network-config", path, "show-configs"]);
+        let network = cli.get_network().unwrap();
+        assert_eq!(network.logical_name, "privatenet");
+        assert!(SimulatorComponentAddress::from_str(
+            "account_sim1c9yeaya6pehau0fn7vgavuggeev64gahsh05dauae2uu25njk224xz"
+        )
+        .unwrap()
+        .decode(&AddressBech32Decoder::new(&network))
+        .is_err());
+
+        let cli = ResimCli::parse_from([
+            "resim",
+            "--network-config",
+            path,
+            "run",
+            "manifest.rtm",
+            "--network",
+            "stokenet",
+        ]);
+        assert_eq!(cli.get_network().unwrap(), NetworkDefinition::stokenet());
     }
 
     #[test]
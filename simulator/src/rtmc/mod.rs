@@ -1,7 +1,7 @@
+use crate::utils::{resolve_network, NetworkConfigError};
 use clap::Parser;
 use radix_engine::{types::*, utils::*};
 use std::path::PathBuf;
-use std::str::FromStr;
 use transaction::manifest::{compile, BlobProvider};
 
 /// Radix transaction manifest compiler
@@ -16,6 +16,10 @@ pub struct Args {
     #[clap(short, long)]
     network: Option<String>,
 
+    /// Path to a JSON file defining a custom network, with its `id`, `logical_name` and `hrp_suffix`
+    #[clap(long, conflicts_with = "network")]
+    network_config: Option<PathBuf>,
+
     /// The paths to blobs
     #[clap(short, long, multiple = true)]
     blobs: Option<Vec<String>>,
@@ -31,6 +35,7 @@ pub enum Error {
     EncodeError(sbor::EncodeError),
     CompileError(transaction::manifest::CompileError),
     ParseNetworkError(ParseNetworkError),
+    NetworkConfigError(NetworkConfigError),
     InstructionSchemaValidationError(radix_engine::utils::LocatedInstructionSchemaValidationError),
 }
 
@@ -38,10 +43,8 @@ pub fn run() -> Result<(), Error> {
     let args = Args::parse();
 
     let content = std::fs::read_to_string(&args.input).map_err(Error::IoError)?;
-    let network = match args.network {
-        Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
-        None => NetworkDefinition::simulator(),
-    };
+    let network =
+        resolve_network(&args.network, &args.network_config).map_err(Error::NetworkConfigError)?;
     let mut blobs = Vec::new();
     if let Some(paths) = args.blobs {
         for path in paths {
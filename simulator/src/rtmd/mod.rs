@@ -1,10 +1,10 @@
+use crate::utils::{resolve_network, NetworkConfigError};
 use clap::Parser;
 use radix_engine::types::*;
 use radix_engine::utils::validate_call_arguments_to_native_components;
 use radix_engine_interface::crypto::hash;
 use radix_engine_interface::data::manifest::manifest_decode;
 use std::path::PathBuf;
-use std::str::FromStr;
 use transaction::manifest::decompile;
 use transaction::prelude::*;
 
@@ -20,6 +20,10 @@ pub struct Args {
     #[clap(short, long)]
     network: Option<String>,
 
+    /// Path to a JSON file defining a custom network, with its `id`, `logical_name` and `hrp_suffix`
+    #[clap(long, conflicts_with = "network")]
+    network_config: Option<PathBuf>,
+
     /// Whether to export blobs
     #[clap(short, long, action)]
     export_blobs: bool,
@@ -35,6 +39,7 @@ pub enum Error {
     DecodeError(sbor::DecodeError),
     DecompileError(transaction::manifest::DecompileError),
     ParseNetworkError(ParseNetworkError),
+    NetworkConfigError(NetworkConfigError),
     InstructionSchemaValidationError(radix_engine::utils::LocatedInstructionSchemaValidationError),
 }
 
@@ -42,10 +47,8 @@ pub fn run() -> Result<(), Error> {
     let args = Args::parse();
 
     let content = std::fs::read(&args.input).map_err(Error::IoError)?;
-    let network = match args.network {
-        Some(n) => NetworkDefinition::from_str(&n).map_err(Error::ParseNetworkError)?,
-        None => NetworkDefinition::simulator(),
-    };
+    let network =
+        resolve_network(&args.network, &args.network_config).map_err(Error::NetworkConfigError)?;
 
     let (manifest_instructions, blobs) =
         match manifest_decode::<TransactionManifestV1>(&content).map_err(Error::DecodeError) {
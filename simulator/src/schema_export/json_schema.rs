@@ -0,0 +1,374 @@
+//! Generation of [JSON Schema](https://json-schema.org/) documents describing the programmatic
+//! JSON representation of the values in a blueprint's interface.
+//!
+//! Numeric bounds can't be expressed on the string-encoded integers, so are not included.
+
+use radix_engine_interface::prelude::*;
+use serde_json::{json, Map, Value};
+
+use super::*;
+
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Generates a JSON Schema document for the blueprint, with a definition under `$defs` for the
+/// input and output of each function (`function.<name>.input` and `function.<name>.output`),
+/// each event (`event.<name>`), each field (`field.<index>`) and each registered type
+/// (`type.<name>`), alongside the named types they reference.
+pub fn blueprint_json_schema<S>(
+    blueprint_name: &str,
+    blueprint_types: &BlueprintTypes,
+    definitions: &IndexMap<ScopedTypeId, String>,
+    schema_resolver: &S,
+) -> Result<Value, SchemaError>
+where
+    S: PackageSchemaResolver,
+{
+    let mut generator = JsonSchemaGenerator {
+        schema_resolver,
+        definitions,
+        referenced: index_set_new(),
+    };
+
+    let mut defs = Map::new();
+    for (function_name, function_types) in blueprint_types.functions.iter() {
+        defs.insert(
+            format!("function.{function_name}.input"),
+            generator.type_schema(&function_types.input)?,
+        );
+        defs.insert(
+            format!("function.{function_name}.output"),
+            generator.type_schema(&function_types.output)?,
+        );
+    }
+    for (event_name, type_id) in blueprint_types.events.iter() {
+        defs.insert(
+            format!("event.{event_name}"),
+            generator.type_schema(type_id)?,
+        );
+    }
+    for (index, type_id) in blueprint_types.fields.iter().enumerate() {
+        defs.insert(format!("field.{index}"), generator.type_schema(type_id)?);
+    }
+    for (type_name, type_id) in blueprint_types.types.iter() {
+        defs.insert(format!("type.{type_name}"), generator.type_schema(type_id)?);
+    }
+
+    // Generating a definition can reference further definitions, so the set grows as we go.
+    let mut index = 0;
+    while let Some(type_id) = generator.referenced.get_index(index).cloned() {
+        let name = definitions.get(&type_id).unwrap().clone();
+        let schema = generator.inline_type_schema(&type_id)?;
+        defs.insert(name, schema);
+        index += 1;
+    }
+
+    Ok(json!({
+        "$schema": JSON_SCHEMA_DIALECT,
+        "title": blueprint_name,
+        "description": format!(
+            "The programmatic JSON representation of the values in the interface of the {} blueprint",
+            blueprint_name
+        ),
+        "$defs": defs,
+    }))
+}
+
+struct JsonSchemaGenerator<'a, S>
+where
+    S: PackageSchemaResolver,
+{
+    schema_resolver: &'a S,
+    definitions: &'a IndexMap<ScopedTypeId, String>,
+    referenced: IndexSet<ScopedTypeId>,
+}
+
+impl<'a, S> JsonSchemaGenerator<'a, S>
+where
+    S: PackageSchemaResolver,
+{
+    /// Returns a reference to the type's definition if it has one, else the type's schema.
+    fn type_schema(&mut self, type_id: &ScopedTypeId) -> Result<Value, SchemaError> {
+        match self.definitions.get(type_id) {
+            Some(name) => {
+                self.referenced.insert(*type_id);
+                Ok(json!({ "$ref": format!("#/$defs/{name}") }))
+            }
+            None => self.inline_type_schema(type_id),
+        }
+    }
+
+    fn inline_type_schema(&mut self, type_id: &ScopedTypeId) -> Result<Value, SchemaError> {
+        let type_kind = self.schema_resolver.resolve_type_kind(type_id)?;
+        let type_metadata = self.schema_resolver.resolve_type_metadata(type_id)?;
+        let type_validation = self.schema_resolver.resolve_type_validation(type_id)?;
+
+        let Some(kind_name) = value_kind_name(&type_kind) else {
+            return Ok(json!({
+                "description": "Any value",
+                "type": "object",
+                "required": ["kind"],
+            }));
+        };
+
+        let mut properties = Map::new();
+        let mut required = vec!["kind"];
+        properties.insert("kind".to_string(), json!({ "const": kind_name }));
+        if let Some(type_name) = type_metadata.get_name() {
+            properties.insert("type_name".to_string(), json!({ "const": type_name }));
+        }
+        properties.insert("field_name".to_string(), json!({ "type": "string" }));
+
+        match &type_kind {
+            TypeKind::Any => unreachable!("Any has no value kind"),
+            TypeKind::Bool => {
+                properties.insert("value".to_string(), json!({ "type": "boolean" }));
+                required.push("value");
+            }
+            TypeKind::I8 | TypeKind::I16 | TypeKind::I32 | TypeKind::I64 | TypeKind::I128 => {
+                properties.insert(
+                    "value".to_string(),
+                    json!({ "type": "string", "pattern": "^-?[0-9]+$" }),
+                );
+                required.push("value");
+            }
+            TypeKind::U8 | TypeKind::U16 | TypeKind::U32 | TypeKind::U64 | TypeKind::U128 => {
+                properties.insert(
+                    "value".to_string(),
+                    json!({ "type": "string", "pattern": "^[0-9]+$" }),
+                );
+                required.push("value");
+            }
+            TypeKind::String => {
+                let mut value_schema = json!({ "type": "string" });
+                if let TypeValidation::String(length_validation) = &type_validation {
+                    add_length_validation(&mut value_schema, length_validation, "Length");
+                }
+                properties.insert("value".to_string(), value_schema);
+                required.push("value");
+            }
+            TypeKind::Array { element_type } => {
+                let element_type_id = ScopedTypeId(type_id.0, *element_type);
+                let element_kind = self.schema_resolver.resolve_type_kind(&element_type_id)?;
+                properties.insert(
+                    "element_kind".to_string(),
+                    kind_schema(value_kind_name(&element_kind)),
+                );
+                properties.insert("element_name".to_string(), json!({ "type": "string" }));
+                required.push("element_kind");
+
+                if is_bytes(type_id, &type_kind, self.schema_resolver)? {
+                    properties.insert("kind".to_string(), json!({ "const": "Bytes" }));
+                    properties.insert(
+                        "hex".to_string(),
+                        json!({ "type": "string", "pattern": "^([0-9a-f]{2})*$" }),
+                    );
+                    required.push("hex");
+                } else {
+                    let mut elements_schema = json!({
+                        "type": "array",
+                        "items": self.type_schema(&element_type_id)?,
+                    });
+                    if let TypeValidation::Array(length_validation) = &type_validation {
+                        add_length_validation(&mut elements_schema, length_validation, "Items");
+                    }
+                    properties.insert("elements".to_string(), elements_schema);
+                    required.push("elements");
+                }
+            }
+            TypeKind::Tuple { field_types } => {
+                let field_names = field_names(&type_metadata, None, field_types.len());
+                properties.insert(
+                    "fields".to_string(),
+                    self.fields_schema(type_id, field_types, field_names)?,
+                );
+                required.push("fields");
+            }
+            TypeKind::Enum { variants } => {
+                let mut variant_schemas = vec![];
+                for (variant_id, field_types) in variants.iter() {
+                    let mut variant_properties = properties.clone();
+                    variant_properties.insert(
+                        "variant_id".to_string(),
+                        json!({ "const": variant_id.to_string() }),
+                    );
+                    if let Some(variant_name) = variant_name(&type_metadata, *variant_id) {
+                        variant_properties
+                            .insert("variant_name".to_string(), json!({ "const": variant_name }));
+                    }
+                    let field_names =
+                        field_names(&type_metadata, Some(*variant_id), field_types.len());
+                    variant_properties.insert(
+                        "fields".to_string(),
+                        self.fields_schema(type_id, field_types, field_names)?,
+                    );
+                    variant_schemas.push(json!({
+                        "type": "object",
+                        "properties": variant_properties,
+                        "required": ["kind", "variant_id", "fields"],
+                    }));
+                }
+                if variant_schemas.is_empty() {
+                    return Ok(Value::Bool(false));
+                }
+                return Ok(json!({ "oneOf": variant_schemas }));
+            }
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => {
+                let key_type_id = ScopedTypeId(type_id.0, *key_type);
+                let value_type_id = ScopedTypeId(type_id.0, *value_type);
+                let key_kind = self.schema_resolver.resolve_type_kind(&key_type_id)?;
+                let value_kind = self.schema_resolver.resolve_type_kind(&value_type_id)?;
+                properties.insert(
+                    "key_kind".to_string(),
+                    kind_schema(value_kind_name(&key_kind)),
+                );
+                properties.insert("key_type_name".to_string(), json!({ "type": "string" }));
+                properties.insert(
+                    "value_kind".to_string(),
+                    kind_schema(value_kind_name(&value_kind)),
+                );
+                properties.insert("value_type_name".to_string(), json!({ "type": "string" }));
+
+                let mut entries_schema = json!({
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "key": self.type_schema(&key_type_id)?,
+                            "value": self.type_schema(&value_type_id)?,
+                        },
+                        "required": ["key", "value"],
+                    },
+                });
+                if let TypeValidation::Map(length_validation) = &type_validation {
+                    add_length_validation(&mut entries_schema, length_validation, "Items");
+                }
+                properties.insert("entries".to_string(), entries_schema);
+                required.extend(["key_kind", "value_kind", "entries"]);
+            }
+            TypeKind::Custom(custom_type_kind) => {
+                properties.insert("value".to_string(), custom_value_schema(custom_type_kind));
+                required.push("value");
+            }
+        }
+
+        Ok(json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+        }))
+    }
+
+    fn fields_schema(
+        &mut self,
+        type_id: &ScopedTypeId,
+        field_types: &[LocalTypeId],
+        field_names: Option<Vec<String>>,
+    ) -> Result<Value, SchemaError> {
+        let mut prefix_items = vec![];
+        for (index, field_type) in field_types.iter().enumerate() {
+            let field_schema = self.type_schema(&ScopedTypeId(type_id.0, *field_type))?;
+            let field_schema = match &field_names {
+                Some(field_names) => json!({
+                    "allOf": [
+                        field_schema,
+                        { "properties": { "field_name": { "const": field_names[index] } } },
+                    ],
+                }),
+                None => field_schema,
+            };
+            prefix_items.push(field_schema);
+        }
+        Ok(json!({
+            "type": "array",
+            "prefixItems": prefix_items,
+            "minItems": field_types.len(),
+            "maxItems": field_types.len(),
+        }))
+    }
+}
+
+fn kind_schema(kind_name: Option<String>) -> Value {
+    match kind_name {
+        Some(kind_name) => json!({ "const": kind_name }),
+        None => json!({ "type": "string" }),
+    }
+}
+
+fn custom_value_schema(custom_type_kind: &ScryptoCustomTypeKind) -> Value {
+    match custom_type_kind {
+        ScryptoCustomTypeKind::Reference => json!({
+            "type": "string",
+            "description": "A Bech32m-encoded address",
+        }),
+        ScryptoCustomTypeKind::Own => json!({
+            "type": "string",
+            "description": "The Bech32m-encoded address of an owned node",
+        }),
+        ScryptoCustomTypeKind::Decimal | ScryptoCustomTypeKind::PreciseDecimal => json!({
+            "type": "string",
+            "pattern": "^-?[0-9]+(\\.[0-9]+)?$",
+        }),
+        ScryptoCustomTypeKind::NonFungibleLocalId => json!({
+            "type": "string",
+            "pattern": "^(<[a-zA-Z0-9_]+>|#[0-9]+#|\\[[0-9a-f]+\\]|\\{[0-9a-f]{16}-[0-9a-f]{16}-[0-9a-f]{16}-[0-9a-f]{16}\\})$",
+        }),
+    }
+}
+
+fn add_length_validation(schema: &mut Value, length_validation: &LengthValidation, suffix: &str) {
+    let schema = schema.as_object_mut().unwrap();
+    if let Some(min) = length_validation.min {
+        schema.insert(format!("min{suffix}"), json!(min));
+    }
+    if let Some(max) = length_validation.max {
+        schema.insert(format!("max{suffix}"), json!(max));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn events_reference_their_named_definitions() {
+        let (package_types, schema_resolver) = order_types();
+        let blueprint_types = package_types.blueprints.get("OrderBook").unwrap();
+
+        let document = blueprint_json_schema(
+            "OrderBook",
+            blueprint_types,
+            &package_types.definitions,
+            &schema_resolver,
+        )
+        .unwrap();
+
+        assert_eq!(
+            document["$defs"]["event.OrderPlaced"],
+            json!({ "$ref": "#/$defs/Order" })
+        );
+        let order = &document["$defs"]["Order"];
+        assert_eq!(
+            order["properties"]["type_name"],
+            json!({ "const": "Order" })
+        );
+        let fields = &order["properties"]["fields"];
+        assert_eq!(fields["minItems"], json!(6));
+        assert_eq!(
+            fields["prefixItems"][1]["allOf"][1],
+            json!({ "properties": { "field_name": { "const": "price" } } })
+        );
+        assert_eq!(
+            fields["prefixItems"][4]["allOf"][0]["properties"]["kind"],
+            json!({ "const": "Bytes" })
+        );
+        let side = &document["$defs"]["Side"];
+        assert_eq!(
+            side["oneOf"][1]["properties"]["variant_name"],
+            json!({ "const": "Sell" })
+        );
+    }
+}
@@ -0,0 +1,433 @@
+pub mod json_schema;
+pub mod typescript;
+
+use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::prelude::*;
+use std::collections::BTreeMap;
+
+use crate::scrypto_bindgen::schema::*;
+
+/// The names of the types declared by the TypeScript prelude, which can't be used as definition
+/// names.
+const RESERVED_NAMES: [&str; 27] = [
+    "SborValueBase",
+    "Named",
+    "Bool",
+    "I8",
+    "I16",
+    "I32",
+    "I64",
+    "I128",
+    "U8",
+    "U16",
+    "U32",
+    "U64",
+    "U128",
+    "SborString",
+    "Bytes",
+    "SborArray",
+    "SborMap",
+    "SborTuple",
+    "SborEnumVariant",
+    "SborAny",
+    "Reference",
+    "Own",
+    "Decimal",
+    "PreciseDecimal",
+    "NonFungibleLocalId",
+    "Record",
+    "Array",
+];
+
+/// The types in the interface of a package, which are exported as JSON Schema and TypeScript.
+#[derive(Clone, Debug, Default)]
+pub struct PackageTypes {
+    /// The types of each blueprint of the package, keyed by the blueprint name.
+    pub blueprints: IndexMap<String, BlueprintTypes>,
+    /// The named tuples and enums reachable from the blueprints, which are exported as standalone
+    /// definitions rather than inlined. Names are unique, and valid TypeScript identifiers.
+    pub definitions: IndexMap<ScopedTypeId, String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct BlueprintTypes {
+    pub functions: IndexMap<String, FunctionTypes>,
+    pub events: IndexMap<String, ScopedTypeId>,
+    pub fields: Vec<ScopedTypeId>,
+    pub types: IndexMap<String, ScopedTypeId>,
+}
+
+#[derive(Clone, Debug)]
+pub struct FunctionTypes {
+    pub input: ScopedTypeId,
+    pub output: ScopedTypeId,
+}
+
+/// Collects the types of the functions, events, fields and registered types of each blueprint.
+///
+/// Payloads which are generic over the instance (such as the fields of a key value store) can't
+/// be described statically, so they are skipped.
+pub fn package_types_from_package_definition<S>(
+    package_definition: BTreeMap<BlueprintVersionKey, BlueprintDefinition>,
+    schema_resolver: &S,
+) -> Result<PackageTypes, SchemaError>
+where
+    S: PackageSchemaResolver,
+{
+    let mut package_types = PackageTypes::default();
+    let mut visited = index_set_new();
+
+    for (blueprint_key, blueprint_definition) in package_definition.into_iter() {
+        let interface = blueprint_definition.interface;
+        let mut blueprint_types = BlueprintTypes::default();
+
+        for (function_name, function_schema) in interface.functions {
+            if let (BlueprintPayloadDef::Static(input), BlueprintPayloadDef::Static(output)) =
+                (function_schema.input, function_schema.output)
+            {
+                blueprint_types
+                    .functions
+                    .insert(function_name, FunctionTypes { input, output });
+            }
+        }
+        for (event_name, payload_def) in interface.events {
+            if let BlueprintPayloadDef::Static(type_id) = payload_def {
+                blueprint_types.events.insert(event_name, type_id);
+            }
+        }
+        if let Some((_, fields)) = interface.state.fields {
+            for field in fields {
+                if let BlueprintPayloadDef::Static(type_id) = field.field {
+                    blueprint_types.fields.push(type_id);
+                }
+            }
+        }
+        blueprint_types.types = interface.types;
+
+        let roots = blueprint_types
+            .functions
+            .values()
+            .flat_map(|function| [function.input, function.output])
+            .chain(blueprint_types.events.values().cloned())
+            .chain(blueprint_types.fields.iter().cloned())
+            .chain(blueprint_types.types.values().cloned())
+            .collect::<Vec<_>>();
+        for root in roots {
+            collect_definitions(
+                &root,
+                schema_resolver,
+                &mut visited,
+                &mut package_types.definitions,
+            )?;
+        }
+
+        package_types
+            .blueprints
+            .insert(blueprint_key.blueprint, blueprint_types);
+    }
+
+    Ok(package_types)
+}
+
+/// Walks the type, giving a unique name to each named tuple or enum encountered.
+fn collect_definitions<S>(
+    type_id: &ScopedTypeId,
+    schema_resolver: &S,
+    visited: &mut IndexSet<ScopedTypeId>,
+    definitions: &mut IndexMap<ScopedTypeId, String>,
+) -> Result<(), SchemaError>
+where
+    S: PackageSchemaResolver,
+{
+    if !visited.insert(*type_id) {
+        return Ok(());
+    }
+
+    let type_kind = schema_resolver.resolve_type_kind(type_id)?;
+    if let TypeKind::Tuple { .. } | TypeKind::Enum { .. } = type_kind {
+        let type_metadata = schema_resolver.resolve_type_metadata(type_id)?;
+        if let Some(type_name) = type_metadata.get_name() {
+            let name = unique_definition_name(type_name, definitions);
+            definitions.insert(*type_id, name);
+        }
+    }
+
+    for child_type_id in child_type_ids(type_id, &type_kind) {
+        collect_definitions(&child_type_id, schema_resolver, visited, definitions)?;
+    }
+
+    Ok(())
+}
+
+fn unique_definition_name(type_name: &str, definitions: &IndexMap<ScopedTypeId, String>) -> String {
+    let mut base_name = type_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    if base_name.is_empty() || base_name.starts_with(|c: char| c.is_ascii_digit()) {
+        base_name.insert(0, '_');
+    }
+    let is_taken = |name: &str| {
+        RESERVED_NAMES.contains(&name) || definitions.values().any(|existing| existing == name)
+    };
+    if !is_taken(&base_name) {
+        return base_name;
+    }
+    (2..)
+        .map(|suffix| format!("{base_name}_{suffix}"))
+        .find(|name| !is_taken(name))
+        .unwrap()
+}
+
+pub(crate) fn child_type_ids(
+    type_id: &ScopedTypeId,
+    type_kind: &SchemaTypeKind<ScryptoCustomSchema>,
+) -> Vec<ScopedTypeId> {
+    match type_kind {
+        TypeKind::Any
+        | TypeKind::Bool
+        | TypeKind::I8
+        | TypeKind::I16
+        | TypeKind::I32
+        | TypeKind::I64
+        | TypeKind::I128
+        | TypeKind::U8
+        | TypeKind::U16
+        | TypeKind::U32
+        | TypeKind::U64
+        | TypeKind::U128
+        | TypeKind::String
+        | TypeKind::Custom(..) => vec![],
+        TypeKind::Array { element_type } => vec![ScopedTypeId(type_id.0, *element_type)],
+        TypeKind::Tuple { field_types } => field_types
+            .iter()
+            .map(|field_type| ScopedTypeId(type_id.0, *field_type))
+            .collect(),
+        TypeKind::Enum { variants } => variants
+            .values()
+            .flatten()
+            .map(|field_type| ScopedTypeId(type_id.0, *field_type))
+            .collect(),
+        TypeKind::Map {
+            key_type,
+            value_type,
+        } => vec![
+            ScopedTypeId(type_id.0, *key_type),
+            ScopedTypeId(type_id.0, *value_type),
+        ],
+    }
+}
+
+/// The value kind output in the programmatic JSON for a value of the given type kind, if it's
+/// fixed by the type.
+pub(crate) fn value_kind_name(type_kind: &SchemaTypeKind<ScryptoCustomSchema>) -> Option<String> {
+    let value_kind: ValueKind<ScryptoCustomValueKind> = match type_kind {
+        TypeKind::Any => return None,
+        TypeKind::Bool => ValueKind::Bool,
+        TypeKind::I8 => ValueKind::I8,
+        TypeKind::I16 => ValueKind::I16,
+        TypeKind::I32 => ValueKind::I32,
+        TypeKind::I64 => ValueKind::I64,
+        TypeKind::I128 => ValueKind::I128,
+        TypeKind::U8 => ValueKind::U8,
+        TypeKind::U16 => ValueKind::U16,
+        TypeKind::U32 => ValueKind::U32,
+        TypeKind::U64 => ValueKind::U64,
+        TypeKind::U128 => ValueKind::U128,
+        TypeKind::String => ValueKind::String,
+        TypeKind::Array { .. } => ValueKind::Array,
+        TypeKind::Tuple { .. } => ValueKind::Tuple,
+        TypeKind::Enum { .. } => ValueKind::Enum,
+        TypeKind::Map { .. } => ValueKind::Map,
+        TypeKind::Custom(custom_type_kind) => ValueKind::Custom(match custom_type_kind {
+            ScryptoCustomTypeKind::Reference => ScryptoCustomValueKind::Reference,
+            ScryptoCustomTypeKind::Own => ScryptoCustomValueKind::Own,
+            ScryptoCustomTypeKind::Decimal => ScryptoCustomValueKind::Decimal,
+            ScryptoCustomTypeKind::PreciseDecimal => ScryptoCustomValueKind::PreciseDecimal,
+            ScryptoCustomTypeKind::NonFungibleLocalId => ScryptoCustomValueKind::NonFungibleLocalId,
+        }),
+    };
+    Some(value_kind.to_string())
+}
+
+/// Returns whether values of the type are serialized as `Bytes` (hex) rather than an array.
+pub(crate) fn is_bytes<S>(
+    type_id: &ScopedTypeId,
+    type_kind: &SchemaTypeKind<ScryptoCustomSchema>,
+    schema_resolver: &S,
+) -> Result<bool, SchemaError>
+where
+    S: PackageSchemaResolver,
+{
+    match type_kind {
+        TypeKind::Array { element_type } => Ok(matches!(
+            schema_resolver.resolve_type_kind(&ScopedTypeId(type_id.0, *element_type))?,
+            TypeKind::U8
+        )),
+        _ => Ok(false),
+    }
+}
+
+/// Returns the names of the fields of a tuple, or of the given variant of an enum, if they're
+/// named.
+pub(crate) fn field_names(
+    type_metadata: &TypeMetadata,
+    variant_id: Option<u8>,
+    field_count: usize,
+) -> Option<Vec<String>> {
+    let field_names = match variant_id {
+        None => type_metadata.get_field_names()?,
+        Some(variant_id) => match &type_metadata.child_names {
+            Some(ChildNames::EnumVariants(variants)) => {
+                variants.get(&variant_id)?.get_field_names()?
+            }
+            _ => return None,
+        },
+    };
+    if field_names.len() != field_count {
+        return None;
+    }
+    Some(field_names.iter().map(|name| name.to_string()).collect())
+}
+
+pub(crate) fn variant_name(type_metadata: &TypeMetadata, variant_id: u8) -> Option<String> {
+    match &type_metadata.child_names {
+        Some(ChildNames::EnumVariants(variants)) => variants
+            .get(&variant_id)
+            .and_then(|variant| variant.get_name_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(ScryptoSbor)]
+    pub struct Order {
+        pub id: u64,
+        pub price: Decimal,
+        pub side: Side,
+        pub memo: Option<String>,
+        pub payload: Vec<u8>,
+        pub children: Vec<Order>,
+    }
+
+    #[derive(ScryptoSbor)]
+    pub enum Side {
+        Buy,
+        Sell { limit: Decimal },
+    }
+
+    pub struct TestSchemaResolver {
+        schema_hash: SchemaHash,
+        schema: Rc<VersionedScryptoSchema>,
+    }
+
+    impl PackageSchemaResolver for TestSchemaResolver {
+        fn lookup_schema(&self, schema_hash: &SchemaHash) -> Option<Rc<VersionedScryptoSchema>> {
+            (*schema_hash == self.schema_hash).then(|| self.schema.clone())
+        }
+
+        fn resolve_type_kind(
+            &self,
+            type_identifier: &ScopedTypeId,
+        ) -> Result<SchemaTypeKind<ScryptoCustomSchema>, SchemaError> {
+            self.schema
+                .v1()
+                .resolve_type_kind(type_identifier.1)
+                .cloned()
+                .ok_or(SchemaError::NonExistentLocalTypeIndex(type_identifier.1))
+        }
+
+        fn resolve_type_metadata(
+            &self,
+            type_identifier: &ScopedTypeId,
+        ) -> Result<TypeMetadata, SchemaError> {
+            self.schema
+                .v1()
+                .resolve_type_metadata(type_identifier.1)
+                .cloned()
+                .ok_or(SchemaError::NonExistentLocalTypeIndex(type_identifier.1))
+        }
+
+        fn resolve_type_validation(
+            &self,
+            type_identifier: &ScopedTypeId,
+        ) -> Result<TypeValidation<ScryptoCustomTypeValidation>, SchemaError> {
+            self.schema
+                .v1()
+                .resolve_type_validation(type_identifier.1)
+                .cloned()
+                .ok_or(SchemaError::NonExistentLocalTypeIndex(type_identifier.1))
+        }
+
+        fn package_address(&self) -> PackageAddress {
+            PACKAGE_PACKAGE
+        }
+    }
+
+    pub fn order_types() -> (PackageTypes, TestSchemaResolver) {
+        let (type_id, schema) =
+            generate_full_schema_from_single_type::<Order, ScryptoCustomSchema>();
+        let schema_hash = schema.generate_schema_hash();
+        let order_type_id = ScopedTypeId(schema_hash, type_id);
+
+        let mut definitions = index_map_new();
+        collect_definitions(
+            &order_type_id,
+            &TestSchemaResolver {
+                schema_hash,
+                schema: Rc::new(schema.clone()),
+            },
+            &mut index_set_new(),
+            &mut definitions,
+        )
+        .unwrap();
+        let package_types = PackageTypes {
+            blueprints: indexmap!(
+                "OrderBook".to_string() => BlueprintTypes {
+                    events: indexmap!("OrderPlaced".to_string() => order_type_id),
+                    ..Default::default()
+                }
+            ),
+            definitions,
+        };
+        (
+            package_types,
+            TestSchemaResolver {
+                schema_hash,
+                schema: Rc::new(schema),
+            },
+        )
+    }
+
+    #[test]
+    fn named_tuples_and_enums_become_definitions() {
+        let (package_types, _) = order_types();
+
+        let mut names = package_types
+            .definitions
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        names.sort();
+
+        assert_eq!(names, vec!["Option", "Order", "Side"]);
+    }
+
+    #[test]
+    fn definition_names_are_unique_and_avoid_reserved_names() {
+        let definitions = indexmap!(
+            ScopedTypeId(SchemaHash(Hash([0; 32])), LocalTypeId::SchemaLocalIndex(0)) =>
+                "Pair".to_string()
+        );
+
+        assert_eq!(unique_definition_name("Pair", &definitions), "Pair_2");
+        assert_eq!(unique_definition_name("Decimal", &definitions), "Decimal_2");
+        assert_eq!(
+            unique_definition_name("Map<u8, u8>", &definitions),
+            "Map_u8__u8_"
+        );
+    }
+}
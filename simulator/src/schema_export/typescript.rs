@@ -0,0 +1,329 @@
+//! Generation of TypeScript declarations for the programmatic JSON representation of the values in
+//! a package's interface.
+
+use radix_engine_interface::prelude::*;
+use std::fmt::Write;
+
+use super::*;
+
+/// Declarations for the kinds of value in the programmatic JSON representation, which the
+/// generated types are built from.
+const PRELUDE: &str = r#"export interface SborValueBase {
+    type_name?: string;
+    field_name?: string;
+}
+
+export type Named<T, N extends string> = T & { field_name?: N };
+
+export interface Bool extends SborValueBase { kind: "Bool"; value: boolean; }
+export interface I8 extends SborValueBase { kind: "I8"; value: string; }
+export interface I16 extends SborValueBase { kind: "I16"; value: string; }
+export interface I32 extends SborValueBase { kind: "I32"; value: string; }
+export interface I64 extends SborValueBase { kind: "I64"; value: string; }
+export interface I128 extends SborValueBase { kind: "I128"; value: string; }
+export interface U8 extends SborValueBase { kind: "U8"; value: string; }
+export interface U16 extends SborValueBase { kind: "U16"; value: string; }
+export interface U32 extends SborValueBase { kind: "U32"; value: string; }
+export interface U64 extends SborValueBase { kind: "U64"; value: string; }
+export interface U128 extends SborValueBase { kind: "U128"; value: string; }
+export interface SborString extends SborValueBase { kind: "String"; value: string; }
+export interface Reference extends SborValueBase { kind: "Reference"; value: string; }
+export interface Own extends SborValueBase { kind: "Own"; value: string; }
+export interface Decimal extends SborValueBase { kind: "Decimal"; value: string; }
+export interface PreciseDecimal extends SborValueBase { kind: "PreciseDecimal"; value: string; }
+export interface NonFungibleLocalId extends SborValueBase { kind: "NonFungibleLocalId"; value: string; }
+
+export interface Bytes extends SborValueBase {
+    kind: "Bytes";
+    element_kind: "U8";
+    element_name?: string;
+    hex: string;
+}
+
+export interface SborArray<T> extends SborValueBase {
+    kind: "Array";
+    element_kind: string;
+    element_name?: string;
+    elements: T[];
+}
+
+export interface SborMap<K, V> extends SborValueBase {
+    kind: "Map";
+    key_kind: string;
+    key_type_name?: string;
+    value_kind: string;
+    value_type_name?: string;
+    entries: { key: K; value: V }[];
+}
+
+export interface SborTuple<F extends unknown[]> extends SborValueBase {
+    kind: "Tuple";
+    fields: F;
+}
+
+export interface SborEnumVariant<Id extends string, F extends unknown[]> extends SborValueBase {
+    kind: "Enum";
+    variant_id: Id;
+    variant_name?: string;
+    fields: F;
+}
+
+export type SborAny =
+    | Bool
+    | I8
+    | I16
+    | I32
+    | I64
+    | I128
+    | U8
+    | U16
+    | U32
+    | U64
+    | U128
+    | SborString
+    | Bytes
+    | SborArray<SborAny>
+    | SborMap<SborAny, SborAny>
+    | SborTuple<SborAny[]>
+    | SborEnumVariant<string, SborAny[]>
+    | Reference
+    | Own
+    | Decimal
+    | PreciseDecimal
+    | NonFungibleLocalId;
+"#;
+
+/// Generates a TypeScript module declaring the named types of the package, and for each
+/// blueprint:
+/// * A `<Blueprint>Functions` interface with the `input` and `output` of each function.
+/// * A `<Blueprint>Events` interface with the payload of each event.
+/// * A `<Blueprint>Fields` tuple with the type of each field.
+/// * A `<Blueprint>Types` interface with each registered type.
+pub fn package_typescript<S>(
+    package_types: &PackageTypes,
+    schema_resolver: &S,
+) -> Result<String, SchemaError>
+where
+    S: PackageSchemaResolver,
+{
+    let generator = TypeScriptGenerator {
+        schema_resolver,
+        definitions: &package_types.definitions,
+    };
+
+    let mut out = String::new();
+    out.push_str("// Generated by `resim export-package-types`. Do not edit.\n\n");
+    out.push_str(PRELUDE);
+
+    for (type_id, name) in package_types.definitions.iter() {
+        let declaration = generator.inline_type(type_id)?;
+        write!(out, "\nexport type {name} ={declaration};\n").unwrap();
+    }
+
+    for (blueprint_name, blueprint_types) in package_types.blueprints.iter() {
+        writeln!(out, "\nexport interface {blueprint_name}Functions {{").unwrap();
+        for (function_name, function_types) in blueprint_types.functions.iter() {
+            writeln!(
+                out,
+                "    {function_name}: {{ input: {}; output: {} }};",
+                generator.type_reference(&function_types.input)?,
+                generator.type_reference(&function_types.output)?
+            )
+            .unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+
+        writeln!(out, "\nexport interface {blueprint_name}Events {{").unwrap();
+        for (event_name, type_id) in blueprint_types.events.iter() {
+            writeln!(
+                out,
+                "    {event_name}: {};",
+                generator.type_reference(type_id)?
+            )
+            .unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+
+        let fields = blueprint_types
+            .fields
+            .iter()
+            .map(|type_id| generator.type_reference(type_id))
+            .collect::<Result<Vec<_>, _>>()?;
+        writeln!(
+            out,
+            "\nexport type {blueprint_name}Fields = [{}];",
+            fields.join(", ")
+        )
+        .unwrap();
+
+        writeln!(out, "\nexport interface {blueprint_name}Types {{").unwrap();
+        for (type_name, type_id) in blueprint_types.types.iter() {
+            writeln!(
+                out,
+                "    {type_name}: {};",
+                generator.type_reference(type_id)?
+            )
+            .unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+    }
+
+    Ok(out)
+}
+
+struct TypeScriptGenerator<'a, S>
+where
+    S: PackageSchemaResolver,
+{
+    schema_resolver: &'a S,
+    definitions: &'a IndexMap<ScopedTypeId, String>,
+}
+
+impl<'a, S> TypeScriptGenerator<'a, S>
+where
+    S: PackageSchemaResolver,
+{
+    /// Returns the name of the type's definition if it has one, else the type's declaration.
+    fn type_reference(&self, type_id: &ScopedTypeId) -> Result<String, SchemaError> {
+        match self.definitions.get(type_id) {
+            Some(name) => Ok(name.clone()),
+            None => Ok(self.inline_type(type_id)?.trim_start().to_owned()),
+        }
+    }
+
+    /// Returns the declaration of the type. Enums are output as a union with one variant per
+    /// line, so every declaration starts with whitespace.
+    fn inline_type(&self, type_id: &ScopedTypeId) -> Result<String, SchemaError> {
+        let type_kind = self.schema_resolver.resolve_type_kind(type_id)?;
+        let type_metadata = self.schema_resolver.resolve_type_metadata(type_id)?;
+        let type_name = match &type_kind {
+            TypeKind::Tuple { .. } | TypeKind::Enum { .. } => type_metadata.get_name(),
+            _ => None,
+        };
+
+        let declaration = match &type_kind {
+            TypeKind::Any => "SborAny".to_owned(),
+            TypeKind::Bool => "Bool".to_owned(),
+            TypeKind::I8 => "I8".to_owned(),
+            TypeKind::I16 => "I16".to_owned(),
+            TypeKind::I32 => "I32".to_owned(),
+            TypeKind::I64 => "I64".to_owned(),
+            TypeKind::I128 => "I128".to_owned(),
+            TypeKind::U8 => "U8".to_owned(),
+            TypeKind::U16 => "U16".to_owned(),
+            TypeKind::U32 => "U32".to_owned(),
+            TypeKind::U64 => "U64".to_owned(),
+            TypeKind::U128 => "U128".to_owned(),
+            TypeKind::String => "SborString".to_owned(),
+            TypeKind::Custom(ScryptoCustomTypeKind::Reference) => "Reference".to_owned(),
+            TypeKind::Custom(ScryptoCustomTypeKind::Own) => "Own".to_owned(),
+            TypeKind::Custom(ScryptoCustomTypeKind::Decimal) => "Decimal".to_owned(),
+            TypeKind::Custom(ScryptoCustomTypeKind::PreciseDecimal) => "PreciseDecimal".to_owned(),
+            TypeKind::Custom(ScryptoCustomTypeKind::NonFungibleLocalId) => {
+                "NonFungibleLocalId".to_owned()
+            }
+            TypeKind::Array { element_type } => {
+                if is_bytes(type_id, &type_kind, self.schema_resolver)? {
+                    "Bytes".to_owned()
+                } else {
+                    format!(
+                        "SborArray<{}>",
+                        self.type_reference(&ScopedTypeId(type_id.0, *element_type))?
+                    )
+                }
+            }
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => format!(
+                "SborMap<{}, {}>",
+                self.type_reference(&ScopedTypeId(type_id.0, *key_type))?,
+                self.type_reference(&ScopedTypeId(type_id.0, *value_type))?
+            ),
+            TypeKind::Tuple { field_types } => {
+                let field_names = field_names(&type_metadata, None, field_types.len());
+                let fields = self.fields(type_id, field_types, field_names)?;
+                match type_name {
+                    Some(type_name) => {
+                        format!("SborTuple<[{fields}]> & {{ type_name?: \"{type_name}\" }}")
+                    }
+                    None => format!("SborTuple<[{fields}]>"),
+                }
+            }
+            TypeKind::Enum { variants } => {
+                if variants.is_empty() {
+                    return Ok(" never".to_owned());
+                }
+                let mut declaration = String::new();
+                for (variant_id, field_types) in variants.iter() {
+                    let field_names =
+                        field_names(&type_metadata, Some(*variant_id), field_types.len());
+                    let fields = self.fields(type_id, field_types, field_names)?;
+                    let mut details = vec![];
+                    if let Some(type_name) = type_name {
+                        details.push(format!("type_name?: \"{type_name}\""));
+                    }
+                    if let Some(variant_name) = variant_name(&type_metadata, *variant_id) {
+                        details.push(format!("variant_name?: \"{variant_name}\""));
+                    }
+                    let variant = if details.is_empty() {
+                        format!("SborEnumVariant<\"{variant_id}\", [{fields}]>")
+                    } else {
+                        format!(
+                            "(SborEnumVariant<\"{variant_id}\", [{fields}]> & {{ {} }})",
+                            details.join("; ")
+                        )
+                    };
+                    write!(declaration, "\n    | {variant}").unwrap();
+                }
+                return Ok(declaration);
+            }
+        };
+
+        Ok(format!(" {declaration}"))
+    }
+
+    fn fields(
+        &self,
+        type_id: &ScopedTypeId,
+        field_types: &[LocalTypeId],
+        field_names: Option<Vec<String>>,
+    ) -> Result<String, SchemaError> {
+        let mut fields = vec![];
+        for (index, field_type) in field_types.iter().enumerate() {
+            let field = self.type_reference(&ScopedTypeId(type_id.0, *field_type))?;
+            let field = match &field_names {
+                Some(field_names) => format!("Named<{field}, \"{}\">", field_names[index]),
+                None => field,
+            };
+            fields.push(field);
+        }
+        Ok(fields.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::*;
+    use super::*;
+
+    #[test]
+    fn named_types_are_declared_once_and_referenced_by_name() {
+        let (package_types, schema_resolver) = order_types();
+
+        let typescript = package_typescript(&package_types, &schema_resolver).unwrap();
+
+        assert!(typescript.contains(
+            "export type Order = SborTuple<[Named<U64, \"id\">, Named<Decimal, \"price\">, \
+            Named<Side, \"side\">, Named<Option, \"memo\">, Named<Bytes, \"payload\">, \
+            Named<SborArray<Order>, \"children\">]> & { type_name?: \"Order\" };"
+        ));
+        assert!(typescript.contains(
+            "    | (SborEnumVariant<\"1\", [Named<Decimal, \"limit\">]> \
+            & { type_name?: \"Side\"; variant_name?: \"Sell\" })"
+        ));
+        assert!(
+            typescript.contains("export interface OrderBookEvents {\n    OrderPlaced: Order;\n}")
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use heck::ToSnakeCase;
 use proc_macro2::*;
 use quote::*;
 use radix_engine_interface::prelude::*;
@@ -27,6 +28,7 @@ pub struct BlueprintStub {
     pub blueprint_name: String,
     pub fn_signatures: Vec<FnSignature>,
     pub package_address: PackageAddress,
+    pub view: BlueprintView,
 }
 
 impl ToTokens for BlueprintStub {
@@ -52,6 +54,7 @@ impl ToTokens for BlueprintStub {
             .iter()
             .filter(|func| matches!(func.fn_type, FnType::Method { .. }))
             .collect::<Vec<_>>();
+        let view = &self.view;
 
         quote! {
             extern_blueprint_internal! {
@@ -68,6 +71,71 @@ impl ToTokens for BlueprintStub {
                     #(#methods;)*
                 }
             }
+
+            #view
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// A read-only typed view of the fields, events and registered types of a blueprint, which allows
+/// their payloads to be decoded without copying the blueprint's type definitions.
+///
+/// This is generated as a module named `<blueprint_name>_view`, which contains:
+/// * The types which appear in the fields, events and registered types.
+/// * A `Field<index>` alias for the type of each field.
+/// * An alias for each event and registered type, where its name differs from that of its type.
+pub struct BlueprintView {
+    pub blueprint_name: String,
+    pub fields: IndexMap<usize, String>,
+    pub aliases: IndexMap<String, String>,
+    pub types: Vec<AuxiliaryType>,
+}
+
+impl ToTokens for BlueprintView {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let blueprint_name = &self.blueprint_name;
+        let module_name = Ident::new(
+            &format!("{}_view", self.blueprint_name.to_snake_case()),
+            Span::call_site(),
+        );
+        let types = &self.types;
+        let field_names = self
+            .fields
+            .keys()
+            .map(|field_index| Ident::new(&format!("Field{field_index}"), Span::call_site()));
+        let field_types = self
+            .fields
+            .values()
+            .map(|string| token_stream_from_str!(string));
+        let alias_names = self
+            .aliases
+            .keys()
+            .map(|string| token_stream_from_str!(string));
+        let alias_types = self
+            .aliases
+            .values()
+            .map(|string| token_stream_from_str!(string));
+
+        quote! {
+            // We allow dead code because not all of the view might be used
+            #[allow(dead_code, unused_imports)]
+            pub mod #module_name {
+                use super::*;
+                use ::scrypto::prelude::*;
+
+                pub const BLUEPRINT_NAME: &str = #blueprint_name;
+
+                #(#types)*
+
+                #(
+                    pub type #field_names = #field_types;
+                )*
+
+                #(
+                    pub type #alias_names = #alias_types;
+                )*
+            }
         }
         .to_tokens(tokens)
     }
@@ -133,6 +201,10 @@ pub enum AuxiliaryType {
         enum_name: String,
         variants: Vec<EnumVariant>,
     },
+    TypeAlias {
+        alias_name: String,
+        aliased_type: String,
+    },
 }
 
 impl ToTokens for AuxiliaryType {
@@ -151,7 +223,7 @@ impl ToTokens for AuxiliaryType {
                     #[derive(::scrypto::prelude::ScryptoSbor)]
                     pub struct #struct_name(
                         #(
-                            #field_types
+                            pub #field_types
                         ),*
                     );
                 }
@@ -169,7 +241,7 @@ impl ToTokens for AuxiliaryType {
                     #[derive(::scrypto::prelude::ScryptoSbor)]
                     pub struct #struct_name {
                         #(
-                            #field_names: #field_types
+                            pub #field_names: #field_types
                         ),*
                     }
                 }
@@ -191,6 +263,18 @@ impl ToTokens for AuxiliaryType {
                 }
                 .to_tokens(tokens)
             }
+            Self::TypeAlias {
+                alias_name,
+                aliased_type,
+            } => {
+                let alias_name = token_stream_from_str!(alias_name);
+                let aliased_type = token_stream_from_str!(aliased_type);
+
+                quote! {
+                    pub type #alias_name = #aliased_type;
+                }
+                .to_tokens(tokens)
+            }
         }
     }
 }
@@ -268,3 +352,226 @@ impl ToTokens for EnumVariant {
         }
     }
 }
+
+/// Typed extensions of the `ManifestBuilder` for invoking the blueprints of a package, for use by
+/// off-ledger Rust code which builds manifests. The generated code expects the
+/// `transaction::prelude` to be in scope.
+pub struct PackageManifestBuilderExtensions {
+    pub blueprints: Vec<BlueprintManifestBuilderExtension>,
+}
+
+impl ToTokens for PackageManifestBuilderExtensions {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let blueprints = &self.blueprints;
+        quote! {
+            #(#blueprints)*
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// A `<Blueprint>ManifestBuilderExt` trait with a `<blueprint>_<function>` method for each
+/// function and method of the blueprint, which is implemented for the `ManifestBuilder`.
+pub struct BlueprintManifestBuilderExtension {
+    pub blueprint_name: String,
+    pub package_address: PackageAddress,
+    pub fn_signatures: Vec<ManifestFnSignature>,
+}
+
+impl ToTokens for BlueprintManifestBuilderExtension {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let package_address_bytes = self.package_address.to_vec();
+        let blueprint_name = &self.blueprint_name;
+        let trait_ident = format_ident!("{}ManifestBuilderExt", self.blueprint_name);
+        let blueprint_snake_case = self.blueprint_name.to_snake_case();
+
+        let mut signatures = vec![];
+        let mut bodies = vec![];
+        for fn_signature in self.fn_signatures.iter() {
+            let fn_name = fn_signature.ident.to_string();
+            let builder_fn_ident = format_ident!("{}_{}", blueprint_snake_case, fn_name);
+            let input_names = fn_signature
+                .inputs
+                .iter()
+                .map(|(name, _)| name)
+                .collect::<Vec<_>>();
+            let input_types = fn_signature
+                .inputs
+                .iter()
+                .map(|(_, argument)| match argument {
+                    ManifestArgument::Bucket => quote! { impl ExistingManifestBucket },
+                    ManifestArgument::Proof => quote! { impl ExistingManifestProof },
+                    ManifestArgument::Value(type_name, _) => type_name.clone(),
+                });
+            let consumed_inputs =
+                fn_signature
+                    .inputs
+                    .iter()
+                    .filter_map(|(name, argument)| match argument {
+                        ManifestArgument::Bucket => Some(quote! {
+                            let #name = self.consume_bucket(#name);
+                        }),
+                        ManifestArgument::Proof => Some(quote! {
+                            let #name = self.consume_proof(#name);
+                        }),
+                        ManifestArgument::Value(_, consumable) => consumable
+                            .as_ref()
+                            .map(|consumable| consumable.consume(quote! { &#name })),
+                    });
+
+            if fn_signature.is_method {
+                signatures.push(quote! {
+                    fn #builder_fn_ident(
+                        self,
+                        component_address: impl ResolvableGlobalAddress,
+                        #( #input_names: #input_types ),*
+                    ) -> Self
+                });
+                bodies.push(quote! {
+                    #(#consumed_inputs)*
+                    self.call_method(
+                        component_address,
+                        #fn_name,
+                        manifest_args!( #(#input_names),* ),
+                    )
+                });
+            } else {
+                signatures.push(quote! {
+                    fn #builder_fn_ident(
+                        self,
+                        #( #input_names: #input_types ),*
+                    ) -> Self
+                });
+                bodies.push(quote! {
+                    #(#consumed_inputs)*
+                    self.call_function(
+                        PackageAddress::new_or_panic([ #(#package_address_bytes),* ]),
+                        #blueprint_name,
+                        #fn_name,
+                        manifest_args!( #(#input_names),* ),
+                    )
+                });
+            }
+        }
+
+        quote! {
+            pub trait #trait_ident: Sized {
+                #( #signatures; )*
+            }
+
+            impl #trait_ident for ManifestBuilder {
+                #(
+                    #signatures {
+                        #bodies
+                    }
+                )*
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+pub struct ManifestFnSignature {
+    pub ident: syn::Ident,
+    pub inputs: Vec<(syn::Ident, ManifestArgument)>,
+    pub is_method: bool,
+}
+
+/// An argument of an invocation added by a manifest builder extension. Buckets and proofs which are
+/// passed directly are taken by name, and marked as consumed. Values of other manifest types are
+/// passed as they are, and any buckets and proofs within them are marked as consumed.
+pub enum ManifestArgument {
+    Bucket,
+    Proof,
+    Value(TokenStream, Option<ManifestConsumable>),
+}
+
+/// Where the buckets and proofs are within a value, which are marked as consumed when the value is
+/// passed to an invocation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestConsumable {
+    Bucket,
+    Proof,
+    Elements(Box<ManifestConsumable>),
+    MapEntries {
+        key: Option<Box<ManifestConsumable>>,
+        value: Option<Box<ManifestConsumable>>,
+    },
+    TupleFields(Vec<(usize, ManifestConsumable)>),
+    Option(Box<ManifestConsumable>),
+    Result {
+        ok: Option<Box<ManifestConsumable>>,
+        err: Option<Box<ManifestConsumable>>,
+    },
+}
+
+impl ManifestConsumable {
+    /// The statements which mark the buckets and proofs within the value as consumed, given an
+    /// expression which references the value.
+    pub fn consume(&self, value: TokenStream) -> TokenStream {
+        // Binds a part of the value which may contain buckets or proofs, or ignores it if it can't
+        let bind =
+            |consumable: &Option<Box<ManifestConsumable>>, name: TokenStream| match consumable {
+                Some(consumable) => (name.clone(), consumable.consume(name)),
+                None => (quote! { _ }, quote! {}),
+            };
+
+        match self {
+            Self::Bucket => quote! {
+                let _ = self.consume_bucket(*(#value));
+            },
+            Self::Proof => quote! {
+                let _ = self.consume_proof(*(#value));
+            },
+            Self::Elements(element) => {
+                let consume_element = element.consume(quote! { element });
+                quote! {
+                    for element in (#value).iter() {
+                        #consume_element
+                    }
+                }
+            }
+            Self::MapEntries { key, value: entry } => {
+                let (key_pattern, consume_key) = bind(key, quote! { key });
+                let (value_pattern, consume_value) = bind(entry, quote! { value });
+                quote! {
+                    for (#key_pattern, #value_pattern) in (#value).iter() {
+                        #consume_key
+                        #consume_value
+                    }
+                }
+            }
+            Self::TupleFields(fields) => {
+                let consume_fields = fields.iter().map(|(field_index, field)| {
+                    let field_index = syn::Index::from(*field_index);
+                    field.consume(quote! { &(#value).#field_index })
+                });
+                quote! {
+                    #(#consume_fields)*
+                }
+            }
+            Self::Option(some) => {
+                let consume_some = some.consume(quote! { some });
+                quote! {
+                    if let Some(some) = #value {
+                        #consume_some
+                    }
+                }
+            }
+            Self::Result { ok, err } => {
+                let (ok_pattern, consume_ok) = bind(ok, quote! { ok });
+                let (err_pattern, consume_err) = bind(err, quote! { err });
+                quote! {
+                    match #value {
+                        Ok(#ok_pattern) => {
+                            #consume_ok
+                        }
+                        Err(#err_pattern) => {
+                            #consume_err
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -24,6 +24,11 @@ pub struct Args {
     /// the bindings.
     #[clap(short, long)]
     reset_ledger: bool,
+
+    /// When enabled, typed `ManifestBuilder` extension traits are generated for building manifests
+    /// which invoke the package, rather than Scrypto stubs.
+    #[clap(short, long)]
+    manifest_builder: bool,
 }
 
 #[derive(Debug)]
@@ -64,20 +69,31 @@ pub fn run() -> Result<(), Error> {
         let package_interface =
             schema::package_interface_from_package_definition(definition, &schema_resolver)
                 .map_err(Error::SchemaError)?;
-        let mut ast_package_interface = translation::package_schema_interface_to_ast_interface(
-            package_interface,
-            package_address,
-            &schema_resolver,
-        )
-        .map_err(Error::SchemaError)?;
-
-        // Scrypto-bindgen does not generate the aux-types. Only ledger-tools does.
-        ast_package_interface.auxiliary_types = Default::default();
-
-        ast_package_interface
+        if args.manifest_builder {
+            let extensions = translation::package_schema_interface_to_manifest_builder_extensions(
+                package_interface,
+                package_address,
+                &schema_resolver,
+            )
+            .map_err(Error::SchemaError)?;
+
+            quote::quote!(#extensions)
+        } else {
+            let mut ast_package_interface = translation::package_schema_interface_to_ast_interface(
+                package_interface,
+                package_address,
+                &schema_resolver,
+            )
+            .map_err(Error::SchemaError)?;
+
+            // Scrypto-bindgen does not generate the aux-types. Only ledger-tools does.
+            ast_package_interface.auxiliary_types = Default::default();
+
+            quote::quote!(#ast_package_interface)
+        }
     };
 
-    writeln!(&mut out, "{}", quote::quote!(#bindings)).map_err(Error::IOError)?;
+    writeln!(&mut out, "{}", bindings).map_err(Error::IOError)?;
 
     Ok(())
 }
@@ -36,20 +36,47 @@ where
 
     for (blueprint_key, blueprint_definition) in package_definition.into_iter() {
         let blueprint_name = blueprint_key.blueprint;
+        let blueprint_interface = package_interface
+            .blueprints
+            .entry(blueprint_name)
+            .or_default();
 
+        // View types
+        // The fields, events and registered types of the blueprint are exposed as read-only typed
+        // views, along with the types which need generating for them to make sense. Payloads
+        // which are generic over the instance can't be described statically, so are skipped.
         if let Some((_, fields)) = blueprint_definition.interface.state.fields {
-            for field in fields {
+            for (field_index, field) in fields.into_iter().enumerate() {
                 if let BlueprintPayloadDef::Static(scoped_type_id) = field.field {
                     package_interface.auxiliary_types.insert(scoped_type_id);
+                    blueprint_interface
+                        .fields
+                        .insert(field_index, scoped_type_id);
                 }
             }
         }
+        for (event_name, event_payload) in blueprint_definition.interface.events {
+            if let BlueprintPayloadDef::Static(scoped_type_id) = event_payload {
+                blueprint_interface
+                    .events
+                    .insert(event_name, scoped_type_id);
+            }
+        }
+        blueprint_interface.types = blueprint_definition.interface.types;
+        for view_type in blueprint_interface
+            .fields
+            .values()
+            .chain(blueprint_interface.events.values())
+            .chain(blueprint_interface.types.values())
+        {
+            get_scoped_type_ids_in_path(
+                view_type,
+                schema_resolver,
+                &mut blueprint_interface.view_types,
+            )?;
+        }
 
-        let functions = &mut package_interface
-            .blueprints
-            .entry(blueprint_name)
-            .or_default()
-            .functions;
+        let functions = &mut blueprint_interface.functions;
 
         for (function_name, function_schema) in blueprint_definition.interface.functions {
             let BlueprintPayloadDef::Static(input_type_identifier) = &function_schema.input else {
@@ -147,6 +174,15 @@ pub struct PackageInterface {
 pub struct BlueprintInterface {
     /// The functions and methods encountered in the blueprint interface.
     pub functions: Vec<Function>,
+    /// The types of the statically typed fields of the blueprint, keyed by the field index.
+    pub fields: IndexMap<usize, ScopedTypeId>,
+    /// The types of the statically typed events of the blueprint, keyed by the event name.
+    pub events: IndexMap<String, ScopedTypeId>,
+    /// The types registered by the blueprint, keyed by their registered name.
+    pub types: IndexMap<String, ScopedTypeId>,
+    /// A set of [`ScopedTypeId`] of the types found in the fields, events and registered types of
+    /// the blueprint, which are generated as part of its view.
+    pub view_types: HashSet<ScopedTypeId>,
 }
 
 #[derive(Clone, Debug)]
@@ -230,6 +266,9 @@ pub enum SchemaError {
     FailedToGetSchemaFromSchemaHash,
     GenericTypeRefsNotSupported,
     NoNameFound,
+    /// A type which can't be used in manifests, such as a named type defined by the package, which
+    /// isn't generated for manifests.
+    ManifestTypeNotSupported(String),
 }
 
 impl Display for SchemaError {
@@ -43,6 +43,12 @@ pub fn blueprint_schema_interface_to_ast_interface<S>(
 where
     S: schema::PackageSchemaResolver,
 {
+    let view = blueprint_schema_interface_to_ast_view(
+        &schema_interface,
+        blueprint_name.clone(),
+        schema_resolver,
+    )?;
+
     Ok(ast::BlueprintStub {
         fn_signatures: schema_interface
             .functions
@@ -51,6 +57,48 @@ where
             .collect::<Result<_, _>>()?,
         blueprint_name,
         package_address,
+        view,
+    })
+}
+
+pub fn blueprint_schema_interface_to_ast_view<S>(
+    schema_interface: &schema::BlueprintInterface,
+    blueprint_name: String,
+    schema_resolver: &S,
+) -> Result<ast::BlueprintView, schema::SchemaError>
+where
+    S: schema::PackageSchemaResolver,
+{
+    let fields = schema_interface
+        .fields
+        .iter()
+        .map(|(field_index, field_type)| {
+            type_name(field_type, schema_resolver).map(|type_name| (*field_index, type_name))
+        })
+        .collect::<Result<_, _>>()?;
+
+    // Events and registered types are usually named after their type, in which case the type
+    // itself is their view and no alias is needed.
+    let mut aliases = index_map_new();
+    for (name, type_id) in schema_interface
+        .events
+        .iter()
+        .chain(schema_interface.types.iter())
+    {
+        let type_name = type_name(type_id, schema_resolver)?;
+        if *name != type_name {
+            aliases.insert(name.clone(), type_name);
+        }
+    }
+
+    Ok(ast::BlueprintView {
+        blueprint_name,
+        fields,
+        aliases,
+        types: schema_auxiliary_types_to_ast_types(
+            schema_interface.view_types.clone(),
+            schema_resolver,
+        )?,
     })
 }
 
@@ -97,10 +145,188 @@ where
     })
 }
 
+pub fn package_schema_interface_to_manifest_builder_extensions<S>(
+    schema_interface: schema::PackageInterface,
+    package_address: PackageAddress,
+    schema_resolver: &S,
+) -> Result<ast::PackageManifestBuilderExtensions, schema::SchemaError>
+where
+    S: schema::PackageSchemaResolver,
+{
+    Ok(ast::PackageManifestBuilderExtensions {
+        blueprints: schema_interface
+            .blueprints
+            .into_iter()
+            .map(|(blueprint_name, blueprint_interface)| {
+                let mut fn_signatures = vec![];
+                for func in blueprint_interface.functions {
+                    let ident = func.ident.clone();
+                    match function_schema_interface_to_manifest_signature(func, schema_resolver) {
+                        Ok(fn_signature) => fn_signatures.push(fn_signature),
+                        // Functions taking types which can't be expressed in manifests are left
+                        // out, rather than failing the generation for the whole package
+                        Err(schema::SchemaError::ManifestTypeNotSupported(type_name)) => {
+                            eprintln!(
+                                "Warning: skipping `{}::{}`, as its argument type `{}` isn't supported in manifests",
+                                blueprint_name, ident, type_name
+                            );
+                        }
+                        Err(error) => return Err(error),
+                    }
+                }
+                Ok(ast::BlueprintManifestBuilderExtension {
+                    fn_signatures,
+                    blueprint_name,
+                    package_address,
+                })
+            })
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+pub fn function_schema_interface_to_manifest_signature<S>(
+    schema_interface: schema::Function,
+    schema_resolver: &S,
+) -> Result<ast::ManifestFnSignature, schema::SchemaError>
+where
+    S: schema::PackageSchemaResolver,
+{
+    let inputs = schema_interface
+        .arguments
+        .into_iter()
+        .map(|(arg_name, arg_type_index)| {
+            manifest_argument(&arg_type_index, schema_resolver)
+                .map(|argument| (ident!(&arg_name), argument))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(ast::ManifestFnSignature {
+        ident: ident!(&schema_interface.ident),
+        inputs,
+        is_method: schema_interface.receiver.is_some(),
+    })
+}
+
+fn manifest_argument<S>(
+    type_identifier: &ScopedTypeId,
+    schema_resolver: &S,
+) -> Result<ast::ManifestArgument, schema::SchemaError>
+where
+    S: schema::PackageSchemaResolver,
+{
+    let argument = match manifest_consumable(type_identifier, schema_resolver)? {
+        Some(ast::ManifestConsumable::Bucket) => ast::ManifestArgument::Bucket,
+        Some(ast::ManifestConsumable::Proof) => ast::ManifestArgument::Proof,
+        consumable => ast::ManifestArgument::Value(
+            token_stream_from_str!(&type_name_for_target(
+                type_identifier,
+                schema_resolver,
+                TypeNameTarget::Manifest
+            )?),
+            consumable,
+        ),
+    };
+    Ok(argument)
+}
+
+/// Finds the buckets and proofs within a type, which are consumed when a value of the type is
+/// passed to an invocation. This is `None` if the type can't contain any.
+fn manifest_consumable<S>(
+    type_identifier: &ScopedTypeId,
+    schema_resolver: &S,
+) -> Result<Option<ast::ManifestConsumable>, schema::SchemaError>
+where
+    S: schema::PackageSchemaResolver,
+{
+    let type_kind = schema_resolver.resolve_type_kind(type_identifier)?;
+    let type_metadata = schema_resolver.resolve_type_metadata(type_identifier)?;
+    let type_validation = schema_resolver.resolve_type_validation(type_identifier)?;
+    let consumable_at = |local_type_id: LocalTypeId| {
+        manifest_consumable(
+            &ScopedTypeId(type_identifier.0, local_type_id),
+            schema_resolver,
+        )
+    };
+
+    let consumable = match type_kind {
+        TypeKind::Custom(ScryptoCustomTypeKind::Own) => match type_validation {
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Own(own_type_validation)) => {
+                manifest_consumable_own(&own_type_validation)
+            }
+            _ => None,
+        },
+        TypeKind::Array { element_type } => consumable_at(element_type)?
+            .map(|element| ast::ManifestConsumable::Elements(Box::new(element))),
+        TypeKind::Map {
+            key_type,
+            value_type,
+        } => match (consumable_at(key_type)?, consumable_at(value_type)?) {
+            (None, None) => None,
+            (key, value) => Some(ast::ManifestConsumable::MapEntries {
+                key: key.map(Box::new),
+                value: value.map(Box::new),
+            }),
+        },
+        // The named tuples which can be used in manifests are well-known types, which don't contain
+        // buckets or proofs, so only unnamed tuples are walked
+        TypeKind::Tuple { field_types } if type_metadata.get_name().is_none() => {
+            let mut fields = vec![];
+            for (field_index, field_type) in field_types.into_iter().enumerate() {
+                if let Some(field) = consumable_at(field_type)? {
+                    fields.push((field_index, field));
+                }
+            }
+            (!fields.is_empty()).then_some(ast::ManifestConsumable::TupleFields(fields))
+        }
+        TypeKind::Enum { variants } => match (
+            type_metadata.get_name(),
+            variants.get(&0).map(|vec| vec.as_slice()),
+            variants.get(&1).map(|vec| vec.as_slice()),
+        ) {
+            (Some("Option"), Some([]), Some([some_type_index])) => consumable_at(*some_type_index)?
+                .map(|some| ast::ManifestConsumable::Option(Box::new(some))),
+            (Some("Result"), Some([ok_type_index]), Some([err_type_index])) => {
+                match (
+                    consumable_at(*ok_type_index)?,
+                    consumable_at(*err_type_index)?,
+                ) {
+                    (None, None) => None,
+                    (ok, err) => Some(ast::ManifestConsumable::Result {
+                        ok: ok.map(Box::new),
+                        err: err.map(Box::new),
+                    }),
+                }
+            }
+            _ => None,
+        },
+        _ => None,
+    };
+    Ok(consumable)
+}
+
+/// The code which a type name is generated for, which determines the names of types which
+/// differ between Scrypto and manifests.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TypeNameTarget {
+    Scrypto,
+    Manifest,
+}
+
 fn type_name<S>(
     type_identifier: &ScopedTypeId,
     schema_resolver: &S,
 ) -> Result<String, schema::SchemaError>
+where
+    S: schema::PackageSchemaResolver,
+{
+    type_name_for_target(type_identifier, schema_resolver, TypeNameTarget::Scrypto)
+}
+
+fn type_name_for_target<S>(
+    type_identifier: &ScopedTypeId,
+    schema_resolver: &S,
+    target: TypeNameTarget,
+) -> Result<String, schema::SchemaError>
 where
     S: schema::PackageSchemaResolver,
 {
@@ -109,6 +335,32 @@ where
     let type_validation = schema_resolver.resolve_type_validation(type_identifier)?;
     let metadata_type_name = type_metadata.get_name_string();
 
+    if target == TypeNameTarget::Manifest {
+        if let Some(manifest_type_name) = manifest_type_name(&type_kind, &type_validation) {
+            return Ok(manifest_type_name.to_owned());
+        }
+
+        // Other owned objects can't be passed in manifests.
+        if let TypeKind::Custom(ScryptoCustomTypeKind::Own) = type_kind {
+            return Err(schema::SchemaError::ManifestTypeNotSupported(
+                metadata_type_name.unwrap_or("Own".to_owned()),
+            ));
+        }
+
+        // The named types defined by the package aren't generated for manifests, and may not be
+        // manifest encodable, so they can't be used. `Option` and `Result` are named after their
+        // generic parameters below.
+        if let (LocalTypeId::SchemaLocalIndex(..), Some(name)) =
+            (type_identifier.1, type_metadata.get_name())
+        {
+            if name != "Option" && name != "Result" {
+                return Err(schema::SchemaError::ManifestTypeNotSupported(
+                    name.to_owned(),
+                ));
+            }
+        }
+    }
+
     let name = match type_kind {
         TypeKind::Any => metadata_type_name.unwrap_or("ScryptoValue".to_owned()),
         TypeKind::Bool => metadata_type_name.unwrap_or("bool".to_owned()),
@@ -123,29 +375,37 @@ where
         TypeKind::U64 => metadata_type_name.unwrap_or("u64".to_owned()),
         TypeKind::U128 => metadata_type_name.unwrap_or("u128".to_owned()),
         TypeKind::String => metadata_type_name.unwrap_or("String".to_owned()),
-        TypeKind::Array { element_type } => metadata_type_name.unwrap_or(format!(
-            "Vec<{}>",
-            type_name(
-                &ScopedTypeId(type_identifier.0, element_type),
-                schema_resolver,
-            )?
-        )),
-        TypeKind::Tuple { field_types } => {
-            metadata_type_name.unwrap_or(match field_types.as_slice() {
+        TypeKind::Array { element_type } => match metadata_type_name {
+            Some(name) => name,
+            None => format!(
+                "Vec<{}>",
+                type_name_for_target(
+                    &ScopedTypeId(type_identifier.0, element_type),
+                    schema_resolver,
+                    target,
+                )?
+            ),
+        },
+        // Named types are referred to by their name, without resolving their fields, which is
+        // also what stops recursive types from being resolved indefinitely.
+        TypeKind::Tuple { field_types } => match metadata_type_name {
+            Some(name) => name,
+            None => match field_types.as_slice() {
                 [] => "()".to_owned(),
                 types => format!(
                     "({},)",
                     types
                         .iter()
-                        .map(|local_type_index| type_name(
+                        .map(|local_type_index| type_name_for_target(
                             &ScopedTypeId(type_identifier.0, *local_type_index),
                             schema_resolver,
+                            target,
                         ))
                         .collect::<Result<Vec<String>, _>>()?
                         .join(", ")
                 ),
-            })
-        }
+            },
+        },
         TypeKind::Enum { variants } => {
             // There is currently no way to know if this type has generics or not. Thus, we need to
             // deal with generic enums from the standard library in a special way. We determine if
@@ -159,21 +419,24 @@ where
             ) {
                 (Some("Option"), 2usize, Some([]), Some([some_type_index])) => Ok(format!(
                     "Option<{}>",
-                    type_name(
+                    type_name_for_target(
                         &ScopedTypeId(type_identifier.0, *some_type_index),
                         schema_resolver,
+                        target,
                     )?
                 )),
                 (Some("Result"), 2usize, Some([ok_type_index]), Some([err_type_index])) => {
                     Ok(format!(
                         "Result<{}, {}>",
-                        type_name(
+                        type_name_for_target(
                             &ScopedTypeId(type_identifier.0, *ok_type_index),
                             schema_resolver,
+                            target,
                         )?,
-                        type_name(
+                        type_name_for_target(
                             &ScopedTypeId(type_identifier.0, *err_type_index),
                             schema_resolver,
+                            target,
                         )?
                     ))
                 }
@@ -184,14 +447,22 @@ where
         TypeKind::Map {
             key_type,
             value_type,
-        } => metadata_type_name.unwrap_or(format!(
-            "IndexMap<{}, {}>",
-            type_name(&ScopedTypeId(type_identifier.0, key_type), schema_resolver,)?,
-            type_name(
-                &ScopedTypeId(type_identifier.0, value_type),
-                schema_resolver,
-            )?
-        )),
+        } => match metadata_type_name {
+            Some(name) => name,
+            None => format!(
+                "IndexMap<{}, {}>",
+                type_name_for_target(
+                    &ScopedTypeId(type_identifier.0, key_type),
+                    schema_resolver,
+                    target
+                )?,
+                type_name_for_target(
+                    &ScopedTypeId(type_identifier.0, value_type),
+                    schema_resolver,
+                    target,
+                )?
+            ),
+        },
         TypeKind::Custom(custom_type_kind) => match custom_type_kind {
             ScryptoCustomTypeKind::Reference => match type_validation {
                 TypeValidation::None => metadata_type_name.unwrap_or("Reference".to_owned()),
@@ -289,6 +560,56 @@ where
     Ok(name)
 }
 
+/// The name of the manifest type which is used in place of the Scrypto type, for the types which
+/// are encoded differently in manifests.
+fn manifest_type_name(
+    type_kind: &SchemaTypeKind<ScryptoCustomSchema>,
+    type_validation: &TypeValidation<ScryptoCustomTypeValidation>,
+) -> Option<&'static str> {
+    match (type_kind, type_validation) {
+        (TypeKind::Any, _) => Some("ManifestValue"),
+        (
+            TypeKind::Custom(ScryptoCustomTypeKind::Own),
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Own(own_type_validation)),
+        ) => match manifest_consumable_own(own_type_validation) {
+            Some(ast::ManifestConsumable::Bucket) => Some("ManifestBucket"),
+            Some(ast::ManifestConsumable::Proof) => Some("ManifestProof"),
+            _ => match own_type_validation {
+                OwnValidation::IsGlobalAddressReservation => Some("ManifestAddressReservation"),
+                _ => None,
+            },
+        },
+        (
+            TypeKind::Custom(ScryptoCustomTypeKind::Reference),
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Reference(
+                ReferenceValidation::IsGlobalTyped(..),
+            )),
+        ) => Some("GlobalAddress"),
+        _ => None,
+    }
+}
+
+/// Whether an owned type is a bucket or a proof, including the typed buckets and proofs of the
+/// resource package, which are all passed to invocations as manifest buckets and proofs.
+fn manifest_consumable_own(own_type_validation: &OwnValidation) -> Option<ast::ManifestConsumable> {
+    match own_type_validation {
+        OwnValidation::IsBucket => Some(ast::ManifestConsumable::Bucket),
+        OwnValidation::IsProof => Some(ast::ManifestConsumable::Proof),
+        OwnValidation::IsTypedObject(Some(RESOURCE_PACKAGE), blueprint_name) => {
+            match blueprint_name.as_str() {
+                FUNGIBLE_BUCKET_BLUEPRINT | NON_FUNGIBLE_BUCKET_BLUEPRINT => {
+                    Some(ast::ManifestConsumable::Bucket)
+                }
+                FUNGIBLE_PROOF_BLUEPRINT | NON_FUNGIBLE_PROOF_BLUEPRINT => {
+                    Some(ast::ManifestConsumable::Proof)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
 pub fn schema_auxiliary_types_to_ast_types<S>(
     auxiliary_types: HashSet<ScopedTypeId>,
     schema_resolver: &S,
@@ -433,8 +754,9 @@ where
                     variants: enum_variants,
                 }
             }
-            TypeKind::Any
-            | TypeKind::Bool
+            /* Named arrays, maps and primitives come from transparent structs, which are encoded
+            the same as the type they wrap, and so are aliased to it */
+            TypeKind::Bool
             | TypeKind::I8
             | TypeKind::I16
             | TypeKind::I32
@@ -447,8 +769,47 @@ where
             | TypeKind::U128
             | TypeKind::String
             | TypeKind::Array { .. }
-            | TypeKind::Map { .. }
-            | TypeKind::Custom(..) => {
+            | TypeKind::Map { .. } => {
+                let Some(ref alias_name) = type_metadata.type_name else {
+                    continue;
+                };
+
+                let aliased_type = match type_kind {
+                    TypeKind::Array { element_type } => format!(
+                        "Vec<{}>",
+                        type_name(
+                            &ScopedTypeId(scoped_type_id.0, element_type),
+                            schema_resolver
+                        )?
+                    ),
+                    TypeKind::Map {
+                        key_type,
+                        value_type,
+                    } => format!(
+                        "IndexMap<{}, {}>",
+                        type_name(&ScopedTypeId(scoped_type_id.0, key_type), schema_resolver)?,
+                        type_name(&ScopedTypeId(scoped_type_id.0, value_type), schema_resolver)?
+                    ),
+                    TypeKind::Bool => "bool".to_owned(),
+                    TypeKind::I8 => "i8".to_owned(),
+                    TypeKind::I16 => "i16".to_owned(),
+                    TypeKind::I32 => "i32".to_owned(),
+                    TypeKind::I64 => "i64".to_owned(),
+                    TypeKind::I128 => "i128".to_owned(),
+                    TypeKind::U8 => "u8".to_owned(),
+                    TypeKind::U16 => "u16".to_owned(),
+                    TypeKind::U32 => "u32".to_owned(),
+                    TypeKind::U64 => "u64".to_owned(),
+                    TypeKind::U128 => "u128".to_owned(),
+                    _ => "String".to_owned(),
+                };
+
+                ast::AuxiliaryType::TypeAlias {
+                    alias_name: alias_name.as_ref().into(),
+                    aliased_type,
+                }
+            }
+            TypeKind::Any | TypeKind::Custom(..) => {
                 /* Not considered an auxiliary type - no generation needed */
                 continue;
             }
@@ -461,3 +822,406 @@ where
 
     Ok(ast_auxiliary_types)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quote::quote;
+
+    #[derive(ScryptoSbor)]
+    pub struct Order {
+        pub id: u64,
+        pub side: Side,
+        pub children: Vec<Order>,
+    }
+
+    #[derive(ScryptoSbor)]
+    pub enum Side {
+        Buy,
+        Sell { limit: Decimal },
+    }
+
+    #[derive(ScryptoSbor)]
+    #[sbor(transparent)]
+    pub struct OrderIds {
+        pub ids: Vec<u64>,
+    }
+
+    pub struct TestSchemaResolver {
+        schema_hash: SchemaHash,
+        schema: Rc<VersionedScryptoSchema>,
+    }
+
+    impl TestSchemaResolver {
+        pub fn of_single_type<T: ScryptoDescribe>() -> (Self, ScopedTypeId) {
+            let (type_id, schema) =
+                generate_full_schema_from_single_type::<T, ScryptoCustomSchema>();
+            let schema_hash = schema.generate_schema_hash();
+            (
+                Self {
+                    schema_hash,
+                    schema: Rc::new(schema),
+                },
+                ScopedTypeId(schema_hash, type_id),
+            )
+        }
+
+        pub fn local_type_ids(&self) -> HashSet<ScopedTypeId> {
+            (0..self.schema.v1().type_kinds.len())
+                .map(|index| ScopedTypeId(self.schema_hash, LocalTypeId::SchemaLocalIndex(index)))
+                .collect()
+        }
+    }
+
+    impl schema::PackageSchemaResolver for TestSchemaResolver {
+        fn lookup_schema(&self, schema_hash: &SchemaHash) -> Option<Rc<VersionedScryptoSchema>> {
+            (*schema_hash == self.schema_hash).then(|| self.schema.clone())
+        }
+
+        fn resolve_type_kind(
+            &self,
+            type_identifier: &ScopedTypeId,
+        ) -> Result<SchemaTypeKind<ScryptoCustomSchema>, schema::SchemaError> {
+            self.schema
+                .v1()
+                .resolve_type_kind(type_identifier.1)
+                .cloned()
+                .ok_or(schema::SchemaError::NonExistentLocalTypeIndex(
+                    type_identifier.1,
+                ))
+        }
+
+        fn resolve_type_metadata(
+            &self,
+            type_identifier: &ScopedTypeId,
+        ) -> Result<TypeMetadata, schema::SchemaError> {
+            self.schema
+                .v1()
+                .resolve_type_metadata(type_identifier.1)
+                .cloned()
+                .ok_or(schema::SchemaError::NonExistentLocalTypeIndex(
+                    type_identifier.1,
+                ))
+        }
+
+        fn resolve_type_validation(
+            &self,
+            type_identifier: &ScopedTypeId,
+        ) -> Result<TypeValidation<ScryptoCustomTypeValidation>, schema::SchemaError> {
+            self.schema
+                .v1()
+                .resolve_type_validation(type_identifier.1)
+                .cloned()
+                .ok_or(schema::SchemaError::NonExistentLocalTypeIndex(
+                    type_identifier.1,
+                ))
+        }
+
+        fn package_address(&self) -> PackageAddress {
+            PACKAGE_PACKAGE
+        }
+    }
+
+    fn order_book_view() -> ast::BlueprintView {
+        let (schema_resolver, order_type_id) = TestSchemaResolver::of_single_type::<Order>();
+        let blueprint_interface = schema::BlueprintInterface {
+            fields: indexmap!(0 => order_type_id),
+            events: indexmap!("OrderPlaced".to_string() => order_type_id),
+            types: indexmap!("Order".to_string() => order_type_id),
+            view_types: schema_resolver.local_type_ids(),
+            ..Default::default()
+        };
+
+        blueprint_schema_interface_to_ast_view(
+            &blueprint_interface,
+            "OrderBook".to_string(),
+            &schema_resolver,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn view_aliases_fields_and_differently_named_events_and_types() {
+        let view = order_book_view();
+
+        assert_eq!(view.fields, indexmap!(0 => "Order".to_string()));
+        assert_eq!(
+            view.aliases,
+            indexmap!("OrderPlaced".to_string() => "Order".to_string())
+        );
+    }
+
+    #[test]
+    fn view_generates_the_named_types_of_its_payloads() {
+        let view = order_book_view();
+
+        assert_eq!(view.types.len(), 2);
+        assert!(view.types.contains(&ast::AuxiliaryType::NamedFieldsStruct {
+            struct_name: "Order".to_string(),
+            fields: indexmap!(
+                "id".to_string() => "u64".to_string(),
+                "side".to_string() => "Side".to_string(),
+                "children".to_string() => "Vec<Order>".to_string(),
+            ),
+        }));
+        assert!(view.types.contains(&ast::AuxiliaryType::Enum {
+            enum_name: "Side".to_string(),
+            variants: vec![
+                ast::EnumVariant::Unit {
+                    variant_name: "Buy".to_string(),
+                    variant_index: 0,
+                },
+                ast::EnumVariant::NamedFields {
+                    variant_name: "Sell".to_string(),
+                    variant_index: 1,
+                    fields: indexmap!("limit".to_string() => "Decimal".to_string()),
+                },
+            ],
+        }));
+    }
+
+    #[test]
+    fn view_aliases_transparent_types_to_the_type_they_wrap() {
+        let (schema_resolver, order_ids_type_id) = TestSchemaResolver::of_single_type::<OrderIds>();
+        let blueprint_interface = schema::BlueprintInterface {
+            fields: indexmap!(0 => order_ids_type_id),
+            view_types: schema_resolver.local_type_ids(),
+            ..Default::default()
+        };
+
+        let view = blueprint_schema_interface_to_ast_view(
+            &blueprint_interface,
+            "OrderBook".to_string(),
+            &schema_resolver,
+        )
+        .unwrap();
+
+        assert_eq!(view.fields, indexmap!(0 => "OrderIds".to_string()));
+        assert_eq!(
+            view.types,
+            vec![ast::AuxiliaryType::TypeAlias {
+                alias_name: "OrderIds".to_string(),
+                aliased_type: "Vec<u64>".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn view_is_generated_as_a_module_named_after_the_blueprint() {
+        let view = order_book_view();
+
+        let code = quote!(#view).to_string();
+
+        assert!(code.contains(&quote!(pub mod order_book_view).to_string()));
+        assert!(code.contains(
+            &quote!(
+                pub const BLUEPRINT_NAME: &str = "OrderBook";
+            )
+            .to_string()
+        ));
+        assert!(code.contains(
+            &quote!(
+                pub type Field0 = Order;
+            )
+            .to_string()
+        ));
+        assert!(code.contains(
+            &quote!(
+                pub type OrderPlaced = Order;
+            )
+            .to_string()
+        ));
+        assert!(code
+            .contains(&quote!(pub id: u64, pub side: Side, pub children: Vec<Order>).to_string()));
+        assert!(!code.contains(
+            &quote!(
+                pub type Order = Order;
+            )
+            .to_string()
+        ));
+    }
+
+    /// Resolves the types of the fields of a tuple, as if they're the arguments of a function.
+    fn argument_types<T: ScryptoDescribe>() -> (TestSchemaResolver, Vec<ScopedTypeId>) {
+        let (schema_resolver, type_id) = TestSchemaResolver::of_single_type::<T>();
+        let TypeKind::Tuple { field_types } =
+            schema::PackageSchemaResolver::resolve_type_kind(&schema_resolver, &type_id).unwrap()
+        else {
+            panic!("The arguments must be a tuple");
+        };
+        let argument_types = field_types
+            .into_iter()
+            .map(|field_type| ScopedTypeId(type_id.0, field_type))
+            .collect();
+        (schema_resolver, argument_types)
+    }
+
+    #[test]
+    fn buckets_and_proofs_passed_directly_are_taken_by_name() {
+        let (schema_resolver, argument_types) = argument_types::<(Bucket, FungibleBucket, Proof)>();
+
+        let arguments = argument_types
+            .iter()
+            .map(|type_id| manifest_argument(type_id, &schema_resolver).unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(matches!(
+            arguments.as_slice(),
+            [
+                ast::ManifestArgument::Bucket,
+                ast::ManifestArgument::Bucket,
+                ast::ManifestArgument::Proof
+            ]
+        ));
+    }
+
+    #[test]
+    fn buckets_and_proofs_within_arguments_are_found() {
+        let (schema_resolver, type_id) = TestSchemaResolver::of_single_type::<(
+            Vec<Bucket>,
+            Option<Proof>,
+            IndexMap<String, FungibleBucket>,
+            u8,
+        )>();
+
+        let ast::ManifestArgument::Value(type_name, consumable) =
+            manifest_argument(&type_id, &schema_resolver).unwrap()
+        else {
+            panic!("The argument must be passed as a value");
+        };
+
+        // The spacing of the parsed type name depends on how it's parsed, so it's ignored
+        assert_eq!(
+            type_name.to_string().replace(' ', ""),
+            "(Vec<ManifestBucket>,Option<ManifestProof>,IndexMap<String,ManifestBucket>,u8,)"
+        );
+        assert_eq!(
+            consumable,
+            Some(ast::ManifestConsumable::TupleFields(vec![
+                (
+                    0,
+                    ast::ManifestConsumable::Elements(Box::new(ast::ManifestConsumable::Bucket))
+                ),
+                (
+                    1,
+                    ast::ManifestConsumable::Option(Box::new(ast::ManifestConsumable::Proof))
+                ),
+                (
+                    2,
+                    ast::ManifestConsumable::MapEntries {
+                        key: None,
+                        value: Some(Box::new(ast::ManifestConsumable::Bucket)),
+                    }
+                ),
+            ]))
+        );
+    }
+
+    #[test]
+    fn arguments_without_buckets_or_proofs_consume_nothing() {
+        let (schema_resolver, type_id) =
+            TestSchemaResolver::of_single_type::<(Vec<Decimal>, Option<String>)>();
+
+        let ast::ManifestArgument::Value(_, consumable) =
+            manifest_argument(&type_id, &schema_resolver).unwrap()
+        else {
+            panic!("The argument must be passed as a value");
+        };
+
+        assert_eq!(consumable, None);
+    }
+
+    #[test]
+    fn named_types_of_the_package_and_owned_objects_are_rejected_in_manifests() {
+        let (schema_resolver, type_id) = TestSchemaResolver::of_single_type::<Order>();
+        assert!(matches!(
+            manifest_argument(&type_id, &schema_resolver),
+            Err(schema::SchemaError::ManifestTypeNotSupported(name)) if name == "Order"
+        ));
+
+        let (schema_resolver, type_id) = TestSchemaResolver::of_single_type::<Vec<Vault>>();
+        assert!(matches!(
+            manifest_argument(&type_id, &schema_resolver),
+            Err(schema::SchemaError::ManifestTypeNotSupported(name)) if name == "Vault"
+        ));
+    }
+
+    #[test]
+    fn manifest_builder_extension_consumes_the_buckets_within_arguments() {
+        let (schema_resolver, argument_types) = argument_types::<(Bucket, Vec<FungibleBucket>)>();
+        let function = schema::Function {
+            ident: "add_liquidity".to_string(),
+            receiver: None,
+            arguments: indexmap!(
+                "bucket".to_string() => argument_types[0],
+                "buckets".to_string() => argument_types[1],
+            ),
+            returns: argument_types[0],
+        };
+        let extension = ast::BlueprintManifestBuilderExtension {
+            blueprint_name: "Pool".to_string(),
+            package_address: PACKAGE_PACKAGE,
+            fn_signatures: vec![function_schema_interface_to_manifest_signature(
+                function,
+                &schema_resolver,
+            )
+            .unwrap()],
+        };
+
+        let code = quote!(#extension).to_string();
+
+        assert!(code.contains(
+            &quote!(
+                fn pool_add_liquidity(
+                    self,
+                    bucket: impl ExistingManifestBucket,
+                    buckets: Vec<ManifestBucket>
+                ) -> Self
+            )
+            .to_string()
+        ));
+        assert!(code.contains(
+            &quote!(
+                let bucket = self.consume_bucket(bucket);
+                for element in (&buckets).iter() {
+                    let _ = self.consume_bucket(*(element));
+                }
+            )
+            .to_string()
+        ));
+    }
+
+    #[test]
+    fn functions_with_arguments_unsupported_in_manifests_are_skipped() {
+        let (schema_resolver, argument_types) = argument_types::<(Bucket, Vault)>();
+        let function = |ident: &str, argument_type: ScopedTypeId| schema::Function {
+            ident: ident.to_string(),
+            receiver: None,
+            arguments: indexmap!("argument".to_string() => argument_type),
+            returns: argument_types[0],
+        };
+        let package_interface = schema::PackageInterface {
+            blueprints: indexmap!(
+                "Pool".to_string() => schema::BlueprintInterface {
+                    functions: vec![
+                        function("deposit", argument_types[0]),
+                        function("deposit_vault", argument_types[1]),
+                    ],
+                    ..Default::default()
+                }
+            ),
+            auxiliary_types: Default::default(),
+        };
+
+        let extensions = package_schema_interface_to_manifest_builder_extensions(
+            package_interface,
+            PACKAGE_PACKAGE,
+            &schema_resolver,
+        )
+        .unwrap();
+
+        let fn_signatures = &extensions.blueprints[0].fn_signatures;
+        assert_eq!(fn_signatures.len(), 1);
+        assert_eq!(fn_signatures[0].ident.to_string(), "deposit");
+    }
+}
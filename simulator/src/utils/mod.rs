@@ -3,6 +3,7 @@ mod common_instructions;
 mod coverage;
 mod display;
 mod iter;
+mod network;
 mod resource_specifier;
 
 pub use cargo::*;
@@ -10,4 +11,5 @@ pub use common_instructions::*;
 pub use coverage::*;
 pub use display::list_item_prefix;
 pub use iter::{IdentifyLast, Iter};
+pub use network::*;
 pub use resource_specifier::*;
@@ -0,0 +1,114 @@
+use radix_engine_interface::network::{NetworkDefinition, ParseNetworkError};
+use serde::Deserialize;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The environment variable holding the path of a network config file, which is used when no
+/// network is given on the command line.
+pub const ENV_NETWORK_CONFIG: &str = "NETWORK_CONFIG";
+
+/// The definition of a network which isn't built in, as read from a JSON file, eg:
+///
+/// ```json
+/// { "id": 240, "logical_name": "privatenet", "hrp_suffix": "tdx_f0_" }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct NetworkConfig {
+    pub id: u8,
+    pub logical_name: String,
+    pub hrp_suffix: String,
+}
+
+#[derive(Debug)]
+pub enum NetworkConfigError {
+    IOError(std::io::Error, PathBuf),
+    JsonError(serde_json::Error, PathBuf),
+    ParseNetworkError(ParseNetworkError),
+}
+
+impl NetworkConfig {
+    pub fn load(path: &Path) -> Result<NetworkDefinition, NetworkConfigError> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| NetworkConfigError::IOError(err, path.to_owned()))?;
+        let config: NetworkConfig = serde_json::from_str(&content)
+            .map_err(|err| NetworkConfigError::JsonError(err, path.to_owned()))?;
+        NetworkDefinition::custom(config.id, &config.logical_name, &config.hrp_suffix)
+            .map_err(NetworkConfigError::ParseNetworkError)
+    }
+}
+
+/// Resolves the network to use from the command line arguments and the environment.
+///
+/// In order of precedence, this is the network in the given config file, the built-in network with
+/// the given name, the network in the config file of the `NETWORK_CONFIG` environment variable, and
+/// finally the simulator network.
+pub fn resolve_network(
+    network: &Option<String>,
+    network_config: &Option<PathBuf>,
+) -> Result<NetworkDefinition, NetworkConfigError> {
+    if let Some(path) = network_config {
+        return NetworkConfig::load(path);
+    }
+    if let Some(name) = network {
+        return NetworkDefinition::from_str(name).map_err(NetworkConfigError::ParseNetworkError);
+    }
+    match env::var(ENV_NETWORK_CONFIG) {
+        Ok(path) => NetworkConfig::load(Path::new(&path)),
+        Err(_) => Ok(NetworkDefinition::simulator()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn network_can_be_loaded_from_config_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("network.json");
+        std::fs::write(
+            &path,
+            r#"{ "id": 240, "logical_name": "privatenet", "hrp_suffix": "tdx_f0_" }"#,
+        )
+        .unwrap();
+
+        let network = resolve_network(&None, &Some(path.clone())).unwrap();
+        assert_eq!(
+            network,
+            NetworkDefinition::custom(240, "privatenet", "tdx_f0_").unwrap()
+        );
+
+        let network = temp_env::with_var(ENV_NETWORK_CONFIG, Some(&path), || {
+            resolve_network(&None, &None).unwrap()
+        });
+        assert_eq!(network.logical_name, "privatenet");
+
+        let network = temp_env::with_var(ENV_NETWORK_CONFIG, Some(&path), || {
+            resolve_network(&Some("stokenet".to_owned()), &None).unwrap()
+        });
+        assert_eq!(network, NetworkDefinition::stokenet());
+    }
+
+    #[test]
+    fn invalid_config_file_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("network.json");
+        std::fs::write(
+            &path,
+            r#"{ "id": 240, "logical_name": "privatenet", "hrp_suffix": "TDX" }"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            resolve_network(&None, &Some(path)),
+            Err(NetworkConfigError::ParseNetworkError(
+                ParseNetworkError::InvalidHrpSuffix(_)
+            ))
+        ));
+        assert!(matches!(
+            resolve_network(&None, &Some(dir.path().join("missing.json"))),
+            Err(NetworkConfigError::IOError(..))
+        ));
+    }
+}
@@ -0,0 +1,81 @@
+// This file has been generated from `assets/radiswap.wasm` by publishing it with resim and running
+// `scrypto-bindgen <package address> --manifest-builder`. It's checked in as is, to test that the
+// generated manifest builder extensions compile against `transaction::prelude`.
+
+pub trait RadiswapManifestBuilderExt: Sized {
+    fn radiswap_new(
+        self,
+        owner_role: OwnerRole,
+        resource_address1: ResourceAddress,
+        resource_address2: ResourceAddress,
+    ) -> Self;
+    fn radiswap_add_liquidity(
+        self,
+        component_address: impl ResolvableGlobalAddress,
+        resource1: impl ExistingManifestBucket,
+        resource2: impl ExistingManifestBucket,
+    ) -> Self;
+    fn radiswap_remove_liquidity(
+        self,
+        component_address: impl ResolvableGlobalAddress,
+        pool_units: impl ExistingManifestBucket,
+    ) -> Self;
+    fn radiswap_swap(
+        self,
+        component_address: impl ResolvableGlobalAddress,
+        input_bucket: impl ExistingManifestBucket,
+    ) -> Self;
+}
+impl RadiswapManifestBuilderExt for ManifestBuilder {
+    fn radiswap_new(
+        self,
+        owner_role: OwnerRole,
+        resource_address1: ResourceAddress,
+        resource_address2: ResourceAddress,
+    ) -> Self {
+        self.call_function(
+            PackageAddress::new_or_panic([
+                13u8, 163u8, 141u8, 245u8, 103u8, 130u8, 235u8, 46u8, 171u8, 112u8, 62u8, 228u8,
+                2u8, 43u8, 37u8, 10u8, 236u8, 114u8, 107u8, 6u8, 171u8, 217u8, 80u8, 77u8, 35u8,
+                178u8, 38u8, 108u8, 5u8, 86u8,
+            ]),
+            "Radiswap",
+            "new",
+            manifest_args!(owner_role, resource_address1, resource_address2),
+        )
+    }
+    fn radiswap_add_liquidity(
+        self,
+        component_address: impl ResolvableGlobalAddress,
+        resource1: impl ExistingManifestBucket,
+        resource2: impl ExistingManifestBucket,
+    ) -> Self {
+        let resource1 = self.consume_bucket(resource1);
+        let resource2 = self.consume_bucket(resource2);
+        self.call_method(
+            component_address,
+            "add_liquidity",
+            manifest_args!(resource1, resource2),
+        )
+    }
+    fn radiswap_remove_liquidity(
+        self,
+        component_address: impl ResolvableGlobalAddress,
+        pool_units: impl ExistingManifestBucket,
+    ) -> Self {
+        let pool_units = self.consume_bucket(pool_units);
+        self.call_method(
+            component_address,
+            "remove_liquidity",
+            manifest_args!(pool_units),
+        )
+    }
+    fn radiswap_swap(
+        self,
+        component_address: impl ResolvableGlobalAddress,
+        input_bucket: impl ExistingManifestBucket,
+    ) -> Self {
+        let input_bucket = self.consume_bucket(input_bucket);
+        self.call_method(component_address, "swap", manifest_args!(input_bucket))
+    }
+}
@@ -21,4 +21,6 @@ export resource=`echo $output | cut -d " " -f2`
 
 $resim run ./tests/m2.rtm
 
+$resim estimate-fee ./tests/m3.rtm
+
 $resim show-ledger
\ No newline at end of file
@@ -0,0 +1,30 @@
+use transaction::prelude::*;
+
+include!("bindings/radiswap_manifest_builder.rs");
+
+#[test]
+fn generated_radiswap_swap_calls_the_component_with_the_named_bucket() {
+    // Arrange
+    let account = ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+    );
+    let radiswap =
+        ComponentAddress::new_or_panic([EntityType::GlobalGenericComponent as u8; NodeId::LENGTH]);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, XRD, dec!(10))
+        .take_all_from_worktop(XRD, "xrd")
+        .radiswap_swap(radiswap, "xrd")
+        .build();
+
+    // Assert
+    assert_eq!(
+        manifest.instructions.last(),
+        Some(&InstructionV1::CallMethod {
+            address: radiswap.into(),
+            method_name: "swap".to_string(),
+            args: manifest_args!(ManifestBucket(0)).into(),
+        })
+    );
+}
@@ -72,6 +72,9 @@ $resim publish ../examples/hello-world/target/wasm32-unknown-unknown/release/hel
 # Test - export package definition
 $resim export-package-definition $package target/temp.rpd
 
+# Test - export package types
+$resim export-package-types $package target/temp-types
+
 # Test - dump component state
 $resim show $package
 $resim show $component
@@ -94,6 +97,13 @@ $resim run ./target/temp3.rtm
 $resim generate-key-pair
 $resim run ./target/temp2.rtm --blobs $blobs
 
+# Test - sign a transaction with several keys (including an Ed25519 one) and submit it later
+ed25519_key=`$resim generate-key-pair --ed25519 | awk '/Private key:/ {print $NF}'`
+default_key=`$resim show-configs | awk '/Account Private Key:/ {print $NF}'`
+$resim transfer resource_sim1tknxxxxxxxxxradxrdxxxxxxxxx009923554798xxxxxxxxxakj8n3:1 $account2 --manifest ./target/temp4.rtm
+$resim sign ./target/temp4.rtm --signing-keys "$default_key,$ed25519_key" --output ./target/temp4.tx
+$resim submit ./target/temp4.tx
+
 # Test - nft
 package=`$resim publish ./tests/blueprints --owner-badge $owner_badge | awk '/Package:/ {print $NF}'`
 $resim call-function $package Foo nfts
@@ -128,3 +138,14 @@ token_address=`$resim new-token-mutable $non_fungible_global_id | awk '/Resource
 
 # Test - mint and transfer (Mintable that requires a `NonFungibleGlobalId`)
 $resim mint 777 $token_address --proofs "$non_fungible_global_id"
+
+# Test - export the ledger to a snapshot, and import it back after a reset
+$resim export ./target/ledger.snapshot
+$resim reset
+$resim import ./target/ledger.snapshot
+account_dump=`$resim show $account`
+if [[ ${account_dump} != *"$token_address"* ]];then
+    echo "Ledger not imported!"
+    exit 1
+fi
+$resim mint 1 $token_address --proofs "$non_fungible_global_id"
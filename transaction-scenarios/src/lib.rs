@@ -22,6 +22,7 @@ compile_error!("Feature `std` and `alloc` can't be enabled at the same time.");
 //====================================================================================
 
 pub mod accounts;
+pub mod recording;
 #[allow(unused)] // Some things are only used in std build
 pub mod runners;
 pub mod scenario;
@@ -32,6 +33,7 @@ pub mod prelude {}
 // Extra things which this crate wants which upstream crates likely don't
 pub(crate) mod internal_prelude {
     pub use crate::accounts::*;
+    pub use crate::recording::*;
     pub use crate::scenario::*;
     pub use radix_engine::transaction::*;
     pub use radix_engine_interface::prelude::*;
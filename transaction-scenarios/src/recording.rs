@@ -0,0 +1,110 @@
+use crate::internal_prelude::*;
+
+/// A scenario recorded from an ad-hoc session (e.g. of a `TestRunner`), which can be replayed
+/// deterministically onto a freshly bootstrapped ledger - see [`run_recorded_scenario()`].
+///
+/// [`run_recorded_scenario()`]: crate::runners::replayer::run_recorded_scenario
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct RecordedScenario {
+    pub logical_name: String,
+    pub protocol_updates: RecordedProtocolUpdates,
+    pub steps: Vec<RecordedStep>,
+}
+
+/// The protocol updates applied after the (default) genesis of the recorded ledger.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct RecordedProtocolUpdates {
+    pub seconds_precision: bool,
+    pub crypto_utils: bool,
+    pub validator_fee_fix: bool,
+    pub pools_v1_1: bool,
+    pub vesting_package: bool,
+    pub account_v1_1: bool,
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub enum RecordedStep {
+    /// A test transaction, executed with the given initial proofs (i.e. the badges of its signers).
+    /// The payload is a manifest-encoded [`TestTransaction`].
+    TestTransaction {
+        payload: Vec<u8>,
+        initial_proofs: BTreeSet<NonFungibleGlobalId>,
+        costing_parameters: CostingParameters,
+    },
+    /// A system transaction, e.g. a round change (which is how the ledger's time is advanced).
+    SystemTransaction {
+        raw: RawSystemTransaction,
+        proofs: BTreeSet<NonFungibleGlobalId>,
+    },
+    NotarizedTransaction {
+        raw: RawNotarizedTransaction,
+        network: NetworkDefinition,
+    },
+    /// A direct override of the consensus manager's epoch.
+    SetCurrentEpoch(Epoch),
+    /// A transaction which can't be replayed, e.g. one executed from a bare `Executable` (without
+    /// its payload), or one executed with a custom system. A scenario containing it fails to replay.
+    Unreplayable { reason: String },
+}
+
+impl RecordedScenario {
+    pub fn new(logical_name: impl Into<String>, protocol_updates: RecordedProtocolUpdates) -> Self {
+        Self {
+            logical_name: logical_name.into(),
+            protocol_updates,
+            steps: vec![],
+        }
+    }
+
+    pub fn record(&mut self, step: RecordedStep) {
+        self.steps.push(step);
+    }
+
+    /// Whether none of the steps is [`RecordedStep::Unreplayable`].
+    pub fn is_replayable(&self) -> bool {
+        !self
+            .steps
+            .iter()
+            .any(|step| matches!(step, RecordedStep::Unreplayable { .. }))
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EncodeError> {
+        scrypto_encode(self)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        scrypto_decode(bytes)
+    }
+}
+
+impl RecordedStep {
+    pub fn test_transaction(
+        transaction: &TestTransaction,
+        initial_proofs: &BTreeSet<NonFungibleGlobalId>,
+        costing_parameters: &CostingParameters,
+    ) -> Self {
+        Self::TestTransaction {
+            payload: manifest_encode(transaction).expect("Test transaction could be encoded"),
+            initial_proofs: initial_proofs.clone(),
+            costing_parameters: *costing_parameters,
+        }
+    }
+
+    pub fn unreplayable(reason: impl Into<String>) -> Self {
+        Self::Unreplayable {
+            reason: reason.into(),
+        }
+    }
+
+    pub fn system_transaction(
+        transaction: &SystemTransactionV1,
+        proofs: &BTreeSet<NonFungibleGlobalId>,
+    ) -> Self {
+        Self::SystemTransaction {
+            raw: transaction
+                .to_raw()
+                .expect("System transaction could be encoded"),
+            proofs: proofs.clone(),
+        }
+    }
+}
@@ -1 +1,2 @@
 pub mod dumper;
+pub mod replayer;
@@ -0,0 +1,169 @@
+use crate::internal_prelude::*;
+use radix_engine::blueprints::consensus_manager::*;
+use radix_engine::blueprints::models::FieldPayload;
+use radix_engine::system::bootstrap::Bootstrapper;
+use radix_engine::system::system_callback_api::SystemCallbackObject;
+use radix_engine::system::system_db_reader::{SystemDatabaseReader, SystemDatabaseWriter};
+use radix_engine::utils::*;
+use radix_engine_interface::api::ModuleId;
+use radix_engine_store_interface::db_key_mapper::SpreadPrefixKeyMapper;
+use radix_engine_store_interface::interface::*;
+use transaction::validation::{
+    NotarizedTransactionValidator, TransactionValidator, ValidationConfig,
+};
+
+/// Bootstraps the given (empty) database into the state in which the ledger of the recorded
+/// scenario started.
+pub fn bootstrap_for_recorded_scenario<S, V>(
+    substate_db: &mut S,
+    vm: V,
+    protocol_updates: &RecordedProtocolUpdates,
+) where
+    S: SubstateDatabase + CommittableSubstateDatabase,
+    V: SystemCallbackObject + Clone,
+{
+    Bootstrapper::new(NetworkDefinition::simulator(), substate_db, vm, false)
+        .bootstrap_test_default()
+        .expect("Database should be empty");
+
+    if protocol_updates.seconds_precision {
+        let state_updates = generate_seconds_precision_state_updates(substate_db);
+        substate_db.commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+    }
+    if protocol_updates.crypto_utils {
+        let state_updates = generate_vm_boot_scrypto_minor_version_state_updates();
+        substate_db.commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+    }
+    if protocol_updates.validator_fee_fix {
+        let state_updates = generate_validator_fee_fix_state_updates(substate_db);
+        substate_db.commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+    }
+    if protocol_updates.pools_v1_1 {
+        let state_updates = generate_pools_v1_1_state_updates(substate_db);
+        substate_db.commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+    }
+    if protocol_updates.vesting_package {
+        let state_updates = generate_vesting_package_state_updates();
+        substate_db.commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+    }
+    if protocol_updates.account_v1_1 {
+        let state_updates = generate_account_v1_1_state_updates(substate_db);
+        substate_db.commit(&state_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+    }
+}
+
+/// Replays all steps of the recorded scenario, in order, onto a database bootstrapped with
+/// [`bootstrap_for_recorded_scenario()`].
+///
+/// Note: a recorded transaction's failure is not an error - it is replayed faithfully, and the
+/// receipt is passed to the handler just like any other. However, reaching a
+/// [`RecordedStep::Unreplayable`] step is an error.
+pub fn run_recorded_scenario<S, V, F>(
+    substate_db: &mut S,
+    vm: V,
+    scenario: &RecordedScenario,
+    mut receipt_handler: F,
+) -> Result<(), FullScenarioError>
+where
+    S: SubstateDatabase + CommittableSubstateDatabase,
+    V: SystemCallbackObject + Clone,
+    F: FnMut(&TransactionReceipt),
+{
+    let into_full = |error: ScenarioError| FullScenarioError {
+        scenario: scenario.logical_name.clone(),
+        error,
+    };
+    for (index, step) in scenario.steps.iter().enumerate() {
+        let invalid_step =
+            |error: String| into_full(ScenarioError::InvalidRecordedStep(index, error));
+        let receipt = match step {
+            RecordedStep::TestTransaction {
+                payload,
+                initial_proofs,
+                costing_parameters,
+            } => {
+                let transaction = manifest_decode::<TestTransaction>(payload)
+                    .map_err(|err| invalid_step(format!("{:?}", err)))?
+                    .prepare()
+                    .map_err(|err| invalid_step(format!("{:?}", err)))?;
+                execute_and_commit_transaction(
+                    substate_db,
+                    vm.clone(),
+                    costing_parameters,
+                    &ExecutionConfig::for_test_transaction(),
+                    &transaction.get_executable(initial_proofs.clone()),
+                )
+            }
+            RecordedStep::SystemTransaction { raw, proofs } => {
+                let transaction = SystemTransactionV1::from_raw(raw)
+                    .map_err(|err| invalid_step(format!("{:?}", err)))?
+                    .prepare()
+                    .map_err(|err| invalid_step(format!("{:?}", err)))?;
+                execute_and_commit_transaction(
+                    substate_db,
+                    vm.clone(),
+                    &CostingParameters::default(),
+                    &ExecutionConfig::for_system_transaction(NetworkDefinition::simulator()),
+                    &transaction.get_executable(proofs.clone()),
+                )
+            }
+            RecordedStep::NotarizedTransaction { raw, network } => {
+                let transaction =
+                    NotarizedTransactionValidator::new(ValidationConfig::default(network.id))
+                        .validate_from_raw(raw)
+                        .map_err(|err| {
+                            into_full(ScenarioError::TransactionValidationFailed(
+                                format!("recorded step {}", index),
+                                err,
+                            ))
+                        })?;
+                execute_and_commit_transaction(
+                    substate_db,
+                    vm.clone(),
+                    &CostingParameters::default(),
+                    &ExecutionConfig::for_notarized_transaction(network.clone()),
+                    &transaction.get_executable(),
+                )
+            }
+            RecordedStep::SetCurrentEpoch(epoch) => {
+                set_current_epoch(substate_db, *epoch);
+                continue;
+            }
+            RecordedStep::Unreplayable { reason } => {
+                return Err(into_full(ScenarioError::UnreplayableRecordedStep(
+                    index,
+                    reason.clone(),
+                )));
+            }
+        };
+        receipt_handler(&receipt);
+    }
+    Ok(())
+}
+
+fn set_current_epoch<S: SubstateDatabase + CommittableSubstateDatabase>(
+    substate_db: &mut S,
+    epoch: Epoch,
+) {
+    let reader = SystemDatabaseReader::new(substate_db);
+    let mut substate = reader
+        .read_typed_object_field::<ConsensusManagerStateFieldPayload>(
+            CONSENSUS_MANAGER.as_node_id(),
+            ModuleId::Main,
+            ConsensusManagerField::State.field_index(),
+        )
+        .unwrap()
+        .into_latest();
+
+    substate.epoch = epoch;
+
+    let mut writer = SystemDatabaseWriter::new(substate_db);
+    writer
+        .write_typed_object_field(
+            CONSENSUS_MANAGER.as_node_id(),
+            ModuleId::Main,
+            ConsensusManagerField::State.field_index(),
+            ConsensusManagerStateFieldPayload::from_content_source(substate),
+        )
+        .unwrap();
+}
@@ -276,6 +276,8 @@ pub enum ScenarioError {
     TransactionRejected(String, RejectResult),
     TransactionAborted(String, AbortResult),
     TransactionValidationFailed(String, TransactionValidationError),
+    InvalidRecordedStep(usize, String),
+    UnreplayableRecordedStep(usize, String),
     StateReadBeforeSet,
     Custom(String),
 }
@@ -12,6 +12,7 @@ use radix_engine_interface::blueprints::consensus_manager::*;
 use radix_engine_interface::blueprints::identity::*;
 use radix_engine_interface::blueprints::package::*;
 use radix_engine_interface::blueprints::resource::*;
+use radix_engine_interface::blueprints::vesting::*;
 
 /// A manifest builder for use in tests.
 ///
@@ -131,6 +132,18 @@ impl ManifestBuilder {
         self.name_lookup().bucket(name)
     }
 
+    /// Resolves a bucket which is passed to an invocation, and marks it as consumed.
+    /// This can be used by extensions of the builder which add invocations taking buckets.
+    pub fn consume_bucket(&self, bucket: impl ExistingManifestBucket) -> ManifestBucket {
+        bucket.mark_consumed(&self.registrar)
+    }
+
+    /// Resolves a proof which is passed to an invocation, and marks it as consumed.
+    /// This can be used by extensions of the builder which add invocations taking proofs.
+    pub fn consume_proof(&self, proof: impl ExistingManifestProof) -> ManifestProof {
+        proof.mark_consumed(&self.registrar)
+    }
+
     pub fn proof(&self, name: impl AsRef<str>) -> ManifestProof {
         self.name_lookup().proof(name)
     }
@@ -2014,6 +2027,44 @@ impl ManifestBuilder {
         )
     }
 
+    /// Creates a vesting component which holds the tokens of the bucket, and releases them to
+    /// the beneficiary according to the schedule.
+    pub fn create_vesting(
+        self,
+        owner_role: OwnerRole,
+        beneficiary_rule: AccessRule,
+        admin_rule: AccessRule,
+        bucket: impl ExistingManifestBucket,
+        schedule: VestingSchedule,
+    ) -> Self {
+        let bucket = bucket.mark_consumed(&self.registrar);
+        self.call_function(
+            VESTING_PACKAGE,
+            VESTING_BLUEPRINT,
+            VESTING_INSTANTIATE_IDENT,
+            VestingInstantiateManifestInput {
+                owner_role,
+                beneficiary_rule,
+                admin_rule,
+                bucket,
+                schedule,
+                address_reservation: None,
+            },
+        )
+    }
+
+    /// Claims all vested tokens, putting them on the worktop.
+    pub fn claim_vested(self, vesting_address: impl ResolvableComponentAddress) -> Self {
+        let address = vesting_address.resolve(&self.registrar);
+        self.call_method(address, VESTING_CLAIM_IDENT, ())
+    }
+
+    /// Revokes the vesting, putting all tokens which have not vested yet on the worktop.
+    pub fn revoke_vesting(self, vesting_address: impl ResolvableComponentAddress) -> Self {
+        let address = vesting_address.resolve(&self.registrar);
+        self.call_method(address, VESTING_REVOKE_IDENT, ())
+    }
+
     /// Builds a transaction manifest.
     pub fn build(self) -> TransactionManifestV1 {
         let manifest = TransactionManifestV1 {
@@ -1,5 +1,9 @@
+use crate::internal_prelude::PublicKey;
 use crate::model::*;
-use crate::signing::Signer;
+use crate::signing::{
+    sign_externally, ExternalSignPayload, ExternalSigner, ExternalSignerError, Signer,
+};
+use rand_core::{CryptoRng, RngCore};
 
 pub struct TransactionBuilder {
     manifest: Option<TransactionManifestV1>,
@@ -35,6 +39,17 @@ impl TransactionBuilder {
         self
     }
 
+    /// Sets the message to the given message, encrypted for the given decryptors.
+    pub fn encrypted_message<R: RngCore + CryptoRng>(
+        self,
+        message: PlaintextMessageV1,
+        decryptors: &[PublicKey],
+        rng: &mut R,
+    ) -> Result<Self, MessageEncryptionError> {
+        let encrypted = message.encrypt(decryptors, rng)?;
+        Ok(self.message(MessageV1::Encrypted(encrypted)))
+    }
+
     pub fn sign<S: Signer>(mut self, signer: &S) -> Self {
         let intent = self.transaction_intent();
         let prepared = intent.prepare().expect("Intent could be prepared");
@@ -76,6 +91,47 @@ impl TransactionBuilder {
         self
     }
 
+    /// Signs the intent with the key at the given derivation path of an external signer, which
+    /// must be the public key the signer reports for that path.
+    pub fn sign_with_external<S: ExternalSigner>(
+        mut self,
+        signer: &S,
+        derivation_path: &str,
+    ) -> Result<Self, ExternalSignerError> {
+        let intent = self.transaction_intent();
+        let prepared = intent.prepare().expect("Intent could be prepared");
+        let (_, signature) = sign_externally(
+            signer,
+            derivation_path,
+            ExternalSignPayload::IntentHash(prepared.intent_hash()),
+        )?;
+        self.intent_signatures.push(signature);
+        Ok(self)
+    }
+
+    /// Notarizes the transaction with the key at the given derivation path of an external signer,
+    /// which must be the notary public key of the header.
+    pub fn notarize_with_external<S: ExternalSigner>(
+        mut self,
+        signer: &S,
+        derivation_path: &str,
+    ) -> Result<Self, ExternalSignerError> {
+        let signed_intent = self.signed_transaction_intent();
+        let prepared = signed_intent
+            .prepare()
+            .expect("Signed intent could be prepared");
+        let (public_key, signature) = sign_externally(
+            signer,
+            derivation_path,
+            ExternalSignPayload::SignedIntentHash(prepared.signed_intent_hash()),
+        )?;
+        if public_key != signed_intent.intent.header.notary_public_key {
+            return Err(ExternalSignerError::InvalidSignature);
+        }
+        self.notary_signature = Some(signature.signature());
+        Ok(self)
+    }
+
     pub fn build(&self) -> NotarizedTransactionV1 {
         NotarizedTransactionV1 {
             signed_intent: self.signed_transaction_intent(),
@@ -122,7 +178,8 @@ mod tests {
 
     use super::*;
     use crate::builder::*;
-    use crate::internal_prelude::Secp256k1PrivateKey;
+    use crate::internal_prelude::{PublicKey, Secp256k1PrivateKey};
+    use crate::signing::MockExternalSigner;
 
     #[test]
     fn notary_as_signatory() {
@@ -153,4 +210,92 @@ mod tests {
             true
         );
     }
+
+    fn header(notary_public_key: PublicKey) -> TransactionHeaderV1 {
+        TransactionHeaderV1 {
+            network_id: NetworkDefinition::simulator().id,
+            start_epoch_inclusive: Epoch::zero(),
+            end_epoch_exclusive: Epoch::of(100),
+            nonce: 5,
+            notary_public_key,
+            notary_is_signatory: false,
+            tip_percentage: 5,
+        }
+    }
+
+    #[test]
+    fn external_signer_is_asked_to_sign_the_intent_and_signed_intent_hashes() {
+        let notary_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let signatory_key = Secp256k1PrivateKey::from_u64(2).unwrap();
+        let signer = MockExternalSigner::new()
+            .with_key("m/0", Secp256k1PrivateKey::from_u64(1).unwrap())
+            .with_key("m/1", signatory_key);
+
+        let transaction = TransactionBuilder::new()
+            .header(header(notary_key.public_key().into()))
+            .manifest(ManifestBuilder::new().drop_auth_zone_proofs().build())
+            .sign_with_external(&signer, "m/1")
+            .unwrap()
+            .notarize_with_external(&signer, "m/0")
+            .unwrap()
+            .build();
+
+        let prepared = transaction.prepare().unwrap();
+        let requests = signer.requests();
+        assert_eq!(
+            requests[0].payload,
+            ExternalSignPayload::IntentHash(prepared.intent_hash())
+        );
+        assert_eq!(
+            requests[1].payload,
+            ExternalSignPayload::SignedIntentHash(prepared.signed_intent_hash())
+        );
+        assert_eq!(requests[1].derivation_path, "m/0");
+    }
+
+    #[test]
+    fn notarizing_with_a_key_other_than_the_notary_key_fails() {
+        let notary_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let signer =
+            MockExternalSigner::new().with_key("m/0", Secp256k1PrivateKey::from_u64(2).unwrap());
+
+        let result = TransactionBuilder::new()
+            .header(header(notary_key.public_key().into()))
+            .manifest(ManifestBuilder::new().drop_auth_zone_proofs().build())
+            .notarize_with_external(&signer, "m/0");
+
+        assert!(matches!(result, Err(ExternalSignerError::InvalidSignature)));
+    }
+
+    #[test]
+    fn signing_with_a_key_other_than_the_reported_key_fails() {
+        let notary_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let signer = MockExternalSigner::new()
+            .with_key("m/1", Secp256k1PrivateKey::from_u64(2).unwrap())
+            .signing_with_wrong_key("m/1", Secp256k1PrivateKey::from_u64(3).unwrap());
+
+        let result = TransactionBuilder::new()
+            .header(header(notary_key.public_key().into()))
+            .manifest(ManifestBuilder::new().drop_auth_zone_proofs().build())
+            .sign_with_external(&signer, "m/1");
+
+        assert!(matches!(result, Err(ExternalSignerError::InvalidSignature)));
+    }
+
+    #[test]
+    fn rejected_sign_requests_fail() {
+        let notary_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let signer = MockExternalSigner::new()
+            .with_key("m/0", Secp256k1PrivateKey::from_u64(1).unwrap())
+            .rejecting("Not allowed by policy");
+
+        let result = TransactionBuilder::new()
+            .header(header(notary_key.public_key().into()))
+            .manifest(ManifestBuilder::new().drop_auth_zone_proofs().build())
+            .sign_with_external(&signer, "m/0");
+
+        assert!(
+            matches!(result, Err(ExternalSignerError::Rejected(reason)) if reason == "Not allowed by policy")
+        );
+    }
 }
@@ -19,7 +19,7 @@ pub mod prelude {
     // Exports from this crate
     pub use crate::builder::*;
     pub use crate::model::*;
-    pub use crate::signing::{PrivateKey, Signer};
+    pub use crate::signing::{ExternalSigner, ExternalSignerError, PrivateKey, Signer};
 }
 
 // Extra things which this crate wants which upstream crates likely don't
@@ -9,8 +9,10 @@ pub mod generator;
 pub mod lexer;
 pub mod manifest_enums;
 pub mod parser;
+pub mod static_analysis;
 
 pub use blob_provider::*;
 pub use compiler::{compile, CompileError};
 pub use decompiler::{decompile, DecompileError};
 pub use manifest_enums::*;
+pub use static_analysis::{analyze, ManifestClassification, ManifestSummary};
@@ -0,0 +1,938 @@
+use crate::data::{transform, TransformHandler};
+use crate::internal_prelude::*;
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::consensus_manager::{
+    VALIDATOR_CLAIM_XRD_IDENT, VALIDATOR_STAKE_IDENT, VALIDATOR_UNSTAKE_IDENT,
+};
+use radix_engine_interface::blueprints::pool::{
+    MULTI_RESOURCE_POOL_CONTRIBUTE_IDENT, MULTI_RESOURCE_POOL_REDEEM_IDENT,
+    ONE_RESOURCE_POOL_CONTRIBUTE_IDENT, ONE_RESOURCE_POOL_REDEEM_IDENT,
+    TWO_RESOURCE_POOL_CONTRIBUTE_IDENT, TWO_RESOURCE_POOL_REDEEM_IDENT,
+};
+use sbor::rust::convert::Infallible;
+
+/// A semantic summary of a manifest, obtained without executing it (see [`analyze()`]).
+///
+/// All the reported quantities are only as precise as the manifest itself - e.g. a deposit of
+/// `ENTIRE_WORKTOP` after a call to an arbitrary component can only be reported as
+/// [`AccountDeposit::Unknown`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ManifestSummary {
+    /// Resources withdrawn from accounts, in the order of the withdraw calls.
+    pub account_withdraws: IndexMap<ComponentAddress, Vec<ResourceMovement>>,
+    /// Resources deposited into accounts, in the order of the deposit calls.
+    pub account_deposits: IndexMap<ComponentAddress, Vec<AccountDeposit>>,
+    /// Proofs created by the manifest, in the order of creation.
+    pub proofs_created: Vec<CreatedProof>,
+    /// Resources whose proofs are created directly from accounts (i.e. the badges presented by the
+    /// transaction's signers).
+    pub presented_badges: IndexSet<ResourceAddress>,
+    /// Resources guaranteed by the `ASSERT_WORKTOP_CONTAINS*` instructions.
+    pub guaranteed_resources: Vec<ResourceMovement>,
+    pub classification: ManifestClassification,
+}
+
+impl ManifestSummary {
+    pub fn accounts_withdrawn_from(&self) -> IndexSet<ComponentAddress> {
+        self.account_withdraws.keys().cloned().collect()
+    }
+
+    pub fn accounts_deposited_into(&self) -> IndexSet<ComponentAddress> {
+        self.account_deposits.keys().cloned().collect()
+    }
+}
+
+/// A kind of a manifest, recognized by the native blueprint methods it calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ManifestClassification {
+    /// A transfer of a single resource from one account into another account.
+    SimpleTransfer,
+    /// A transfer of resources between accounts, not calling any other components.
+    Transfer,
+    /// A contribution of resources to pools.
+    PoolContribution,
+    /// A redemption of pool units.
+    PoolRedemption,
+    /// A staking of XRD to validators.
+    ValidatorStake,
+    /// An unstaking of stake units from validators.
+    ValidatorUnstake,
+    /// A claim of unstaked XRD from validators.
+    ValidatorClaim,
+    /// An update of accounts' deposit rules or authorized depositors.
+    AccountDepositSettingsUpdate,
+    /// Any other (or mixed) kind of manifest.
+    #[default]
+    General,
+}
+
+/// A statically known quantity of a single resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceMovement {
+    pub resource_address: ResourceAddress,
+    pub quantity: ResourceQuantity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceQuantity {
+    Amount(Decimal),
+    NonFungibles(IndexSet<NonFungibleLocalId>),
+    /// A quantity which cannot be determined statically (e.g. a `TAKE_ALL_FROM_WORKTOP` of a
+    /// resource returned by some component), or any non-zero quantity in case of a guarantee.
+    Unspecified,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountDeposit {
+    Known(ResourceMovement),
+    /// A deposit of worktop contents which cannot be determined statically.
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatedProof {
+    pub source: ProofSource,
+    pub resource_address: ResourceAddress,
+    pub quantity: ResourceQuantity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProofSource {
+    Account(ComponentAddress),
+    AuthZone,
+    Bucket(ManifestBucket),
+}
+
+/// Analyzes the given instructions and summarizes their effects.
+///
+/// Instructions are assumed to be valid (see `TransactionValidator::validate_instructions_v1()`);
+/// the effects of invalid ones are reported on a best-effort basis.
+pub fn analyze(instructions: &[InstructionV1]) -> ManifestSummary {
+    let mut analyzer = ManifestAnalyzer::default();
+    for instruction in instructions {
+        analyzer.process_instruction(instruction);
+    }
+    analyzer.finish()
+}
+
+/// A kind of an invocation, relevant for the classification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum InvocationKind {
+    AccountDepositSettings,
+    PoolContribution,
+    PoolRedemption,
+    ValidatorStake,
+    ValidatorUnstake,
+    ValidatorClaim,
+    Other,
+}
+
+#[derive(Default)]
+struct ManifestAnalyzer {
+    summary: ManifestSummary,
+    id_allocator: ManifestIdAllocator,
+    buckets: IndexMap<ManifestBucket, ResourceMovement>,
+    worktop: WorktopTracker,
+    invocation_kinds: IndexSet<InvocationKind>,
+}
+
+impl ManifestAnalyzer {
+    fn process_instruction(&mut self, instruction: &InstructionV1) {
+        match instruction {
+            InstructionV1::TakeAllFromWorktop { resource_address } => {
+                let quantity = self.worktop.take_all(resource_address);
+                self.new_bucket(*resource_address, quantity);
+            }
+            InstructionV1::TakeFromWorktop {
+                resource_address,
+                amount,
+            } => {
+                let quantity = ResourceQuantity::Amount(*amount);
+                self.worktop.take(resource_address, &quantity);
+                self.new_bucket(*resource_address, quantity);
+            }
+            InstructionV1::TakeNonFungiblesFromWorktop {
+                resource_address,
+                ids,
+            } => {
+                let quantity = ResourceQuantity::NonFungibles(ids.iter().cloned().collect());
+                self.worktop.take(resource_address, &quantity);
+                self.new_bucket(*resource_address, quantity);
+            }
+            InstructionV1::ReturnToWorktop { bucket_id } => {
+                if let Some(movement) = self.buckets.remove(bucket_id) {
+                    self.worktop.put(movement);
+                }
+            }
+            InstructionV1::AssertWorktopContainsAny { resource_address } => {
+                self.guarantee(*resource_address, ResourceQuantity::Unspecified);
+            }
+            InstructionV1::AssertWorktopContains {
+                resource_address,
+                amount,
+            } => {
+                self.guarantee(*resource_address, ResourceQuantity::Amount(*amount));
+            }
+            InstructionV1::AssertWorktopContainsNonFungibles {
+                resource_address,
+                ids,
+            } => {
+                self.guarantee(
+                    *resource_address,
+                    ResourceQuantity::NonFungibles(ids.iter().cloned().collect()),
+                );
+            }
+            InstructionV1::CreateProofFromAuthZoneOfAmount {
+                resource_address,
+                amount,
+            } => {
+                self.new_proof(
+                    ProofSource::AuthZone,
+                    *resource_address,
+                    ResourceQuantity::Amount(*amount),
+                );
+            }
+            InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
+                resource_address,
+                ids,
+            } => {
+                self.new_proof(
+                    ProofSource::AuthZone,
+                    *resource_address,
+                    ResourceQuantity::NonFungibles(ids.iter().cloned().collect()),
+                );
+            }
+            InstructionV1::CreateProofFromAuthZoneOfAll { resource_address } => {
+                self.new_proof(
+                    ProofSource::AuthZone,
+                    *resource_address,
+                    ResourceQuantity::Unspecified,
+                );
+            }
+            InstructionV1::CreateProofFromBucketOfAmount { bucket_id, amount } => {
+                self.new_bucket_proof(bucket_id, Some(ResourceQuantity::Amount(*amount)));
+            }
+            InstructionV1::CreateProofFromBucketOfNonFungibles { bucket_id, ids } => {
+                self.new_bucket_proof(
+                    bucket_id,
+                    Some(ResourceQuantity::NonFungibles(
+                        ids.iter().cloned().collect(),
+                    )),
+                );
+            }
+            InstructionV1::CreateProofFromBucketOfAll { bucket_id } => {
+                self.new_bucket_proof(bucket_id, None);
+            }
+            InstructionV1::BurnResource { bucket_id } => {
+                self.buckets.remove(bucket_id);
+                self.invocation_kinds.insert(InvocationKind::Other);
+            }
+            InstructionV1::CallMethod {
+                address,
+                method_name,
+                args,
+            } => {
+                self.process_method_call(address, method_name, args);
+            }
+            InstructionV1::CallFunction { args, .. }
+            | InstructionV1::CallRoyaltyMethod { args, .. }
+            | InstructionV1::CallMetadataMethod { args, .. }
+            | InstructionV1::CallRoleAssignmentMethod { args, .. }
+            | InstructionV1::CallDirectVaultMethod { args, .. } => {
+                self.process_other_call(args);
+            }
+            InstructionV1::AllocateGlobalAddress { .. } => {
+                self.invocation_kinds.insert(InvocationKind::Other);
+            }
+            InstructionV1::PopFromAuthZone
+            | InstructionV1::PushToAuthZone { .. }
+            | InstructionV1::DropAuthZoneProofs
+            | InstructionV1::DropAuthZoneRegularProofs
+            | InstructionV1::DropAuthZoneSignatureProofs
+            | InstructionV1::CloneProof { .. }
+            | InstructionV1::DropProof { .. }
+            | InstructionV1::DropNamedProofs
+            | InstructionV1::DropAllProofs => {}
+        }
+    }
+
+    fn process_method_call(
+        &mut self,
+        address: &DynamicGlobalAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) {
+        let DynamicGlobalAddress::Static(global_address) = address else {
+            return self.process_other_call(args);
+        };
+        let entity_type = global_address.as_node_id().entity_type();
+        let processed = match entity_type {
+            Some(EntityType::GlobalAccount)
+            | Some(EntityType::GlobalVirtualSecp256k1Account)
+            | Some(EntityType::GlobalVirtualEd25519Account) => {
+                let account = ComponentAddress::new_or_panic(global_address.as_node_id().0);
+                self.process_account_call(account, method_name, args)
+            }
+            Some(EntityType::GlobalValidator) => {
+                let kind = match method_name {
+                    VALIDATOR_STAKE_IDENT => Some(InvocationKind::ValidatorStake),
+                    VALIDATOR_UNSTAKE_IDENT => Some(InvocationKind::ValidatorUnstake),
+                    VALIDATOR_CLAIM_XRD_IDENT => Some(InvocationKind::ValidatorClaim),
+                    _ => None,
+                };
+                self.process_known_call(kind, args)
+            }
+            Some(EntityType::GlobalOneResourcePool) => {
+                let kind = match method_name {
+                    ONE_RESOURCE_POOL_CONTRIBUTE_IDENT => Some(InvocationKind::PoolContribution),
+                    ONE_RESOURCE_POOL_REDEEM_IDENT => Some(InvocationKind::PoolRedemption),
+                    _ => None,
+                };
+                self.process_known_call(kind, args)
+            }
+            Some(EntityType::GlobalTwoResourcePool) => {
+                let kind = match method_name {
+                    TWO_RESOURCE_POOL_CONTRIBUTE_IDENT => Some(InvocationKind::PoolContribution),
+                    TWO_RESOURCE_POOL_REDEEM_IDENT => Some(InvocationKind::PoolRedemption),
+                    _ => None,
+                };
+                self.process_known_call(kind, args)
+            }
+            Some(EntityType::GlobalMultiResourcePool) => {
+                let kind = match method_name {
+                    MULTI_RESOURCE_POOL_CONTRIBUTE_IDENT => Some(InvocationKind::PoolContribution),
+                    MULTI_RESOURCE_POOL_REDEEM_IDENT => Some(InvocationKind::PoolRedemption),
+                    _ => None,
+                };
+                self.process_known_call(kind, args)
+            }
+            // Locking a fee from the faucet (in tests and on test networks) does not change the
+            // nature of the manifest.
+            _ if global_address.as_node_id() == FAUCET.as_node_id()
+                && method_name == ACCOUNT_LOCK_FEE_IDENT =>
+            {
+                true
+            }
+            _ => false,
+        };
+        if !processed {
+            self.process_other_call(args);
+        }
+    }
+
+    /// Returns `false` if the method is not a known account method (or has unexpected arguments).
+    fn process_account_call(
+        &mut self,
+        account: ComponentAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) -> bool {
+        match method_name {
+            ACCOUNT_LOCK_FEE_IDENT | ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => true,
+            ACCOUNT_WITHDRAW_IDENT => {
+                let Ok(input) = from_manifest_value::<AccountWithdrawInput>(args) else {
+                    return false;
+                };
+                self.withdraw(
+                    account,
+                    input.resource_address,
+                    ResourceQuantity::Amount(input.amount),
+                );
+                true
+            }
+            ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                let Ok(input) = from_manifest_value::<AccountWithdrawNonFungiblesInput>(args)
+                else {
+                    return false;
+                };
+                self.withdraw(
+                    account,
+                    input.resource_address,
+                    ResourceQuantity::NonFungibles(input.ids),
+                );
+                true
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+                let Ok(input) = from_manifest_value::<AccountLockFeeAndWithdrawInput>(args) else {
+                    return false;
+                };
+                self.withdraw(
+                    account,
+                    input.resource_address,
+                    ResourceQuantity::Amount(input.amount),
+                );
+                true
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                let Ok(input) =
+                    from_manifest_value::<AccountLockFeeAndWithdrawNonFungiblesInput>(args)
+                else {
+                    return false;
+                };
+                self.withdraw(
+                    account,
+                    input.resource_address,
+                    ResourceQuantity::NonFungibles(input.ids),
+                );
+                true
+            }
+            ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT => {
+                let Ok(input) = from_manifest_value::<AccountCreateProofOfAmountInput>(args) else {
+                    return false;
+                };
+                self.new_proof(
+                    ProofSource::Account(account),
+                    input.resource_address,
+                    ResourceQuantity::Amount(input.amount),
+                );
+                true
+            }
+            ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => {
+                let Ok(input) = from_manifest_value::<AccountCreateProofOfNonFungiblesInput>(args)
+                else {
+                    return false;
+                };
+                self.new_proof(
+                    ProofSource::Account(account),
+                    input.resource_address,
+                    ResourceQuantity::NonFungibles(input.ids),
+                );
+                true
+            }
+            ACCOUNT_DEPOSIT_IDENT
+            | ACCOUNT_DEPOSIT_BATCH_IDENT
+            | ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT
+            | ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT
+            | ACCOUNT_TRY_DEPOSIT_OR_ABORT_IDENT
+            | ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT => {
+                let consumed = ConsumedArgs::collect(args);
+                let deposits = self.summary.account_deposits.entry(account).or_default();
+                for bucket_id in consumed.buckets {
+                    deposits.push(
+                        self.buckets
+                            .remove(&bucket_id)
+                            .map(AccountDeposit::Known)
+                            .unwrap_or(AccountDeposit::Unknown),
+                    );
+                }
+                if consumed.entire_worktop {
+                    match self.worktop.drain() {
+                        Some(movements) => {
+                            deposits.extend(movements.into_iter().map(AccountDeposit::Known))
+                        }
+                        None => deposits.push(AccountDeposit::Unknown),
+                    }
+                }
+                // The refunded resources (if any) are returned to the worktop.
+                if method_name == ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT
+                    || method_name == ACCOUNT_TRY_DEPOSIT_BATCH_OR_REFUND_IDENT
+                {
+                    self.worktop.invalidate();
+                }
+                true
+            }
+            ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT
+            | ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT
+            | ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT
+            | ACCOUNT_ADD_AUTHORIZED_DEPOSITOR
+            | ACCOUNT_REMOVE_AUTHORIZED_DEPOSITOR => {
+                self.process_known_call(Some(InvocationKind::AccountDepositSettings), args)
+            }
+            _ => false,
+        }
+    }
+
+    /// Records an invocation of the given kind, which may consume any buckets and return any
+    /// resources to the worktop. Returns `false` if the kind is not known.
+    fn process_known_call(&mut self, kind: Option<InvocationKind>, args: &ManifestValue) -> bool {
+        let Some(kind) = kind else {
+            return false;
+        };
+        self.consume_args(args);
+        self.invocation_kinds.insert(kind);
+        true
+    }
+
+    fn process_other_call(&mut self, args: &ManifestValue) {
+        self.consume_args(args);
+        self.invocation_kinds.insert(InvocationKind::Other);
+    }
+
+    fn consume_args(&mut self, args: &ManifestValue) {
+        let consumed = ConsumedArgs::collect(args);
+        for bucket_id in consumed.buckets {
+            self.buckets.remove(&bucket_id);
+        }
+        if consumed.entire_worktop {
+            self.worktop.drain();
+        }
+        self.worktop.invalidate();
+    }
+
+    fn new_bucket(&mut self, resource_address: ResourceAddress, quantity: ResourceQuantity) {
+        let bucket_id = self.id_allocator.new_bucket_id();
+        self.buckets.insert(
+            bucket_id,
+            ResourceMovement {
+                resource_address,
+                quantity,
+            },
+        );
+    }
+
+    fn new_bucket_proof(&mut self, bucket_id: &ManifestBucket, quantity: Option<ResourceQuantity>) {
+        if let Some(movement) = self.buckets.get(bucket_id) {
+            let resource_address = movement.resource_address;
+            let quantity = quantity.unwrap_or_else(|| movement.quantity.clone());
+            self.new_proof(ProofSource::Bucket(*bucket_id), resource_address, quantity);
+        }
+    }
+
+    fn new_proof(
+        &mut self,
+        source: ProofSource,
+        resource_address: ResourceAddress,
+        quantity: ResourceQuantity,
+    ) {
+        if let ProofSource::Account(_) = source {
+            self.summary.presented_badges.insert(resource_address);
+        }
+        self.summary.proofs_created.push(CreatedProof {
+            source,
+            resource_address,
+            quantity,
+        });
+    }
+
+    fn withdraw(
+        &mut self,
+        account: ComponentAddress,
+        resource_address: ResourceAddress,
+        quantity: ResourceQuantity,
+    ) {
+        let movement = ResourceMovement {
+            resource_address,
+            quantity,
+        };
+        self.worktop.put(movement.clone());
+        self.summary
+            .account_withdraws
+            .entry(account)
+            .or_default()
+            .push(movement);
+    }
+
+    fn guarantee(&mut self, resource_address: ResourceAddress, quantity: ResourceQuantity) {
+        self.summary.guaranteed_resources.push(ResourceMovement {
+            resource_address,
+            quantity,
+        });
+    }
+
+    fn finish(mut self) -> ManifestSummary {
+        self.summary.classification = self.classify();
+        self.summary
+    }
+
+    fn classify(&self) -> ManifestClassification {
+        let summary = &self.summary;
+        let moves_resources =
+            !summary.account_withdraws.is_empty() && !summary.account_deposits.is_empty();
+        if self.invocation_kinds.is_empty() {
+            if !moves_resources {
+                return ManifestClassification::General;
+            }
+            let is_simple_transfer = match (
+                summary.account_withdraws.first(),
+                summary.account_deposits.first(),
+            ) {
+                (Some((from, withdraws)), Some((to, deposits))) => {
+                    summary.account_withdraws.len() == 1
+                        && summary.account_deposits.len() == 1
+                        && from != to
+                        && withdraws.len() == 1
+                        && deposits.len() == 1
+                        && deposits[0] == AccountDeposit::Known(withdraws[0].clone())
+                }
+                _ => false,
+            };
+            return if is_simple_transfer {
+                ManifestClassification::SimpleTransfer
+            } else {
+                ManifestClassification::Transfer
+            };
+        }
+        if self.invocation_kinds.len() > 1 {
+            return ManifestClassification::General;
+        }
+        match self.invocation_kinds[0] {
+            InvocationKind::AccountDepositSettings if summary.account_withdraws.is_empty() => {
+                ManifestClassification::AccountDepositSettingsUpdate
+            }
+            InvocationKind::PoolContribution => ManifestClassification::PoolContribution,
+            InvocationKind::PoolRedemption => ManifestClassification::PoolRedemption,
+            InvocationKind::ValidatorStake => ManifestClassification::ValidatorStake,
+            InvocationKind::ValidatorUnstake => ManifestClassification::ValidatorUnstake,
+            InvocationKind::ValidatorClaim => ManifestClassification::ValidatorClaim,
+            InvocationKind::AccountDepositSettings | InvocationKind::Other => {
+                ManifestClassification::General
+            }
+        }
+    }
+}
+
+/// The resources on the worktop, as long as they can be determined statically.
+#[derive(Default)]
+struct WorktopTracker {
+    contents: IndexMap<ResourceAddress, ResourceQuantity>,
+    /// Whether the worktop may contain resources which are not captured by `contents` (i.e.
+    /// returned by some invocation).
+    has_unknown_contents: bool,
+}
+
+impl WorktopTracker {
+    fn put(&mut self, movement: ResourceMovement) {
+        match self.contents.get_mut(&movement.resource_address) {
+            Some(existing) => *existing = existing.added(&movement.quantity),
+            None => {
+                self.contents
+                    .insert(movement.resource_address, movement.quantity);
+            }
+        }
+    }
+
+    fn take(&mut self, resource_address: &ResourceAddress, quantity: &ResourceQuantity) {
+        if let Some(existing) = self.contents.get_mut(resource_address) {
+            match existing.subtracted(quantity) {
+                Some(remaining) => *existing = remaining,
+                None => {
+                    self.contents.shift_remove(resource_address);
+                }
+            }
+        }
+    }
+
+    fn take_all(&mut self, resource_address: &ResourceAddress) -> ResourceQuantity {
+        match self.contents.shift_remove(resource_address) {
+            Some(quantity) if !self.has_unknown_contents => quantity,
+            _ => ResourceQuantity::Unspecified,
+        }
+    }
+
+    /// Empties the worktop, returning its contents (or `None` if they cannot be determined).
+    fn drain(&mut self) -> Option<Vec<ResourceMovement>> {
+        let contents = self
+            .contents
+            .drain(..)
+            .map(|(resource_address, quantity)| ResourceMovement {
+                resource_address,
+                quantity,
+            })
+            .collect();
+        let has_unknown_contents = self.has_unknown_contents;
+        self.has_unknown_contents = false;
+        if has_unknown_contents {
+            None
+        } else {
+            Some(contents)
+        }
+    }
+
+    fn invalidate(&mut self) {
+        self.has_unknown_contents = true;
+    }
+}
+
+impl ResourceQuantity {
+    fn added(&self, other: &ResourceQuantity) -> ResourceQuantity {
+        match (self, other) {
+            (ResourceQuantity::Amount(amount), ResourceQuantity::Amount(other_amount)) => amount
+                .checked_add(*other_amount)
+                .map(ResourceQuantity::Amount)
+                .unwrap_or(ResourceQuantity::Unspecified),
+            (ResourceQuantity::NonFungibles(ids), ResourceQuantity::NonFungibles(other_ids)) => {
+                ResourceQuantity::NonFungibles(ids.union(other_ids).cloned().collect())
+            }
+            _ => ResourceQuantity::Unspecified,
+        }
+    }
+
+    /// Returns the remaining quantity (or `None` if nothing remains).
+    fn subtracted(&self, other: &ResourceQuantity) -> Option<ResourceQuantity> {
+        match (self, other) {
+            (ResourceQuantity::Amount(amount), ResourceQuantity::Amount(other_amount)) => {
+                match amount.checked_sub(*other_amount) {
+                    Some(remaining) if remaining.is_zero() => None,
+                    Some(remaining) if remaining.is_positive() => {
+                        Some(ResourceQuantity::Amount(remaining))
+                    }
+                    _ => Some(ResourceQuantity::Unspecified),
+                }
+            }
+            (ResourceQuantity::NonFungibles(ids), ResourceQuantity::NonFungibles(other_ids)) => {
+                let remaining = ids.difference(other_ids).cloned().collect::<IndexSet<_>>();
+                if remaining.is_empty() {
+                    None
+                } else {
+                    Some(ResourceQuantity::NonFungibles(remaining))
+                }
+            }
+            _ => Some(ResourceQuantity::Unspecified),
+        }
+    }
+}
+
+/// The buckets and expressions passed to an invocation.
+#[derive(Default)]
+struct ConsumedArgs {
+    buckets: Vec<ManifestBucket>,
+    entire_worktop: bool,
+}
+
+impl ConsumedArgs {
+    fn collect(args: &ManifestValue) -> Self {
+        let mut consumed = Self::default();
+        let _ = transform(args.clone(), &mut consumed);
+        consumed
+    }
+}
+
+impl TransformHandler<Infallible> for ConsumedArgs {
+    fn replace_bucket(&mut self, b: ManifestBucket) -> Result<Own, Infallible> {
+        self.buckets.push(b);
+        Ok(Own(NodeId([0u8; NodeId::LENGTH])))
+    }
+
+    fn replace_proof(&mut self, _p: ManifestProof) -> Result<Own, Infallible> {
+        Ok(Own(NodeId([0u8; NodeId::LENGTH])))
+    }
+
+    fn replace_address_reservation(
+        &mut self,
+        _r: ManifestAddressReservation,
+    ) -> Result<Own, Infallible> {
+        Ok(Own(NodeId([0u8; NodeId::LENGTH])))
+    }
+
+    fn replace_named_address(&mut self, _a: u32) -> Result<Reference, Infallible> {
+        Ok(Reference(NodeId([0u8; NodeId::LENGTH])))
+    }
+
+    fn replace_expression(&mut self, e: ManifestExpression) -> Result<Vec<Own>, Infallible> {
+        if let ManifestExpression::EntireWorktop = e {
+            self.entire_worktop = true;
+        }
+        Ok(Vec::new())
+    }
+
+    fn replace_blob(&mut self, _b: ManifestBlobRef) -> Result<Vec<u8>, Infallible> {
+        Ok(Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(seed: u8) -> ComponentAddress {
+        ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(seed as u64)
+                .unwrap()
+                .public_key(),
+        )
+    }
+
+    #[test]
+    fn simple_transfer_is_recognized() {
+        let manifest = ManifestBuilder::new()
+            .lock_fee(account(1), 10)
+            .withdraw_from_account(account(1), XRD, 5)
+            .take_from_worktop(XRD, 5, "xrd")
+            .try_deposit_or_abort(account(2), None, "xrd")
+            .build();
+
+        let summary = analyze(&manifest.instructions);
+
+        let xrd_5 = ResourceMovement {
+            resource_address: XRD,
+            quantity: ResourceQuantity::Amount(dec!(5)),
+        };
+        assert_eq!(
+            summary.classification,
+            ManifestClassification::SimpleTransfer
+        );
+        assert_eq!(
+            summary.account_withdraws,
+            indexmap!(account(1) => vec![xrd_5.clone()])
+        );
+        assert_eq!(
+            summary.account_deposits,
+            indexmap!(account(2) => vec![AccountDeposit::Known(xrd_5)])
+        );
+    }
+
+    #[test]
+    fn entire_worktop_deposit_of_withdrawn_resources_is_tracked() {
+        let nf_resource = ResourceAddress::new_or_panic(
+            [EntityType::GlobalNonFungibleResourceManager as u8; NodeId::LENGTH],
+        );
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(account(1), XRD, 5)
+            .withdraw_from_account(account(1), XRD, 2)
+            .withdraw_non_fungibles_from_account(
+                account(1),
+                nf_resource,
+                [
+                    NonFungibleLocalId::integer(1),
+                    NonFungibleLocalId::integer(2),
+                ],
+            )
+            .take_from_worktop(XRD, 3, "xrd")
+            .try_deposit_or_abort(account(2), None, "xrd")
+            .try_deposit_entire_worktop_or_abort(account(3), None)
+            .build();
+
+        let summary = analyze(&manifest.instructions);
+
+        assert_eq!(summary.classification, ManifestClassification::Transfer);
+        assert_eq!(
+            summary.accounts_deposited_into(),
+            indexset!(account(2), account(3))
+        );
+        assert_eq!(
+            summary.account_deposits[&account(3)],
+            vec![
+                AccountDeposit::Known(ResourceMovement {
+                    resource_address: XRD,
+                    quantity: ResourceQuantity::Amount(dec!(4)),
+                }),
+                AccountDeposit::Known(ResourceMovement {
+                    resource_address: nf_resource,
+                    quantity: ResourceQuantity::NonFungibles(indexset!(
+                        NonFungibleLocalId::integer(1),
+                        NonFungibleLocalId::integer(2)
+                    )),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn deposit_of_component_results_is_unknown_and_guarantees_are_reported() {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .get_free_xrd_from_faucet()
+            .assert_worktop_contains(XRD, 100)
+            .try_deposit_entire_worktop_or_abort(account(1), None)
+            .build();
+
+        let summary = analyze(&manifest.instructions);
+
+        assert_eq!(summary.classification, ManifestClassification::General);
+        assert_eq!(
+            summary.guaranteed_resources,
+            vec![ResourceMovement {
+                resource_address: XRD,
+                quantity: ResourceQuantity::Amount(dec!(100)),
+            }]
+        );
+        assert_eq!(
+            summary.account_deposits,
+            indexmap!(account(1) => vec![AccountDeposit::Unknown])
+        );
+    }
+
+    #[test]
+    fn validator_stake_is_recognized() {
+        let validator =
+            ComponentAddress::new_or_panic([EntityType::GlobalValidator as u8; NodeId::LENGTH]);
+        let manifest = ManifestBuilder::new()
+            .lock_fee(account(1), 10)
+            .withdraw_from_account(account(1), XRD, 100)
+            .take_all_from_worktop(XRD, "xrd")
+            .stake_validator(validator, "xrd")
+            .try_deposit_entire_worktop_or_abort(account(1), None)
+            .build();
+
+        let summary = analyze(&manifest.instructions);
+
+        assert_eq!(
+            summary.classification,
+            ManifestClassification::ValidatorStake
+        );
+        assert_eq!(
+            summary.account_deposits,
+            indexmap!(account(1) => vec![AccountDeposit::Unknown])
+        );
+    }
+
+    #[test]
+    fn pool_contribution_is_recognized() {
+        let pool = ComponentAddress::new_or_panic(
+            [EntityType::GlobalOneResourcePool as u8; NodeId::LENGTH],
+        );
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(account(1), XRD, 100)
+            .take_all_from_worktop(XRD, "xrd")
+            .call_method_with_name_lookup(pool, ONE_RESOURCE_POOL_CONTRIBUTE_IDENT, |lookup| {
+                (lookup.bucket("xrd"),)
+            })
+            .try_deposit_entire_worktop_or_abort(account(1), None)
+            .build();
+
+        let summary = analyze(&manifest.instructions);
+
+        assert_eq!(
+            summary.classification,
+            ManifestClassification::PoolContribution
+        );
+    }
+
+    #[test]
+    fn proofs_and_presented_badges_are_reported() {
+        let badge = ResourceAddress::new_or_panic(
+            [EntityType::GlobalFungibleResourceManager as u8; NodeId::LENGTH],
+        );
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_amount(account(1), badge, 1)
+            .create_proof_from_auth_zone_of_all(badge, "proof")
+            .withdraw_from_account(account(1), XRD, 1)
+            .take_from_worktop(XRD, 1, "xrd")
+            .create_proof_from_bucket_of_all("xrd", "xrd_proof")
+            .drop_all_proofs()
+            .call_method(
+                account(1),
+                ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT,
+                AccountSetDefaultDepositRuleInput {
+                    default: DefaultDepositRule::Reject,
+                },
+            )
+            .build();
+
+        let summary = analyze(&manifest.instructions);
+
+        assert_eq!(summary.presented_badges, indexset!(badge));
+        assert_eq!(
+            summary.proofs_created,
+            vec![
+                CreatedProof {
+                    source: ProofSource::Account(account(1)),
+                    resource_address: badge,
+                    quantity: ResourceQuantity::Amount(dec!(1)),
+                },
+                CreatedProof {
+                    source: ProofSource::AuthZone,
+                    resource_address: badge,
+                    quantity: ResourceQuantity::Unspecified,
+                },
+                CreatedProof {
+                    source: ProofSource::Bucket(ManifestBucket(0)),
+                    resource_address: XRD,
+                    quantity: ResourceQuantity::Amount(dec!(1)),
+                },
+            ]
+        );
+        // Withdrawing alongside a settings update does not fit any specific class.
+        assert_eq!(summary.classification, ManifestClassification::General);
+    }
+}
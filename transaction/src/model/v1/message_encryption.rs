@@ -0,0 +1,380 @@
+use super::*;
+use crate::internal_prelude::*;
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes128Gcm, Nonce};
+use aes_kw::KekAes256;
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use curve25519_dalek::scalar::Scalar;
+use hkdf::SimpleHkdf;
+use rand_core::{CryptoRng, RngCore};
+
+const MESSAGE_KEY_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+const TAG_LENGTH: usize = 16;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageEncryptionError {
+    NoDecryptors,
+    InvalidDecryptorPublicKey(PublicKey),
+    EncodeError(EncodeError),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageDecryptionError {
+    /// The message isn't encrypted for the public key of the private key.
+    NotADecryptor,
+    InvalidEphemeralPublicKey,
+    /// The message key couldn't be unwrapped, or the payload fails its authentication check.
+    DecryptionFailed,
+    DecodeError(DecodeError),
+}
+
+impl PlaintextMessageV1 {
+    /// Encrypts the message for the given decryptors, as described on [`EncryptedMessageV1`].
+    ///
+    /// The message key, the nonce and the ephemeral keys are all drawn from `rng`.
+    pub fn encrypt<R: RngCore + CryptoRng>(
+        &self,
+        decryptors: &[PublicKey],
+        rng: &mut R,
+    ) -> Result<EncryptedMessageV1, MessageEncryptionError> {
+        if decryptors.is_empty() {
+            return Err(MessageEncryptionError::NoDecryptors);
+        }
+
+        let payload = manifest_encode(self).map_err(MessageEncryptionError::EncodeError)?;
+        let mut message_key = [0u8; MESSAGE_KEY_LENGTH];
+        rng.fill_bytes(&mut message_key);
+        let mut nonce = [0u8; NONCE_LENGTH];
+        rng.fill_bytes(&mut nonce);
+        let encrypted = Aes128Gcm::new(&message_key.into())
+            .encrypt(Nonce::from_slice(&nonce), payload.as_slice())
+            .expect("Encryption of a message of bounded size can't fail");
+
+        let mut ed25519_decryptors = index_map_new();
+        let mut secp256k1_decryptors = index_map_new();
+        let ed25519_ephemeral_key = random_ed25519_private_key(rng);
+        let secp256k1_ephemeral_key = random_secp256k1_private_key(rng);
+        for decryptor in decryptors {
+            let invalid_decryptor =
+                || MessageEncryptionError::InvalidDecryptorPublicKey(*decryptor);
+            match decryptor {
+                PublicKey::Ed25519(public_key) => {
+                    let secret = ed25519_shared_secret(&ed25519_ephemeral_key, public_key)
+                        .ok_or_else(invalid_decryptor)?;
+                    ed25519_decryptors.insert(
+                        PublicKeyFingerprint::from(*decryptor),
+                        wrap_message_key(&secret, &message_key),
+                    );
+                }
+                PublicKey::Secp256k1(public_key) => {
+                    let secret = secp256k1_shared_secret(&secp256k1_ephemeral_key, public_key)
+                        .ok_or_else(invalid_decryptor)?;
+                    secp256k1_decryptors.insert(
+                        PublicKeyFingerprint::from(*decryptor),
+                        wrap_message_key(&secret, &message_key),
+                    );
+                }
+            }
+        }
+
+        let mut decryptors_by_curve = index_map_new();
+        if !ed25519_decryptors.is_empty() {
+            decryptors_by_curve.insert(
+                CurveType::Ed25519,
+                DecryptorsByCurve::Ed25519 {
+                    dh_ephemeral_public_key: ed25519_ephemeral_key.public_key(),
+                    decryptors: ed25519_decryptors,
+                },
+            );
+        }
+        if !secp256k1_decryptors.is_empty() {
+            decryptors_by_curve.insert(
+                CurveType::Secp256k1,
+                DecryptorsByCurve::Secp256k1 {
+                    dh_ephemeral_public_key: secp256k1_ephemeral_key.public_key(),
+                    decryptors: secp256k1_decryptors,
+                },
+            );
+        }
+
+        Ok(EncryptedMessageV1 {
+            encrypted: AesGcmPayload([nonce.as_slice(), encrypted.as_slice()].concat()),
+            decryptors_by_curve,
+        })
+    }
+}
+
+impl EncryptedMessageV1 {
+    /// Decrypts the message with the private key of one of its decryptors.
+    pub fn decrypt(
+        &self,
+        private_key: &PrivateKey,
+    ) -> Result<PlaintextMessageV1, MessageDecryptionError> {
+        let fingerprint = PublicKeyFingerprint::from(private_key.public_key());
+        let (secret, wrapped_key) = match (
+            private_key,
+            self.decryptors_by_curve.get(&private_key.curve_type()),
+        ) {
+            (
+                PrivateKey::Ed25519(private_key),
+                Some(DecryptorsByCurve::Ed25519 {
+                    dh_ephemeral_public_key,
+                    decryptors,
+                }),
+            ) => {
+                let wrapped_key = decryptors
+                    .get(&fingerprint)
+                    .ok_or(MessageDecryptionError::NotADecryptor)?;
+                let secret = ed25519_shared_secret(private_key, dh_ephemeral_public_key)
+                    .ok_or(MessageDecryptionError::InvalidEphemeralPublicKey)?;
+                (secret, wrapped_key)
+            }
+            (
+                PrivateKey::Secp256k1(private_key),
+                Some(DecryptorsByCurve::Secp256k1 {
+                    dh_ephemeral_public_key,
+                    decryptors,
+                }),
+            ) => {
+                let wrapped_key = decryptors
+                    .get(&fingerprint)
+                    .ok_or(MessageDecryptionError::NotADecryptor)?;
+                let secret = secp256k1_shared_secret(private_key, dh_ephemeral_public_key)
+                    .ok_or(MessageDecryptionError::InvalidEphemeralPublicKey)?;
+                (secret, wrapped_key)
+            }
+            _ => return Err(MessageDecryptionError::NotADecryptor),
+        };
+
+        let mut message_key = [0u8; MESSAGE_KEY_LENGTH];
+        kek(&secret)
+            .unwrap(&wrapped_key.0, &mut message_key)
+            .map_err(|_| MessageDecryptionError::DecryptionFailed)?;
+
+        let encrypted = &self.encrypted.0;
+        if encrypted.len() < NONCE_LENGTH + TAG_LENGTH {
+            return Err(MessageDecryptionError::DecryptionFailed);
+        }
+        let (nonce, cipher) = encrypted.split_at(NONCE_LENGTH);
+        let payload = Aes128Gcm::new(&message_key.into())
+            .decrypt(Nonce::from_slice(nonce), cipher)
+            .map_err(|_| MessageDecryptionError::DecryptionFailed)?;
+
+        manifest_decode(&payload).map_err(MessageDecryptionError::DecodeError)
+    }
+}
+
+fn random_ed25519_private_key<R: RngCore + CryptoRng>(rng: &mut R) -> Ed25519PrivateKey {
+    let mut bytes = [0u8; 32];
+    rng.fill_bytes(&mut bytes);
+    Ed25519PrivateKey::from_bytes(&bytes).expect("Any 32 bytes are a valid Ed25519 private key")
+}
+
+fn random_secp256k1_private_key<R: RngCore + CryptoRng>(rng: &mut R) -> Secp256k1PrivateKey {
+    // Almost all 32 byte strings are valid keys, except zero and those above the curve order.
+    loop {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes);
+        if let Ok(private_key) = Secp256k1PrivateKey::from_bytes(&bytes) {
+            return private_key;
+        }
+    }
+}
+
+/// X25519 between the birationally equivalent Montgomery forms of the Ed25519 keys, as in
+/// RFC 7748, with the private scalar derived from the Ed25519 seed as in RFC 8032.
+///
+/// The all-zero secret which small-order public keys produce is rejected, as per RFC 7748 §6.1.
+fn ed25519_shared_secret(
+    private_key: &Ed25519PrivateKey,
+    public_key: &Ed25519PublicKey,
+) -> Option<[u8; 32]> {
+    let secret_key = ed25519_dalek::SecretKey::from_bytes(&private_key.to_bytes()).ok()?;
+    let expanded_key = ed25519_dalek::ExpandedSecretKey::from(&secret_key).to_bytes();
+    let scalar = Scalar::from_bits(copy_u8_array(&expanded_key[..32]));
+    let point = CompressedEdwardsY(public_key.0)
+        .decompress()?
+        .to_montgomery();
+    let secret = (point * scalar).to_bytes();
+    if secret == [0u8; 32] {
+        return None;
+    }
+    Some(secret)
+}
+
+/// ECDH on secp256k1, with the `x` coordinate of the shared point as the secret.
+fn secp256k1_shared_secret(
+    private_key: &Secp256k1PrivateKey,
+    public_key: &Secp256k1PublicKey,
+) -> Option<[u8; 32]> {
+    let secret_key = ::secp256k1::SecretKey::from_slice(&private_key.to_bytes()).ok()?;
+    let public_key = ::secp256k1::PublicKey::from_slice(&public_key.0).ok()?;
+    let point = ::secp256k1::ecdh::shared_secret_point(&public_key, &secret_key);
+    Some(copy_u8_array(&point[..32]))
+}
+
+fn kek(secret: &[u8; 32]) -> KekAes256 {
+    let mut kek = [0u8; 32];
+    SimpleHkdf::<Blake2b256>::new(None, secret)
+        .expand(&[], &mut kek)
+        .expect("32 bytes is a valid HKDF output length");
+    KekAes256::from(kek)
+}
+
+fn wrap_message_key(
+    secret: &[u8; 32],
+    message_key: &[u8; MESSAGE_KEY_LENGTH],
+) -> AesWrapped128BitKey {
+    let mut wrapped_key = [0u8; AesWrapped128BitKey::LENGTH];
+    kek(secret)
+        .wrap(message_key, &mut wrapped_key)
+        .expect("The wrapped key buffer has the right length");
+    AesWrapped128BitKey(wrapped_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    fn message() -> PlaintextMessageV1 {
+        PlaintextMessageV1 {
+            mime_type: "text/plain".to_owned(),
+            message: MessageContentsV1::String("Hello encrypted world!".to_string()),
+        }
+    }
+
+    #[test]
+    fn encrypted_message_can_be_decrypted_by_each_decryptor() {
+        let decryptors: Vec<PrivateKey> = vec![
+            Ed25519PrivateKey::from_u64(1).unwrap().into(),
+            Ed25519PrivateKey::from_u64(2).unwrap().into(),
+            Secp256k1PrivateKey::from_u64(3).unwrap().into(),
+        ];
+        let public_keys: Vec<PublicKey> = decryptors.iter().map(|key| key.public_key()).collect();
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+
+        let encrypted = message().encrypt(&public_keys, &mut rng).unwrap();
+
+        assert_eq!(encrypted.decryptors_by_curve.len(), 2);
+        assert_eq!(
+            encrypted.decryptors_by_curve[&CurveType::Ed25519].number_of_decryptors(),
+            2
+        );
+        for decryptor in &decryptors {
+            assert_eq!(encrypted.decrypt(decryptor).unwrap(), message());
+        }
+    }
+
+    #[test]
+    fn encrypted_message_cant_be_decrypted_by_others() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let encrypted = message()
+            .encrypt(
+                &[Ed25519PrivateKey::from_u64(1).unwrap().public_key().into()],
+                &mut rng,
+            )
+            .unwrap();
+
+        assert_eq!(
+            encrypted.decrypt(&Ed25519PrivateKey::from_u64(2).unwrap().into()),
+            Err(MessageDecryptionError::NotADecryptor)
+        );
+        assert_eq!(
+            encrypted.decrypt(&Secp256k1PrivateKey::from_u64(1).unwrap().into()),
+            Err(MessageDecryptionError::NotADecryptor)
+        );
+    }
+
+    #[test]
+    fn tampered_message_fails_to_decrypt() {
+        let decryptor: PrivateKey = Secp256k1PrivateKey::from_u64(1).unwrap().into();
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        let mut encrypted = message()
+            .encrypt(&[decryptor.public_key()], &mut rng)
+            .unwrap();
+        let last = encrypted.encrypted.0.len() - 1;
+        encrypted.encrypted.0[last] ^= 1;
+
+        assert_eq!(
+            encrypted.decrypt(&decryptor),
+            Err(MessageDecryptionError::DecryptionFailed)
+        );
+    }
+
+    #[test]
+    fn message_without_decryptors_cant_be_encrypted() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        assert_eq!(
+            message().encrypt(&[], &mut rng),
+            Err(MessageEncryptionError::NoDecryptors)
+        );
+    }
+
+    #[test]
+    fn message_cant_be_encrypted_for_small_order_public_keys() {
+        let mut rng = ChaCha20Rng::seed_from_u64(0);
+        // The identity point
+        let mut identity = [0u8; Ed25519PublicKey::LENGTH];
+        identity[0] = 1;
+        let decryptor: PublicKey = Ed25519PublicKey(identity).into();
+
+        assert_eq!(
+            message().encrypt(&[decryptor], &mut rng),
+            Err(MessageEncryptionError::InvalidDecryptorPublicKey(decryptor))
+        );
+    }
+
+    /// A test vector for other implementers, with the Ed25519 ephemeral key `from_u64(100)`, the
+    /// Secp256k1 ephemeral key `from_u64(200)`, the message key `01..10` and the nonce `a0..ab`.
+    #[test]
+    fn test_vector_can_be_decrypted() {
+        let encrypted = EncryptedMessageV1 {
+            encrypted: AesGcmPayload(
+                hex::decode(
+                    "a0a1a2a3a4a5a6a7a8a9aaab4f2295aa6cd964ee4354be05924cb9a6be7beb5737d24f82c7f0b32fd5cba094159b717900f009dd3e103af8dee178d6bb5101ebf90107965db9",
+                )
+                .unwrap(),
+            ),
+            decryptors_by_curve: indexmap!(
+                CurveType::Ed25519 => DecryptorsByCurve::Ed25519 {
+                    dh_ephemeral_public_key: Ed25519PublicKey(copy_u8_array(
+                        &hex::decode("01c6b12926ac8cb8778f97ace4de6e6d718c242112dc93d51166303c61104bcc").unwrap(),
+                    )),
+                    decryptors: indexmap!(
+                        PublicKeyFingerprint(copy_u8_array(&hex::decode("f91fa8df2486c9ea").unwrap())) =>
+                            AesWrapped128BitKey(copy_u8_array(
+                                &hex::decode("56082f51535e1897eb16930603310b44c90ab7ce613d32c0").unwrap(),
+                            )),
+                    ),
+                },
+                CurveType::Secp256k1 => DecryptorsByCurve::Secp256k1 {
+                    dh_ephemeral_public_key: Secp256k1PublicKey(copy_u8_array(
+                        &hex::decode("03cd5a3be41717d65683fe7a9de8ae5b4b8feced69f26a8b55eeefbcc2e74b75fb").unwrap(),
+                    )),
+                    decryptors: indexmap!(
+                        PublicKeyFingerprint(copy_u8_array(&hex::decode("4d5fc0f41699cd01").unwrap())) =>
+                            AesWrapped128BitKey(copy_u8_array(
+                                &hex::decode("b338e97bab4f9b22edf8d6ecd19a938f4bd408b1e7814395").unwrap(),
+                            )),
+                    ),
+                },
+            ),
+        };
+
+        assert_eq!(
+            encrypted
+                .decrypt(&Ed25519PrivateKey::from_u64(1).unwrap().into())
+                .unwrap(),
+            message()
+        );
+        assert_eq!(
+            encrypted
+                .decrypt(&Secp256k1PrivateKey::from_u64(2).unwrap().into())
+                .unwrap(),
+            message()
+        );
+    }
+}
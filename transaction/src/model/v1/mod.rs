@@ -8,6 +8,7 @@ mod intent;
 mod intent_signatures;
 mod manifest;
 mod message;
+mod message_encryption;
 mod notarized_transaction;
 mod notary_signature;
 mod preview_transaction;
@@ -24,6 +25,7 @@ pub use intent::*;
 pub use intent_signatures::*;
 pub use manifest::*;
 pub use message::*;
+pub use message_encryption::*;
 pub use notarized_transaction::*;
 pub use notary_signature::*;
 pub use preview_transaction::*;
@@ -0,0 +1,154 @@
+use crate::internal_prelude::*;
+use radix_engine_common::prelude::IsHash;
+use sbor::rust::cell::RefCell;
+
+/// A hash which an external signer is asked to sign.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ManifestSbor)]
+pub enum ExternalSignPayload {
+    /// The hash of a transaction intent, signed by the signatories of the transaction.
+    IntentHash(IntentHash),
+    /// The hash of a signed transaction intent, signed by the notary of the transaction.
+    SignedIntentHash(SignedIntentHash),
+}
+
+impl ExternalSignPayload {
+    pub fn message_hash(&self) -> &Hash {
+        match self {
+            Self::IntentHash(hash) => hash.as_hash(),
+            Self::SignedIntentHash(hash) => hash.as_hash(),
+        }
+    }
+}
+
+/// A request to sign a payload with the key at the given derivation path.
+#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor)]
+pub struct ExternalSignRequest {
+    /// The path of the key in the signer's key hierarchy, eg `m/44H/1022H/1H/525H/1460H/0H`.
+    pub derivation_path: String,
+    pub payload: ExternalSignPayload,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExternalSignerError {
+    /// The signer couldn't be reached, or responded with something unexpected.
+    Transport(String),
+    /// The signer declined the request, eg because it isn't allowed by its policy.
+    Rejected(String),
+    /// The signature returned by the signer isn't a valid signature of the payload by the
+    /// expected key.
+    InvalidSignature,
+}
+
+/// A signer which keeps its keys outside of the process building the transaction, such as a
+/// hardware wallet or a signing daemon.
+///
+/// Unlike a [`Signer`], requests can fail, and keys are addressed by their derivation path.
+///
+/// Requests are blocking - they may wait on a device, or on a user confirming the request - so
+/// async callers should make them from a thread where blocking is allowed (e.g. with tokio's
+/// `spawn_blocking`), rather than from an async task.
+pub trait ExternalSigner {
+    fn public_key(&self, derivation_path: &str) -> Result<PublicKey, ExternalSignerError>;
+
+    fn sign(
+        &self,
+        request: &ExternalSignRequest,
+    ) -> Result<SignatureWithPublicKeyV1, ExternalSignerError>;
+}
+
+/// Requests a signature of the payload from the signer, and checks that it's a valid signature of
+/// the payload by the signer's public key at the derivation path. Returns that public key.
+pub fn sign_externally<S: ExternalSigner>(
+    signer: &S,
+    derivation_path: &str,
+    payload: ExternalSignPayload,
+) -> Result<(PublicKey, SignatureWithPublicKeyV1), ExternalSignerError> {
+    let request = ExternalSignRequest {
+        derivation_path: derivation_path.to_string(),
+        payload,
+    };
+    let expected_public_key = signer.public_key(derivation_path)?;
+    let signature = signer.sign(&request)?;
+
+    let message_hash = payload.message_hash();
+    let public_key =
+        recover(message_hash, &signature).ok_or(ExternalSignerError::InvalidSignature)?;
+    if public_key != expected_public_key
+        || !verify(message_hash, &public_key, &signature.signature())
+    {
+        return Err(ExternalSignerError::InvalidSignature);
+    }
+    Ok((public_key, signature))
+}
+
+/// An in-process [`ExternalSigner`] for tests, which signs with keys registered against
+/// derivation paths, and records the requests it receives.
+#[derive(Default)]
+pub struct MockExternalSigner {
+    keys: IndexMap<String, PrivateKey>,
+    signing_key_overrides: IndexMap<String, PrivateKey>,
+    rejection_reason: Option<String>,
+    requests: RefCell<Vec<ExternalSignRequest>>,
+}
+
+impl MockExternalSigner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_key(mut self, derivation_path: &str, private_key: impl Into<PrivateKey>) -> Self {
+        self.keys
+            .insert(derivation_path.to_string(), private_key.into());
+        self
+    }
+
+    /// Makes the signer sign requests for the derivation path with the given key, rather than with
+    /// the key whose public key it reports for the path.
+    pub fn signing_with_wrong_key(
+        mut self,
+        derivation_path: &str,
+        private_key: impl Into<PrivateKey>,
+    ) -> Self {
+        self.signing_key_overrides
+            .insert(derivation_path.to_string(), private_key.into());
+        self
+    }
+
+    /// Makes the signer reject all sign requests with the given reason.
+    pub fn rejecting(mut self, reason: &str) -> Self {
+        self.rejection_reason = Some(reason.to_string());
+        self
+    }
+
+    /// The sign requests received by the signer, in order.
+    pub fn requests(&self) -> Vec<ExternalSignRequest> {
+        self.requests.borrow().clone()
+    }
+
+    fn key(&self, derivation_path: &str) -> Result<&PrivateKey, ExternalSignerError> {
+        self.keys.get(derivation_path).ok_or_else(|| {
+            ExternalSignerError::Rejected(format!("Unknown derivation path {}", derivation_path))
+        })
+    }
+}
+
+impl ExternalSigner for MockExternalSigner {
+    fn public_key(&self, derivation_path: &str) -> Result<PublicKey, ExternalSignerError> {
+        self.key(derivation_path).map(|key| key.public_key())
+    }
+
+    fn sign(
+        &self,
+        request: &ExternalSignRequest,
+    ) -> Result<SignatureWithPublicKeyV1, ExternalSignerError> {
+        self.requests.borrow_mut().push(request.clone());
+        if let Some(reason) = &self.rejection_reason {
+            return Err(ExternalSignerError::Rejected(reason.clone()));
+        }
+        let key = match self.signing_key_overrides.get(&request.derivation_path) {
+            Some(key) => key,
+            None => self.key(&request.derivation_path)?,
+        };
+        Ok(key.sign_with_public_key(request.payload.message_hash()))
+    }
+}
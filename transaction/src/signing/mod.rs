@@ -1,3 +1,9 @@
+mod external_signer;
 mod signer;
+#[cfg(all(feature = "std", unix))]
+mod unix_socket_signer;
 
+pub use external_signer::*;
 pub use signer::*;
+#[cfg(all(feature = "std", unix))]
+pub use unix_socket_signer::*;
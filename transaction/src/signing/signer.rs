@@ -13,6 +13,13 @@ impl PrivateKey {
             PrivateKey::Ed25519(key) => key.public_key().into(),
         }
     }
+
+    pub fn curve_type(&self) -> CurveType {
+        match self {
+            PrivateKey::Secp256k1(_) => CurveType::Secp256k1,
+            PrivateKey::Ed25519(_) => CurveType::Ed25519,
+        }
+    }
 }
 
 impl From<Secp256k1PrivateKey> for PrivateKey {
@@ -0,0 +1,234 @@
+use super::*;
+use crate::internal_prelude::*;
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// The maximum size of a message exchanged with a signing daemon.
+const MAX_MESSAGE_SIZE: usize = 64 * 1024;
+
+/// How long to wait for a signing daemon to accept a request and to respond, unless configured
+/// otherwise. It's long enough for a user to confirm a request on a device.
+pub const DEFAULT_SIGNING_DAEMON_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// A request sent to a signing daemon.
+///
+/// Each connection carries a single request and its response. Both are manifest SBOR encoded, and
+/// prefixed with their length as a big-endian `u32`.
+#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor)]
+pub enum SigningDaemonRequest {
+    PublicKey { derivation_path: String },
+    Sign(ExternalSignRequest),
+}
+
+/// A response from a signing daemon.
+#[derive(Debug, Clone, PartialEq, Eq, ManifestSbor)]
+pub enum SigningDaemonResponse {
+    PublicKey(PublicKey),
+    Signature(SignatureWithPublicKeyV1),
+    Rejected(String),
+}
+
+/// An [`ExternalSigner`] which forwards requests to a signing daemon listening on a Unix socket.
+///
+/// Each request blocks the calling thread until the daemon responds, or until the timeout (by
+/// default [`DEFAULT_SIGNING_DAEMON_TIMEOUT`]) elapses.
+pub struct UnixSocketSigner {
+    socket_path: PathBuf,
+    timeout: Duration,
+}
+
+impl UnixSocketSigner {
+    pub fn new(socket_path: impl Into<PathBuf>) -> Self {
+        Self {
+            socket_path: socket_path.into(),
+            timeout: DEFAULT_SIGNING_DAEMON_TIMEOUT,
+        }
+    }
+
+    /// Sets how long to wait for the daemon to accept a request and to respond. The timeout must
+    /// be non-zero.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn send(
+        &self,
+        request: &SigningDaemonRequest,
+    ) -> Result<SigningDaemonResponse, ExternalSignerError> {
+        let mut stream = UnixStream::connect(&self.socket_path).map_err(transport_error)?;
+        stream
+            .set_read_timeout(Some(self.timeout))
+            .map_err(transport_error)?;
+        stream
+            .set_write_timeout(Some(self.timeout))
+            .map_err(transport_error)?;
+        write_message(&mut stream, request)?;
+        read_message(&mut stream)
+    }
+}
+
+impl ExternalSigner for UnixSocketSigner {
+    fn public_key(&self, derivation_path: &str) -> Result<PublicKey, ExternalSignerError> {
+        let request = SigningDaemonRequest::PublicKey {
+            derivation_path: derivation_path.to_string(),
+        };
+        match self.send(&request)? {
+            SigningDaemonResponse::PublicKey(public_key) => Ok(public_key),
+            SigningDaemonResponse::Rejected(reason) => Err(ExternalSignerError::Rejected(reason)),
+            response => Err(unexpected_response(response)),
+        }
+    }
+
+    fn sign(
+        &self,
+        request: &ExternalSignRequest,
+    ) -> Result<SignatureWithPublicKeyV1, ExternalSignerError> {
+        match self.send(&SigningDaemonRequest::Sign(request.clone()))? {
+            SigningDaemonResponse::Signature(signature) => Ok(signature),
+            SigningDaemonResponse::Rejected(reason) => Err(ExternalSignerError::Rejected(reason)),
+            response => Err(unexpected_response(response)),
+        }
+    }
+}
+
+/// Serves the request of a connection to a signing daemon, with the signer holding the keys of the
+/// daemon.
+pub fn serve_signing_daemon_request<S: ExternalSigner>(
+    stream: &mut UnixStream,
+    signer: &S,
+) -> Result<(), ExternalSignerError> {
+    let result = match read_message(stream)? {
+        SigningDaemonRequest::PublicKey { derivation_path } => signer
+            .public_key(&derivation_path)
+            .map(SigningDaemonResponse::PublicKey),
+        SigningDaemonRequest::Sign(request) => {
+            signer.sign(&request).map(SigningDaemonResponse::Signature)
+        }
+    };
+    let response = result.unwrap_or_else(|error| match error {
+        ExternalSignerError::Rejected(reason) => SigningDaemonResponse::Rejected(reason),
+        error => SigningDaemonResponse::Rejected(format!("{:?}", error)),
+    });
+    write_message(stream, &response)
+}
+
+fn write_message<T: ManifestEncode>(
+    stream: &mut UnixStream,
+    message: &T,
+) -> Result<(), ExternalSignerError> {
+    let payload = manifest_encode(message)
+        .map_err(|error| ExternalSignerError::Transport(format!("{:?}", error)))?;
+    if payload.len() > MAX_MESSAGE_SIZE {
+        return Err(ExternalSignerError::Transport(
+            "Message is too large".to_string(),
+        ));
+    }
+    stream
+        .write_all(&(payload.len() as u32).to_be_bytes())
+        .map_err(transport_error)?;
+    stream.write_all(&payload).map_err(transport_error)
+}
+
+fn read_message<T: ManifestDecode>(stream: &mut UnixStream) -> Result<T, ExternalSignerError> {
+    let mut length = [0u8; 4];
+    stream.read_exact(&mut length).map_err(transport_error)?;
+    let length = u32::from_be_bytes(length) as usize;
+    if length > MAX_MESSAGE_SIZE {
+        return Err(ExternalSignerError::Transport(
+            "Message is too large".to_string(),
+        ));
+    }
+    let mut payload = vec![0u8; length];
+    stream.read_exact(&mut payload).map_err(transport_error)?;
+    manifest_decode(&payload)
+        .map_err(|error| ExternalSignerError::Transport(format!("{:?}", error)))
+}
+
+fn transport_error(error: std::io::Error) -> ExternalSignerError {
+    ExternalSignerError::Transport(error.to_string())
+}
+
+fn unexpected_response(response: SigningDaemonResponse) -> ExternalSignerError {
+    ExternalSignerError::Transport(format!("Unexpected response {:?}", response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine_common::prelude::IsHash;
+    use radix_engine_common::types::Epoch;
+    use radix_engine_interface::network::NetworkDefinition;
+    use std::os::unix::net::UnixListener;
+
+    #[test]
+    fn transaction_can_be_signed_and_notarized_through_a_signing_daemon() {
+        let socket_path =
+            std::env::temp_dir().join(format!("signing-daemon-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&socket_path);
+        let listener = UnixListener::bind(&socket_path).unwrap();
+        let daemon = std::thread::spawn(move || {
+            let signer = MockExternalSigner::new()
+                .with_key("m/0", Secp256k1PrivateKey::from_u64(1).unwrap())
+                .with_key("m/1", Ed25519PrivateKey::from_u64(2).unwrap());
+            for _ in 0..6 {
+                let (mut stream, _) = listener.accept().unwrap();
+                serve_signing_daemon_request(&mut stream, &signer).unwrap();
+            }
+        });
+
+        let signer = UnixSocketSigner::new(&socket_path);
+        let notary_public_key = signer.public_key("m/1").unwrap();
+        let transaction = TransactionBuilder::new()
+            .header(TransactionHeaderV1 {
+                network_id: NetworkDefinition::simulator().id,
+                start_epoch_inclusive: Epoch::zero(),
+                end_epoch_exclusive: Epoch::of(100),
+                nonce: 5,
+                notary_public_key,
+                notary_is_signatory: false,
+                tip_percentage: 5,
+            })
+            .manifest(ManifestBuilder::new().drop_auth_zone_proofs().build())
+            .sign_with_external(&signer, "m/0")
+            .unwrap()
+            .notarize_with_external(&signer, "m/1")
+            .unwrap()
+            .build();
+        let rejection = signer.public_key("m/2");
+
+        daemon.join().unwrap();
+        std::fs::remove_file(&socket_path).unwrap();
+
+        let prepared = transaction.prepare().unwrap();
+        assert_eq!(
+            transaction.signed_intent.intent_signatures.signatures.len(),
+            1
+        );
+        assert!(verify(
+            prepared.signed_intent_hash().as_hash(),
+            &notary_public_key,
+            &transaction.notary_signature.0
+        ));
+        assert!(matches!(rejection, Err(ExternalSignerError::Rejected(_))));
+    }
+
+    #[test]
+    fn request_to_an_unresponsive_signing_daemon_times_out() {
+        let socket_path = std::env::temp_dir().join(format!(
+            "unresponsive-signing-daemon-{}.sock",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&socket_path);
+        // The listener accepts connections (into its backlog), but never responds
+        let _listener = UnixListener::bind(&socket_path).unwrap();
+
+        let signer = UnixSocketSigner::new(&socket_path).with_timeout(Duration::from_millis(100));
+        let result = signer.public_key("m/0");
+
+        std::fs::remove_file(&socket_path).unwrap();
+        assert!(matches!(result, Err(ExternalSignerError::Transport(_))));
+    }
+}
@@ -440,6 +440,8 @@ mod tests {
 
     use super::*;
     use crate::{builder::ManifestBuilder, builder::TransactionBuilder};
+    use rand_chacha::rand_core::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
 
     macro_rules! assert_invalid_tx {
         ($result: expr, ($start_epoch: expr, $end_epoch: expr, $nonce: expr, $signers: expr, $notary: expr)) => {{
@@ -564,6 +566,49 @@ mod tests {
             let result = validate_default(&create_transaction_with_message(message));
             assert!(result.is_ok());
         }
+        // Encrypted, and decryptable by each decryptor
+        {
+            let plaintext = PlaintextMessageV1 {
+                mime_type: "text/plain".to_owned(),
+                message: MessageContentsV1::String("Hello world!".to_string()),
+            };
+            let decryptors: Vec<PrivateKey> = vec![
+                Ed25519PrivateKey::from_u64(2).unwrap().into(),
+                Secp256k1PrivateKey::from_u64(3).unwrap().into(),
+                Secp256k1PrivateKey::from_u64(4).unwrap().into(),
+            ];
+            let public_keys: Vec<PublicKey> =
+                decryptors.iter().map(|key| key.public_key()).collect();
+            let sk_notary = Secp256k1PrivateKey::from_u64(1).unwrap();
+            let transaction = TransactionBuilder::new()
+                .header(TransactionHeaderV1 {
+                    network_id: NetworkDefinition::simulator().id,
+                    start_epoch_inclusive: Epoch::of(1),
+                    end_epoch_exclusive: Epoch::of(10),
+                    nonce: 0,
+                    notary_public_key: sk_notary.public_key().into(),
+                    notary_is_signatory: false,
+                    tip_percentage: 5,
+                })
+                .manifest(ManifestBuilder::new().drop_auth_zone_proofs().build())
+                .encrypted_message(
+                    plaintext.clone(),
+                    &public_keys,
+                    &mut ChaCha20Rng::seed_from_u64(0),
+                )
+                .unwrap()
+                .notarize(&sk_notary)
+                .build();
+
+            let result = validate_default(&transaction);
+            assert!(result.is_ok());
+            let MessageV1::Encrypted(encrypted) = &transaction.signed_intent.intent.message else {
+                panic!("Message should be encrypted");
+            };
+            for decryptor in &decryptors {
+                assert_eq!(encrypted.decrypt(decryptor).unwrap(), plaintext);
+            }
+        }
     }
 
     #[test]